@@ -4,16 +4,38 @@
 //! and methods for navigating between views and managing data.
 
 use anyhow::{Context, Result};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
-
-use crate::aws::{EcsClient, Metrics, TimeRange};
-use crate::config::Config;
-use crate::ui::{Theme, ThemePreset};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::aws::{
+    EcsBackend, EcsClient, InsightsQueryResults, LogPage, LogPageRequest, LogStreamCursor, Metrics,
+    ServiceLogs, TaskMetrics, TimeRange, UnavailableLogs,
+};
+use crate::config::{Config, LogsConfig, SavedQuery};
+use crate::definition_export::{format_definition, DefinitionExportDialog, DefinitionSource};
+use crate::log_export::{format_logs, write_export, ExportHeader, LogExportDialog};
+use crate::multiline::group_multiline;
+use crate::rollout::{format_duration, RolloutMonitor, RolloutOutcome, ROLLOUT_STALL_TIMEOUT};
+use crate::structured_log::{pretty_json, LogQuery, StructuredLog};
+use crate::task_definition::{FieldChange, TaskDefinitionDiff, TaskDefinitionInfo};
+use crate::task_definition_cleanup::{
+    plan_cleanup, CleanupBatch, CleanupProgress, TaskDefinitionCleanup, CLEANUP_BATCH_SIZE,
+};
+use crate::task_definition_editor::TaskDefinitionEditor;
+use crate::ui::{Theme, ThemePreset, ToastType};
 
 /// Represents the current view/screen in the application.
 ///
 /// The application follows a hierarchical navigation pattern:
-/// Clusters -> Services -> Tasks -> Details/Logs/Metrics
+/// Clusters -> Services -> Tasks -> Details/Logs/Metrics/ServiceEvents,
+/// with Clusters -> ContainerInstances -> Tasks for EC2 capacity and
+/// TaskDefinitions -> TaskDefinitionRevisions -> TaskDefinitionDetail
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppState {
     /// View showing list of ECS clusters
@@ -26,12 +48,19 @@ pub enum AppState {
     Details,
     /// View showing CloudWatch logs for a task
     Logs,
+    /// View running CloudWatch Logs Insights queries against the logs shown in Logs
+    LogInsights,
     /// View showing CloudWatch metrics for a service
     Metrics,
+    /// View showing deployments and the event stream of a service
+    ServiceEvents,
     /// View showing list of task definition families
     TaskDefinitions,
-    /// View showing details of a specific task definition (planned for future release)
-    #[allow(dead_code)]
+    /// View showing the revisions of the selected task definition family
+    TaskDefinitionRevisions,
+    /// View showing the EC2 container instances of the selected cluster
+    ContainerInstances,
+    /// View showing the containers, volumes and roles of a task definition revision
     TaskDefinitionDetail,
 }
 
@@ -48,6 +77,131 @@ pub enum ModalState {
     ServiceEditor,
     /// Port forwarding setup modal
     PortForwardingSetup,
+    /// Confirmation dialog for a destructive action
+    Confirm,
+    /// Full text of the selected log entry, JSON pretty-printed
+    LogEntry,
+    /// Log export options: format, filters, time range and destination
+    LogExport,
+    /// Bookmarked log entries
+    LogBookmarks,
+    /// Two task definition revisions compared side by side
+    TaskDefinitionDiff,
+    /// Registering a new task definition revision from an edited copy
+    TaskDefinitionEditor,
+    /// Task definition or service export options: format and destination
+    DefinitionExport,
+    /// Cleanup of old task definition revisions: rules, preview and progress
+    TaskDefinitionCleanup,
+}
+
+/// A destructive action waiting for the user's confirmation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingAction {
+    /// Force a new deployment of a service
+    RestartService { cluster: String, service: String },
+    /// Stop a running task
+    StopTask {
+        cluster: String,
+        task_arn: String,
+        task_id: String,
+    },
+    /// Set a container instance to ACTIVE or DRAINING
+    SetContainerInstanceState {
+        cluster: String,
+        arn: String,
+        ec2_instance_id: String,
+        current_status: String,
+        /// New status, "ACTIVE" or "DRAINING"
+        status: String,
+    },
+    /// Change the desired count, task definition and/or auto scaling of a service
+    UpdateService {
+        cluster: String,
+        service: String,
+        /// New desired count, if it changed
+        desired_count: Option<i32>,
+        /// New task definition ARN, if it changed
+        task_definition: Option<String>,
+        /// New auto scaling capacity and suspension, if they changed
+        scaling: Option<ScalingUpdate>,
+    },
+    /// Register a new task definition revision, and optionally deploy it to a service
+    RegisterTaskDefinition {
+        /// Revision the new one was copied from
+        source: Box<TaskDefinitionInfo>,
+        /// Settings of the new revision
+        definition: Box<TaskDefinitionInfo>,
+        /// Cluster and service to deploy the new revision to
+        deploy_to: Option<(String, String)>,
+    },
+    /// Deregister, and optionally delete, old revisions of a task definition family
+    CleanupTaskDefinitions {
+        family: String,
+        /// ARNs of the revisions, oldest first
+        revisions: Vec<String>,
+        /// Whether the revisions are deleted once deregistered
+        delete: bool,
+    },
+}
+
+/// New scalable target settings of a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalingUpdate {
+    /// Minimum number of tasks
+    pub min_capacity: i32,
+    /// Maximum number of tasks
+    pub max_capacity: i32,
+    /// Whether dynamic and scheduled scaling are suspended
+    pub suspended: bool,
+}
+
+/// State of the confirmation dialog shown before a destructive action.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Confirmation {
+    /// Dialog title
+    pub title: String,
+    /// One line per change the action will make
+    pub changes: Vec<String>,
+    /// The action to run once confirmed
+    pub action: PendingAction,
+    /// Name that must be typed to confirm, set for production resources
+    pub required_name: Option<String>,
+    /// Name typed so far
+    pub typed_name: String,
+    /// Whether the confirm button is selected
+    pub confirm_selected: bool,
+}
+
+impl Confirmation {
+    /// Returns `true` if the typed name (when required) matches the resource name.
+    pub fn name_matches(&self) -> bool {
+        self.required_name
+            .as_ref()
+            .is_none_or(|name| *name == self.typed_name)
+    }
+}
+
+/// Grouped and filtered logs of the Logs view, with the filters and multi-line
+/// settings they were computed with.
+struct FilteredLogs {
+    level_filter: Option<LogLevel>,
+    task_filter: Option<String>,
+    search_query: String,
+    multiline_pattern: String,
+    multiline_patterns: HashMap<String, String>,
+    logs: Rc<[LogEntry]>,
+}
+
+impl FilteredLogs {
+    /// Returns whether the logs were computed with the app's current filters and settings.
+    fn is_current(&self, app: &App) -> bool {
+        self.level_filter == app.log_level_filter
+            && self.task_filter == app.log_task_filter
+            && self.search_query == app.log_search_query
+            && self.multiline_pattern == app.config.logs.multiline_pattern
+            && self.multiline_patterns == app.config.logs.multiline_patterns
+    }
 }
 
 /// Main application state container.
@@ -64,12 +218,11 @@ pub struct App {
     pub show_help: bool,
     /// Currently selected item index in lists
     pub selected_index: usize,
-    /// AWS ECS client for API calls
-    pub ecs_client: EcsClient,
+    /// AWS backend for API calls, shared with background requests
+    pub ecs_client: Arc<dyn EcsBackend>,
     /// Application configuration
     pub config: Config,
-    /// UI theme (for future use)
-    #[allow(dead_code)]
+    /// UI theme
     pub theme: Theme,
     /// Whether split-pane view is enabled (for future use)
     #[allow(dead_code)]
@@ -98,8 +251,17 @@ pub struct App {
     pub service_editor_available_revisions: Vec<String>,
     /// Current task definition for the service being edited
     pub service_editor_current_task_def: String,
-    /// Which field is currently being edited (0=desired count, 1=task def)
+    /// Which field is currently being edited (0=desired count, 1=task def,
+    /// 2=min capacity, 3=max capacity, 4=scaling suspended)
     pub service_editor_editing_field: usize,
+    /// Auto scaling configuration of the service being edited, None without a scalable target
+    pub service_editor_scaling: Option<ServiceScaling>,
+    /// Input buffer for auto scaling minimum capacity in service editor
+    pub service_editor_min_capacity_input: String,
+    /// Input buffer for auto scaling maximum capacity in service editor
+    pub service_editor_max_capacity_input: String,
+    /// Whether scaling will be suspended when the service editor saves
+    pub service_editor_scaling_suspended: bool,
     /// Local port number input for port forwarding
     pub port_forward_local_port: String,
     /// Remote port number input for port forwarding
@@ -110,10 +272,20 @@ pub struct App {
     // Data
     /// List of ECS cluster names
     pub clusters: Vec<String>,
+    /// Statistics and configuration of the listed clusters, empty if DescribeClusters failed
+    pub cluster_details: Vec<ClusterInfo>,
     /// List of services in selected cluster
     pub services: Vec<ServiceInfo>,
     /// List of tasks for selected service
     pub tasks: Vec<TaskInfo>,
+    /// EC2 container instances of selected cluster
+    pub container_instances: Vec<ContainerInstanceInfo>,
+    /// ARN of the container instance whose tasks the Tasks view lists, instead of a service's
+    pub selected_container_instance: Option<String>,
+    /// Recently stopped tasks for selected service, most recently stopped first
+    pub stopped_tasks: Vec<StoppedTaskInfo>,
+    /// Whether the Tasks view lists stopped tasks instead of running ones
+    pub show_stopped_tasks: bool,
     /// Currently selected cluster name
     pub selected_cluster: Option<String>,
     /// Currently selected service name
@@ -130,18 +302,46 @@ pub struct App {
     pub details_scroll: usize,
     /// Log entries for selected task
     pub logs: Vec<LogEntry>,
+    /// Grouped and filtered `logs`, reused across frames until the logs change
+    filtered_logs: RefCell<Option<FilteredLogs>>,
     /// Current scroll position in logs
     pub log_scroll: usize,
     /// Whether to auto-scroll to latest logs
     pub auto_tail: bool,
+    /// Read positions of the task's log streams, used to load older pages
+    pub log_cursors: Vec<LogStreamCursor>,
+    /// Time (Unix milliseconds) the logs were jumped to; `None` when showing the latest logs
+    pub log_start_time: Option<i64>,
+    /// Whether the newest entries were dropped from the buffer while paging back, so
+    /// tailing has to reload the latest page instead of appending to the buffer
+    pub log_tail_detached: bool,
+    /// Whether a poll for new log events is in flight
+    pub log_poll_in_flight: bool,
+    /// Whether the Logs view shows every task of the selected service instead of the selected task
+    pub show_service_logs: bool,
+    /// View the Logs view was opened from, which going back returns to
+    pub logs_opened_from: AppState,
+    /// Start (Unix milliseconds) of the oldest time window loaded in service-wide logs
+    pub service_log_window_start: Option<i64>,
+    /// Task ID whose entries are shown in service-wide logs (None = all tasks)
+    pub log_task_filter: Option<String>,
+    /// Containers of the selected task whose logs could not be read, with the reason why
+    pub log_unavailable: Vec<UnavailableLogs>,
     /// CloudWatch metrics for selected service
     pub metrics: Option<Metrics>,
+    /// Container Insights metrics for selected task
+    pub task_metrics: Option<TaskMetrics>,
+    /// Whether the Metrics view shows the selected task instead of the selected service
+    pub show_task_metrics: bool,
     /// Current scroll position in metrics view
     pub metrics_scroll: usize,
+    /// Deployments of the selected service, shown in the service events view
+    pub service_deployments: Vec<DeploymentInfo>,
+    /// Event stream of the selected service, newest first
+    pub service_events: Vec<ServiceEvent>,
     /// List of task definition families
     pub task_definition_families: Vec<String>,
-    /// List of task definition revisions for selected family (planned for future release)
-    #[allow(dead_code)]
+    /// Revisions of the selected task definition family, newest first
     pub task_definitions: Vec<TaskDefinitionInfo>,
     /// Currently selected task definition family
     pub selected_task_definition_family: Option<String>,
@@ -155,6 +355,8 @@ pub struct App {
     pub search_query: String,
     /// Whether regex mode is enabled for search
     pub search_regex_mode: bool,
+    /// Regex compiled from `search_query`, reused while the query stays the same
+    search_regex_cache: RefCell<Option<(String, Option<regex::Regex>)>>,
 
     // Filtering
     /// Active service status filter (None = show all)
@@ -169,8 +371,47 @@ pub struct App {
     pub log_search_mode: bool,
     /// Current log search query
     pub log_search_query: String,
+    /// Whether find input mode is active in the Logs view
+    pub log_find_mode: bool,
+    /// Text (or regex, in regex mode) highlighted in the Logs view without filtering
+    pub log_find_query: String,
     /// Current log level filter (None = show all)
     pub log_level_filter: Option<LogLevel>,
+    /// Whether jump-to-time input mode is active in the Logs view
+    pub log_jump_mode: bool,
+    /// Time typed in jump-to-time input mode, e.g. "-30m" or "2026-10-14T09:00"
+    pub log_jump_input: String,
+    /// Fields of JSON log lines shown as columns in the Logs view
+    pub log_columns: Vec<String>,
+    /// Whether the log columns are being edited
+    pub log_columns_mode: bool,
+    /// Comma-separated field names typed while editing the log columns
+    pub log_columns_input: String,
+    /// Log entry shown in the log entry modal
+    pub log_detail: Option<LogEntry>,
+    /// Multi-line entries (timestamp, container, first line) toggled from the
+    /// default of `expand_all_logs`
+    pub expanded_logs: HashSet<(i64, String, String)>,
+    /// Whether multi-line entries show their continuation lines by default
+    pub expand_all_logs: bool,
+    /// Bookmarked log entries, sorted by timestamp; kept across reloads of the same logs
+    pub log_bookmarks: Vec<LogEntry>,
+    /// Entry (see `log_entry_key`) selected once the logs being loaded replace the buffer
+    log_select_after_load: Option<(i64, String, String)>,
+
+    // Logs Insights
+    /// Logs Insights query being edited or last run
+    pub insights_query: String,
+    /// Whether the Logs Insights query is being edited
+    pub insights_editing: bool,
+    /// Time range, ending now, that Logs Insights queries run over
+    pub insights_time_range: TimeRange,
+    /// Log groups of the service or task whose logs the Logs Insights view queries
+    pub insights_log_groups: Vec<String>,
+    /// Running or finished Logs Insights query
+    pub insights_run: Option<InsightsRun>,
+    /// Index of the saved query last loaded from the config
+    pub insights_saved_index: Option<usize>,
 
     // Status
     /// Status message displayed to user
@@ -183,6 +424,265 @@ pub struct App {
     pub auto_refresh_paused: bool,
     /// Timestamp when auto-refresh was paused
     pub auto_refresh_pause_time: Option<Instant>,
+
+    // Background requests
+    /// Generation of the current view request; responses from older generations are discarded
+    pub request_generation: u64,
+    /// Handle of the in-flight view request, aborted when the user navigates away
+    pub in_flight_request: Option<JoinHandle<()>>,
+    /// Number of background actions (restart, stop, update) that have not completed yet
+    pub pending_actions: usize,
+    /// Sender cloned into background tasks to report their results
+    pub response_tx: mpsc::UnboundedSender<BackgroundResponse>,
+    /// Receiver drained by the event loop to apply background results
+    pub response_rx: mpsc::UnboundedReceiver<BackgroundResponse>,
+
+    // Rollout monitoring
+    /// Monitor following the deployment started by the last restart or update
+    pub rollout_monitor: Option<RolloutMonitor>,
+    /// Toast notification currently shown, if any
+    pub toast: Option<Toast>,
+    /// Destructive action awaiting confirmation in the confirmation dialog
+    pub confirmation: Option<Confirmation>,
+    /// Options of the log export dialog
+    pub log_export: Option<LogExportDialog>,
+    /// Revisions compared in the task definition diff
+    pub task_definition_diff: Option<TaskDefinitionDiff>,
+    /// ARN of the revision marked in the revisions view as the base of the next diff
+    pub task_definition_diff_base: Option<String>,
+    /// Register dialog creating a new revision from an edited copy
+    pub task_definition_editor: Option<TaskDefinitionEditor>,
+    /// Options of the task definition and service export dialog
+    pub definition_export: Option<DefinitionExportDialog>,
+    /// Rules, preview and progress of the task definition cleanup dialog
+    pub task_definition_cleanup: Option<TaskDefinitionCleanup>,
+}
+
+/// How long a toast notification stays on screen.
+pub const TOAST_DURATION: Duration = Duration::from_secs(8);
+
+/// Length (milliseconds) of the time windows in which service-wide logs are loaded.
+pub const SERVICE_LOG_WINDOW_MS: i64 = 5 * 60 * 1000;
+
+/// How far (milliseconds) before the newest entry service-wide logs are polled again,
+/// so that events ingested late are not missed.
+const SERVICE_LOG_TAIL_OVERLAP_MS: i64 = 60 * 1000;
+
+/// Query shown the first time the Logs Insights view opens without saved queries.
+pub const DEFAULT_INSIGHTS_QUERY: &str =
+    "fields @timestamp, @logStream, @message | sort @timestamp desc | limit 100";
+
+/// How often the results of a running Logs Insights query are polled.
+const INSIGHTS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Most pages of a task's logs read when exporting its full history.
+const MAX_EXPORT_PAGES: usize = 200;
+
+/// Most log events of a service read when exporting its full history.
+const MAX_EXPORT_EVENTS: usize = 1_000_000;
+
+/// Most revisions of a task definition family described when browsing its revisions.
+const MAX_TASK_DEFINITION_REVISIONS: usize = 100;
+
+/// Task definition revisions described at the same time when browsing revisions.
+const TASK_DEFINITION_DESCRIBE_CONCURRENCY: usize = 8;
+
+/// Changed fields listed in the confirmation of a task definition registration.
+const MAX_CONFIRMED_FIELD_CHANGES: usize = 12;
+
+/// A Logs Insights query started from the Logs Insights view.
+#[derive(Debug, Clone)]
+pub struct InsightsRun {
+    /// Query ID returned by StartQuery
+    pub query_id: String,
+    /// When the query was started
+    pub started_at: Instant,
+    /// When the results were last requested
+    pub last_poll: Option<Instant>,
+    /// Whether a results request is running
+    pub poll_in_flight: bool,
+    /// Latest results, `None` until the first poll returns
+    pub results: Option<InsightsQueryResults>,
+}
+
+/// A short-lived notification shown over the current view.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Message to display
+    pub message: String,
+    /// Type of toast (affects icon and color)
+    pub toast_type: ToastType,
+    /// When the toast was raised
+    pub created_at: Instant,
+}
+
+/// Completion handler of a background request, applied to the app on the UI loop.
+type Completion = Box<dyn FnOnce(&mut App) + Send>;
+
+/// The result of a background AWS request waiting to be applied to the app state.
+///
+/// Background tasks never touch `App` directly: they send a `BackgroundResponse`
+/// over the app's channel and the event loop applies it via `App::process_responses`.
+pub struct BackgroundResponse {
+    /// What kind of background work produced the response
+    kind: ResponseKind,
+    /// Closure that applies the result to the app state
+    apply: Completion,
+}
+
+/// Kind of background work a response comes from.
+enum ResponseKind {
+    /// View request of the given generation; dropped once superseded
+    Request(u64),
+    /// Mutating action; always applied
+    Action,
+    /// Silent poll; always applied and never shown as loading
+    Poll,
+}
+
+/// One entry of a capacity provider strategy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapacityProviderStrategyItem {
+    /// Capacity provider name (e.g., FARGATE, FARGATE_SPOT, or an Auto Scaling group provider)
+    pub capacity_provider: String,
+    /// Relative share of tasks placed on this provider
+    pub weight: i32,
+    /// Minimum number of tasks placed on this provider
+    pub base: i32,
+}
+
+/// Statistics and configuration of an ECS cluster from DescribeClusters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterInfo {
+    /// Cluster name
+    pub name: String,
+    /// Cluster status (ACTIVE, PROVISIONING, DEPROVISIONING, FAILED, INACTIVE)
+    pub status: String,
+    /// Number of services in ACTIVE state
+    pub active_services: i32,
+    /// Number of tasks in RUNNING state
+    pub running_tasks: i32,
+    /// Number of tasks in PENDING state
+    pub pending_tasks: i32,
+    /// Number of registered EC2 container instances
+    pub registered_container_instances: i32,
+    /// Capacity providers associated with the cluster
+    pub capacity_providers: Vec<String>,
+    /// Default capacity provider strategy for services and tasks
+    pub default_strategy: Vec<CapacityProviderStrategyItem>,
+    /// Container Insights setting (enabled, enhanced, disabled), empty if not reported
+    pub container_insights: String,
+    /// Resource tags as (key, value) pairs
+    pub tags: Vec<(String, String)>,
+}
+
+impl ClusterInfo {
+    /// Formats the default capacity provider strategy, e.g. "FARGATE base 1 weight 1, FARGATE_SPOT weight 4".
+    pub fn strategy_summary(&self) -> String {
+        if self.default_strategy.is_empty() {
+            return "none".to_string();
+        }
+        self.default_strategy
+            .iter()
+            .map(|item| {
+                if item.base > 0 {
+                    format!(
+                        "{} base {} weight {}",
+                        item.capacity_provider, item.base, item.weight
+                    )
+                } else {
+                    format!("{} weight {}", item.capacity_provider, item.weight)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// A scaling policy attached to a service's scalable target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalingPolicyInfo {
+    /// Policy name
+    pub name: String,
+    /// Policy type (TargetTrackingScaling, StepScaling, PredictiveScaling)
+    pub policy_type: String,
+    /// One-line description, e.g. "ECSServiceAverageCPUUtilization target 60"
+    pub summary: String,
+}
+
+/// A recent Application Auto Scaling activity of a service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalingActivityInfo {
+    /// Start time (Unix seconds)
+    pub start_time: i64,
+    /// Status code (Successful, InProgress, Failed, ...)
+    pub status: String,
+    /// What the activity did, e.g. "Setting desired count to 3."
+    pub description: String,
+    /// Why the activity happened
+    pub cause: String,
+}
+
+/// Application Auto Scaling configuration of a service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceScaling {
+    /// Minimum number of tasks
+    pub min_capacity: i32,
+    /// Maximum number of tasks
+    pub max_capacity: i32,
+    /// Whether dynamic and scheduled scaling are suspended
+    pub suspended: bool,
+    /// Attached scaling policies
+    pub policies: Vec<ScalingPolicyInfo>,
+    /// Recent scaling activities, newest first
+    pub activities: Vec<ScalingActivityInfo>,
+}
+
+impl ServiceScaling {
+    /// Formats the scalable target, policies and recent activities as text lines.
+    pub fn describe_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "Capacity: min {}, max {}{}",
+            self.min_capacity,
+            self.max_capacity,
+            if self.suspended {
+                " (scaling suspended)"
+            } else {
+                ""
+            }
+        )];
+        if self.policies.is_empty() {
+            lines.push("Policies: none".to_string());
+        } else {
+            lines.push("Policies:".to_string());
+            for policy in &self.policies {
+                lines.push(format!(
+                    "  - {} ({}): {}",
+                    policy.name, policy.policy_type, policy.summary
+                ));
+            }
+        }
+        if !self.activities.is_empty() {
+            lines.push("Recent Activities:".to_string());
+            for activity in &self.activities {
+                let time = chrono::DateTime::from_timestamp(activity.start_time, 0)
+                    .map(|dt| {
+                        dt.with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M:%S")
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| "N/A".to_string());
+                lines.push(format!(
+                    "  - {time} [{}] {}",
+                    activity.status, activity.description
+                ));
+                if !activity.cause.is_empty() {
+                    lines.push(format!("    Cause: {}", activity.cause));
+                }
+            }
+        }
+        lines
+    }
 }
 
 /// Information about an ECS service.
@@ -225,24 +725,155 @@ pub struct TaskInfo {
     pub memory: String,
 }
 
-/// Information about an ECS task definition.
+/// Information about an EC2 container instance registered to a cluster.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerInstanceInfo {
+    /// Full ARN of the container instance
+    pub arn: String,
+    /// EC2 instance ID (e.g., i-0abc123)
+    pub ec2_instance_id: String,
+    /// Instance status (ACTIVE, DRAINING, INACTIVE, ...)
+    pub status: String,
+    /// Whether the ECS agent is connected
+    pub agent_connected: bool,
+    /// ECS agent version
+    pub agent_version: String,
+    /// CPU units registered with ECS
+    pub registered_cpu: i32,
+    /// CPU units not reserved by tasks
+    pub remaining_cpu: i32,
+    /// Memory (MiB) registered with ECS
+    pub registered_memory: i32,
+    /// Memory (MiB) not reserved by tasks
+    pub remaining_memory: i32,
+    /// Number of running tasks on the instance
+    pub running_tasks: i32,
+    /// Number of pending tasks on the instance
+    pub pending_tasks: i32,
+}
+
+impl ContainerInstanceInfo {
+    /// Returns the container instance ID (last segment of the ARN).
+    pub fn id(&self) -> &str {
+        self.arn.split('/').next_back().unwrap_or(&self.arn)
+    }
+}
+
+/// Exit status of one container of a stopped task.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerExit {
+    /// Container name from the task definition
+    pub name: String,
+    /// Exit code, if the container process ran and exited
+    pub exit_code: Option<i32>,
+    /// Reason reported by ECS (e.g., "OutOfMemoryError: Container killed due to memory usage")
+    pub reason: String,
+}
+
+/// Information about a stopped ECS task.
+///
+/// Contains why the task stopped and how each container exited, as listed by
+/// DescribeTasks for tasks with desired status STOPPED.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoppedTaskInfo {
+    /// Full ARN of the task
+    pub task_arn: String,
+    /// Short task ID (last segment of ARN)
+    pub task_id: String,
+    /// Last known task status (STOPPED, or DEPROVISIONING/STOPPING while shutting down)
+    pub last_status: String,
+    /// Why the task stopped (e.g., "Essential container in task exited")
+    pub stopped_reason: String,
+    /// Stop code (e.g., EssentialContainerExited, TaskFailedToStart, UserInitiated)
+    pub stop_code: String,
+    /// When the task started running (Unix seconds)
+    pub started_at: Option<i64>,
+    /// When the task began stopping (Unix seconds)
+    pub stopping_at: Option<i64>,
+    /// When the task stopped (Unix seconds)
+    pub stopped_at: Option<i64>,
+    /// Exit status of each container
+    pub containers: Vec<ContainerExit>,
+}
+
+impl StoppedTaskInfo {
+    /// Returns the task as a `TaskInfo`, for views shared with running tasks (e.g., logs).
+    pub fn task_info(&self) -> TaskInfo {
+        TaskInfo {
+            task_arn: self.task_arn.clone(),
+            task_id: self.task_id.clone(),
+            status: self.last_status.clone(),
+            desired_status: "STOPPED".to_string(),
+            container_instance: "N/A".to_string(),
+            cpu: "N/A".to_string(),
+            memory: "N/A".to_string(),
+        }
+    }
+
+    /// Returns the container exit codes as a compact string (e.g., "app:137 sidecar:0").
+    pub fn exit_codes(&self) -> String {
+        self.containers
+            .iter()
+            .map(|c| match c.exit_code {
+                Some(code) => format!("{}:{code}", c.name),
+                None => format!("{}:-", c.name),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Information about a deployment of an ECS service.
 ///
-/// Contains task definition metadata including family, revision, and status.
-/// Planned for future task definition detail viewer.
+/// Contains the rollout state and task counts of one deployment, as listed
+/// by DescribeServices.
 #[derive(Debug, Clone)]
-pub struct TaskDefinitionInfo {
-    /// Task definition family name
-    #[allow(dead_code)]
-    pub family: String,
-    /// Revision number
-    #[allow(dead_code)]
-    pub revision: i32,
-    /// Full ARN of the task definition
-    #[allow(dead_code)]
-    pub arn: String,
-    /// Status (ACTIVE or INACTIVE)
-    #[allow(dead_code)]
+pub struct DeploymentInfo {
+    /// Deployment ID (e.g., ecs-svc/1234567890123456789)
+    pub id: String,
+    /// Deployment status (PRIMARY, ACTIVE, or INACTIVE)
     pub status: String,
+    /// Rollout state (IN_PROGRESS, COMPLETED, or FAILED)
+    pub rollout_state: String,
+    /// Reason for the current rollout state
+    pub rollout_state_reason: String,
+    /// Task definition ARN used by the deployment
+    pub task_definition: String,
+    /// Number of tasks the deployment should run
+    pub desired_count: i32,
+    /// Number of tasks currently running
+    pub running_count: i32,
+    /// Number of tasks pending startup
+    pub pending_count: i32,
+    /// Number of tasks that failed to start
+    pub failed_tasks: i32,
+    /// Unix timestamp in seconds when the deployment was last updated
+    pub updated_at: i64,
+}
+
+/// An entry of an ECS service's event stream.
+#[derive(Debug, Clone)]
+pub struct ServiceEvent {
+    /// Unix timestamp in seconds when the event occurred
+    pub created_at: i64,
+    /// Event message (e.g., "(service web) has reached a steady state.")
+    pub message: String,
+}
+
+/// Deployments, events and deployment settings of a service.
+///
+/// Snapshot returned by the backend for the service events view and the
+/// rollout monitor.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceActivity {
+    /// Deployments of the service, PRIMARY first
+    pub deployments: Vec<DeploymentInfo>,
+    /// Recent service events, newest first
+    pub events: Vec<ServiceEvent>,
+    /// Whether the deployment circuit breaker is enabled
+    pub circuit_breaker_enabled: bool,
+    /// Whether the circuit breaker rolls back failed deployments
+    pub circuit_breaker_rollback: bool,
 }
 
 /// Log level parsed from log message.
@@ -259,25 +890,56 @@ pub enum LogLevel {
 impl LogLevel {
     /// Parses log level from a message string.
     ///
-    /// Looks for common log level indicators like [INFO], ERROR:, etc.
-    /// Note: FATAL must be checked before ERROR since FATAL contains ERROR as a substring.
+    /// Looks for common log level indicators like [INFO], ERROR:, etc. Level names
+    /// only count as whole words, so "error_count=0" is not an error.
     pub fn from_message(message: &str) -> Self {
         let upper = message.to_uppercase();
-        // Check FATAL before ERROR since FATAL contains ERROR as substring
-        if upper.contains("FATAL") || upper.contains("[FTL]") {
+        let words: Vec<&str> = upper
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .collect();
+        let mentions = |names: &[&str], tag: &str| {
+            upper.contains(tag) || words.iter().any(|word| names.contains(word))
+        };
+        if mentions(&["FATAL"], "[FTL]") {
             LogLevel::Fatal
-        } else if upper.contains("ERROR") || upper.contains("[ERR]") {
+        } else if mentions(&["ERROR"], "[ERR]") {
             LogLevel::Error
-        } else if upper.contains("WARN") || upper.contains("[WRN]") {
+        } else if mentions(&["WARN", "WARNING"], "[WRN]") {
             LogLevel::Warn
-        } else if upper.contains("DEBUG") || upper.contains("[DBG]") {
+        } else if mentions(&["DEBUG"], "[DBG]") {
             LogLevel::Debug
-        } else if upper.contains("INFO") || upper.contains("[INF]") {
+        } else if mentions(&["INFO"], "[INF]") {
             LogLevel::Info
         } else {
             LogLevel::Unknown
         }
     }
+
+    /// Parses the value of a structured log's level field, e.g. "warning" or 40.
+    ///
+    /// Numeric levels follow pino/bunyan (10 trace to 60 fatal).
+    pub fn from_name(name: &str) -> Option<Self> {
+        if let Ok(number) = name.parse::<u32>() {
+            return match number {
+                10..=29 => Some(LogLevel::Debug),
+                30..=39 => Some(LogLevel::Info),
+                40..=49 => Some(LogLevel::Warn),
+                50..=59 => Some(LogLevel::Error),
+                60.. => Some(LogLevel::Fatal),
+                _ => None,
+            };
+        }
+        match name.to_lowercase().as_str() {
+            "trace" | "debug" | "dbg" | "verbose" => Some(LogLevel::Debug),
+            "info" | "inf" | "information" | "notice" => Some(LogLevel::Info),
+            "warn" | "warning" | "wrn" => Some(LogLevel::Warn),
+            "error" | "err" => Some(LogLevel::Error),
+            "fatal" | "ftl" | "critical" | "crit" | "panic" | "alert" | "emergency" => {
+                Some(LogLevel::Fatal)
+            }
+            _ => None,
+        }
+    }
 }
 
 /// A single log entry from CloudWatch Logs.
@@ -293,45 +955,123 @@ pub struct LogEntry {
     pub container_name: String,
     /// Parsed log level
     pub level: LogLevel,
+    /// CloudWatch Logs event ID, for APIs that report one (GetLogEvents does not)
+    pub event_id: Option<String>,
+    /// ID of the task that produced the log, set in service-wide logs
+    pub task_id: Option<String>,
+    /// Fields of the log line, when it is a JSON object
+    pub structured: Option<StructuredLog>,
+    /// Continuation lines (e.g. stack trace frames) merged into the entry
+    pub continuation: Vec<String>,
 }
 
 impl LogEntry {
     /// Creates a new log entry with parsed log level.
+    ///
+    /// JSON log lines are parsed into fields; their level comes from the level field,
+    /// or from the message field when there is none.
     pub fn new(timestamp: i64, message: String, container_name: String) -> Self {
-        let level = LogLevel::from_message(&message);
+        let structured = StructuredLog::parse(&message);
+        let level = match &structured {
+            Some(log) => log.level.clone().unwrap_or_else(|| {
+                log.message
+                    .as_deref()
+                    .map_or(LogLevel::Unknown, LogLevel::from_message)
+            }),
+            None => LogLevel::from_message(&message),
+        };
         Self {
             timestamp,
             message,
             container_name,
             level,
+            event_id: None,
+            task_id: None,
+            structured,
+            continuation: Vec::new(),
+        }
+    }
+
+    /// Returns the message with its continuation lines, one per line.
+    pub fn full_message(&self) -> String {
+        let mut message = self.message.clone();
+        for line in &self.continuation {
+            message.push('\n');
+            message.push_str(line);
+        }
+        message
+    }
+
+    /// Returns the value of a field for log columns and `field=value` filters.
+    ///
+    /// Fields of JSON log lines are looked up first; `level`, `container` and `task`
+    /// are also available for plain text lines.
+    pub fn field(&self, name: &str) -> Option<String> {
+        if let Some(value) = self.structured.as_ref().and_then(|log| log.get(name)) {
+            return Some(value.to_string());
+        }
+        match name {
+            "level" if self.level != LogLevel::Unknown => {
+                Some(format!("{:?}", self.level).to_lowercase())
+            }
+            "container" => Some(self.container_name.clone()),
+            "task" => self.task_id.clone(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether both entries are the same log event.
+    ///
+    /// Entries are compared by event ID when either has one, and by container,
+    /// timestamp and message only when neither does. Identical lines without an ID
+    /// can still be distinct events, see `App::append_log_page`.
+    pub fn is_same_event(&self, other: &LogEntry) -> bool {
+        match (&self.event_id, &other.event_id) {
+            (None, None) => {
+                self.timestamp == other.timestamp
+                    && self.container_name == other.container_name
+                    && self.message == other.message
+            }
+            (id, other_id) => id == other_id,
         }
     }
 }
 
 impl App {
-    /// Creates a new application instance and loads initial data.
+    /// Creates a new application instance and starts loading initial data.
     ///
     /// Initializes the AWS ECS client using configuration settings,
-    /// sets up the initial state, and performs the first data refresh to load
+    /// sets up the initial state, and starts the first data refresh to load
     /// the list of clusters.
     ///
     /// # Arguments
     /// * `config` - Application configuration loaded from config file
     ///
     /// # Returns
-    /// Returns a new `App` instance with cluster data loaded, or an error if
-    /// initialization fails.
+    /// Returns a new `App` instance with the initial cluster load started in the
+    /// background, or an error if initialization fails.
     ///
     /// # Errors
     /// This function will return an error if:
-    /// - AWS credentials are not configured or invalid
     /// - AWS SDK client initialization fails
-    /// - The initial cluster list API call fails
     pub async fn new(config: Config) -> Result<Self> {
         // Initialize ECS client with config settings
         let ecs_client =
             EcsClient::new(config.aws.region.clone(), config.aws.profile.clone()).await?;
 
+        Ok(Self::with_backend(config, Arc::new(ecs_client)))
+    }
+
+    /// Creates a new application instance on top of an existing AWS backend.
+    ///
+    /// Used by `App::new` with the real AWS client, and by tests with an
+    /// in-memory fake. Starts the initial cluster load in the background, so it
+    /// must be called from within a Tokio runtime.
+    ///
+    /// # Arguments
+    /// * `config` - Application configuration
+    /// * `ecs_client` - Backend used for all AWS calls
+    pub fn with_backend(config: Config, ecs_client: Arc<dyn EcsBackend>) -> Self {
         // Determine initial state based on config
         let initial_state = match config.behavior.default_view.as_str() {
             "services" => AppState::Services,
@@ -358,6 +1098,7 @@ impl App {
             _ => ThemePreset::Dark,
         };
         let theme = Theme::from_preset(theme_preset);
+        let log_columns = config.logs.columns.clone();
 
         // Load available profiles from ~/.aws/credentials
         let available_profiles =
@@ -380,6 +1121,8 @@ impl App {
             "ca-central-1".to_string(),
         ];
 
+        let (response_tx, response_rx) = mpsc::unbounded_channel();
+
         let mut app = Self {
             state: initial_state,
             previous_state: None,
@@ -400,12 +1143,21 @@ impl App {
             service_editor_available_revisions: Vec::new(),
             service_editor_current_task_def: String::new(),
             service_editor_editing_field: 0,
+            service_editor_scaling: None,
+            service_editor_min_capacity_input: String::new(),
+            service_editor_max_capacity_input: String::new(),
+            service_editor_scaling_suspended: false,
             port_forward_local_port: String::new(),
             port_forward_remote_port: String::new(),
             port_forward_editing_field: 0,
             clusters: Vec::new(),
+            cluster_details: Vec::new(),
             services: Vec::new(),
             tasks: Vec::new(),
+            container_instances: Vec::new(),
+            selected_container_instance: None,
+            stopped_tasks: Vec::new(),
+            show_stopped_tasks: false,
             selected_cluster: None,
             selected_service: None,
             selected_task: None,
@@ -414,10 +1166,24 @@ impl App {
             show_json_view: false,
             details_scroll: 0,
             logs: Vec::new(),
+            filtered_logs: RefCell::new(None),
             log_scroll: 0,
             auto_tail: true,
+            log_cursors: Vec::new(),
+            log_start_time: None,
+            log_tail_detached: false,
+            log_poll_in_flight: false,
+            show_service_logs: false,
+            logs_opened_from: AppState::Tasks,
+            service_log_window_start: None,
+            log_task_filter: None,
+            log_unavailable: Vec::new(),
             metrics: None,
+            task_metrics: None,
+            show_task_metrics: false,
             metrics_scroll: 0,
+            service_deployments: Vec::new(),
+            service_events: Vec::new(),
             task_definition_families: Vec::new(),
             task_definitions: Vec::new(),
             selected_task_definition_family: None,
@@ -425,21 +1191,54 @@ impl App {
             search_mode: false,
             search_query: String::new(),
             search_regex_mode: false,
+            search_regex_cache: RefCell::new(None),
             service_status_filter: None,
             task_status_filter: None,
             launch_type_filter: None,
             log_search_mode: false,
+            log_find_mode: false,
+            log_find_query: String::new(),
             log_search_query: String::new(),
             log_level_filter: None,
+            log_jump_mode: false,
+            log_jump_input: String::new(),
+            log_columns,
+            log_columns_mode: false,
+            log_columns_input: String::new(),
+            log_detail: None,
+            expanded_logs: HashSet::new(),
+            expand_all_logs: false,
+            log_bookmarks: Vec::new(),
+            log_select_after_load: None,
+            insights_query: String::new(),
+            insights_editing: false,
+            insights_time_range: TimeRange::OneHour,
+            insights_log_groups: Vec::new(),
+            insights_run: None,
+            insights_saved_index: None,
             status_message: "Loading clusters...".to_string(),
             loading: false,
             last_refresh: Instant::now(),
             auto_refresh_paused: false,
             auto_refresh_pause_time: None,
+            request_generation: 0,
+            in_flight_request: None,
+            pending_actions: 0,
+            response_tx,
+            response_rx,
+            rollout_monitor: None,
+            toast: None,
+            confirmation: None,
+            log_export: None,
+            task_definition_diff: None,
+            task_definition_diff_base: None,
+            task_definition_editor: None,
+            definition_export: None,
+            task_definition_cleanup: None,
         };
 
-        app.refresh().await?;
-        Ok(app)
+        app.refresh();
+        app
     }
 
     pub fn toggle_help(&mut self) {
@@ -465,7 +1264,9 @@ impl App {
         );
     }
 
+    /// Switches to another view, cancelling any request still loading the old one.
     pub fn set_view(&mut self, state: AppState) {
+        self.cancel_requests();
         self.previous_state = Some(self.state.clone());
         self.state = state;
         self.selected_index = 0;
@@ -478,8 +1279,13 @@ impl App {
         let len = match self.state {
             AppState::Clusters => self.get_filtered_clusters().len(),
             AppState::Services => self.get_filtered_services().len(),
+            AppState::Tasks if self.show_stopped_tasks => self.get_filtered_stopped_tasks().len(),
             AppState::Tasks => self.get_filtered_tasks().len(),
             AppState::TaskDefinitions => self.get_filtered_task_definition_families().len(),
+            AppState::TaskDefinitionRevisions => self.get_filtered_task_definitions().len(),
+            AppState::ServiceEvents => self.get_filtered_service_events().len(),
+            AppState::ContainerInstances => self.get_filtered_container_instances().len(),
+            AppState::LogInsights => self.insights_results().map_or(0, |r| r.rows.len()),
             AppState::Details | AppState::TaskDefinitionDetail => {
                 // Scroll down in details view
                 self.details_scroll = self.details_scroll.saturating_add(1);
@@ -512,17 +1318,26 @@ impl App {
         let len = match self.state {
             AppState::Clusters => self.get_filtered_clusters().len(),
             AppState::Services => self.get_filtered_services().len(),
+            AppState::Tasks if self.show_stopped_tasks => self.get_filtered_stopped_tasks().len(),
             AppState::Tasks => self.get_filtered_tasks().len(),
             AppState::TaskDefinitions => self.get_filtered_task_definition_families().len(),
+            AppState::TaskDefinitionRevisions => self.get_filtered_task_definitions().len(),
+            AppState::ServiceEvents => self.get_filtered_service_events().len(),
+            AppState::ContainerInstances => self.get_filtered_container_instances().len(),
+            AppState::LogInsights => self.insights_results().map_or(0, |r| r.rows.len()),
             AppState::Details | AppState::TaskDefinitionDetail => {
                 // Scroll up in details view
                 self.details_scroll = self.details_scroll.saturating_sub(1);
                 return;
             }
             AppState::Logs => {
-                // Scroll up in logs
-                self.log_scroll = self.log_scroll.saturating_sub(1);
+                // Scroll up in logs, loading the previous page once the top is reached
                 self.auto_tail = false;
+                if self.log_scroll == 0 {
+                    self.load_older_logs();
+                } else {
+                    self.log_scroll -= 1;
+                }
                 return;
             }
             AppState::Metrics => {
@@ -541,49 +1356,52 @@ impl App {
         }
     }
 
-    pub async fn select(&mut self) -> Result<()> {
+    pub fn select(&mut self) {
         match self.state {
             AppState::Clusters => {
-                if let Some(cluster) = self.clusters.get(self.selected_index) {
+                if let Some(cluster) = self.clusters.get(self.selected_index).cloned() {
                     self.selected_cluster = Some(cluster.clone());
-                    self.loading = true;
-                    self.status_message = format!("Loading services for cluster: {cluster}");
-                    self.services = self.ecs_client.list_services(cluster).await?;
-                    self.loading = false;
+                    self.services.clear();
                     self.set_view(AppState::Services);
-                    self.status_message = format!("Loaded {} services", self.services.len());
+                    self.load_services(cluster);
                 }
             }
             AppState::Services => {
                 if let Some(service) = self.services.get(self.selected_index) {
-                    self.selected_service = Some(service.name.clone());
-                    if let Some(cluster) = &self.selected_cluster {
-                        self.loading = true;
-                        self.status_message =
-                            format!("Loading tasks for service: {}", service.name);
-                        self.tasks = self.ecs_client.list_tasks(cluster, &service.name).await?;
-                        self.loading = false;
+                    let service_name = service.name.clone();
+                    self.selected_service = Some(service_name.clone());
+                    if let Some(cluster) = self.selected_cluster.clone() {
+                        self.tasks.clear();
+                        self.stopped_tasks.clear();
+                        self.show_stopped_tasks = false;
+                        self.selected_container_instance = None;
                         self.set_view(AppState::Tasks);
-                        self.status_message = format!("Loaded {} tasks", self.tasks.len());
+                        self.load_tasks(cluster, service_name);
                     }
                 }
             }
+            AppState::Tasks if self.show_stopped_tasks => {
+                // Stopped tasks can still be described while ECS retains them
+                let stopped_tasks = self.get_filtered_stopped_tasks();
+                if let (Some(task), Some(cluster)) = (
+                    stopped_tasks.get(self.selected_index),
+                    self.selected_cluster.clone(),
+                ) {
+                    self.details = None;
+                    self.details_json = None;
+                    self.set_view(AppState::Details);
+                    self.load_task_details(cluster, task.task_arn.clone());
+                }
+            }
             AppState::Tasks => {
                 // View task details
                 if let Some(task) = self.tasks.get(self.selected_index) {
-                    if let Some(cluster) = &self.selected_cluster {
-                        self.loading = true;
-                        self.status_message = "Loading task details...".to_string();
-                        let (formatted, json) = self
-                            .ecs_client
-                            .describe_task(cluster, &task.task_arn)
-                            .await?;
-                        self.details = Some(formatted);
-                        self.details_json = Some(json);
-                        self.loading = false;
+                    let task_arn = task.task_arn.clone();
+                    if let Some(cluster) = self.selected_cluster.clone() {
+                        self.details = None;
+                        self.details_json = None;
                         self.set_view(AppState::Details);
-                        self.status_message =
-                            "Task details loaded (Press 'J' for JSON view)".to_string();
+                        self.load_task_details(cluster, task_arn);
                     }
                 }
             }
@@ -592,15 +1410,43 @@ impl App {
                 let filtered_families = self.get_filtered_task_definition_families();
                 if let Some(family) = filtered_families.get(self.selected_index) {
                     self.selected_task_definition_family = Some(family.clone());
-                    self.status_message = format!("Selected task definition family: {family}");
-                    // TODO: Load revisions for this family and navigate to TaskDefinitionDetail view
+                    self.task_definitions.clear();
+                    self.task_definition_diff_base = None;
+                    self.set_view(AppState::TaskDefinitionRevisions);
+                    self.load_task_definition_revisions(family.clone());
+                }
+            }
+            AppState::TaskDefinitionRevisions => {
+                // Revisions are fully described when listed
+                let revisions = self.get_filtered_task_definitions();
+                if let Some(revision) = revisions.get(self.selected_index) {
+                    self.selected_task_definition = Some(revision.clone());
+                    self.details_scroll = 0;
+                    self.set_view(AppState::TaskDefinitionDetail);
+                }
+            }
+            AppState::ContainerInstances => {
+                // List the tasks placed on the instance
+                let instances = self.get_filtered_container_instances();
+                if let (Some(instance), Some(cluster)) = (
+                    instances.get(self.selected_index),
+                    self.selected_cluster.clone(),
+                ) {
+                    let arn = instance.arn.clone();
+                    self.selected_container_instance = Some(arn.clone());
+                    self.selected_service = None;
+                    self.tasks.clear();
+                    self.show_stopped_tasks = false;
+                    self.set_view(AppState::Tasks);
+                    self.load_container_instance_tasks(cluster, arn);
                 }
             }
             AppState::Details | AppState::TaskDefinitionDetail => {}
-            AppState::Logs => {}
+            AppState::Logs => self.show_log_entry_detail(),
+            AppState::LogInsights => self.run_insights_query(),
             AppState::Metrics => {}
+            AppState::ServiceEvents => {}
         }
-        Ok(())
     }
 
     pub fn back(&mut self) {
@@ -610,27 +1456,61 @@ impl App {
                 self.selected_service = None;
             }
             AppState::Tasks => {
+                // Tasks listed for a container instance go back to the instances
+                if self.selected_container_instance.take().is_some() {
+                    self.set_view(AppState::ContainerInstances);
+                } else {
+                    self.set_view(AppState::Services);
+                }
+            }
+            AppState::ContainerInstances => {
                 self.set_view(AppState::Services);
+                self.container_instances.clear();
+                if let Some(cluster) = self.selected_cluster.clone() {
+                    self.load_services(cluster);
+                }
             }
             AppState::Details => {
                 self.set_view(AppState::Tasks);
                 self.details = None;
             }
             AppState::Logs => {
-                self.set_view(AppState::Tasks);
-                self.logs.clear();
-                self.log_scroll = 0;
-                self.auto_tail = true;
+                // Context of all tasks turns task logs into service-wide logs, so the
+                // view they were opened from is recorded separately
+                self.show_service_logs = false;
+                self.set_view(self.logs_opened_from.clone());
+                self.reset_logs();
+            }
+            AppState::LogInsights => {
+                self.stop_insights_query();
+                self.insights_editing = false;
+                self.set_view(AppState::Logs);
             }
             AppState::Metrics => {
-                self.set_view(AppState::Services);
-                self.metrics = None;
+                if std::mem::take(&mut self.show_task_metrics) {
+                    self.set_view(AppState::Tasks);
+                    self.task_metrics = None;
+                } else {
+                    self.set_view(AppState::Services);
+                    self.metrics = None;
+                }
                 self.metrics_scroll = 0;
             }
-            AppState::TaskDefinitionDetail => {
+            AppState::ServiceEvents => {
+                self.set_view(AppState::Services);
+                self.service_deployments.clear();
+                self.service_events.clear();
+            }
+            AppState::TaskDefinitionRevisions => {
                 self.set_view(AppState::TaskDefinitions);
+                self.selected_task_definition_family = None;
+                self.task_definitions.clear();
+                self.task_definition_diff_base = None;
+            }
+            AppState::TaskDefinitionDetail => {
+                self.set_view(AppState::TaskDefinitionRevisions);
                 self.selected_task_definition = None;
-                self.details = None;
+                self.details_scroll = 0;
             }
             AppState::Clusters | AppState::TaskDefinitions => {}
         }
@@ -638,87 +1518,73 @@ impl App {
 
     /// Refreshes data for the current view.
     ///
-    /// Handles AWS API errors gracefully by displaying error messages to the user
-    /// instead of crashing the application.
-    ///
-    /// # Returns
-    /// Always returns `Ok(())` - errors are handled internally and displayed as status messages
-    pub async fn refresh(&mut self) -> Result<()> {
-        self.loading = true;
+    /// The AWS calls run on a background task so the UI stays responsive; the
+    /// result is applied by `process_responses`. Errors are displayed to the user
+    /// as status messages instead of crashing the application.
+    pub fn refresh(&mut self) {
         self.last_refresh = Instant::now();
 
         // Resume auto-refresh on refresh attempt (clears any pause)
         self.resume_auto_refresh();
 
         match self.state {
-            AppState::Clusters => {
-                self.status_message = "Refreshing clusters...".to_string();
-                match self.ecs_client.list_clusters().await {
-                    Ok(clusters) => {
-                        self.clusters = clusters;
-                        self.status_message = format!("Loaded {} clusters", self.clusters.len());
-                    }
-                    Err(e) => {
-                        self.status_message = format!("Error loading clusters: {e}");
-                    }
-                }
-            }
+            AppState::Clusters => self.load_clusters(),
             AppState::Services => {
-                if let Some(cluster) = &self.selected_cluster {
-                    self.status_message = "Refreshing services...".to_string();
-                    match self.ecs_client.list_services(cluster).await {
-                        Ok(services) => {
-                            self.services = services;
-                            self.status_message =
-                                format!("Loaded {} services", self.services.len());
-                        }
-                        Err(e) => {
-                            self.status_message = format!("Error loading services: {e}");
-                        }
-                    }
+                if let Some(cluster) = self.selected_cluster.clone() {
+                    self.load_services(cluster);
                 }
             }
             AppState::Tasks => {
-                if let (Some(cluster), Some(service)) =
-                    (&self.selected_cluster, &self.selected_service)
+                if let (Some(cluster), Some(instance)) = (
+                    self.selected_cluster.clone(),
+                    self.selected_container_instance.clone(),
+                ) {
+                    self.load_container_instance_tasks(cluster, instance);
+                } else if let (Some(cluster), Some(service)) =
+                    (self.selected_cluster.clone(), self.selected_service.clone())
                 {
-                    self.status_message = "Refreshing tasks...".to_string();
-                    match self.ecs_client.list_tasks(cluster, service).await {
-                        Ok(tasks) => {
-                            self.tasks = tasks;
-                            self.status_message = format!("Loaded {} tasks", self.tasks.len());
-                        }
-                        Err(e) => {
-                            self.status_message = format!("Error loading tasks: {e}");
-                        }
-                    }
+                    self.load_tasks(cluster, service);
+                }
+            }
+            AppState::ContainerInstances => {
+                if let Some(cluster) = self.selected_cluster.clone() {
+                    self.load_container_instances(cluster);
                 }
             }
             AppState::Details => {}
             AppState::Logs => {
-                // Refresh logs if we have a selected task
-                if let (Some(cluster), Some(task)) = (&self.selected_cluster, &self.selected_task) {
-                    self.status_message = "Refreshing logs...".to_string();
-                    match self.ecs_client.get_task_logs(cluster, &task.task_arn).await {
-                        Ok(logs) => {
-                            self.logs = logs;
-                            if self.auto_tail && !self.logs.is_empty() {
-                                self.log_scroll = self.logs.len().saturating_sub(1);
-                            }
-                            self.status_message = format!("Loaded {} log entries", self.logs.len());
-                        }
-                        Err(e) => {
-                            self.status_message = format!("Error loading logs: {e}");
-                        }
+                // Reload a jumped-to time, otherwise tail new events
+                let request = match self.log_start_time {
+                    Some(start_time) => LogPageRequest::At(start_time),
+                    None if self.log_tail_detached
+                        || (!self.show_service_logs && self.log_cursors.is_empty()) =>
+                    {
+                        LogPageRequest::Latest
                     }
+                    None => LogPageRequest::Newer(self.log_cursors.clone()),
+                };
+                self.request_logs(request);
+            }
+            AppState::LogInsights => self.run_insights_query(),
+            AppState::Metrics if self.show_task_metrics => {
+                if let (Some(cluster), Some(task)) =
+                    (self.selected_cluster.clone(), self.selected_task.clone())
+                {
+                    let time_range = self
+                        .task_metrics
+                        .as_ref()
+                        .map(|m| m.time_range)
+                        .unwrap_or_else(|| {
+                            TimeRange::from_minutes(self.config.metrics.time_range_minutes)
+                        });
+                    self.load_task_metrics(cluster, task.task_arn, time_range);
                 }
             }
             AppState::Metrics => {
                 // Refresh metrics if we have a selected service
                 if let (Some(cluster), Some(service)) =
-                    (&self.selected_cluster, &self.selected_service)
+                    (self.selected_cluster.clone(), self.selected_service.clone())
                 {
-                    self.status_message = "Refreshing metrics...".to_string();
                     // Use current time range from metrics or default from config
                     let time_range =
                         self.metrics
@@ -727,2771 +1593,9255 @@ impl App {
                             .unwrap_or_else(|| {
                                 TimeRange::from_minutes(self.config.metrics.time_range_minutes)
                             });
-
-                    match self
-                        .ecs_client
-                        .get_service_metrics(cluster, service, time_range)
-                        .await
-                    {
-                        Ok(metrics) => {
-                            self.metrics = Some(metrics);
-                            self.status_message = "Metrics refreshed".to_string();
-                        }
-                        Err(e) => {
-                            self.status_message = format!("Error loading metrics: {e}");
-                        }
-                    }
+                    self.load_metrics(cluster, service, time_range);
                 }
             }
-            AppState::TaskDefinitions => {
-                self.status_message = "Refreshing task definition families...".to_string();
-                match self.ecs_client.list_task_definition_families().await {
-                    Ok(families) => {
-                        self.task_definition_families = families;
-                        self.status_message = format!(
-                            "Loaded {} task definition families",
-                            self.task_definition_families.len()
-                        );
-                    }
-                    Err(e) => {
-                        self.status_message =
-                            format!("Error loading task definition families: {e}");
-                    }
+            AppState::ServiceEvents => {
+                if let (Some(cluster), Some(service)) =
+                    (self.selected_cluster.clone(), self.selected_service.clone())
+                {
+                    self.load_service_events(cluster, service);
+                }
+            }
+            AppState::TaskDefinitions => self.load_task_definition_families(),
+            AppState::TaskDefinitionRevisions => {
+                if let Some(family) = self.selected_task_definition_family.clone() {
+                    self.load_task_definition_revisions(family);
                 }
             }
             AppState::TaskDefinitionDetail => {
-                // Placeholder - will be implemented with full task definition viewer
-                self.status_message =
-                    "Task definition detail refresh not yet implemented".to_string();
+                if let Some(arn) = self
+                    .selected_task_definition
+                    .as_ref()
+                    .map(|revision| revision.arn.clone())
+                {
+                    self.load_task_definition(arn);
+                }
             }
         }
-
-        self.loading = false;
-
-        // Always return Ok - errors are shown to user in status message
-        Ok(())
     }
 
-    pub async fn describe(&mut self) -> Result<()> {
-        match self.state {
-            AppState::Services => {
-                if let Some(service) = self.services.get(self.selected_index) {
-                    if let Some(cluster) = &self.selected_cluster {
-                        self.loading = true;
-                        self.status_message = format!("Describing service: {}", service.name);
-                        let (formatted, json) = self
-                            .ecs_client
-                            .describe_service(cluster, &service.name)
-                            .await?;
-                        self.details = Some(formatted);
-                        self.details_json = Some(json);
-                        self.loading = false;
-                        self.set_view(AppState::Details);
-                        self.status_message =
-                            "Service details loaded (Press 'j' for JSON view)".to_string();
+    /// Starts loading the cluster list in the background.
+    fn load_clusters(&mut self) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            "Refreshing clusters...",
+            async move {
+                let clusters = client.list_clusters().await?;
+                // Statistics are secondary: the cluster list stays usable without DescribeClusters
+                let details = client.describe_clusters(&clusters).await;
+                Ok((clusters, details))
+            },
+            |app, result| match result {
+                Ok((clusters, details)) => {
+                    app.clusters = clusters;
+                    match details {
+                        Ok(details) => {
+                            app.cluster_details = details;
+                            app.status_message = format!("Loaded {} clusters", app.clusters.len());
+                        }
+                        Err(e) => {
+                            app.cluster_details.clear();
+                            app.status_message = format!(
+                                "Loaded {} clusters (statistics unavailable: {e})",
+                                app.clusters.len()
+                            );
+                        }
                     }
                 }
-            }
-            AppState::Tasks => {
-                if let Some(task) = self.tasks.get(self.selected_index) {
-                    if let Some(cluster) = &self.selected_cluster {
-                        self.loading = true;
-                        self.status_message = "Describing task...".to_string();
-                        let (formatted, json) = self
-                            .ecs_client
-                            .describe_task(cluster, &task.task_arn)
-                            .await?;
-                        self.details = Some(formatted);
-                        self.details_json = Some(json);
-                        self.loading = false;
-                        self.set_view(AppState::Details);
-                        self.status_message =
-                            "Task details loaded (Press 'j' for JSON view)".to_string();
-                    }
-                }
-            }
-            _ => {}
-        }
-        Ok(())
+                Err(e) => app.status_message = format!("Error loading clusters: {e}"),
+            },
+        );
     }
 
-    pub async fn execute_action(&mut self) -> Result<()> {
-        // Block destructive actions in read-only mode
-        if self.config.behavior.read_only {
-            self.status_message = "Read-only mode enabled - cannot perform this action".to_string();
-            return Ok(());
-        }
-
-        match self.state {
-            AppState::Services => {
-                if let Some(service) = self.services.get(self.selected_index) {
-                    if let Some(cluster) = &self.selected_cluster {
-                        self.loading = true;
-                        self.status_message = format!("Restarting service: {}", service.name);
-                        self.ecs_client
-                            .restart_service(cluster, &service.name)
-                            .await?;
-                        self.status_message = format!("Service {} restarted", service.name);
-                        self.refresh().await?;
-                        self.loading = false;
-                    }
+    /// Starts loading the services of a cluster in the background.
+    fn load_services(&mut self, cluster: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!("Loading services for cluster: {cluster}"),
+            async move { client.list_services(&cluster).await },
+            |app, result| match result {
+                Ok(services) => {
+                    app.services = services;
+                    app.status_message = format!("Loaded {} services", app.services.len());
                 }
-            }
-            AppState::Tasks => {
-                if let Some(task) = self.tasks.get(self.selected_index) {
-                    if let Some(cluster) = &self.selected_cluster {
-                        self.loading = true;
-                        self.status_message = format!("Stopping task: {}", task.task_id);
-                        self.ecs_client.stop_task(cluster, &task.task_arn).await?;
-                        self.status_message = format!("Task {} stopped", task.task_id);
-                        self.refresh().await?;
-                        self.loading = false;
-                    }
-                }
-            }
-            _ => {}
-        }
-        Ok(())
+                Err(e) => app.status_message = format!("Error loading services: {e}"),
+            },
+        );
     }
 
-    /// Pauses auto-refresh temporarily due to user interaction.
+    /// Starts loading the tasks of a service in the background.
     ///
-    /// Auto-refresh will automatically resume after 10 seconds.
-    pub fn pause_auto_refresh(&mut self) {
-        self.auto_refresh_paused = true;
-        self.auto_refresh_pause_time = Some(Instant::now());
+    /// Loads stopped tasks instead while stopped-task mode is on.
+    fn load_tasks(&mut self, cluster: String, service: String) {
+        if self.show_stopped_tasks {
+            self.load_stopped_tasks(cluster, service);
+            return;
+        }
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!("Loading tasks for service: {service}"),
+            async move { client.list_tasks(&cluster, &service).await },
+            |app, result| match result {
+                Ok(tasks) => {
+                    app.tasks = tasks;
+                    app.status_message = format!("Loaded {} tasks", app.tasks.len());
+                }
+                Err(e) => app.status_message = format!("Error loading tasks: {e}"),
+            },
+        );
     }
 
-    /// Resumes auto-refresh if it was paused.
-    pub fn resume_auto_refresh(&mut self) {
-        self.auto_refresh_paused = false;
-        self.auto_refresh_pause_time = None;
+    /// Starts loading the recently stopped tasks of a service in the background.
+    fn load_stopped_tasks(&mut self, cluster: String, service: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!("Loading stopped tasks for service: {service}"),
+            async move { client.list_stopped_tasks(&cluster, &service).await },
+            |app, result| match result {
+                Ok(tasks) => {
+                    app.stopped_tasks = tasks;
+                    app.status_message =
+                        format!("Loaded {} stopped tasks", app.stopped_tasks.len());
+                }
+                Err(e) => app.status_message = format!("Error loading stopped tasks: {e}"),
+            },
+        );
     }
 
-    /// Determines if auto-refresh should occur.
-    ///
-    /// Auto-refresh is skipped if:
-    /// - Disabled in config
-    /// - Paused due to user interaction (and pause hasn't expired)
-    ///
-    /// Auto-refresh pauses automatically resume after 10 seconds.
-    ///
-    /// # Returns
-    /// `true` if refresh should occur, `false` otherwise
-    pub fn should_refresh(&self) -> bool {
-        // Skip auto-refresh if disabled in config
-        if !self.config.behavior.auto_refresh {
-            return false;
-        }
+    /// Starts loading the container instances of a cluster in the background.
+    fn load_container_instances(&mut self, cluster: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!("Loading container instances for cluster: {cluster}"),
+            async move { client.list_container_instances(&cluster).await },
+            |app, result| match result {
+                Ok(instances) => {
+                    app.container_instances = instances;
+                    app.status_message = format!(
+                        "Loaded {} container instances",
+                        app.container_instances.len()
+                    );
+                }
+                Err(e) => app.status_message = format!("Error loading container instances: {e}"),
+            },
+        );
+    }
 
-        // Skip auto-refresh if paused and pause hasn't expired
-        if self.auto_refresh_paused {
-            if let Some(pause_time) = self.auto_refresh_pause_time {
-                // Resume auto-refresh after 10 seconds of pause
-                if pause_time.elapsed() < Duration::from_secs(10) {
-                    return false;
+    /// Starts loading the tasks placed on a container instance in the background.
+    fn load_container_instance_tasks(&mut self, cluster: String, container_instance_arn: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            "Loading tasks for container instance...",
+            async move {
+                client
+                    .list_container_instance_tasks(&cluster, &container_instance_arn)
+                    .await
+            },
+            |app, result| match result {
+                Ok(tasks) => {
+                    app.tasks = tasks;
+                    app.status_message = format!("Loaded {} tasks", app.tasks.len());
                 }
-            }
-        }
+                Err(e) => app.status_message = format!("Error loading tasks: {e}"),
+            },
+        );
+    }
 
-        // Auto-refresh logs more frequently when in Logs view
-        let refresh_interval = if self.state == AppState::Logs && self.auto_tail {
-            Duration::from_secs(5)
-        } else {
-            Duration::from_secs(self.config.behavior.refresh_interval)
+    /// Shows the container instances of the highlighted cluster (Clusters view)
+    /// or the selected cluster (Services view).
+    pub fn view_container_instances(&mut self) {
+        let cluster = match self.state {
+            AppState::Clusters => self
+                .get_filtered_clusters()
+                .get(self.selected_index)
+                .cloned(),
+            AppState::Services => self.selected_cluster.clone(),
+            _ => return,
         };
-        self.last_refresh.elapsed() > refresh_interval
+        let Some(cluster) = cluster else {
+            self.status_message = "No cluster selected".to_string();
+            return;
+        };
+        self.selected_cluster = Some(cluster.clone());
+        self.container_instances.clear();
+        self.set_view(AppState::ContainerInstances);
+        self.load_container_instances(cluster);
     }
 
-    pub async fn view_logs(&mut self) -> Result<()> {
-        if self.state == AppState::Tasks {
-            if let Some(task) = self.tasks.get(self.selected_index) {
-                self.selected_task = Some(task.clone());
-                if let Some(cluster) = &self.selected_cluster {
-                    self.loading = true;
-                    self.status_message = format!("Loading logs for task: {}", task.task_id);
-                    self.logs = self
-                        .ecs_client
-                        .get_task_logs(cluster, &task.task_arn)
-                        .await?;
-                    self.loading = false;
-                    self.log_scroll = if !self.logs.is_empty() {
-                        self.logs.len().saturating_sub(1)
-                    } else {
-                        0
-                    };
-                    self.auto_tail = true;
-                    self.set_view(AppState::Logs);
-                    self.status_message =
-                        format!("Loaded {} log entries (auto-tail enabled)", self.logs.len());
-                }
-            }
+    /// Switches the Tasks view between running and recently stopped tasks.
+    pub fn toggle_stopped_tasks(&mut self) {
+        if self.state != AppState::Tasks {
+            return;
         }
-        Ok(())
+        if self.selected_container_instance.is_some() {
+            self.status_message = "Stopped tasks are listed per service".to_string();
+            return;
+        }
+        self.show_stopped_tasks = !self.show_stopped_tasks;
+        self.selected_index = 0;
+        self.refresh();
+    }
+
+    /// Starts loading the details of a task in the background.
+    fn load_task_details(&mut self, cluster: String, task_arn: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            "Loading task details...",
+            async move { client.describe_task(&cluster, &task_arn).await },
+            |app, result| match result {
+                Ok((formatted, json)) => {
+                    app.details = Some(formatted);
+                    app.details_json = Some(json);
+                    app.status_message =
+                        "Task details loaded (Press 'J' for JSON view)".to_string();
+                }
+                Err(e) => app.status_message = format!("Error loading task details: {e}"),
+            },
+        );
     }
 
-    pub fn toggle_auto_tail(&mut self) {
-        self.auto_tail = !self.auto_tail;
-        if self.auto_tail && !self.logs.is_empty() {
-            self.log_scroll = self.logs.len().saturating_sub(1);
-        }
-        self.status_message = format!(
-            "Auto-tail {}",
-            if self.auto_tail {
-                "enabled"
+    /// Starts loading a page of the CloudWatch logs of a task in the background.
+    ///
+    /// The latest page and pages starting at a point in time replace the log buffer;
+    /// older pages are prepended to it.
+    fn load_logs(&mut self, cluster: String, task_arn: String, request: LogPageRequest) {
+        let client = Arc::clone(&self.ecs_client);
+        let older = matches!(request, LogPageRequest::Older(_));
+        self.spawn_request(
+            if older {
+                "Loading older logs..."
             } else {
-                "disabled"
-            }
+                "Refreshing logs..."
+            },
+            async move { client.get_task_logs(&cluster, &task_arn, &request).await },
+            move |app, result| match result {
+                Ok(page) if older => app.prepend_log_page(page),
+                Ok(page) => app.replace_logs(page),
+                Err(e) => app.status_message = format!("Error loading logs: {e}"),
+            },
         );
     }
 
-    /// Enters log search mode.
-    pub fn enter_log_search_mode(&mut self) {
-        self.log_search_mode = true;
-        self.log_search_query.clear();
-        self.status_message = "Log search: ".to_string();
-    }
+    /// Starts loading a time window of the logs of every task of a service.
+    ///
+    /// The latest window and windows starting at a point in time replace the log
+    /// buffer; the window before the oldest loaded one is prepended to it. Newer events
+    /// are polled silently from shortly before the newest entry, and duplicates are
+    /// skipped by event ID.
+    fn load_service_logs(&mut self, cluster: String, service: String, request: LogPageRequest) {
+        let client = Arc::clone(&self.ecs_client);
+        let now = chrono::Utc::now().timestamp_millis();
+
+        if let LogPageRequest::Newer(_) = request {
+            if self.log_poll_in_flight {
+                return;
+            }
+            self.log_poll_in_flight = true;
+            let since = self
+                .logs
+                .last()
+                .map(|log| log.timestamp - SERVICE_LOG_TAIL_OVERLAP_MS)
+                .or(self.service_log_window_start)
+                .unwrap_or(now - SERVICE_LOG_WINDOW_MS);
+            let source = self.log_source();
+            let limit = self.config.logs.max_buffer_lines.max(1);
+            self.spawn_poll(
+                async move {
+                    // A poll stopped at the limit is continued by the next one, which
+                    // starts from the newest entry read
+                    let logs = client
+                        .get_service_logs(&cluster, &service, since, None, limit)
+                        .await?;
+                    Ok(LogPage {
+                        entries: logs.entries,
+                        cursors: Vec::new(),
+                        unavailable: Vec::new(),
+                    })
+                },
+                move |app, result| app.apply_log_poll(source, result),
+            );
+            return;
+        }
 
-    /// Exits log search mode.
-    pub fn exit_log_search_mode(&mut self) {
-        self.log_search_mode = false;
-        let filtered_count = self.get_filtered_logs().len();
-        self.status_message = format!(
-            "Found {} logs matching '{}'",
-            filtered_count, self.log_search_query
+        let older = matches!(request, LogPageRequest::Older(_));
+        let (start_time, end_time) = match request {
+            LogPageRequest::At(start_time) => {
+                (start_time, Some(start_time + SERVICE_LOG_WINDOW_MS))
+            }
+            // Service-wide logs have no cursors; older windows end where the oldest began
+            LogPageRequest::Older(_) => {
+                let end_time = self.service_log_window_start.unwrap_or(now);
+                (end_time - SERVICE_LOG_WINDOW_MS, Some(end_time))
+            }
+            _ => (now - SERVICE_LOG_WINDOW_MS, None),
+        };
+        let limit = self.config.logs.max_buffer_lines.max(1);
+        self.spawn_request(
+            if older {
+                "Loading older logs..."
+            } else {
+                "Refreshing logs..."
+            },
+            async move {
+                client
+                    .get_service_logs(&cluster, &service, start_time, end_time, limit)
+                    .await
+            },
+            move |app, result| {
+                let logs = match result {
+                    Ok(logs) => logs,
+                    Err(e) => {
+                        app.status_message = format!("Error loading logs: {e}");
+                        return;
+                    }
+                };
+                app.service_log_window_start = Some(start_time);
+                let page = LogPage {
+                    entries: logs.entries,
+                    cursors: Vec::new(),
+                    unavailable: Vec::new(),
+                };
+                if !older {
+                    app.replace_logs(page);
+                } else if page.entries.is_empty() {
+                    let time = |millis: i64| {
+                        chrono::DateTime::from_timestamp_millis(millis)
+                            .map(|dt| {
+                                dt.with_timezone(&chrono::Local)
+                                    .format("%H:%M:%S")
+                                    .to_string()
+                            })
+                            .unwrap_or_default()
+                    };
+                    app.status_message = format!(
+                        "No log events between {} and {}; scroll up to look further back",
+                        time(start_time),
+                        time(start_time + SERVICE_LOG_WINDOW_MS)
+                    );
+                } else {
+                    app.prepend_log_page(page);
+                }
+                if logs.truncated {
+                    app.note_truncated_service_logs(limit);
+                }
+            },
         );
     }
 
-    /// Updates the log search query.
-    ///
-    /// Adds a character to the log search query for filtering displayed logs.
-    pub fn update_log_search(&mut self, c: char) {
-        self.log_search_query.push(c);
+    /// Notes in the status bar that service-wide logs were read only up to `limit` events.
+    fn note_truncated_service_logs(&mut self, limit: usize) {
+        self.status_message.push_str(&format!(
+            "; stopped at the first {limit} events of the time range (logs.max_buffer_lines)"
+        ));
     }
 
-    /// Deletes the last character from the log search query.
-    pub fn delete_log_search_char(&mut self) {
-        self.log_search_query.pop();
+    /// Replaces the log buffer with a freshly loaded page.
+    fn replace_logs(&mut self, page: LogPage) {
+        self.logs = page.entries;
+        self.logs_changed();
+        self.log_cursors = page.cursors;
+        self.log_unavailable = page.unavailable;
+        self.log_tail_detached = false;
+        let dropped = self.trim_log_buffer(false);
+        if self.auto_tail && !self.logs.is_empty() {
+            self.log_scroll = self.logs.len().saturating_sub(1);
+        }
+        if let Some(key) = self.log_select_after_load.take() {
+            if let Some(index) = self
+                .get_filtered_logs()
+                .iter()
+                .position(|log| log_entry_key(log) == key)
+            {
+                self.log_scroll = index;
+            }
+        }
+        self.status_message = format!("Loaded {} log entries", self.logs.len());
+        if dropped > 0 {
+            self.status_message
+                .push_str(&format!(" (buffer full, dropped {dropped} oldest)"));
+        }
+        for logs in &self.log_unavailable {
+            self.status_message.push_str(&format!(
+                "; no logs for {}: {}",
+                logs.container_name, logs.reason
+            ));
+        }
     }
 
-    /// Clears the log search query.
-    pub fn clear_log_search(&mut self) {
-        self.log_search_mode = false;
-        self.log_search_query.clear();
-        self.status_message = "Log search cleared".to_string();
-    }
+    /// Prepends a page of older logs to the buffer, keeping the visible entries in place.
+    fn prepend_log_page(&mut self, page: LogPage) {
+        let visible_before = self.get_filtered_logs().len();
+        let loaded = page.entries.len();
+        let reached_start = page.reached_start();
 
-    /// Cycles to the next log level filter.
-    ///
-    /// Cycles through: None -> Debug -> Info -> Warn -> Error -> Fatal -> None
-    pub fn cycle_log_level_filter(&mut self) {
-        self.log_level_filter = match self.log_level_filter {
-            None => Some(LogLevel::Debug),
-            Some(LogLevel::Debug) => Some(LogLevel::Info),
-            Some(LogLevel::Info) => Some(LogLevel::Warn),
-            Some(LogLevel::Warn) => Some(LogLevel::Error),
-            Some(LogLevel::Error) => Some(LogLevel::Fatal),
-            Some(LogLevel::Fatal) => None,
-            Some(LogLevel::Unknown) => None,
-        };
+        let mut logs = page.entries;
+        logs.append(&mut self.logs);
+        self.logs = logs;
+        self.logs_changed();
+        self.log_cursors = page.cursors;
+        self.log_scroll += self.get_filtered_logs().len() - visible_before;
+        let dropped = self.trim_log_buffer(true);
 
-        let filter_msg = match &self.log_level_filter {
-            None => "all levels".to_string(),
-            Some(level) => format!("{level:?}"),
+        self.status_message = if loaded == 0 && reached_start {
+            "Reached the beginning of the logs".to_string()
+        } else {
+            format!("Loaded {loaded} older log entries")
         };
-        self.status_message = format!("Log filter: {filter_msg}");
+        if dropped > 0 {
+            self.log_tail_detached = true;
+            self.status_message
+                .push_str(&format!(" (buffer full, dropped {dropped} newest)"));
+        }
     }
 
-    /// Returns filtered logs based on search query and level filter.
+    /// Polls the task's log streams for events newer than the buffer in the background.
     ///
-    /// Applies both text search and log level filtering to the logs.
-    pub fn get_filtered_logs(&self) -> Vec<LogEntry> {
-        let mut filtered = self.logs.clone();
-
-        // Apply log level filter
-        if let Some(ref level) = self.log_level_filter {
-            filtered.retain(|log| &log.level == level);
-        }
-
-        // Apply search filter
-        if !self.log_search_query.is_empty() {
-            let query_lower = self.log_search_query.to_lowercase();
-            filtered.retain(|log| {
-                log.message.to_lowercase().contains(&query_lower)
-                    || log.container_name.to_lowercase().contains(&query_lower)
-            });
+    /// The poll is silent so that tailing does not flicker, and only one runs at a time.
+    fn poll_new_logs(&mut self, cluster: String, task_arn: String) {
+        if self.log_poll_in_flight {
+            return;
         }
-
-        filtered
+        self.log_poll_in_flight = true;
+
+        let client = Arc::clone(&self.ecs_client);
+        let request = LogPageRequest::Newer(self.log_cursors.clone());
+        let source = self.log_source();
+        self.spawn_poll(
+            async move { client.get_task_logs(&cluster, &task_arn, &request).await },
+            move |app, result| app.apply_log_poll(source, result),
+        );
     }
 
-    /// Exports logs to a file.
-    ///
-    /// Writes all current logs (including filtered) to a timestamped file.
-    ///
-    /// # Returns
-    /// Returns `Ok(filename)` with the created file path, or an error if file operations fail
-    pub fn export_logs(&self) -> Result<String> {
-        use std::fs;
-        use std::path::PathBuf;
-
-        // Expand tilde in export directory
-        let export_dir = self.config.logs.export_dir.replace(
-            '~',
-            &dirs::home_dir()
-                .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?
-                .to_string_lossy(),
-        );
+    /// Applies polled log events, unless the Logs view stopped tailing or shows other logs.
+    fn apply_log_poll(&mut self, source: Option<String>, result: Result<LogPage>) {
+        self.log_poll_in_flight = false;
 
-        // Create export directory if it doesn't exist
-        let export_path = PathBuf::from(&export_dir);
-        if !export_path.exists() {
-            fs::create_dir_all(&export_path)?;
+        // Ignore polls for logs that are no longer tailed
+        if self.state != AppState::Logs
+            || self.log_source() != source
+            || self.log_start_time.is_some()
+            || self.log_tail_detached
+        {
+            return;
         }
 
-        // Generate filename with timestamp
-        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-        let task_id = self
-            .selected_task
-            .as_ref()
-            .map(|t| t.task_id.as_str())
-            .unwrap_or("unknown");
-        let filename = format!("ecs-logs-{task_id}-{timestamp}.txt");
-        let file_path = export_path.join(&filename);
+        match result {
+            Ok(page) => self.append_log_page(page),
+            Err(e) => self.status_message = format!("Error tailing logs: {e}"),
+        }
+    }
 
-        // Get filtered logs
-        let logs = self.get_filtered_logs();
+    /// Returns what the Logs view shows: the selected service for service-wide logs,
+    /// otherwise the ARN of the selected task.
+    fn log_source(&self) -> Option<String> {
+        if self.show_service_logs {
+            self.selected_service.clone()
+        } else {
+            self.selected_task
+                .as_ref()
+                .map(|task| task.task_arn.clone())
+        }
+    }
 
-        // Write logs to file
-        let mut content = String::new();
-        content.push_str("ECS Voyager Log Export\n");
-        content.push_str(&format!(
-            "Exported: {}\n",
-            chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
-        ));
-        content.push_str(&format!("Task: {task_id}\n"));
-        content.push_str(&format!("Total logs: {}\n", logs.len()));
-        if let Some(ref filter) = self.log_level_filter {
-            content.push_str(&format!("Level filter: {filter:?}\n"));
+    /// Loads or polls a page of the logs shown in the Logs view.
+    fn request_logs(&mut self, request: LogPageRequest) {
+        let Some(cluster) = self.selected_cluster.clone() else {
+            return;
+        };
+        if self.show_service_logs {
+            if let Some(service) = self.selected_service.clone() {
+                self.load_service_logs(cluster, service, request);
+            }
+        } else if let Some(task) = self.selected_task.clone() {
+            match request {
+                LogPageRequest::Newer(_) => self.poll_new_logs(cluster, task.task_arn),
+                _ => self.load_logs(cluster, task.task_arn, request),
+            }
         }
-        if !self.log_search_query.is_empty() {
-            content.push_str(&format!("Search query: {}\n", self.log_search_query));
+    }
+
+    /// Clears the logs, their paging state and filters before other logs are shown.
+    fn reset_logs(&mut self) {
+        self.logs.clear();
+        self.logs_changed();
+        self.log_cursors.clear();
+        self.log_start_time = None;
+        self.log_tail_detached = false;
+        self.service_log_window_start = None;
+        self.log_task_filter = None;
+        self.log_unavailable.clear();
+        self.log_bookmarks.clear();
+        self.log_select_after_load = None;
+        self.log_scroll = 0;
+        self.auto_tail = true;
+    }
+
+    /// Appends polled log events to the buffer, skipping events it already holds.
+    ///
+    /// Events with an ID are skipped if the buffer or the page already has that ID.
+    /// Events without one (GetLogEvents returns none) can be legitimately repeated
+    /// lines logged in the same millisecond, so each buffered copy only absorbs one
+    /// identical polled event, and repeats within the page are kept.
+    ///
+    /// Only the forward position of each log stream is taken from the page, so older
+    /// pages loaded while the poll was in flight are kept.
+    fn append_log_page(&mut self, page: LogPage) {
+        for polled in page.cursors {
+            match self.log_cursors.iter_mut().find(|cursor| {
+                cursor.log_group == polled.log_group && cursor.log_stream == polled.log_stream
+            }) {
+                Some(cursor) => cursor.forward_token = polled.forward_token,
+                None => self.log_cursors.push(polled),
+            }
         }
-        content.push_str(&format!("{}\n\n", "=".repeat(80)));
 
-        for log in logs {
-            let timestamp_str = if self.config.logs.show_timestamps {
-                let dt = chrono::DateTime::from_timestamp_millis(log.timestamp)
-                    .map(|dt| {
-                        dt.with_timezone(&chrono::Local)
-                            .format("%Y-%m-%d %H:%M:%S%.3f")
-                            .to_string()
-                    })
-                    .unwrap_or_else(|| log.timestamp.to_string());
-                format!("[{dt}] ")
+        // The buffer is sorted, so only its tail can hold the same events
+        let oldest = page
+            .entries
+            .iter()
+            .map(|entry| entry.timestamp)
+            .min()
+            .unwrap_or(i64::MAX);
+        let tail_start = self.logs.partition_point(|log| log.timestamp < oldest);
+        let mut absorbed = vec![false; self.logs.len() - tail_start];
+        let mut fresh: Vec<LogEntry> = Vec::new();
+        for entry in page.entries {
+            let seen = if entry.event_id.is_some() {
+                self.logs[tail_start..]
+                    .iter()
+                    .chain(fresh.iter())
+                    .any(|log| log.is_same_event(&entry))
             } else {
-                String::new()
+                let copy = self.logs[tail_start..]
+                    .iter()
+                    .zip(absorbed.iter())
+                    .position(|(log, absorbed)| !absorbed && log.is_same_event(&entry));
+                if let Some(index) = copy {
+                    absorbed[index] = true;
+                }
+                copy.is_some()
             };
-
-            content.push_str(&format!(
-                "{}[{:?}] [{}] {}\n",
-                timestamp_str, log.level, log.container_name, log.message
-            ));
+            if !seen {
+                fresh.push(entry);
+            }
+        }
+        if fresh.is_empty() {
+            return;
         }
 
-        fs::write(&file_path, content)?;
-
-        Ok(file_path.to_string_lossy().to_string())
+        self.logs.append(&mut fresh);
+        self.logs.sort_by_key(|log| log.timestamp);
+        self.logs_changed();
+        self.trim_log_buffer(false);
+        if self.auto_tail {
+            self.log_scroll = self.logs.len().saturating_sub(1);
+        }
     }
 
-    /// Fetches and displays CloudWatch metrics for the selected service.
+    /// Drops log entries beyond the configured `max_buffer_lines`.
     ///
-    /// Retrieves CPU and Memory utilization metrics for the currently selected service
-    /// and switches to the Metrics view.
-    pub async fn view_metrics(&mut self) -> Result<()> {
-        if self.state == AppState::Services {
-            if let Some(service) = self.services.get(self.selected_index) {
-                if let Some(cluster) = &self.selected_cluster {
-                    let service_name = service.name.clone();
-                    let cluster_name = cluster.clone();
-
-                    self.loading = true;
-                    self.status_message = format!("Loading metrics for service: {service_name}");
-
-                    let time_range =
-                        TimeRange::from_minutes(self.config.metrics.time_range_minutes);
-                    self.metrics = self
-                        .ecs_client
-                        .get_service_metrics(&cluster_name, &service_name, time_range)
-                        .await
-                        .ok();
-
-                    self.loading = false;
-                    self.set_view(AppState::Metrics);
-
-                    if self.metrics.is_some() {
-                        self.status_message = format!("Metrics loaded for {service_name}");
-                    } else {
-                        self.status_message = "Failed to load metrics".to_string();
-                    }
-                }
+    /// The newest entries are dropped while paging back through older logs, the oldest
+    /// otherwise. Returns the number of dropped entries.
+    fn trim_log_buffer(&mut self, drop_newest: bool) -> usize {
+        let excess = self
+            .logs
+            .len()
+            .saturating_sub(self.config.logs.max_buffer_lines.max(1));
+        if excess > 0 {
+            self.logs_changed();
+            if drop_newest {
+                self.logs.truncate(self.logs.len() - excess);
+            } else {
+                self.logs.drain(..excess);
+                self.log_scroll = self.log_scroll.saturating_sub(excess);
             }
         }
-        Ok(())
+        excess
     }
 
-    /// Cycles to the next time range for metrics view.
+    /// Loads the page of logs before the oldest entry in the buffer.
     ///
-    /// Changes the time range (1h -> 6h -> 24h -> 7d -> 1h) and refreshes metrics.
-    pub async fn cycle_metrics_time_range(&mut self) -> Result<()> {
-        if self.state == AppState::Metrics {
-            if let Some(metrics) = &self.metrics {
-                let new_time_range = metrics.time_range.next();
-                let cluster_name = metrics.cluster_name.clone();
-                let service_name = metrics.service_name.clone();
-
-                self.loading = true;
-                self.status_message =
-                    format!("Switching to {} time range...", new_time_range.label());
-
-                match self
-                    .ecs_client
-                    .get_service_metrics(&cluster_name, &service_name, new_time_range)
+    /// Does nothing but report it when every log stream of the task has been read to its
+    /// start. Service-wide logs go back one time window at a time.
+    pub fn load_older_logs(&mut self) {
+        if !self.show_service_logs && self.log_cursors.iter().all(|cursor| cursor.exhausted) {
+            self.status_message = "Reached the beginning of the logs".to_string();
+            return;
+        }
+        self.request_logs(LogPageRequest::Older(self.log_cursors.clone()));
+    }
+
+    /// Starts loading Container Insights metrics for a task in the background.
+    fn load_task_metrics(&mut self, cluster: String, task_arn: String, time_range: TimeRange) {
+        let client = Arc::clone(&self.ecs_client);
+        let task_id = task_arn
+            .split('/')
+            .next_back()
+            .unwrap_or(&task_arn)
+            .to_string();
+        self.spawn_request(
+            format!("Loading {} metrics for task: {task_id}", time_range.label()),
+            async move {
+                client
+                    .get_task_metrics(&cluster, &task_arn, time_range)
                     .await
-                {
-                    Ok(new_metrics) => {
-                        self.metrics = Some(new_metrics);
-                        self.status_message =
-                            format!("Metrics time range: {}", new_time_range.label());
-                    }
-                    Err(e) => {
-                        self.status_message = format!("Error loading metrics: {e}");
-                    }
+            },
+            move |app, result| match result {
+                Ok(metrics) => {
+                    app.status_message = if metrics.has_data() {
+                        format!(
+                            "Metrics loaded for task {} ({})",
+                            metrics.task_id,
+                            time_range.label()
+                        )
+                    } else {
+                        format!(
+                            "No Container Insights metrics for task {} ({})",
+                            metrics.task_id,
+                            time_range.label()
+                        )
+                    };
+                    app.task_metrics = Some(metrics);
                 }
-
-                self.loading = false;
-            }
-        }
-        Ok(())
+                Err(e) => app.status_message = format!("Error loading task metrics: {e}"),
+            },
+        );
     }
 
-    /// Starts an interactive ECS Exec session with the selected task.
-    ///
-    /// Opens an interactive shell (/bin/sh) in the selected task's container using AWS ECS Exec.
-    /// This requires:
-    /// - The task must have `enableExecuteCommand` enabled
-    /// - The `session-manager-plugin` must be installed locally
-    /// - Appropriate IAM permissions for ECS ExecuteCommand and SSM StartSession
-    ///
-    /// # Errors
-    /// Returns an error if:
-    /// - No task is currently selected
-    /// - The task doesn't have ECS Exec enabled
-    /// - The session-manager-plugin is not installed
-    /// - The AWS API call fails
-    /// - The session fails to start
-    ///
-    /// # Important
-    /// This method spawns the session-manager-plugin subprocess which takes over
-    /// the terminal. The caller (main.rs) must handle suspending/resuming the TUI
-    /// by disabling raw mode before calling this and re-enabling after.
-    pub async fn exec_into_task(&mut self) -> Result<()> {
-        if self.state != AppState::Tasks {
-            return Ok(());
-        }
-
-        if let Some(task) = self.tasks.get(self.selected_index) {
-            if let Some(cluster) = &self.selected_cluster {
-                let task_arn = task.task_arn.clone();
-                let task_id = task.task_id.clone();
-                let cluster_name = cluster.clone();
-
-                // Check if task has exec enabled
-                self.loading = true;
-                self.status_message = format!("Checking ECS Exec capability for task: {task_id}");
-
-                match self
-                    .ecs_client
-                    .check_task_exec_enabled(&cluster_name, &task_arn)
+    /// Starts loading CloudWatch metrics for a service in the background.
+    fn load_metrics(&mut self, cluster: String, service: String, time_range: TimeRange) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!(
+                "Loading {} metrics for service: {service}",
+                time_range.label()
+            ),
+            async move {
+                client
+                    .get_service_metrics(&cluster, &service, time_range)
                     .await
-                {
-                    Ok(false) => {
-                        self.loading = false;
-                        self.status_message = format!(
-                            "Task {task_id} does not have ECS Exec enabled. \
-                            Ensure the task definition and service have enableExecuteCommand=true"
-                        );
-                        return Ok(());
-                    }
-                    Err(e) => {
-                        self.loading = false;
-                        self.status_message = format!("Error checking ECS Exec: {e}");
-                        return Err(e);
-                    }
-                    Ok(true) => {
-                        // Continue - exec is enabled
-                    }
+            },
+            move |app, result| match result {
+                Ok(metrics) => {
+                    app.status_message = format!(
+                        "Metrics loaded for {} ({})",
+                        metrics.service_name,
+                        time_range.label()
+                    );
+                    app.metrics = Some(metrics);
                 }
+                Err(e) => app.status_message = format!("Error loading metrics: {e}"),
+            },
+        );
+    }
 
-                self.loading = false;
-                self.status_message = format!("Starting ECS Exec session for task: {task_id}...");
-
-                // Get the AWS region from current region
-                let region = self.current_region.clone();
-
-                // Start the exec session (this will block until session ends)
-                match self
-                    .ecs_client
-                    .start_exec_session(&cluster_name, &task_arn, None, &region)
-                    .await
-                {
-                    Ok(()) => {
-                        self.status_message = format!("ECS Exec session ended for task: {task_id}");
-                    }
-                    Err(e) => {
-                        self.status_message = format!("ECS Exec session failed: {e}");
-                        return Err(e);
-                    }
+    /// Starts loading the deployments and events of a service in the background.
+    fn load_service_events(&mut self, cluster: String, service: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!("Loading events for service: {service}"),
+            async move { client.get_service_events(&cluster, &service).await },
+            |app, result| match result {
+                Ok(activity) => {
+                    app.service_deployments = activity.deployments;
+                    app.service_events = activity.events;
+                    app.status_message = if app.rollout_in_progress() {
+                        "Rollout in progress - refreshing every 5s".to_string()
+                    } else {
+                        format!("Loaded {} service events", app.service_events.len())
+                    };
                 }
-            }
-        }
+                Err(e) => app.status_message = format!("Error loading service events: {e}"),
+            },
+        );
+    }
 
-        Ok(())
+    /// Starts loading task definition families in the background.
+    fn load_task_definition_families(&mut self) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            "Refreshing task definition families...",
+            async move { client.list_task_definition_families().await },
+            |app, result| match result {
+                Ok(families) => {
+                    app.task_definition_families = families;
+                    app.status_message = format!(
+                        "Loaded {} task definition families",
+                        app.task_definition_families.len()
+                    );
+                }
+                Err(e) => {
+                    app.status_message = format!("Error loading task definition families: {e}")
+                }
+            },
+        );
     }
 
-    // Search methods
-    pub fn enter_search_mode(&mut self) {
-        self.search_mode = true;
-        self.search_query.clear();
-        self.selected_index = 0;
+    /// Starts loading the newest revisions of a task definition family in the background.
+    fn load_task_definition_revisions(&mut self, family: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!("Loading revisions of {family}..."),
+            async move {
+                let arns = client.list_task_definition_revisions(&family).await?;
+                let total = arns.len();
+                let arns: Vec<String> = arns
+                    .into_iter()
+                    .take(MAX_TASK_DEFINITION_REVISIONS)
+                    .collect();
+                let revisions = describe_task_definitions(client, arns).await?;
+                Ok((family, revisions, total))
+            },
+            |app, result| match result {
+                Ok((family, revisions, total)) => {
+                    app.task_definitions = revisions;
+                    app.status_message = if total > app.task_definitions.len() {
+                        format!(
+                            "Loaded the newest {} of {total} revisions of {family}",
+                            app.task_definitions.len()
+                        )
+                    } else {
+                        format!("Loaded {total} revisions of {family}")
+                    };
+                }
+                Err(e) => {
+                    app.status_message = format!("Error loading task definition revisions: {e}")
+                }
+            },
+        );
     }
 
-    pub fn exit_search_mode(&mut self) {
-        self.search_mode = false;
+    /// Starts describing the task definition revision shown in the detail view again.
+    fn load_task_definition(&mut self, arn: String) {
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            "Refreshing task definition...",
+            async move { client.describe_task_definition(&arn).await },
+            |app, result| match result {
+                Ok(revision) => {
+                    app.status_message =
+                        format!("Loaded task definition {}", revision.family_revision());
+                    if let Some(listed) = app
+                        .task_definitions
+                        .iter_mut()
+                        .find(|listed| listed.arn == revision.arn)
+                    {
+                        *listed = revision.clone();
+                    }
+                    app.selected_task_definition = Some(revision);
+                }
+                Err(e) => app.status_message = format!("Error loading task definition: {e}"),
+            },
+        );
     }
 
-    pub fn clear_search(&mut self) {
-        self.search_mode = false;
-        self.search_query.clear();
-        self.selected_index = 0;
+    /// Marks the selected revision as the base of the next diff, or clears the mark.
+    pub fn mark_task_definition_diff_base(&mut self) {
+        let revisions = self.get_filtered_task_definitions();
+        let Some(revision) = revisions.get(self.selected_index) else {
+            return;
+        };
+        if self.task_definition_diff_base.as_deref() == Some(revision.arn.as_str()) {
+            self.task_definition_diff_base = None;
+            self.status_message = "Cleared the revision to compare against".to_string();
+        } else {
+            self.task_definition_diff_base = Some(revision.arn.clone());
+            self.status_message = format!(
+                "Marked {} - select another revision and press D to compare",
+                revision.family_revision()
+            );
+        }
     }
 
-    pub fn update_search(&mut self, c: char) {
-        self.search_query.push(c);
-        self.selected_index = 0;
+    /// Compares the selected revision with the marked one, or with the revision before it.
+    ///
+    /// The older of the two revisions is shown on the left.
+    pub fn show_task_definition_diff(&mut self) {
+        let selected = match self.state {
+            AppState::TaskDefinitionRevisions => self
+                .get_filtered_task_definitions()
+                .get(self.selected_index)
+                .cloned(),
+            AppState::TaskDefinitionDetail => self.selected_task_definition.clone(),
+            _ => None,
+        };
+        let Some(selected) = selected else {
+            return;
+        };
+
+        let marked = self
+            .task_definition_diff_base
+            .as_ref()
+            .filter(|arn| **arn != selected.arn)
+            .and_then(|arn| self.task_definitions.iter().find(|r| r.arn == *arn));
+        let previous = || {
+            self.task_definitions
+                .iter()
+                .filter(|r| r.revision < selected.revision)
+                .max_by_key(|r| r.revision)
+        };
+        let Some(base) = marked.or_else(previous).cloned() else {
+            self.status_message = format!(
+                "No earlier revision of {} to compare with; mark one with Space",
+                selected.family
+            );
+            return;
+        };
+
+        let (old, new) = if base.revision <= selected.revision {
+            (base, selected)
+        } else {
+            (selected, base)
+        };
+        self.open_task_definition_diff(TaskDefinitionDiff::new(old, new));
     }
 
-    pub fn delete_search_char(&mut self) {
-        self.search_query.pop();
-        self.selected_index = 0;
+    /// Compares the revision the service runs with the revision selected in the service
+    /// editor, describing both in the background.
+    pub fn show_service_editor_diff(&mut self) {
+        let current = self.service_editor_current_task_def.clone();
+        let Some(target) = self
+            .service_editor_available_revisions
+            .get(self.service_editor_selected_revision)
+            .cloned()
+        else {
+            return;
+        };
+        if target.contains(&current) {
+            self.status_message = "The selected revision is the one the service runs".to_string();
+            return;
+        }
+
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            "Comparing task definitions...",
+            async move {
+                let current = client.describe_task_definition(&current).await?;
+                let target = client.describe_task_definition(&target).await?;
+                Ok((current, target))
+            },
+            |app, result| match result {
+                // The editor may have been closed while the revisions were described
+                Ok((current, target)) if app.modal_state == ModalState::ServiceEditor => {
+                    let mut diff = TaskDefinitionDiff::new(current, target);
+                    diff.from_service_editor = true;
+                    app.open_task_definition_diff(diff);
+                }
+                Ok(_) => {}
+                Err(e) => app.status_message = format!("Error comparing task definitions: {e}"),
+            },
+        );
     }
 
-    /// Toggles regex mode for search queries
-    pub fn toggle_regex_mode(&mut self) {
-        self.search_regex_mode = !self.search_regex_mode;
+    /// Shows a task definition diff in its modal.
+    fn open_task_definition_diff(&mut self, diff: TaskDefinitionDiff) {
+        let (added, removed, changed) = diff.summary();
         self.status_message = format!(
-            "Regex mode: {}",
-            if self.search_regex_mode { "ON" } else { "OFF" }
+            "{} → {}: {changed} changed, {added} added, {removed} removed",
+            diff.old.family_revision(),
+            diff.new.family_revision()
         );
+        self.task_definition_diff = Some(diff);
+        self.modal_state = ModalState::TaskDefinitionDiff;
+        self.modal_selected_index = 0;
     }
 
-    /// Cycles through service status filters (None -> ACTIVE -> DRAINING -> None)
-    pub fn cycle_service_status_filter(&mut self) {
-        self.service_status_filter = match &self.service_status_filter {
-            None => Some("ACTIVE".to_string()),
-            Some(status) if status == "ACTIVE" => Some("DRAINING".to_string()),
-            _ => None,
-        };
-        let filter_msg = match &self.service_status_filter {
-            None => "all statuses".to_string(),
-            Some(status) => status.clone(),
-        };
-        self.status_message = format!("Service status filter: {filter_msg}");
+    /// Shows or hides the fields that are the same in both revisions of the diff.
+    pub fn toggle_diff_unchanged(&mut self) {
+        if let Some(diff) = &mut self.task_definition_diff {
+            diff.show_unchanged = !diff.show_unchanged;
+            self.modal_selected_index = 0;
+        }
     }
 
-    /// Cycles through task status filters (None -> RUNNING -> PENDING -> STOPPED -> None)
-    pub fn cycle_task_status_filter(&mut self) {
-        self.task_status_filter = match &self.task_status_filter {
-            None => Some("RUNNING".to_string()),
-            Some(status) if status == "RUNNING" => Some("PENDING".to_string()),
-            Some(status) if status == "PENDING" => Some("STOPPED".to_string()),
+    /// Opens the register dialog with a copy of the selected revision.
+    ///
+    /// When a service is selected and runs the same family, deploying the new
+    /// revision to it is offered, unselected.
+    pub fn show_task_definition_editor(&mut self) {
+        if self.config.behavior.read_only {
+            self.status_message =
+                "Read-only mode enabled - cannot register task definitions".to_string();
+            return;
+        }
+        let source = match self.state {
+            AppState::TaskDefinitionRevisions => self
+                .get_filtered_task_definitions()
+                .get(self.selected_index)
+                .cloned(),
+            AppState::TaskDefinitionDetail => self.selected_task_definition.clone(),
             _ => None,
         };
-        let filter_msg = match &self.task_status_filter {
-            None => "all statuses".to_string(),
-            Some(status) => status.clone(),
+        let Some(source) = source else {
+            return;
         };
-        self.status_message = format!("Task status filter: {filter_msg}");
+
+        let (Some(cluster), Some(service)) =
+            (self.selected_cluster.clone(), self.selected_service.clone())
+        else {
+            self.open_task_definition_editor(source, None, false);
+            return;
+        };
+        let client = Arc::clone(&self.ecs_client);
+        let service_name = service.clone();
+        let cluster_name = cluster.clone();
+        self.spawn_request(
+            format!("Loading task definition of {service}..."),
+            async move {
+                let (details, _) = client
+                    .describe_service(&cluster_name, &service_name)
+                    .await?;
+                Ok(details_task_definition(&details))
+            },
+            move |app, result| {
+                // The dialog still opens, without deploying, if the service can't be described
+                let runs_family = result
+                    .ok()
+                    .flatten()
+                    .is_some_and(|running| task_definition_family(&running) == source.family);
+                let deploy_target = runs_family.then_some((cluster, service));
+                app.open_task_definition_editor(source, deploy_target, false);
+            },
+        );
     }
 
-    /// Cycles through launch type filters (None -> FARGATE -> EC2 -> EXTERNAL -> None)
-    pub fn cycle_launch_type_filter(&mut self) {
-        self.launch_type_filter = match &self.launch_type_filter {
-            None => Some("FARGATE".to_string()),
-            Some(lt) if lt == "FARGATE" => Some("EC2".to_string()),
-            Some(lt) if lt == "EC2" => Some("EXTERNAL".to_string()),
-            _ => None,
+    /// Opens the register dialog with a copy of the revision selected in the service
+    /// editor, deploying the new revision to the edited service by default.
+    pub fn register_from_service_editor(&mut self) {
+        let (Some(cluster), Some(service)) =
+            (self.selected_cluster.clone(), self.selected_service.clone())
+        else {
+            return;
         };
-        let filter_msg = match &self.launch_type_filter {
-            None => "all types".to_string(),
-            Some(lt) => lt.clone(),
+        let Some(revision) = self
+            .service_editor_available_revisions
+            .get(self.service_editor_selected_revision)
+            .cloned()
+        else {
+            return;
         };
-        self.status_message = format!("Launch type filter: {filter_msg}");
-    }
 
-    /// Clears all active filters
-    pub fn clear_all_filters(&mut self) {
-        self.service_status_filter = None;
-        self.task_status_filter = None;
-        self.launch_type_filter = None;
-        self.status_message = "All filters cleared".to_string();
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_request(
+            format!(
+                "Loading task definition {}...",
+                short_task_definition(&revision)
+            ),
+            async move { client.describe_task_definition(&revision).await },
+            |app, result| match result {
+                // The editor may have been closed while the revision was described
+                Ok(source) if app.modal_state == ModalState::ServiceEditor => {
+                    app.open_task_definition_editor(source, Some((cluster, service)), true);
+                }
+                Ok(_) => {}
+                Err(e) => app.status_message = format!("Error loading task definition: {e}"),
+            },
+        );
     }
 
-    /// Returns filtered clusters based on search query and regex mode
-    pub fn get_filtered_clusters(&self) -> Vec<String> {
-        if self.search_query.is_empty() {
-            return self.clusters.clone();
-        }
-
-        if self.search_regex_mode {
-            // Use regex matching
-            if let Ok(re) = regex::Regex::new(&self.search_query) {
-                self.clusters
-                    .iter()
-                    .filter(|cluster| re.is_match(cluster))
-                    .cloned()
-                    .collect()
-            } else {
-                // Invalid regex, return all clusters
-                self.clusters.clone()
-            }
-        } else {
-            // Use simple substring matching (case-insensitive)
-            let query_lower = self.search_query.to_lowercase();
-            self.clusters
-                .iter()
-                .filter(|cluster| cluster.to_lowercase().contains(&query_lower))
-                .cloned()
-                .collect()
-        }
+    /// Shows the register dialog for a copy of `source`.
+    fn open_task_definition_editor(
+        &mut self,
+        source: TaskDefinitionInfo,
+        deploy_target: Option<(String, String)>,
+        deploy: bool,
+    ) {
+        self.status_message = format!(
+            "New revision from {} - Ctrl+E edits every setting as JSON",
+            source.family_revision()
+        );
+        self.task_definition_editor =
+            Some(TaskDefinitionEditor::new(source, deploy_target, deploy));
+        self.modal_state = ModalState::TaskDefinitionEditor;
+        self.modal_selected_index = 0;
     }
 
-    /// Returns filtered services based on search query, regex mode, and active filters
-    pub fn get_filtered_services(&self) -> Vec<ServiceInfo> {
-        let mut filtered = self.services.clone();
-
-        // Apply status filter
-        if let Some(ref status_filter) = self.service_status_filter {
-            filtered.retain(|s| s.status == *status_filter);
+    /// Returns the definition of the register dialog as JSON to edit in `$EDITOR`,
+    /// or the JSON that failed to parse last time.
+    pub fn task_definition_editor_json(&mut self) -> Option<(String, String)> {
+        let editor = self.task_definition_editor.as_ref()?;
+        if let Some(draft) = &editor.draft_json {
+            return Some((draft.clone(), editor.base.family.clone()));
         }
-
-        // Apply launch type filter
-        if let Some(ref lt_filter) = self.launch_type_filter {
-            filtered.retain(|s| s.launch_type == *lt_filter);
+        match editor
+            .definition()
+            .and_then(|definition| definition.to_json())
+        {
+            Ok(json) => Some((json, editor.base.family.clone())),
+            Err(e) => {
+                self.status_message = format!("{e:#}");
+                None
+            }
         }
+    }
 
-        // Apply search query
-        if !self.search_query.is_empty() {
-            if self.search_regex_mode {
-                // Use regex matching
-                if let Ok(re) = regex::Regex::new(&self.search_query) {
-                    filtered.retain(|service| {
-                        re.is_match(&service.name)
-                            || re.is_match(&service.status)
-                            || re.is_match(&service.launch_type)
-                    });
-                }
-                // If regex is invalid, keep all results from previous filters
-            } else {
-                // Use simple substring matching (case-insensitive)
-                let query_lower = self.search_query.to_lowercase();
-                filtered.retain(|service| {
-                    service.name.to_lowercase().contains(&query_lower)
-                        || service.status.to_lowercase().contains(&query_lower)
-                        || service.launch_type.to_lowercase().contains(&query_lower)
-                });
+    /// Applies JSON edited in `$EDITOR` to the register dialog.
+    ///
+    /// JSON that doesn't parse is kept, and reopened by the next edit.
+    pub fn apply_task_definition_json(&mut self, edited: Result<String>) {
+        let Some(editor) = self.task_definition_editor.as_mut() else {
+            return;
+        };
+        let json = match edited {
+            Ok(json) => json,
+            Err(e) => {
+                self.status_message = format!("{e:#}");
+                return;
+            }
+        };
+        match TaskDefinitionInfo::from_json(&json) {
+            Ok(definition) => {
+                editor.replace_definition(definition);
+                self.status_message =
+                    "Applied the edited JSON - press Enter to register".to_string();
+            }
+            Err(e) => {
+                editor.draft_json = Some(json);
+                self.status_message = format!("{e:#} - press Ctrl+E to fix it");
             }
         }
-
-        filtered
     }
 
-    /// Returns filtered tasks based on search query, regex mode, and active filters
-    pub fn get_filtered_tasks(&self) -> Vec<TaskInfo> {
-        let mut filtered = self.tasks.clone();
-
-        // Apply task status filter
-        if let Some(ref status_filter) = self.task_status_filter {
-            filtered.retain(|t| t.status == *status_filter);
+    /// Validates the register dialog and asks to confirm registering the new revision.
+    ///
+    /// Problems found keep the dialog open and are listed in it.
+    pub fn submit_task_definition_editor(&mut self) {
+        if self.config.behavior.read_only {
+            self.status_message =
+                "Read-only mode enabled - cannot register task definitions".to_string();
+            self.close_modal();
+            return;
         }
-
-        // Apply search query
-        if !self.search_query.is_empty() {
-            if self.search_regex_mode {
-                // Use regex matching
-                if let Ok(re) = regex::Regex::new(&self.search_query) {
-                    filtered.retain(|task| {
-                        re.is_match(&task.task_id)
-                            || re.is_match(&task.status)
-                            || re.is_match(&task.desired_status)
-                    });
+        let Some(editor) = self.task_definition_editor.as_mut() else {
+            return;
+        };
+        let problems = match editor.definition() {
+            Ok(definition) => {
+                let problems = definition.validate();
+                if problems.is_empty() {
+                    let action = PendingAction::RegisterTaskDefinition {
+                        source: Box::new(editor.source.clone()),
+                        definition: Box::new(definition),
+                        deploy_to: editor.deploy_to(),
+                    };
+                    editor.problems.clear();
+                    self.request_confirmation(action);
+                    return;
                 }
-                // If regex is invalid, keep all results from previous filters
-            } else {
-                // Use simple substring matching (case-insensitive)
-                let query_lower = self.search_query.to_lowercase();
-                filtered.retain(|task| {
-                    task.task_id.to_lowercase().contains(&query_lower)
-                        || task.status.to_lowercase().contains(&query_lower)
-                        || task.desired_status.to_lowercase().contains(&query_lower)
-                });
+                problems
             }
-        }
+            Err(e) => vec![format!("{e:#}")],
+        };
+        self.status_message = match problems.len() {
+            1 => format!("Cannot register: {}", problems[0]),
+            n => format!(
+                "Cannot register: {} (and {} more problems)",
+                problems[0],
+                n - 1
+            ),
+        };
+        editor.problems = problems;
+    }
 
-        filtered
+    /// Opens the export dialog for the selected task definition revision, or the
+    /// selected service in the Services view.
+    ///
+    /// The dialog starts out exporting JSON to a file in `export_dir` named after the
+    /// revision or service.
+    pub fn show_definition_export_dialog(&mut self) {
+        let source = match self.state {
+            AppState::TaskDefinitionRevisions => self
+                .get_filtered_task_definitions()
+                .get(self.selected_index)
+                .map(|definition| DefinitionSource::TaskDefinition(definition.arn.clone())),
+            AppState::TaskDefinitionDetail => self
+                .selected_task_definition
+                .as_ref()
+                .map(|definition| DefinitionSource::TaskDefinition(definition.arn.clone())),
+            AppState::Services => self
+                .selected_cluster
+                .clone()
+                .zip(
+                    self.get_filtered_services()
+                        .get(self.selected_index)
+                        .cloned(),
+                )
+                .map(|(cluster, service)| DefinitionSource::Service {
+                    cluster,
+                    service: service.name,
+                }),
+            _ => None,
+        };
+        let Some(source) = source else {
+            return;
+        };
+        self.definition_export = Some(DefinitionExportDialog::new(
+            &self.config.logs.export_dir,
+            source,
+        ));
+        self.modal_state = ModalState::DefinitionExport;
     }
 
-    /// Returns filtered task definition families based on search query and regex mode
-    pub fn get_filtered_task_definition_families(&self) -> Vec<String> {
-        if self.search_query.is_empty() {
-            return self.task_definition_families.clone();
+    /// Adds a character to the destination path of the definition export dialog.
+    pub fn update_definition_export_path(&mut self, c: char) {
+        if let Some(dialog) = &mut self.definition_export {
+            dialog.path.push(c);
         }
+    }
 
-        if self.search_regex_mode {
-            // Use regex matching
-            if let Ok(re) = regex::Regex::new(&self.search_query) {
-                self.task_definition_families
-                    .iter()
-                    .filter(|family| re.is_match(family))
-                    .cloned()
-                    .collect()
-            } else {
-                // Invalid regex, return all families
-                self.task_definition_families.clone()
-            }
-        } else {
-            // Use simple substring matching (case-insensitive)
-            let query_lower = self.search_query.to_lowercase();
-            self.task_definition_families
-                .iter()
-                .filter(|family| family.to_lowercase().contains(&query_lower))
-                .cloned()
-                .collect()
+    /// Removes the last character from the destination path of the definition export dialog.
+    pub fn delete_definition_export_path_char(&mut self) {
+        if let Some(dialog) = &mut self.definition_export {
+            dialog.path.pop();
         }
     }
 
-    // Modal management methods
-    pub fn show_profile_selector(&mut self) {
-        self.modal_state = ModalState::ProfileSelector;
-        self.modal_selected_index = 0;
-        // Try to find current profile in the list
-        if let Some(idx) = self
-            .available_profiles
-            .iter()
-            .position(|p| p == &self.current_profile)
-        {
-            self.modal_selected_index = idx;
-        }
+    /// Exports the task definition or service of the export dialog.
+    ///
+    /// The revision or service is described in the background and written to the
+    /// dialog's path in the chosen format, without the fields ECS sets itself.
+    pub fn submit_definition_export(&mut self) {
+        let Some(dialog) = self.definition_export.take() else {
+            return;
+        };
+        self.close_modal();
+
+        let client = Arc::clone(&self.ecs_client);
+        let source = dialog.source.clone();
+        self.spawn_action(
+            format!("Exporting {}...", dialog.source.label()),
+            async move {
+                match &source {
+                    DefinitionSource::TaskDefinition(task_definition) => {
+                        client
+                            .describe_task_definition_document(task_definition)
+                            .await
+                    }
+                    DefinitionSource::Service { cluster, service } => {
+                        client.describe_service_document(cluster, service).await
+                    }
+                }
+            },
+            move |app, result| {
+                let written = result
+                    .and_then(|document| {
+                        format_definition(&document, &dialog.source, dialog.format)
+                    })
+                    .and_then(|content| write_export(&dialog.path, &content));
+                app.status_message = match written {
+                    Ok(path) => format!(
+                        "Exported {} ({}) to: {path}",
+                        dialog.source.label(),
+                        dialog.format.label()
+                    ),
+                    Err(e) => format!("Export failed: {e}"),
+                };
+            },
+        );
     }
 
-    pub fn show_region_selector(&mut self) {
-        self.modal_state = ModalState::RegionSelector;
-        self.modal_selected_index = 0;
-        // Try to find current region in the list
-        if let Some(idx) = self
-            .available_regions
-            .iter()
-            .position(|r| r == &self.current_region)
-        {
-            self.modal_selected_index = idx;
+    /// Opens the cleanup dialog for the selected task definition family, or the
+    /// family whose revisions are being browsed.
+    pub fn show_task_definition_cleanup(&mut self) {
+        if self.config.behavior.read_only {
+            self.status_message =
+                "Read-only mode enabled - cannot clean up task definitions".to_string();
+            return;
         }
+        let family = match self.state {
+            AppState::TaskDefinitions => self
+                .get_filtered_task_definition_families()
+                .get(self.selected_index)
+                .cloned(),
+            AppState::TaskDefinitionRevisions => self.selected_task_definition_family.clone(),
+            _ => None,
+        };
+        let Some(family) = family else {
+            return;
+        };
+        self.status_message =
+            format!("Set the rules for cleaning up {family} and press Enter to preview");
+        self.task_definition_cleanup = Some(TaskDefinitionCleanup::new(family));
+        self.modal_state = ModalState::TaskDefinitionCleanup;
     }
 
-    /// Shows the service editor modal for the currently selected service.
+    /// Previews, cleans up or closes the cleanup dialog, depending on its stage.
     ///
-    /// Loads the current service configuration and available task definition revisions.
-    /// Only works when a service is selected in the Services view.
-    pub async fn show_service_editor(&mut self) -> Result<()> {
-        // Block service editing in read-only mode
-        if self.config.behavior.read_only {
-            self.status_message = "Read-only mode enabled - cannot edit services".to_string();
-            return Ok(());
+    /// The revisions the rules select are previewed first; once previewed, the cleanup
+    /// is confirmed and run, and once it has finished the dialog is closed.
+    pub fn submit_task_definition_cleanup(&mut self) {
+        let Some(cleanup) = self.task_definition_cleanup.as_ref() else {
+            return;
+        };
+        if let Some(progress) = &cleanup.progress {
+            if progress.is_finished() {
+                self.close_modal();
+            }
+            return;
+        }
+        match &cleanup.plan {
+            None => self.preview_task_definition_cleanup(),
+            Some(plan) if plan.revisions.is_empty() => {
+                self.status_message =
+                    format!("No revisions of {} match the cleanup rules", cleanup.family);
+            }
+            Some(plan) => {
+                let action = PendingAction::CleanupTaskDefinitions {
+                    family: cleanup.family.clone(),
+                    revisions: plan.revisions.clone(),
+                    delete: cleanup.delete,
+                };
+                self.request_confirmation(action);
+            }
         }
+    }
 
-        // Only allow editing when we have a selected service
-        let (Some(cluster), Some(service_name)) = (
-            self.selected_cluster.as_ref(),
-            self.selected_service.as_ref(),
-        ) else {
-            self.status_message = "No service selected".to_string();
-            return Ok(());
+    /// Selects the revisions matching the rules of the cleanup dialog in the background.
+    ///
+    /// Revisions are listed newest first, and the newest one older than the age rule
+    /// is found by binary search. If in-use revisions are skipped, the services of
+    /// every cluster in the region are described too.
+    fn preview_task_definition_cleanup(&mut self) {
+        let Some(cleanup) = self.task_definition_cleanup.as_ref() else {
+            return;
         };
-
-        // Find the selected service to get current task definition
-        if let Some(service) = self.services.get(self.selected_index) {
-            // Initialize editor with current values
-            self.service_editor_desired_count_input = service.desired_count.to_string();
-            self.service_editor_editing_field = 0; // Start editing desired count
-
-            // Get full service details to extract task definition
-            let (details, _) = self
-                .ecs_client
-                .describe_service(cluster, service_name)
-                .await?;
-
-            // Extract task definition from details
-            if let Some(task_def_line) = details.lines().find(|l| l.starts_with("Task Definition:"))
-            {
-                let task_def = task_def_line
-                    .trim_start_matches("Task Definition:")
-                    .trim()
-                    .to_string();
-
-                self.service_editor_current_task_def = task_def.clone();
-
-                // Extract family name from task definition ARN
-                // Format: arn:aws:ecs:region:account:task-definition/family:revision
-                let family = if let Some(family_part) = task_def.split('/').next_back() {
-                    // Remove revision number (e.g., "my-task:1" -> "my-task")
-                    family_part.split(':').next().unwrap_or(family_part)
+        let (Some(days), Some(keep_latest)) = (cleanup.older_than_days(), cleanup.keep_latest())
+        else {
+            self.status_message =
+                "Enter the age in days and the number of revisions to keep".to_string();
+            return;
+        };
+        let family = cleanup.family.clone();
+        let skip_in_use = cleanup.skip_in_use;
+        let cutoff = chrono::Utc::now().timestamp() - i64::from(days) * 86_400;
+
+        let client = Arc::clone(&self.ecs_client);
+        let family_name = family.clone();
+        self.spawn_request(
+            format!("Selecting revisions of {family} to clean up..."),
+            async move {
+                let revisions = client.list_task_definition_revisions(&family_name).await?;
+                let in_use = if skip_in_use {
+                    client.list_task_definitions_in_use().await?
                 } else {
-                    ""
+                    HashSet::new()
                 };
-
-                // Load available task definition revisions
-                if !family.is_empty() {
-                    self.loading = true;
-                    self.service_editor_available_revisions = self
-                        .ecs_client
-                        .list_task_definition_revisions(family)
+                // The latest revisions are kept whatever their age, so they aren't described
+                let kept = keep_latest.min(revisions.len());
+                let first_old = kept
+                    + first_revision_registered_before(&*client, &revisions[kept..], cutoff)
                         .await?;
-                    self.loading = false;
-
-                    // Find current task def in the list and select it
-                    if let Some(idx) = self
-                        .service_editor_available_revisions
-                        .iter()
-                        .position(|r| r.contains(&task_def))
-                    {
-                        self.service_editor_selected_revision = idx;
+                Ok(plan_cleanup(&revisions, keep_latest, first_old, &in_use))
+            },
+            move |app, result| {
+                // The preview is stale if the rules changed while it was loading
+                let Some(cleanup) = app.task_definition_cleanup.as_mut().filter(|cleanup| {
+                    cleanup.family == family
+                        && cleanup.older_than_days() == Some(days)
+                        && cleanup.keep_latest() == Some(keep_latest)
+                        && cleanup.skip_in_use == skip_in_use
+                }) else {
+                    return;
+                };
+                match result {
+                    Ok(plan) => {
+                        app.status_message = format!(
+                            "{} of {} active revisions of {family} selected (Enter to clean up)",
+                            plan.revisions.len(),
+                            plan.active
+                        );
+                        cleanup.plan = Some(plan);
+                    }
+                    Err(e) => {
+                        app.status_message =
+                            format!("Failed to select revisions of {family} to clean up: {e}")
                     }
                 }
-            }
+            },
+        );
+    }
 
-            self.modal_state = ModalState::ServiceEditor;
-            self.modal_selected_index = 0;
-        }
+    /// Cleans up the next batch of revisions in the background, chaining the
+    /// following batches until none remain.
+    ///
+    /// The progress is shown in the cleanup dialog while it stays open; closing the
+    /// dialog doesn't stop the cleanup.
+    fn run_task_definition_cleanup_batch(
+        &mut self,
+        family: String,
+        mut remaining: Vec<String>,
+        delete: bool,
+        mut progress: CleanupProgress,
+    ) {
+        let batch: Vec<String> = remaining
+            .drain(..remaining.len().min(CLEANUP_BATCH_SIZE))
+            .collect();
+        let size = batch.len();
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_action(
+            format!(
+                "Cleaning up {family}: {} of {} revisions done...",
+                progress.processed, progress.total
+            ),
+            async move { Ok(cleanup_task_definitions(client, batch, delete).await) },
+            move |app, result| {
+                // Cleaning up a batch reports its failures rather than failing
+                progress.record(size, result.unwrap_or_default());
+                if let Some(cleanup) = app
+                    .task_definition_cleanup
+                    .as_mut()
+                    .filter(|cleanup| cleanup.family == family)
+                {
+                    cleanup.progress = Some(progress.clone());
+                }
+                if remaining.is_empty() {
+                    app.finish_task_definition_cleanup(&family, delete, &progress);
+                } else {
+                    app.run_task_definition_cleanup_batch(family, remaining, delete, progress);
+                }
+            },
+        );
+    }
 
-        Ok(())
+    /// Reports the outcome of a finished cleanup and reloads the task definitions.
+    fn finish_task_definition_cleanup(
+        &mut self,
+        family: &str,
+        delete: bool,
+        progress: &CleanupProgress,
+    ) {
+        self.refresh();
+        let mut summary = format!(
+            "Deregistered {} of {} revisions of {family}",
+            progress.deregistered, progress.total
+        );
+        if delete {
+            summary.push_str(&format!(" and deleted {}", progress.deleted));
+        }
+        match progress.failures.first() {
+            None => {
+                self.show_toast(summary.clone(), ToastType::Success);
+                self.status_message = summary;
+            }
+            Some((arn, reason)) => {
+                self.status_message = format!(
+                    "{summary}; {} failed, e.g. {}: {reason}",
+                    progress.failures.len(),
+                    short_task_definition(arn)
+                );
+                self.show_toast(
+                    format!(
+                        "{} revisions of {family} could not be cleaned up",
+                        progress.failures.len()
+                    ),
+                    ToastType::Warning,
+                );
+            }
+        }
     }
 
-    /// Saves changes made in the service editor.
+    pub fn describe(&mut self) {
+        match self.state {
+            AppState::Services => {
+                if let Some(service) = self.services.get(self.selected_index) {
+                    let service_name = service.name.clone();
+                    if let Some(cluster) = self.selected_cluster.clone() {
+                        self.details = None;
+                        self.details_json = None;
+                        self.set_view(AppState::Details);
+
+                        let client = Arc::clone(&self.ecs_client);
+                        self.spawn_request(
+                            format!("Describing service: {service_name}"),
+                            async move {
+                                let (mut formatted, json) =
+                                    client.describe_service(&cluster, &service_name).await?;
+                                // Auto scaling is optional; describe the service without it
+                                // if Application Auto Scaling can't be queried
+                                formatted.push_str("\nAuto Scaling:\n");
+                                match client.get_service_scaling(&cluster, &service_name).await {
+                                    Ok(Some(scaling)) => {
+                                        for line in scaling.describe_lines() {
+                                            formatted.push_str(&format!("  {line}\n"));
+                                        }
+                                    }
+                                    Ok(None) => formatted.push_str("  Not configured\n"),
+                                    Err(e) => formatted.push_str(&format!("  Unavailable: {e}\n")),
+                                }
+                                Ok((formatted, json))
+                            },
+                            |app, result| match result {
+                                Ok((formatted, json)) => {
+                                    app.details = Some(formatted);
+                                    app.details_json = Some(json);
+                                    app.status_message =
+                                        "Service details loaded (Press 'j' for JSON view)"
+                                            .to_string();
+                                }
+                                Err(e) => {
+                                    app.status_message = format!("Error describing service: {e}")
+                                }
+                            },
+                        );
+                    }
+                }
+            }
+            AppState::Tasks => {
+                if let Some(task) = self.tasks.get(self.selected_index) {
+                    let task_arn = task.task_arn.clone();
+                    if let Some(cluster) = self.selected_cluster.clone() {
+                        self.details = None;
+                        self.details_json = None;
+                        self.set_view(AppState::Details);
+                        self.load_task_details(cluster, task_arn);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Asks to confirm the action for the selected item.
     ///
-    /// Applies the modified desired count and/or task definition to the service.
-    pub async fn save_service_changes(&mut self) -> Result<()> {
-        // Block service updates in read-only mode (defensive check)
+    /// In the Services view this restarts the selected service; in the Tasks view
+    /// it stops the selected task. Nothing runs until the user confirms.
+    pub fn execute_action(&mut self) {
+        // Block destructive actions in read-only mode
         if self.config.behavior.read_only {
-            self.status_message = "Read-only mode enabled - cannot save changes".to_string();
-            self.close_modal();
-            return Ok(());
+            self.status_message = "Read-only mode enabled - cannot perform this action".to_string();
+            return;
         }
 
-        let cluster = match &self.selected_cluster {
-            Some(c) => c.clone(),
-            None => {
-                self.status_message = "No cluster selected".to_string();
-                return Ok(());
+        let Some(cluster) = self.selected_cluster.clone() else {
+            return;
+        };
+        let action = match self.state {
+            AppState::Services => match self.get_filtered_services().get(self.selected_index) {
+                Some(service) => PendingAction::RestartService {
+                    cluster,
+                    service: service.name.clone(),
+                },
+                None => return,
+            },
+            AppState::Tasks if self.show_stopped_tasks => {
+                self.status_message = "Task has already stopped".to_string();
+                return;
+            }
+            AppState::ContainerInstances => {
+                match self
+                    .get_filtered_container_instances()
+                    .get(self.selected_index)
+                {
+                    Some(instance) => PendingAction::SetContainerInstanceState {
+                        cluster,
+                        arn: instance.arn.clone(),
+                        ec2_instance_id: instance.ec2_instance_id.clone(),
+                        current_status: instance.status.clone(),
+                        // Draining instances are reactivated, everything else is drained
+                        status: if instance.status == "DRAINING" {
+                            "ACTIVE".to_string()
+                        } else {
+                            "DRAINING".to_string()
+                        },
+                    },
+                    None => return,
+                }
             }
+            AppState::Tasks => match self.get_filtered_tasks().get(self.selected_index) {
+                Some(task) => PendingAction::StopTask {
+                    cluster,
+                    task_arn: task.task_arn.clone(),
+                    task_id: task.task_id.clone(),
+                },
+                None => return,
+            },
+            _ => return,
         };
+        self.request_confirmation(action);
+    }
 
-        let service = match &self.selected_service {
-            Some(s) => s.clone(),
-            None => {
-                self.status_message = "No service selected".to_string();
-                return Ok(());
+    /// Opens the confirmation dialog for a destructive action.
+    ///
+    /// The dialog lists exactly what will change. Actions on resources matching
+    /// `behavior.production_patterns` additionally require typing the resource name.
+    ///
+    /// # Arguments
+    /// * `action` - The action to run once confirmed
+    pub fn request_confirmation(&mut self, action: PendingAction) {
+        let (title, changes, names, resource_name) = match &action {
+            PendingAction::RestartService { cluster, service } => (
+                "Restart Service",
+                vec![
+                    format!("Force new deployment of {service} in cluster {cluster}"),
+                    "All running tasks will be replaced".to_string(),
+                ],
+                vec![cluster.clone(), service.clone()],
+                service.clone(),
+            ),
+            PendingAction::StopTask {
+                cluster, task_id, ..
+            } => {
+                let mut names = vec![cluster.clone()];
+                names.extend(self.selected_service.clone());
+                (
+                    "Stop Task",
+                    vec![format!("Stop task {task_id} in cluster {cluster}")],
+                    names,
+                    task_id.clone(),
+                )
+            }
+            PendingAction::SetContainerInstanceState {
+                cluster,
+                ec2_instance_id,
+                current_status,
+                status,
+                ..
+            } => {
+                let mut changes = vec![format!(
+                    "status {current_status} → {status} on {ec2_instance_id} in cluster {cluster}"
+                )];
+                if status == "DRAINING" {
+                    changes.push(
+                        "No new tasks will be placed; service tasks move to other instances"
+                            .to_string(),
+                    );
+                }
+                (
+                    if status == "DRAINING" {
+                        "Drain Container Instance"
+                    } else {
+                        "Activate Container Instance"
+                    },
+                    changes,
+                    vec![cluster.clone()],
+                    ec2_instance_id.clone(),
+                )
+            }
+            PendingAction::UpdateService {
+                cluster,
+                service,
+                desired_count,
+                task_definition,
+                scaling,
+            } => {
+                let mut changes = Vec::new();
+                if let Some(desired_count) = desired_count {
+                    let current = self
+                        .services
+                        .iter()
+                        .find(|s| &s.name == service)
+                        .map(|s| s.desired_count.to_string())
+                        .unwrap_or_else(|| "?".to_string());
+                    changes.push(format!(
+                        "desired {current} → {desired_count} on {service} in cluster {cluster}"
+                    ));
+                }
+                if let Some(task_definition) = task_definition {
+                    changes.push(format!(
+                        "task definition {} → {} on {service} in cluster {cluster}",
+                        short_task_definition(&self.service_editor_current_task_def),
+                        short_task_definition(task_definition)
+                    ));
+                }
+                if let (Some(update), Some(current)) = (scaling, &self.service_editor_scaling) {
+                    if (update.min_capacity, update.max_capacity)
+                        != (current.min_capacity, current.max_capacity)
+                    {
+                        changes.push(format!(
+                            "auto scaling min {} → {}, max {} → {} on {service} in cluster {cluster}",
+                            current.min_capacity,
+                            update.min_capacity,
+                            current.max_capacity,
+                            update.max_capacity
+                        ));
+                    }
+                    if update.suspended != current.suspended {
+                        changes.push(format!(
+                            "{} auto scaling of {service} in cluster {cluster}",
+                            if update.suspended {
+                                "Suspend"
+                            } else {
+                                "Resume"
+                            }
+                        ));
+                    }
+                }
+                (
+                    "Update Service",
+                    changes,
+                    vec![cluster.clone(), service.clone()],
+                    service.clone(),
+                )
+            }
+            PendingAction::RegisterTaskDefinition {
+                source,
+                definition,
+                deploy_to,
+            } => {
+                let mut changes = vec![format!(
+                    "Register a new revision of {} copied from {}",
+                    definition.family,
+                    source.family_revision()
+                )];
+                let diff = TaskDefinitionDiff::new((**source).clone(), (**definition).clone());
+                let fields = diff.visible_fields();
+                if fields.is_empty() {
+                    changes.push("No settings changed".to_string());
+                }
+                for field in fields.iter().take(MAX_CONFIRMED_FIELD_CHANGES) {
+                    let old = field.old.as_deref().unwrap_or_default();
+                    let new = field.new.as_deref().unwrap_or_default();
+                    changes.push(match field.change() {
+                        FieldChange::Added => format!("+ {}: {new}", field.field),
+                        FieldChange::Removed => format!("- {}: {old}", field.field),
+                        _ => format!("{}: {old} → {new}", field.field),
+                    });
+                }
+                if fields.len() > MAX_CONFIRMED_FIELD_CHANGES {
+                    changes.push(format!(
+                        "... and {} more changes",
+                        fields.len() - MAX_CONFIRMED_FIELD_CHANGES
+                    ));
+                }
+                match deploy_to {
+                    Some((cluster, service)) => {
+                        changes.push(format!(
+                            "Deploy the new revision to {service} in cluster {cluster}"
+                        ));
+                        (
+                            "Register and Deploy Task Definition",
+                            changes,
+                            vec![cluster.clone(), service.clone()],
+                            service.clone(),
+                        )
+                    }
+                    None => (
+                        "Register Task Definition",
+                        changes,
+                        vec![definition.family.clone()],
+                        definition.family.clone(),
+                    ),
+                }
+            }
+            PendingAction::CleanupTaskDefinitions {
+                family,
+                revisions,
+                delete,
+            } => {
+                let mut changes = vec![format!(
+                    "Deregister {} revisions of {family}",
+                    revisions.len()
+                )];
+                changes.extend(
+                    revisions
+                        .iter()
+                        .take(MAX_CONFIRMED_FIELD_CHANGES)
+                        .map(|arn| format!("- {}", short_task_definition(arn))),
+                );
+                if revisions.len() > MAX_CONFIRMED_FIELD_CHANGES {
+                    changes.push(format!(
+                        "... and {} more revisions",
+                        revisions.len() - MAX_CONFIRMED_FIELD_CHANGES
+                    ));
+                }
+                if *delete {
+                    changes.push(
+                        "Delete them permanently once deregistered; this cannot be undone"
+                            .to_string(),
+                    );
+                }
+                (
+                    if *delete {
+                        "Delete Task Definitions"
+                    } else {
+                        "Deregister Task Definitions"
+                    },
+                    changes,
+                    vec![family.clone()],
+                    family.clone(),
+                )
             }
         };
 
-        self.loading = true;
-        self.close_modal();
-
-        // Parse desired count from input
-        let desired_count = self
-            .service_editor_desired_count_input
-            .parse::<i32>()
-            .unwrap_or(0);
-
-        // Get selected task definition revision
-        let task_def = self
-            .service_editor_available_revisions
-            .get(self.service_editor_selected_revision)
-            .cloned();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let required_name = self
+            .config
+            .behavior
+            .is_production(&names)
+            .then_some(resource_name);
+
+        self.confirmation = Some(Confirmation {
+            title: title.to_string(),
+            changes,
+            action,
+            // Typing the name is the safeguard, so confirm is preselected then
+            confirm_selected: required_name.is_some(),
+            required_name,
+            typed_name: String::new(),
+        });
+        self.modal_state = ModalState::Confirm;
+        self.modal_selected_index = 0;
+    }
 
-        // Determine what changes to make
-        let current_service = self.services.get(self.selected_index);
-        let count_changed = current_service
-            .map(|s| s.desired_count != desired_count)
-            .unwrap_or(false);
-        let task_def_changed = task_def
+    /// Returns `true` if the open confirmation dialog requires typing a name.
+    pub fn confirmation_requires_name(&self) -> bool {
+        self.confirmation
             .as_ref()
-            .map(|td| !td.contains(&self.service_editor_current_task_def))
-            .unwrap_or(false);
-
-        // Apply changes
-        if count_changed && task_def_changed {
-            // Update both desired count and task definition
-            // AWS doesn't allow updating both in one call, so we do desired count first
-            self.ecs_client
-                .update_service_desired_count(&cluster, &service, desired_count)
-                .await?;
-
-            if let Some(task_definition) = task_def {
-                self.ecs_client
-                    .update_service_task_definition(&cluster, &service, &task_definition)
-                    .await?;
-            }
-
-            self.status_message = format!(
-                "Updated service {service}: desired count={desired_count}, new task definition"
-            );
-        } else if count_changed {
-            self.ecs_client
-                .update_service_desired_count(&cluster, &service, desired_count)
-                .await?;
-
-            self.status_message =
-                format!("Updated service {service}: desired count={desired_count}");
-        } else if task_def_changed {
-            if let Some(task_definition) = task_def {
-                self.ecs_client
-                    .update_service_task_definition(&cluster, &service, &task_definition)
-                    .await?;
+            .is_some_and(|c| c.required_name.is_some())
+    }
 
-                self.status_message = format!("Updated service {service}: new task definition");
-            }
-        } else {
-            self.status_message = "No changes to apply".to_string();
+    /// Switches the confirmation dialog between its confirm and cancel buttons.
+    pub fn toggle_confirm_selection(&mut self) {
+        if let Some(confirmation) = self.confirmation.as_mut() {
+            confirmation.confirm_selected = !confirmation.confirm_selected;
         }
+    }
 
-        // Refresh the services list to show updated values
-        self.loading = true;
-        self.refresh().await?;
-        self.loading = false;
+    /// Appends a character to the name typed in the confirmation dialog.
+    pub fn update_confirm_input(&mut self, c: char) {
+        if let Some(confirmation) = self.confirmation.as_mut() {
+            confirmation.typed_name.push(c);
+        }
+    }
 
-        Ok(())
+    /// Removes the last character of the name typed in the confirmation dialog.
+    pub fn delete_confirm_char(&mut self) {
+        if let Some(confirmation) = self.confirmation.as_mut() {
+            confirmation.typed_name.pop();
+        }
     }
 
-    /// Shows the port forwarding setup modal for the selected task.
+    /// Runs the action of the confirmation dialog.
     ///
-    /// Allows user to specify local and remote ports for forwarding.
-    pub fn show_port_forwarding_setup(&mut self) {
-        // Only allow port forwarding when we're in the Tasks view with a cluster selected
-        if self.state != AppState::Tasks || self.selected_cluster.is_none() {
-            self.status_message = "No task selected".to_string();
+    /// Keeps the dialog open if a typed name is required and does not match yet.
+    pub fn confirm_action(&mut self) {
+        let Some(confirmation) = self.confirmation.as_ref() else {
             return;
-        }
-
-        // Get the currently highlighted task
-        let filtered_tasks = self.get_filtered_tasks();
-        if self.selected_index >= filtered_tasks.len() {
-            self.status_message = "No task selected".to_string();
+        };
+        if let (false, Some(name)) = (confirmation.name_matches(), &confirmation.required_name) {
+            self.status_message = format!("Type '{name}' to confirm");
             return;
         }
 
-        // Store the selected task for port forwarding
-        self.selected_task = Some(filtered_tasks[self.selected_index].clone());
+        let action = confirmation.action.clone();
+        self.close_modal();
+        self.run_action(action);
+    }
 
-        // Initialize with default ports (local=8080, remote=80)
-        self.port_forward_local_port = "8080".to_string();
-        self.port_forward_remote_port = "80".to_string();
-        self.port_forward_editing_field = 0; // Start editing local port
+    /// Runs a confirmed destructive action as a background action.
+    fn run_action(&mut self, action: PendingAction) {
+        // Defensive check: read-only mode may have been enabled after confirmation was requested
+        if self.config.behavior.read_only {
+            self.status_message = "Read-only mode enabled - cannot perform this action".to_string();
+            return;
+        }
 
-        self.modal_state = ModalState::PortForwardingSetup;
-        self.modal_selected_index = 0;
+        let client = Arc::clone(&self.ecs_client);
+        match action {
+            PendingAction::RestartService { cluster, service } => {
+                let cluster_name = cluster.clone();
+                let name = service.clone();
+                self.spawn_action(
+                    format!("Restarting service: {service}"),
+                    async move { client.restart_service(&cluster, &name).await },
+                    move |app, result| match result {
+                        Ok(()) => {
+                            app.refresh();
+                            app.status_message = format!("Service {service} restarted");
+                            app.start_rollout_monitor(cluster_name, service);
+                        }
+                        Err(e) => {
+                            app.status_message = format!("Failed to restart service {service}: {e}")
+                        }
+                    },
+                );
+            }
+            PendingAction::StopTask {
+                cluster,
+                task_arn,
+                task_id,
+            } => {
+                self.spawn_action(
+                    format!("Stopping task: {task_id}"),
+                    async move { client.stop_task(&cluster, &task_arn).await },
+                    move |app, result| match result {
+                        Ok(()) => {
+                            app.refresh();
+                            app.status_message = format!("Task {task_id} stopped");
+                        }
+                        Err(e) => {
+                            app.status_message = format!("Failed to stop task {task_id}: {e}")
+                        }
+                    },
+                );
+            }
+            PendingAction::SetContainerInstanceState {
+                cluster,
+                arn,
+                ec2_instance_id,
+                status,
+                ..
+            } => {
+                let new_status = status.clone();
+                self.spawn_action(
+                    format!("Setting {ec2_instance_id} to {status}"),
+                    async move {
+                        client
+                            .update_container_instance_state(&cluster, &arn, &new_status)
+                            .await
+                    },
+                    move |app, result| match result {
+                        Ok(()) => {
+                            app.refresh();
+                            app.status_message =
+                                format!("Container instance {ec2_instance_id} set to {status}");
+                        }
+                        Err(e) => {
+                            app.status_message = format!(
+                            "Failed to set container instance {ec2_instance_id} to {status}: {e}"
+                        )
+                        }
+                    },
+                );
+            }
+            PendingAction::UpdateService {
+                cluster,
+                service,
+                desired_count,
+                task_definition,
+                scaling,
+            } => {
+                let service_name = service.clone();
+                let cluster_name = cluster.clone();
+                let task_def_changed = task_definition.is_some();
+                self.spawn_action(
+                    format!("Updating service: {service}"),
+                    async move {
+                        // Capacity limits go first so a new desired count isn't clamped to the old ones
+                        if let Some(update) = scaling {
+                            client
+                                .update_service_scaling(&cluster, &service_name, update)
+                                .await?;
+                        }
+                        // AWS doesn't allow updating both in one call, so we do desired count first
+                        if let Some(desired_count) = desired_count {
+                            client
+                                .update_service_desired_count(
+                                    &cluster,
+                                    &service_name,
+                                    desired_count,
+                                )
+                                .await?;
+                        }
+                        if let Some(task_definition) = task_definition {
+                            client
+                                .update_service_task_definition(
+                                    &cluster,
+                                    &service_name,
+                                    &task_definition,
+                                )
+                                .await?;
+                        }
+                        Ok(())
+                    },
+                    move |app, result| match result {
+                        Ok(()) => {
+                            // Refresh the services list to show updated values
+                            app.refresh();
+                            let mut updates = Vec::new();
+                            if let Some(count) = desired_count {
+                                updates.push(format!("desired count={count}"));
+                            }
+                            if task_def_changed {
+                                updates.push("new task definition".to_string());
+                            }
+                            if let Some(update) = scaling {
+                                updates.push(format!(
+                                    "auto scaling {}-{}{}",
+                                    update.min_capacity,
+                                    update.max_capacity,
+                                    if update.suspended { " (suspended)" } else { "" }
+                                ));
+                            }
+                            app.status_message =
+                                format!("Updated service {service}: {}", updates.join(", "));
+                            // A new task definition starts a deployment worth following
+                            if task_def_changed {
+                                app.start_rollout_monitor(cluster_name, service);
+                            }
+                        }
+                        Err(e) => {
+                            app.status_message = format!("Failed to update service {service}: {e}")
+                        }
+                    },
+                );
+            }
+            PendingAction::RegisterTaskDefinition {
+                source,
+                definition,
+                deploy_to,
+            } => {
+                let family = definition.family.clone();
+                self.spawn_action(
+                    format!("Registering task definition: {family}"),
+                    async move {
+                        let registered = client
+                            .register_task_definition(&source.arn, &definition)
+                            .await?;
+                        // The new revision stays registered if deploying it fails
+                        let deployed = match &deploy_to {
+                            Some((cluster, service)) => Some(
+                                client
+                                    .update_service_task_definition(
+                                        cluster,
+                                        service,
+                                        &registered.arn,
+                                    )
+                                    .await,
+                            ),
+                            None => None,
+                        };
+                        Ok((registered, deploy_to, deployed))
+                    },
+                    move |app, result| match result {
+                        Ok((registered, deploy_to, deployed)) => {
+                            app.refresh();
+                            let name = registered.family_revision();
+                            match (deploy_to, deployed) {
+                                (Some((cluster, service)), Some(Ok(()))) => {
+                                    app.status_message =
+                                        format!("Registered {name} and deployed it to {service}");
+                                    app.start_rollout_monitor(cluster, service);
+                                }
+                                (Some((_, service)), Some(Err(e))) => {
+                                    app.status_message = format!(
+                                        "Registered {name} but failed to deploy it to {service}: {e}"
+                                    );
+                                    app.show_toast(
+                                        format!("Failed to deploy {name} to {service}"),
+                                        ToastType::Error,
+                                    );
+                                }
+                                _ => {
+                                    app.status_message = format!("Registered {name}");
+                                    app.show_toast(format!("Registered {name}"), ToastType::Success);
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            app.status_message =
+                                format!("Failed to register task definition {family}: {e}")
+                        }
+                    },
+                );
+            }
+            PendingAction::CleanupTaskDefinitions {
+                family,
+                revisions,
+                delete,
+            } => {
+                let progress = CleanupProgress::new(revisions.len());
+                self.task_definition_cleanup = Some(TaskDefinitionCleanup {
+                    delete,
+                    progress: Some(progress.clone()),
+                    ..TaskDefinitionCleanup::new(family.clone())
+                });
+                self.modal_state = ModalState::TaskDefinitionCleanup;
+                self.run_task_definition_cleanup_batch(family, revisions, delete, progress);
+            }
+        }
     }
 
-    /// Starts a port forwarding session using AWS SSM.
+    /// Runs a view request on a background task.
     ///
-    /// Opens a local port that forwards to a remote port on the ECS task.
-    /// This runs in a blocking manner - the terminal is suspended while the session is active.
-    pub async fn start_port_forwarding(&mut self) -> Result<()> {
-        let cluster = self
-            .selected_cluster
-            .clone()
-            .ok_or_else(|| anyhow::anyhow!("No cluster selected"))?;
-
-        let task = self
-            .selected_task
-            .clone()
-            .ok_or_else(|| anyhow::anyhow!("No task selected"))?;
-
-        // Parse ports
-        let local_port = self
-            .port_forward_local_port
-            .parse::<u16>()
-            .context("Invalid local port number")?;
-
-        let remote_port = self
-            .port_forward_remote_port
-            .parse::<u16>()
-            .context("Invalid remote port number")?;
-
-        // Start port forwarding session (modal is closed by caller in main.rs)
-        self.ecs_client
-            .start_port_forwarding(
-                &cluster,
-                &task.task_arn,
-                local_port,
-                remote_port,
-                &self.current_region,
-            )
-            .await?;
-
-        Ok(())
+    /// Any previous in-flight view request is cancelled first, so only the most
+    /// recent request for the current view is ever applied. The completion handler
+    /// runs on the UI loop when `process_responses` picks up the result.
+    ///
+    /// # Arguments
+    /// * `status` - Status message shown while the request is in flight
+    /// * `request` - Future performing the AWS calls
+    /// * `on_complete` - Applies the result to the app state
+    pub fn spawn_request<T, Fut, F>(
+        &mut self,
+        status: impl Into<String>,
+        request: Fut,
+        on_complete: F,
+    ) where
+        T: Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        F: FnOnce(&mut App, Result<T>) + Send + 'static,
+    {
+        self.cancel_requests();
+        let generation = self.request_generation;
+        self.loading = true;
+        self.status_message = status.into();
+
+        let tx = self.response_tx.clone();
+        self.in_flight_request = Some(tokio::spawn(async move {
+            let result = request.await;
+            // The receiver only disappears when the app shuts down
+            let _ = tx.send(BackgroundResponse {
+                kind: ResponseKind::Request(generation),
+                apply: Box::new(move |app| on_complete(app, result)),
+            });
+        }));
     }
 
-    pub fn close_modal(&mut self) {
-        self.modal_state = ModalState::None;
-        self.modal_selected_index = 0;
+    /// Runs a mutating action (restart, stop, update) on a background task.
+    ///
+    /// Unlike view requests, actions are never aborted by navigation: once an
+    /// update has been sent to AWS its outcome is always reported to the user.
+    ///
+    /// # Arguments
+    /// * `status` - Status message shown while the action is running
+    /// * `action` - Future performing the AWS calls
+    /// * `on_complete` - Applies the result to the app state
+    pub fn spawn_action<T, Fut, F>(
+        &mut self,
+        status: impl Into<String>,
+        action: Fut,
+        on_complete: F,
+    ) where
+        T: Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        F: FnOnce(&mut App, Result<T>) + Send + 'static,
+    {
+        self.pending_actions += 1;
+        self.loading = true;
+        self.status_message = status.into();
+
+        let tx = self.response_tx.clone();
+        tokio::spawn(async move {
+            let result = action.await;
+            let _ = tx.send(BackgroundResponse {
+                kind: ResponseKind::Action,
+                apply: Box::new(move |app| on_complete(app, result)),
+            });
+        });
     }
 
-    pub fn modal_next(&mut self) {
-        let len = match self.modal_state {
-            ModalState::ProfileSelector => self.available_profiles.len(),
-            ModalState::RegionSelector => self.available_regions.len(),
-            ModalState::ServiceEditor => self.service_editor_available_revisions.len(),
-            ModalState::PortForwardingSetup => 0, // Handled by field navigation
-            ModalState::None => 0,
-        };
-        if len > 0 {
-            self.modal_selected_index = (self.modal_selected_index + 1) % len;
-        }
+    /// Runs a silent background poll, such as the rollout monitor's.
+    ///
+    /// Polls neither show the loading indicator nor change the status message,
+    /// and are not cancelled by navigation.
+    ///
+    /// # Arguments
+    /// * `poll` - Future performing the AWS calls
+    /// * `on_complete` - Applies the result to the app state
+    pub fn spawn_poll<T, Fut, F>(&mut self, poll: Fut, on_complete: F)
+    where
+        T: Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        F: FnOnce(&mut App, Result<T>) + Send + 'static,
+    {
+        let tx = self.response_tx.clone();
+        tokio::spawn(async move {
+            let result = poll.await;
+            let _ = tx.send(BackgroundResponse {
+                kind: ResponseKind::Poll,
+                apply: Box::new(move |app| on_complete(app, result)),
+            });
+        });
     }
 
-    pub fn modal_previous(&mut self) {
-        let len = match self.modal_state {
-            ModalState::ProfileSelector => self.available_profiles.len(),
-            ModalState::RegionSelector => self.available_regions.len(),
-            ModalState::ServiceEditor => self.service_editor_available_revisions.len(),
-            ModalState::PortForwardingSetup => 0, // Handled by field navigation
-            ModalState::None => 0,
-        };
-        if len > 0 {
-            self.modal_selected_index = if self.modal_selected_index == 0 {
-                len - 1
-            } else {
-                self.modal_selected_index - 1
-            };
+    /// Cancels the in-flight view request, if any.
+    ///
+    /// The background task is aborted and the generation counter is bumped so a
+    /// response that was already queued is discarded as well.
+    pub fn cancel_requests(&mut self) {
+        if let Some(handle) = self.in_flight_request.take() {
+            handle.abort();
         }
+        self.request_generation += 1;
+        self.loading = self.pending_actions > 0;
     }
 
-    pub async fn modal_select(&mut self) -> Result<()> {
-        match self.modal_state {
-            ModalState::ProfileSelector => {
-                if let Some(profile) = self.available_profiles.get(self.modal_selected_index) {
-                    self.switch_profile(profile.clone()).await?;
+    /// Applies all background responses that have arrived since the last call.
+    ///
+    /// Called by the event loop on every iteration. Responses from cancelled or
+    /// superseded view requests are dropped.
+    ///
+    /// # Returns
+    /// The number of responses that were applied
+    pub fn process_responses(&mut self) -> usize {
+        let mut applied = 0;
+        while let Ok(response) = self.response_rx.try_recv() {
+            match response.kind {
+                ResponseKind::Request(generation) if generation != self.request_generation => {
+                    continue
+                }
+                ResponseKind::Request(_) => self.in_flight_request = None,
+                ResponseKind::Action => {
+                    self.pending_actions = self.pending_actions.saturating_sub(1)
                 }
+                ResponseKind::Poll => {}
             }
-            ModalState::RegionSelector => {
-                if let Some(region) = self.available_regions.get(self.modal_selected_index) {
-                    self.switch_region(region.clone()).await?;
+            self.loading = self.in_flight_request.is_some() || self.pending_actions > 0;
+            (response.apply)(self);
+            applied += 1;
+        }
+        applied
+    }
+
+    /// Pauses auto-refresh temporarily due to user interaction.
+    ///
+    /// Auto-refresh will automatically resume after 10 seconds.
+    pub fn pause_auto_refresh(&mut self) {
+        self.auto_refresh_paused = true;
+        self.auto_refresh_pause_time = Some(Instant::now());
+    }
+
+    /// Resumes auto-refresh if it was paused.
+    pub fn resume_auto_refresh(&mut self) {
+        self.auto_refresh_paused = false;
+        self.auto_refresh_pause_time = None;
+    }
+
+    /// Determines if auto-refresh should occur.
+    ///
+    /// Auto-refresh is skipped if:
+    /// - Disabled in config
+    /// - A request for the current view is still in flight
+    /// - Paused due to user interaction (and pause hasn't expired)
+    ///
+    /// Auto-refresh pauses automatically resume after 10 seconds.
+    ///
+    /// # Returns
+    /// `true` if refresh should occur, `false` otherwise
+    pub fn should_refresh(&self) -> bool {
+        // Skip auto-refresh if disabled in config
+        if !self.config.behavior.auto_refresh {
+            return false;
+        }
+
+        // Skip auto-refresh while the current view is still loading
+        if self.in_flight_request.is_some() {
+            return false;
+        }
+
+        // Skip auto-refresh if paused and pause hasn't expired
+        if self.auto_refresh_paused {
+            if let Some(pause_time) = self.auto_refresh_pause_time {
+                // Resume auto-refresh after 10 seconds of pause
+                if pause_time.elapsed() < Duration::from_secs(10) {
+                    return false;
                 }
             }
-            ModalState::ServiceEditor => {
-                self.save_service_changes().await?;
+        }
+
+        // Leave logs that are not tailed alone, so that older pages stay loaded
+        if self.state == AppState::Logs && !self.auto_tail {
+            return false;
+        }
+
+        // Logs Insights queries are billed by data scanned, so they only run on request
+        if self.state == AppState::LogInsights {
+            return false;
+        }
+
+        // Revisions are immutable, and reloading them re-describes up to
+        // MAX_TASK_DEFINITION_REVISIONS of them against the DescribeTaskDefinition quota
+        if matches!(
+            self.state,
+            AppState::TaskDefinitionRevisions | AppState::TaskDefinitionDetail
+        ) {
+            return false;
+        }
+
+        // Auto-refresh logs and in-progress rollouts more frequently
+        let fast_refresh = (self.state == AppState::Logs && self.auto_tail)
+            || (self.state == AppState::ServiceEvents && self.rollout_in_progress());
+        let refresh_interval = if fast_refresh {
+            Duration::from_secs(5)
+        } else {
+            Duration::from_secs(self.config.behavior.refresh_interval)
+        };
+        self.last_refresh.elapsed() > refresh_interval
+    }
+
+    /// Shows the logs of the selected task, or of every task of the selected service.
+    pub fn view_logs(&mut self) {
+        if self.state == AppState::Services {
+            if let Some(service) = self.get_filtered_services().get(self.selected_index) {
+                self.selected_service = Some(service.name.clone());
+                self.reset_logs();
+                self.show_service_logs = true;
+                self.logs_opened_from = AppState::Services;
+                self.set_view(AppState::Logs);
+                self.request_logs(LogPageRequest::Latest);
             }
-            ModalState::PortForwardingSetup => {
-                // Port forwarding is handled directly in main.rs event loop
-                // because it needs to suspend/resume the TUI
+        } else if self.state == AppState::Tasks {
+            let task = if self.show_stopped_tasks {
+                self.get_filtered_stopped_tasks()
+                    .get(self.selected_index)
+                    .map(StoppedTaskInfo::task_info)
+            } else {
+                self.get_filtered_tasks().get(self.selected_index).cloned()
+            };
+            if let Some(task) = task {
+                self.selected_task = Some(task);
+                self.reset_logs();
+                self.show_service_logs = false;
+                self.logs_opened_from = AppState::Tasks;
+                self.set_view(AppState::Logs);
+                self.request_logs(LogPageRequest::Latest);
             }
-            ModalState::None => {}
         }
-        Ok(())
     }
 
-    // Profile and region switching
-    pub async fn switch_profile(&mut self, profile: String) -> Result<()> {
-        self.loading = true;
-        self.status_message = format!("Switching to profile: {profile}");
-        self.close_modal();
+    pub fn toggle_auto_tail(&mut self) {
+        self.auto_tail = !self.auto_tail;
+        if self.auto_tail && !self.logs.is_empty() {
+            self.log_scroll = self.logs.len().saturating_sub(1);
+        }
+        // Tailing returns from a jumped-to time, or from older pages that pushed the
+        // newest entries out of the buffer, to the latest logs
+        if self.auto_tail && (self.log_start_time.take().is_some() || self.log_tail_detached) {
+            self.request_logs(LogPageRequest::Latest);
+        }
+        self.status_message = format!(
+            "Auto-tail {}",
+            if self.auto_tail {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        );
+    }
 
-        // Update config and save
-        self.config.aws.profile = Some(profile.clone());
-        self.config.save()?;
+    /// Enters jump-to-time input mode in the Logs view.
+    pub fn enter_log_jump_mode(&mut self) {
+        self.log_jump_mode = true;
+        self.log_jump_input.clear();
+    }
 
-        // Reinitialize AWS client
-        self.ecs_client =
-            EcsClient::new(Some(self.current_region.clone()), Some(profile.clone())).await?;
+    /// Adds a character to the jump-to-time input.
+    pub fn update_log_jump(&mut self, c: char) {
+        self.log_jump_input.push(c);
+    }
 
-        self.current_profile = profile;
+    /// Removes the last character from the jump-to-time input.
+    pub fn delete_log_jump_char(&mut self) {
+        self.log_jump_input.pop();
+    }
 
-        // Clear current data
-        self.clusters.clear();
-        self.services.clear();
-        self.tasks.clear();
-        self.selected_cluster = None;
-        self.selected_service = None;
-        self.selected_task = None;
-        self.details = None;
-        self.logs.clear();
+    /// Leaves jump-to-time input mode without jumping.
+    pub fn cancel_log_jump(&mut self) {
+        self.log_jump_mode = false;
+        self.log_jump_input.clear();
+    }
 
-        // Reset to clusters view
-        self.state = AppState::Clusters;
-        self.selected_index = 0;
+    /// Loads the logs starting at the time typed in jump-to-time input mode.
+    ///
+    /// Accepts times relative to now ("-30m", "-2h") and absolute local times
+    /// ("2026-10-14T09:00"). Auto-tail is disabled so the jumped-to logs stay in view;
+    /// enabling it again returns to the latest logs.
+    pub fn submit_log_jump(&mut self) {
+        self.log_jump_mode = false;
+        let start_time = match parse_log_time(&self.log_jump_input, chrono::Local::now()) {
+            Ok(start_time) => start_time,
+            Err(e) => {
+                self.status_message = e.to_string();
+                return;
+            }
+        };
+        if self.log_source().is_some() {
+            self.log_start_time = Some(start_time);
+            self.auto_tail = false;
+            self.log_scroll = 0;
+            self.request_logs(LogPageRequest::At(start_time));
+        }
+    }
 
-        // Refresh data
-        self.refresh().await?;
-        self.loading = false;
-        self.status_message = format!("Switched to profile: {}", self.current_profile);
+    /// Enters log column editing mode, starting from the current columns.
+    pub fn enter_log_columns_mode(&mut self) {
+        self.log_columns_mode = true;
+        self.log_columns_input = self.log_columns.join(", ");
+    }
 
-        Ok(())
+    /// Adds a character to the log columns input.
+    pub fn update_log_columns(&mut self, c: char) {
+        self.log_columns_input.push(c);
     }
 
-    pub async fn switch_region(&mut self, region: String) -> Result<()> {
-        self.loading = true;
-        self.status_message = format!("Switching to region: {region}");
-        self.close_modal();
+    /// Removes the last character from the log columns input.
+    pub fn delete_log_columns_char(&mut self) {
+        self.log_columns_input.pop();
+    }
 
-        // Update config and save
-        self.config.aws.region = Some(region.clone());
-        self.config.save()?;
+    /// Leaves log column editing mode without changing the columns.
+    pub fn cancel_log_columns(&mut self) {
+        self.log_columns_mode = false;
+        self.log_columns_input.clear();
+    }
 
-        // Reinitialize AWS client
-        self.ecs_client =
-            EcsClient::new(Some(region.clone()), Some(self.current_profile.clone())).await?;
+    /// Shows the comma-separated fields typed in log column editing mode as columns.
+    ///
+    /// JSON log lines then show the column values followed by their message field;
+    /// an empty list shows the raw log lines.
+    pub fn submit_log_columns(&mut self) {
+        self.log_columns_mode = false;
+        self.log_columns = self
+            .log_columns_input
+            .split(',')
+            .map(str::trim)
+            .filter(|column| !column.is_empty())
+            .map(str::to_string)
+            .collect();
+        self.log_columns_input.clear();
+        self.status_message = if self.log_columns.is_empty() {
+            "Showing raw log lines".to_string()
+        } else {
+            format!("Log columns: {}", self.log_columns.join(", "))
+        };
+    }
 
-        self.current_region = region;
+    /// Returns the index, in the filtered logs, of the entry the log entry modal opens.
+    ///
+    /// This is the newest entry while tailing, and the entry at the scroll position otherwise.
+    pub fn selected_log_index(&self, filtered_len: usize) -> Option<usize> {
+        let last = filtered_len.checked_sub(1)?;
+        Some(if self.auto_tail {
+            last
+        } else {
+            self.log_scroll.min(last)
+        })
+    }
 
-        // Clear current data
-        self.clusters.clear();
-        self.services.clear();
-        self.tasks.clear();
-        self.selected_cluster = None;
-        self.selected_service = None;
-        self.selected_task = None;
-        self.details = None;
-        self.logs.clear();
+    /// Returns whether a multi-line entry shows its continuation lines.
+    pub fn is_log_expanded(&self, entry: &LogEntry) -> bool {
+        !entry.continuation.is_empty()
+            && self.expand_all_logs != self.expanded_logs.contains(&log_entry_key(entry))
+    }
 
-        // Reset to clusters view
-        self.state = AppState::Clusters;
-        self.selected_index = 0;
+    /// Expands or collapses the continuation lines of the selected log entry.
+    pub fn toggle_log_expanded(&mut self) {
+        let filtered = self.get_filtered_logs();
+        let Some(entry) = self
+            .selected_log_index(filtered.len())
+            .and_then(|index| filtered.get(index))
+        else {
+            return;
+        };
+        if entry.continuation.is_empty() {
+            self.status_message = "Log entry has a single line".to_string();
+            return;
+        }
+        let key = log_entry_key(entry);
+        if !self.expanded_logs.remove(&key) {
+            self.expanded_logs.insert(key);
+        }
+    }
+
+    /// Expands or collapses the continuation lines of every multi-line log entry.
+    pub fn toggle_all_logs_expanded(&mut self) {
+        self.expand_all_logs = !self.expand_all_logs;
+        self.expanded_logs.clear();
+        self.status_message = if self.expand_all_logs {
+            "Expanded multi-line log entries".to_string()
+        } else {
+            "Collapsed multi-line log entries".to_string()
+        };
+    }
+
+    /// Returns the selected entry of the filtered logs, see `selected_log_index`.
+    fn selected_log_entry(&self) -> Option<LogEntry> {
+        let filtered = self.get_filtered_logs();
+        let index = self.selected_log_index(filtered.len())?;
+        filtered.get(index).cloned()
+    }
+
+    /// Returns whether a log entry is bookmarked.
+    pub fn is_log_bookmarked(&self, entry: &LogEntry) -> bool {
+        let key = log_entry_key(entry);
+        self.log_bookmarks
+            .iter()
+            .any(|bookmark| log_entry_key(bookmark) == key)
+    }
+
+    /// Bookmarks the selected log entry, or removes its bookmark.
+    pub fn toggle_log_bookmark(&mut self) {
+        let Some(entry) = self.selected_log_entry() else {
+            return;
+        };
+        let key = log_entry_key(&entry);
+        if let Some(index) = self
+            .log_bookmarks
+            .iter()
+            .position(|bookmark| log_entry_key(bookmark) == key)
+        {
+            self.log_bookmarks.remove(index);
+            self.status_message = "Bookmark removed".to_string();
+            return;
+        }
+        let index = self
+            .log_bookmarks
+            .partition_point(|bookmark| bookmark.timestamp <= entry.timestamp);
+        self.log_bookmarks.insert(index, entry);
+        self.status_message = format!(
+            "Bookmarked entry ({} bookmarks, B lists them)",
+            self.log_bookmarks.len()
+        );
+    }
+
+    /// Opens the list of bookmarked log entries.
+    pub fn show_log_bookmarks(&mut self) {
+        if self.log_bookmarks.is_empty() {
+            self.status_message = "No bookmarks (press b to bookmark a log entry)".to_string();
+            return;
+        }
+        self.modal_state = ModalState::LogBookmarks;
+        self.modal_selected_index = 0;
+    }
+
+    /// Selects the first bookmark after the selected entry, wrapping to the first.
+    pub fn next_log_bookmark(&mut self) {
+        let Some(selected) = self.selected_log_entry() else {
+            return;
+        };
+        let index = self
+            .log_bookmarks
+            .iter()
+            .position(|bookmark| bookmark.timestamp > selected.timestamp)
+            .unwrap_or(0);
+        self.jump_to_log_bookmark(index);
+    }
+
+    /// Selects the last bookmark before the selected entry, wrapping to the last.
+    pub fn previous_log_bookmark(&mut self) {
+        let Some(selected) = self.selected_log_entry() else {
+            return;
+        };
+        let index = self
+            .log_bookmarks
+            .iter()
+            .rposition(|bookmark| bookmark.timestamp < selected.timestamp)
+            .unwrap_or(self.log_bookmarks.len().saturating_sub(1));
+        self.jump_to_log_bookmark(index);
+    }
+
+    /// Selects a bookmarked entry, loading the logs around it when it is no longer
+    /// in the buffer.
+    pub fn jump_to_log_bookmark(&mut self, index: usize) {
+        let Some(bookmark) = self.log_bookmarks.get(index).cloned() else {
+            self.status_message = "No bookmarks (press b to bookmark a log entry)".to_string();
+            return;
+        };
+        let key = log_entry_key(&bookmark);
+        let position = format!("Bookmark {} of {}", index + 1, self.log_bookmarks.len());
+        if let Some(found) = self
+            .get_filtered_logs()
+            .iter()
+            .position(|log| log_entry_key(log) == key)
+        {
+            self.auto_tail = false;
+            self.log_scroll = found;
+            self.status_message = position;
+        } else if self.logs.iter().any(|log| log_entry_key(log) == key) {
+            self.status_message = format!("{position} is hidden by the current filters");
+        } else {
+            self.load_log_context(&bookmark, self.show_service_logs);
+        }
+    }
+
+    /// Shows the logs of every container of the selected entry's task from
+    /// `context_minutes` before to `context_minutes` after it.
+    ///
+    /// With `all_tasks`, the logs of every task of the service are shown instead,
+    /// switching task logs to service-wide logs.
+    pub fn show_log_context(&mut self, all_tasks: bool) {
+        if self.state != AppState::Logs {
+            return;
+        }
+        if let Some(entry) = self.selected_log_entry() {
+            self.load_log_context(&entry, all_tasks);
+        }
+    }
+
+    /// Loads the logs around an entry and selects it once they are loaded.
+    fn load_log_context(&mut self, entry: &LogEntry, all_tasks: bool) {
+        let Some(cluster) = self.selected_cluster.clone() else {
+            return;
+        };
+        let window = i64::from(self.config.logs.context_minutes.max(1)) * 60 * 1000;
+        let (start_time, end_time) = (entry.timestamp - window, entry.timestamp + window);
+        let client = Arc::clone(&self.ecs_client);
+
+        if all_tasks || self.show_service_logs {
+            let Some(service) = self.selected_service.clone() else {
+                self.status_message = "No service selected for service-wide logs".to_string();
+                return;
+            };
+            self.show_service_logs = true;
+            // Service-wide context of one task keeps to the entry's task
+            self.log_task_filter = if all_tasks {
+                None
+            } else {
+                entry.task_id.clone()
+            };
+            self.prepare_log_context(entry, start_time);
+            let limit = self.config.logs.max_buffer_lines.max(1);
+            self.spawn_request(
+                "Loading log context...",
+                async move {
+                    client
+                        .get_service_logs(&cluster, &service, start_time, Some(end_time), limit)
+                        .await
+                },
+                move |app, result| match result {
+                    Ok(logs) => {
+                        app.service_log_window_start = Some(start_time);
+                        app.replace_logs(LogPage {
+                            entries: logs.entries,
+                            cursors: Vec::new(),
+                            unavailable: Vec::new(),
+                        });
+                        if logs.truncated {
+                            app.note_truncated_service_logs(limit);
+                        }
+                    }
+                    Err(e) => app.status_message = format!("Error loading logs: {e}"),
+                },
+            );
+        } else if let Some(task) = self.selected_task.clone() {
+            self.prepare_log_context(entry, start_time);
+            self.spawn_request(
+                "Loading log context...",
+                async move {
+                    fetch_task_log_context(client, &cluster, &task.task_arn, start_time, end_time)
+                        .await
+                },
+                move |app, result| match result {
+                    Ok(page) => app.replace_logs(page),
+                    Err(e) => app.status_message = format!("Error loading logs: {e}"),
+                },
+            );
+        }
+    }
+
+    /// Stops tailing at the start of a context window and selects `entry` once it loads.
+    fn prepare_log_context(&mut self, entry: &LogEntry, start_time: i64) {
+        self.log_start_time = Some(start_time);
+        self.auto_tail = false;
+        self.log_scroll = 0;
+        self.log_select_after_load = Some(log_entry_key(entry));
+    }
+
+    /// Opens the log entry modal for the selected log entry.
+    pub fn show_log_entry_detail(&mut self) {
+        let filtered = self.get_filtered_logs();
+        if let Some(index) = self.selected_log_index(filtered.len()) {
+            self.log_detail = filtered.get(index).cloned();
+            self.modal_state = ModalState::LogEntry;
+            self.modal_selected_index = 0;
+        }
+    }
+
+    /// Returns the lines of the log entry modal: the entry's metadata and well-known
+    /// fields, followed by the full log line with JSON pretty-printed.
+    pub fn log_entry_detail_lines(&self) -> Vec<String> {
+        let Some(entry) = &self.log_detail else {
+            return Vec::new();
+        };
+        let format_time = |timestamp: i64| {
+            chrono::DateTime::from_timestamp_millis(timestamp)
+                .map(|dt| {
+                    dt.with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M:%S%.3f")
+                        .to_string()
+                })
+                .unwrap_or_default()
+        };
+
+        let mut lines = vec![
+            format!("Time:      {}", format_time(entry.timestamp)),
+            format!("Container: {}", entry.container_name),
+        ];
+        if let Some(task_id) = &entry.task_id {
+            lines.push(format!("Task:      {task_id}"));
+        }
+        lines.push(format!("Level:     {:?}", entry.level));
+        if let Some(log) = &entry.structured {
+            if let Some(timestamp) = log.timestamp {
+                lines.push(format!("Logged at: {}", format_time(timestamp)));
+            }
+            if let Some(logger) = &log.logger {
+                lines.push(format!("Logger:    {logger}"));
+            }
+            if let Some(trace_id) = &log.trace_id {
+                lines.push(format!("Trace ID:  {trace_id}"));
+            }
+        }
+        lines.push(String::new());
+        let text = pretty_json(&entry.message).unwrap_or_else(|| entry.full_message());
+        lines.extend(text.lines().map(str::to_string));
+        lines
+    }
+
+    /// Enters log search mode.
+    pub fn enter_log_search_mode(&mut self) {
+        self.log_search_mode = true;
+        self.log_search_query.clear();
+        self.status_message = "Log search: ".to_string();
+    }
+
+    /// Exits log search mode.
+    pub fn exit_log_search_mode(&mut self) {
+        self.log_search_mode = false;
+        let filtered_count = self.get_filtered_logs().len();
+        self.status_message = format!(
+            "Found {} logs matching '{}'",
+            filtered_count, self.log_search_query
+        );
+    }
+
+    /// Updates the log search query.
+    ///
+    /// Adds a character to the log search query for filtering displayed logs.
+    pub fn update_log_search(&mut self, c: char) {
+        self.log_search_query.push(c);
+    }
+
+    /// Deletes the last character from the log search query.
+    pub fn delete_log_search_char(&mut self) {
+        self.log_search_query.pop();
+    }
+
+    /// Clears the log search query.
+    pub fn clear_log_search(&mut self) {
+        self.log_search_mode = false;
+        self.log_search_query.clear();
+        self.status_message = "Log search cleared".to_string();
+    }
+
+    /// Enters find mode, which highlights matches in the Logs view without filtering.
+    pub fn enter_log_find_mode(&mut self) {
+        self.log_find_mode = true;
+        self.log_find_query.clear();
+        self.status_message = format!(
+            "Find ({}): ",
+            if self.search_regex_mode {
+                "regex"
+            } else {
+                "text"
+            }
+        );
+    }
+
+    /// Adds a character to the find query.
+    pub fn update_log_find(&mut self, c: char) {
+        self.log_find_query.push(c);
+    }
+
+    /// Removes the last character from the find query.
+    pub fn delete_log_find_char(&mut self) {
+        self.log_find_query.pop();
+    }
+
+    /// Leaves find mode and clears the find query and its highlights.
+    pub fn cancel_log_find(&mut self) {
+        self.log_find_mode = false;
+        self.log_find_query.clear();
+        self.status_message = "Find cleared".to_string();
+    }
+
+    /// Leaves find mode and selects the match at or before the selected entry, which
+    /// is the newest match while tailing.
+    pub fn submit_log_find(&mut self) {
+        self.log_find_mode = false;
+        if self.log_find_query.is_empty() {
+            self.status_message = "Find cleared".to_string();
+            return;
+        }
+        if self.log_find_regex().is_none() {
+            self.status_message = format!("Invalid regex: {}", self.log_find_query);
+            return;
+        }
+        let logs = self.get_filtered_logs();
+        let matches = self.log_find_matches(&logs);
+        let selected = self.selected_log_index(logs.len()).unwrap_or(0);
+        let target = matches
+            .iter()
+            .rev()
+            .find(|&&index| index <= selected)
+            .or(matches.last())
+            .copied();
+        self.select_log_match(target, &matches);
+    }
+
+    /// Selects the next entry matching the find query, wrapping to the first.
+    pub fn next_log_match(&mut self) {
+        self.step_log_match(true);
+    }
+
+    /// Selects the previous entry matching the find query, wrapping to the last.
+    pub fn previous_log_match(&mut self) {
+        self.step_log_match(false);
+    }
+
+    fn step_log_match(&mut self, forward: bool) {
+        if self.log_find_query.is_empty() {
+            self.status_message = "No find query (press s to find)".to_string();
+            return;
+        }
+        let logs = self.get_filtered_logs();
+        let matches = self.log_find_matches(&logs);
+        let Some(selected) = self.selected_log_index(logs.len()) else {
+            return;
+        };
+        let target = if forward {
+            matches
+                .iter()
+                .find(|&&index| index > selected)
+                .or(matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&index| index < selected)
+                .or(matches.last())
+        };
+        self.select_log_match(target.copied(), &matches);
+    }
+
+    /// Scrolls to a matching entry, stopping auto-tail so it stays selected.
+    fn select_log_match(&mut self, target: Option<usize>, matches: &[usize]) {
+        let Some(index) = target else {
+            self.status_message = format!("No matches for '{}'", self.log_find_query);
+            return;
+        };
+        self.auto_tail = false;
+        self.log_scroll = index;
+        let position = matches.iter().position(|&m| m == index).unwrap_or(0) + 1;
+        self.status_message = format!(
+            "Match {position} of {} for '{}'",
+            matches.len(),
+            self.log_find_query
+        );
+    }
+
+    /// Returns the regex of the find query: the query itself in regex mode, and the
+    /// literal text matched case-insensitively otherwise. `None` if there is no query
+    /// or the regex is invalid.
+    pub fn log_find_regex(&self) -> Option<regex::Regex> {
+        if self.log_find_query.is_empty() {
+            return None;
+        }
+        let pattern = if self.search_regex_mode {
+            self.log_find_query.clone()
+        } else {
+            format!("(?i){}", regex::escape(&self.log_find_query))
+        };
+        regex::Regex::new(&pattern).ok()
+    }
+
+    /// Returns the indices of the entries whose message or continuation lines match
+    /// the find query.
+    pub fn log_find_matches(&self, logs: &[LogEntry]) -> Vec<usize> {
+        let Some(regex) = self.log_find_regex() else {
+            return Vec::new();
+        };
+        logs.iter()
+            .enumerate()
+            .filter(|(_, log)| {
+                regex.is_match(&log.message)
+                    || log.continuation.iter().any(|line| regex.is_match(line))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns the find status shown in the footer: the query, the position of the
+    /// selected entry among the matching entries and the number of matches.
+    pub fn log_find_status(&self) -> Option<String> {
+        if self.state != AppState::Logs || (!self.log_find_mode && self.log_find_query.is_empty()) {
+            return None;
+        }
+        let kind = if self.search_regex_mode {
+            "Find regex"
+        } else {
+            "Find"
+        };
+        if self.log_find_mode {
+            return Some(format!("{kind}: {}_", self.log_find_query));
+        }
+        let Some(regex) = self.log_find_regex() else {
+            return Some(format!("{kind}: {} (invalid)", self.log_find_query));
+        };
+        let logs = self.get_filtered_logs();
+        let matches = self.log_find_matches(&logs);
+        let count: usize = matches
+            .iter()
+            .map(|&index| {
+                let log = &logs[index];
+                regex.find_iter(&log.message).count()
+                    + log
+                        .continuation
+                        .iter()
+                        .map(|line| regex.find_iter(line).count())
+                        .sum::<usize>()
+            })
+            .sum();
+        let position = self
+            .selected_log_index(logs.len())
+            .filter(|_| !self.auto_tail)
+            .and_then(|selected| matches.iter().position(|&index| index == selected))
+            .map_or_else(|| "-".to_string(), |position| (position + 1).to_string());
+        Some(format!(
+            "{kind}: {} ({position}/{} entries, {count} matches)",
+            self.log_find_query,
+            matches.len()
+        ))
+    }
+
+    /// Cycles to the next log level filter.
+    ///
+    /// Cycles through: None -> Debug -> Info -> Warn -> Error -> Fatal -> None
+    pub fn cycle_log_level_filter(&mut self) {
+        self.log_level_filter = match self.log_level_filter {
+            None => Some(LogLevel::Debug),
+            Some(LogLevel::Debug) => Some(LogLevel::Info),
+            Some(LogLevel::Info) => Some(LogLevel::Warn),
+            Some(LogLevel::Warn) => Some(LogLevel::Error),
+            Some(LogLevel::Error) => Some(LogLevel::Fatal),
+            Some(LogLevel::Fatal) => None,
+            Some(LogLevel::Unknown) => None,
+        };
+
+        let filter_msg = match &self.log_level_filter {
+            None => "all levels".to_string(),
+            Some(level) => format!("{level:?}"),
+        };
+        self.status_message = format!("Log filter: {filter_msg}");
+    }
+
+    /// Returns the IDs of the tasks that produced the entries of service-wide logs, sorted.
+    pub fn log_task_ids(&self) -> Vec<&str> {
+        let mut task_ids: Vec<&str> = self
+            .logs
+            .iter()
+            .filter_map(|log| log.task_id.as_deref())
+            .collect();
+        task_ids.sort_unstable();
+        task_ids.dedup();
+        task_ids
+    }
+
+    /// Cycles service-wide logs through showing a single task, and back to all tasks.
+    pub fn cycle_log_task_filter(&mut self) {
+        if !self.show_service_logs {
+            return;
+        }
+        let task_ids = self.log_task_ids();
+        let next = match &self.log_task_filter {
+            None => task_ids.first(),
+            Some(current) => task_ids
+                .iter()
+                .position(|id| id == current)
+                .and_then(|index| task_ids.get(index + 1)),
+        }
+        .map(|id| id.to_string());
+
+        self.status_message = match &next {
+            Some(task_id) => format!("Showing logs of task {task_id}"),
+            None => "Showing logs of all tasks".to_string(),
+        };
+        self.log_task_filter = next;
+        self.log_scroll = if self.auto_tail {
+            self.get_filtered_logs().len().saturating_sub(1)
+        } else {
+            0
+        };
+    }
+
+    /// Returns filtered logs based on search query and level filter.
+    ///
+    /// Groups multi-line entries, then applies the task, level and search filters.
+    /// The result is cached until the logs, the filters or the multi-line settings
+    /// change, since it is drawn on every frame.
+    pub fn get_filtered_logs(&self) -> Rc<[LogEntry]> {
+        let mut cache = self.filtered_logs.borrow_mut();
+        match cache.as_ref() {
+            Some(filtered) if filtered.is_current(self) => Rc::clone(&filtered.logs),
+            _ => {
+                let logs: Rc<[LogEntry]> = filter_log_entries(
+                    self.logs.clone(),
+                    &self.config.logs,
+                    self.log_level_filter.as_ref(),
+                    self.log_task_filter.as_deref(),
+                    &self.log_search_query,
+                )
+                .into();
+                *cache = Some(FilteredLogs {
+                    level_filter: self.log_level_filter.clone(),
+                    task_filter: self.log_task_filter.clone(),
+                    search_query: self.log_search_query.clone(),
+                    multiline_pattern: self.config.logs.multiline_pattern.clone(),
+                    multiline_patterns: self.config.logs.multiline_patterns.clone(),
+                    logs: Rc::clone(&logs),
+                });
+                logs
+            }
+        }
+    }
+
+    /// Discards the cached filtered logs after `logs` changed.
+    fn logs_changed(&mut self) {
+        *self.filtered_logs.get_mut() = None;
+    }
+
+    /// Opens the log export dialog for the logs shown in the Logs view.
+    ///
+    /// The dialog starts out exporting the filtered, loaded entries as plain text to
+    /// a timestamped file in `export_dir`.
+    pub fn show_log_export_dialog(&mut self) {
+        if self.state != AppState::Logs {
+            return;
+        }
+        let (_, source) = self.log_export_source();
+        self.log_export = Some(LogExportDialog::new(&self.config.logs.export_dir, &source));
+        self.modal_state = ModalState::LogExport;
+    }
+
+    /// Returns "Task" or "Service" and the task ID or service name of the shown logs.
+    fn log_export_source(&self) -> (&'static str, String) {
+        let (label, source) = if self.show_service_logs {
+            ("Service", self.selected_service.as_deref())
+        } else {
+            (
+                "Task",
+                self.selected_task.as_ref().map(|t| t.task_id.as_str()),
+            )
+        };
+        (label, source.unwrap_or("unknown").to_string())
+    }
+
+    /// Adds a character to the destination path of the log export dialog.
+    pub fn update_log_export_path(&mut self, c: char) {
+        if let Some(dialog) = &mut self.log_export {
+            dialog.path.push(c);
+        }
+    }
+
+    /// Removes the last character from the destination path of the log export dialog.
+    pub fn delete_log_export_path_char(&mut self) {
+        if let Some(dialog) = &mut self.log_export {
+            dialog.path.pop();
+        }
+    }
+
+    /// Exports logs with the options of the log export dialog.
+    ///
+    /// Loaded entries are written right away. With full history, the task's older
+    /// pages (at most `MAX_EXPORT_PAGES`), or the service's logs over the whole time
+    /// range (at most `MAX_EXPORT_EVENTS`), are fetched in the background first; the
+    /// filters in effect now are applied to them.
+    pub fn submit_log_export(&mut self) {
+        let Some(dialog) = self.log_export.take() else {
+            return;
+        };
+        self.close_modal();
+
+        let (source_label, source) = self.log_export_source();
+        let mut header = ExportHeader {
+            source_label: source_label.to_string(),
+            source,
+            filters: Vec::new(),
+        };
+        let level_filter = self
+            .log_level_filter
+            .clone()
+            .filter(|_| dialog.apply_filters);
+        let task_filter = self
+            .log_task_filter
+            .clone()
+            .filter(|_| dialog.apply_filters);
+        let search_query = if dialog.apply_filters {
+            self.log_search_query.clone()
+        } else {
+            String::new()
+        };
+        if let Some(level) = &level_filter {
+            header.filters.push(format!("Level filter: {level:?}"));
+        }
+        if let Some(task_id) = &task_filter {
+            header.filters.push(format!("Task filter: {task_id}"));
+        }
+        if !search_query.is_empty() {
+            header.filters.push(format!("Search query: {search_query}"));
+        }
+        let start_time = dialog
+            .range
+            .start_time(chrono::Utc::now().timestamp_millis());
+        if start_time.is_some() {
+            header
+                .filters
+                .push(format!("Time range: {}", dialog.range.label()));
+        }
+
+        if !dialog.full_history {
+            let logs = filter_log_entries(
+                self.logs.clone(),
+                &self.config.logs,
+                level_filter.as_ref(),
+                task_filter.as_deref(),
+                &search_query,
+            );
+            self.write_log_export(&dialog, &header, logs, start_time);
+            return;
+        }
+
+        let (Some(cluster), Some(source)) = (self.selected_cluster.clone(), self.log_source())
+        else {
+            return;
+        };
+        let client = Arc::clone(&self.ecs_client);
+        let show_service_logs = self.show_service_logs;
+        self.spawn_action(
+            "Exporting log history...",
+            async move {
+                if show_service_logs {
+                    client
+                        .get_service_logs(
+                            &cluster,
+                            &source,
+                            start_time.unwrap_or(0),
+                            None,
+                            MAX_EXPORT_EVENTS,
+                        )
+                        .await
+                } else {
+                    let entries =
+                        fetch_task_log_history(client, &cluster, &source, start_time).await?;
+                    Ok(ServiceLogs {
+                        entries,
+                        truncated: false,
+                    })
+                }
+            },
+            move |app, result| match result {
+                Ok(logs) => {
+                    let entries = filter_log_entries(
+                        logs.entries,
+                        &app.config.logs,
+                        level_filter.as_ref(),
+                        task_filter.as_deref(),
+                        &search_query,
+                    );
+                    app.write_log_export(&dialog, &header, entries, start_time);
+                    if logs.truncated {
+                        app.status_message.push_str(&format!(
+                            "; stopped at the first {MAX_EXPORT_EVENTS} events of the history"
+                        ));
+                    }
+                }
+                Err(e) => app.status_message = format!("Export failed: {e}"),
+            },
+        );
+    }
+
+    /// Writes the entries of a log export from `start_time` on to the dialog's path.
+    fn write_log_export(
+        &mut self,
+        dialog: &LogExportDialog,
+        header: &ExportHeader,
+        mut logs: Vec<LogEntry>,
+        start_time: Option<i64>,
+    ) {
+        if let Some(start_time) = start_time {
+            logs.retain(|log| log.timestamp >= start_time);
+        }
+        let content = format_logs(
+            &logs,
+            dialog.format,
+            header,
+            self.config.logs.show_timestamps,
+        );
+        self.status_message = match write_export(&dialog.path, &content) {
+            Ok(path) => format!(
+                "Exported {} log entries ({}) to: {path}",
+                logs.len(),
+                dialog.format.label()
+            ),
+            Err(e) => format!("Export failed: {e}"),
+        };
+    }
+
+    /// Opens the Logs Insights view for the service or task whose logs are shown.
+    ///
+    /// Finds the log groups of the service's or task's containers in the background
+    /// and starts editing the query, which is the last one run, the first saved
+    /// query, or `DEFAULT_INSIGHTS_QUERY`.
+    pub fn view_log_insights(&mut self) {
+        if self.state != AppState::Logs {
+            return;
+        }
+        let Some(cluster) = self.selected_cluster.clone() else {
+            return;
+        };
+        if self.insights_query.is_empty() {
+            self.insights_query = self
+                .config
+                .logs
+                .saved_queries
+                .first()
+                .map(|saved| saved.query.clone())
+                .unwrap_or_else(|| DEFAULT_INSIGHTS_QUERY.to_string());
+        }
+        self.insights_log_groups.clear();
+        self.insights_run = None;
+        self.insights_editing = true;
+
+        let client = Arc::clone(&self.ecs_client);
+        let on_complete = |app: &mut App, result: Result<Vec<String>>| match result {
+            Ok(log_groups) if log_groups.is_empty() => {
+                app.status_message =
+                    "No container logs to CloudWatch Logs with the awslogs or FireLens driver"
+                        .to_string();
+            }
+            Ok(log_groups) => {
+                app.status_message = format!(
+                    "Querying {} log group(s); Enter runs the query",
+                    log_groups.len()
+                );
+                app.insights_log_groups = log_groups;
+            }
+            Err(e) => app.status_message = format!("Error finding log groups: {e}"),
+        };
+        if self.show_service_logs {
+            let Some(service) = self.selected_service.clone() else {
+                return;
+            };
+            self.set_view(AppState::LogInsights);
+            self.spawn_request(
+                "Finding log groups...",
+                async move { client.get_service_log_groups(&cluster, &service).await },
+                on_complete,
+            );
+        } else if let Some(task) = self.selected_task.clone() {
+            self.set_view(AppState::LogInsights);
+            self.spawn_request(
+                "Finding log groups...",
+                async move { client.get_task_log_groups(&cluster, &task.task_arn).await },
+                on_complete,
+            );
+        }
+    }
+
+    /// Returns the results of the last Logs Insights query, once polled.
+    pub fn insights_results(&self) -> Option<&InsightsQueryResults> {
+        self.insights_run
+            .as_ref()
+            .and_then(|run| run.results.as_ref())
+    }
+
+    /// Starts editing the Logs Insights query.
+    pub fn enter_insights_edit_mode(&mut self) {
+        self.insights_editing = true;
+    }
+
+    /// Stops editing the Logs Insights query without running it.
+    pub fn exit_insights_edit_mode(&mut self) {
+        self.insights_editing = false;
+    }
+
+    /// Adds a character to the Logs Insights query.
+    pub fn update_insights_query(&mut self, c: char) {
+        self.insights_query.push(c);
+    }
+
+    /// Removes the last character from the Logs Insights query.
+    pub fn delete_insights_query_char(&mut self) {
+        self.insights_query.pop();
+    }
+
+    /// Cycles the time range Logs Insights queries run over (1h/6h/24h/7d).
+    pub fn cycle_insights_time_range(&mut self) {
+        self.insights_time_range = self.insights_time_range.next();
+        self.status_message = format!(
+            "Logs Insights time range: last {}; Enter runs the query",
+            self.insights_time_range.label()
+        );
+    }
+
+    /// Runs the Logs Insights query over the log groups of the service or task.
+    ///
+    /// A query that is still running is stopped first. Queries over a task's logs are
+    /// limited to the task's log streams. Results are polled by `poll_insights_query`.
+    pub fn run_insights_query(&mut self) {
+        self.insights_editing = false;
+        let query = self.insights_query.trim().to_string();
+        if query.is_empty() {
+            self.status_message = "Type a Logs Insights query first (/ to edit)".to_string();
+            return;
+        }
+        if self.insights_log_groups.is_empty() {
+            self.status_message = "No CloudWatch log groups to query".to_string();
+            return;
+        }
+        self.stop_insights_query();
+
+        // Stream names end with the task ID, so this keeps the task's events only
+        let query = match (&self.selected_task, self.show_service_logs) {
+            (Some(task), false) => format!("filter @logStream like /{}/ | {query}", task.task_id),
+            _ => query,
+        };
+        let end_time = chrono::Utc::now().timestamp_millis();
+        let start_time = end_time - i64::from(self.insights_time_range.minutes()) * 60 * 1000;
+
+        let client = Arc::clone(&self.ecs_client);
+        let log_groups = self.insights_log_groups.clone();
+        self.spawn_request(
+            "Starting Logs Insights query...",
+            async move {
+                client
+                    .start_insights_query(&log_groups, &query, start_time, end_time)
+                    .await
+            },
+            move |app, result| match result {
+                Ok(query_id) => {
+                    app.insights_run = Some(InsightsRun {
+                        query_id,
+                        started_at: Instant::now(),
+                        last_poll: None,
+                        poll_in_flight: false,
+                        results: None,
+                    });
+                    app.selected_index = 0;
+                    app.status_message = "Logs Insights query running...".to_string();
+                }
+                Err(e) => app.status_message = format!("Error starting Logs Insights query: {e}"),
+            },
+        );
+    }
+
+    /// Stops the Logs Insights query if it is still running and discards its results.
+    fn stop_insights_query(&mut self) {
+        let Some(run) = self.insights_run.take() else {
+            return;
+        };
+        if run
+            .results
+            .as_ref()
+            .is_some_and(InsightsQueryResults::is_finished)
+        {
+            return;
+        }
+        // Best effort: a query that cannot be stopped times out on its own
+        let client = Arc::clone(&self.ecs_client);
+        self.spawn_poll(
+            async move { client.stop_insights_query(&run.query_id).await },
+            |_, _| {},
+        );
+    }
+
+    /// Polls the results of the running Logs Insights query if a poll is due.
+    ///
+    /// Called by the event loop on every iteration. Partial results are shown while
+    /// the query runs; polling stops once it completes, fails or is cancelled.
+    pub fn poll_insights_query(&mut self) {
+        if self.state != AppState::LogInsights {
+            return;
+        }
+        let Some(run) = self.insights_run.as_mut() else {
+            return;
+        };
+        if run.poll_in_flight
+            || run
+                .results
+                .as_ref()
+                .is_some_and(InsightsQueryResults::is_finished)
+            || run
+                .last_poll
+                .is_some_and(|last_poll| last_poll.elapsed() < INSIGHTS_POLL_INTERVAL)
+        {
+            return;
+        }
+        run.poll_in_flight = true;
+        run.last_poll = Some(Instant::now());
+
+        let client = Arc::clone(&self.ecs_client);
+        let query_id = run.query_id.clone();
+        self.spawn_poll(
+            {
+                let query_id = query_id.clone();
+                async move { client.get_insights_query_results(&query_id).await }
+            },
+            move |app, result| {
+                // Ignore polls for a query that was replaced or stopped
+                let Some(run) = app
+                    .insights_run
+                    .as_mut()
+                    .filter(|run| run.query_id == query_id)
+                else {
+                    return;
+                };
+                run.poll_in_flight = false;
+
+                let results = match result {
+                    Ok(results) => results,
+                    Err(e) => {
+                        app.status_message = format!("Error polling Logs Insights query: {e}");
+                        return;
+                    }
+                };
+                let elapsed = format_duration(run.started_at.elapsed());
+                app.status_message = match results.status.as_str() {
+                    "Complete" => format!(
+                        "Query complete in {elapsed}: {} rows, {:.0} of {:.0} records matched",
+                        results.rows.len(),
+                        results.records_matched,
+                        results.records_scanned
+                    ),
+                    "Scheduled" | "Running" => format!(
+                        "Logs Insights query running ({elapsed}, {:.0} records scanned)...",
+                        results.records_scanned
+                    ),
+                    status => format!("Logs Insights query ended: {status}"),
+                };
+                run.results = Some(results);
+            },
+        );
+    }
+
+    /// Loads the next saved query from the config into the query editor.
+    pub fn load_next_saved_query(&mut self) {
+        let saved_queries = &self.config.logs.saved_queries;
+        let index = self
+            .insights_saved_index
+            .map_or(0, |index| (index + 1) % saved_queries.len().max(1));
+        let Some(SavedQuery { name, query }) = saved_queries.get(index) else {
+            self.status_message =
+                "No saved queries; S saves the current query to the config file".to_string();
+            return;
+        };
+        self.insights_query = query.clone();
+        self.insights_saved_index = Some(index);
+        self.status_message = format!(
+            "Saved query {}/{}: {name}; Enter runs it",
+            index + 1,
+            saved_queries.len()
+        );
+    }
+
+    /// Saves the Logs Insights query to the config file.
+    ///
+    /// The query is named "Query N"; names can be changed in the config file.
+    pub fn save_insights_query(&mut self) {
+        let query = self.insights_query.trim().to_string();
+        if query.is_empty() {
+            self.status_message = "Type a Logs Insights query first (/ to edit)".to_string();
+            return;
+        }
+        let saved_queries = &mut self.config.logs.saved_queries;
+        if let Some(saved) = saved_queries.iter().find(|saved| saved.query == query) {
+            self.status_message = format!("Query is already saved as '{}'", saved.name);
+            return;
+        }
+        let saved = SavedQuery {
+            name: format!("Query {}", saved_queries.len() + 1),
+            query,
+        };
+        let name = saved.name.clone();
+        // Only the query is written: the in-memory config holds command line overrides
+        let result = Config::append_saved_query(&saved);
+        saved_queries.push(saved);
+        self.insights_saved_index = Some(saved_queries.len() - 1);
+        self.status_message = match result {
+            Ok(()) => format!("Saved query as '{name}' in the config file"),
+            Err(e) => format!("Failed to save query: {e}"),
+        };
+    }
+
+    /// Fetches and displays CloudWatch metrics for the selected service or task.
+    ///
+    /// Switches to the Metrics view and loads CPU and Memory utilization metrics
+    /// for the currently selected service in the background. From the Tasks view,
+    /// loads the Container Insights metrics of the selected running or stopped task.
+    pub fn view_metrics(&mut self) {
+        if self.state == AppState::Tasks {
+            let task = if self.show_stopped_tasks {
+                self.get_filtered_stopped_tasks()
+                    .get(self.selected_index)
+                    .map(StoppedTaskInfo::task_info)
+            } else {
+                self.get_filtered_tasks().get(self.selected_index).cloned()
+            };
+            if let (Some(task), Some(cluster)) = (task, self.selected_cluster.clone()) {
+                let task_arn = task.task_arn.clone();
+                self.selected_task = Some(task);
+                self.task_metrics = None;
+                self.show_task_metrics = true;
+                self.metrics_scroll = 0;
+                self.set_view(AppState::Metrics);
+
+                let time_range = TimeRange::from_minutes(self.config.metrics.time_range_minutes);
+                self.load_task_metrics(cluster, task_arn, time_range);
+            }
+        } else if self.state == AppState::Services {
+            if let Some(service) = self.services.get(self.selected_index) {
+                let service_name = service.name.clone();
+                if let Some(cluster) = self.selected_cluster.clone() {
+                    self.selected_service = Some(service_name.clone());
+                    self.metrics = None;
+                    self.show_task_metrics = false;
+                    self.metrics_scroll = 0;
+                    self.set_view(AppState::Metrics);
+
+                    let time_range =
+                        TimeRange::from_minutes(self.config.metrics.time_range_minutes);
+                    self.load_metrics(cluster, service_name, time_range);
+                }
+            }
+        }
+    }
+
+    /// Shows the deployments and event stream of the selected service.
+    ///
+    /// Switches to the ServiceEvents view and loads the data in the background.
+    /// While a rollout is in progress the view auto-refreshes every 5 seconds.
+    pub fn view_service_events(&mut self) {
+        if self.state == AppState::Services {
+            if let Some(service) = self.get_filtered_services().get(self.selected_index) {
+                let service_name = service.name.clone();
+                if let Some(cluster) = self.selected_cluster.clone() {
+                    self.selected_service = Some(service_name.clone());
+                    self.service_deployments.clear();
+                    self.service_events.clear();
+                    self.set_view(AppState::ServiceEvents);
+                    self.load_service_events(cluster, service_name);
+                }
+            }
+        }
+    }
+
+    /// Starts following the deployment of a service that was just restarted or updated.
+    ///
+    /// Replaces any rollout that was being monitored. The first poll happens on the
+    /// next iteration of the event loop.
+    pub fn start_rollout_monitor(&mut self, cluster: String, service: String) {
+        self.show_toast(format!("Monitoring rollout of {service}"), ToastType::Info);
+        self.rollout_monitor = Some(RolloutMonitor::new(cluster, service, Instant::now()));
+    }
+
+    /// Polls the monitored service if a rollout is being followed and a poll is due.
+    ///
+    /// Called by the event loop on every iteration.
+    pub fn poll_rollout(&mut self) {
+        let Some(monitor) = self.rollout_monitor.as_mut() else {
+            return;
+        };
+        let now = Instant::now();
+        if !monitor.is_due(now) {
+            return;
+        }
+        monitor.poll_in_flight = true;
+        monitor.last_poll = Some(now);
+
+        let client = Arc::clone(&self.ecs_client);
+        let cluster = monitor.cluster.clone();
+        let service = monitor.service.clone();
+        self.spawn_poll(
+            async move { client.get_service_events(&cluster, &service).await },
+            move |app, result| {
+                // Ignore polls for a rollout that is no longer monitored
+                let Some(monitor) = app.rollout_monitor.as_mut() else {
+                    return;
+                };
+                if monitor.started_at > now {
+                    return;
+                }
+                monitor.poll_in_flight = false;
+
+                match result {
+                    Ok(activity) => app.apply_rollout_snapshot(activity),
+                    Err(e) => {
+                        app.status_message = format!("Rollout monitor: failed to poll service: {e}")
+                    }
+                }
+            },
+        );
+    }
+
+    /// Applies a polled snapshot to the rollout monitor and reports its outcome.
+    fn apply_rollout_snapshot(&mut self, activity: ServiceActivity) {
+        let Some(monitor) = self.rollout_monitor.as_mut() else {
+            return;
+        };
+        let service = monitor.service.clone();
+        let elapsed = format_duration(monitor.started_at.elapsed());
+        let outcome = monitor.update(&activity, Instant::now());
+
+        // Keep the events view in sync when it shows the monitored service
+        if self.state == AppState::ServiceEvents
+            && self.selected_service.as_deref() == Some(service.as_str())
+        {
+            self.service_deployments = activity.deployments;
+            self.service_events = activity.events;
+        }
+
+        let Some(outcome) = outcome else {
+            return;
+        };
+        let (message, toast_type) = match &outcome {
+            RolloutOutcome::Completed => (
+                format!("Deployment of {service} completed in {elapsed}"),
+                ToastType::Success,
+            ),
+            RolloutOutcome::Failed(reason) => (
+                format!("Deployment of {service} failed: {reason}"),
+                ToastType::Error,
+            ),
+            RolloutOutcome::RolledBack(reason) => (
+                format!("Deployment of {service} rolled back: {reason}"),
+                ToastType::Error,
+            ),
+            RolloutOutcome::Stalled => (
+                format!(
+                    "Deployment of {service} appears stalled: no progress for {}",
+                    format_duration(ROLLOUT_STALL_TIMEOUT)
+                ),
+                ToastType::Warning,
+            ),
+        };
+        self.status_message = message.clone();
+        self.show_toast(message, toast_type);
+        if outcome.is_final() {
+            self.rollout_monitor = None;
+        }
+    }
+
+    /// Shows a toast notification for `TOAST_DURATION`.
+    pub fn show_toast(&mut self, message: impl Into<String>, toast_type: ToastType) {
+        self.toast = Some(Toast {
+            message: message.into(),
+            toast_type,
+            created_at: Instant::now(),
+        });
+    }
+
+    /// Returns the toast to display, if one was raised less than `TOAST_DURATION` ago.
+    pub fn active_toast(&self) -> Option<&Toast> {
+        self.toast
+            .as_ref()
+            .filter(|toast| toast.created_at.elapsed() < TOAST_DURATION)
+    }
+
+    /// Returns `true` if any deployment of the selected service is still rolling out.
+    pub fn rollout_in_progress(&self) -> bool {
+        self.service_deployments
+            .iter()
+            .any(|d| d.rollout_state == "IN_PROGRESS")
+    }
+
+    /// Cycles to the next time range for metrics view.
+    ///
+    /// Changes the time range (1h -> 6h -> 24h -> 7d -> 1h) and refreshes metrics.
+    pub fn cycle_metrics_time_range(&mut self) {
+        if self.state == AppState::Metrics && self.show_task_metrics {
+            if let (Some(metrics), Some(task)) = (&self.task_metrics, &self.selected_task) {
+                let new_time_range = metrics.time_range.next();
+                let cluster_name = metrics.cluster_name.clone();
+                let task_arn = task.task_arn.clone();
+                self.load_task_metrics(cluster_name, task_arn, new_time_range);
+            }
+        } else if self.state == AppState::Metrics {
+            if let Some(metrics) = &self.metrics {
+                let new_time_range = metrics.time_range.next();
+                let cluster_name = metrics.cluster_name.clone();
+                let service_name = metrics.service_name.clone();
+                self.load_metrics(cluster_name, service_name, new_time_range);
+            }
+        }
+    }
+
+    /// Starts an interactive ECS Exec session with the selected task.
+    ///
+    /// Opens an interactive shell (/bin/sh) in the selected task's container using AWS ECS Exec.
+    /// This requires:
+    /// - The task must have `enableExecuteCommand` enabled
+    /// - The `session-manager-plugin` must be installed locally
+    /// - Appropriate IAM permissions for ECS ExecuteCommand and SSM StartSession
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No task is currently selected
+    /// - The task doesn't have ECS Exec enabled
+    /// - The session-manager-plugin is not installed
+    /// - The AWS API call fails
+    /// - The session fails to start
+    ///
+    /// # Important
+    /// This method spawns the session-manager-plugin subprocess which takes over
+    /// the terminal. The caller (main.rs) must handle suspending/resuming the TUI
+    /// by disabling raw mode before calling this and re-enabling after.
+    pub async fn exec_into_task(&mut self) -> Result<()> {
+        if self.state != AppState::Tasks {
+            return Ok(());
+        }
+
+        if let Some(task) = self.tasks.get(self.selected_index) {
+            if let Some(cluster) = &self.selected_cluster {
+                let task_arn = task.task_arn.clone();
+                let task_id = task.task_id.clone();
+                let cluster_name = cluster.clone();
+
+                // Check if task has exec enabled
+                self.loading = true;
+                self.status_message = format!("Checking ECS Exec capability for task: {task_id}");
+
+                match self
+                    .ecs_client
+                    .check_task_exec_enabled(&cluster_name, &task_arn)
+                    .await
+                {
+                    Ok(false) => {
+                        self.loading = false;
+                        self.status_message = format!(
+                            "Task {task_id} does not have ECS Exec enabled. \
+                            Ensure the task definition and service have enableExecuteCommand=true"
+                        );
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        self.loading = false;
+                        self.status_message = format!("Error checking ECS Exec: {e}");
+                        return Err(e);
+                    }
+                    Ok(true) => {
+                        // Continue - exec is enabled
+                    }
+                }
+
+                self.loading = false;
+                self.status_message = format!("Starting ECS Exec session for task: {task_id}...");
+
+                // Get the AWS region from current region
+                let region = self.current_region.clone();
+
+                // Start the exec session (this will block until session ends)
+                match self
+                    .ecs_client
+                    .start_exec_session(&cluster_name, &task_arn, None, &region)
+                    .await
+                {
+                    Ok(()) => {
+                        self.status_message = format!("ECS Exec session ended for task: {task_id}");
+                    }
+                    Err(e) => {
+                        self.status_message = format!("ECS Exec session failed: {e}");
+                        return Err(e);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Search methods
+    pub fn enter_search_mode(&mut self) {
+        self.search_mode = true;
+        self.search_query.clear();
+        self.selected_index = 0;
+    }
+
+    pub fn exit_search_mode(&mut self) {
+        self.search_mode = false;
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search_mode = false;
+        self.search_query.clear();
+        self.selected_index = 0;
+    }
+
+    pub fn update_search(&mut self, c: char) {
+        self.search_query.push(c);
+        self.selected_index = 0;
+    }
+
+    pub fn delete_search_char(&mut self) {
+        self.search_query.pop();
+        self.selected_index = 0;
+    }
+
+    /// Toggles regex mode for search queries
+    pub fn toggle_regex_mode(&mut self) {
+        self.search_regex_mode = !self.search_regex_mode;
+        self.status_message = format!(
+            "Regex mode: {}",
+            if self.search_regex_mode { "ON" } else { "OFF" }
+        );
+    }
+
+    /// Cycles through service status filters (None -> ACTIVE -> DRAINING -> None)
+    pub fn cycle_service_status_filter(&mut self) {
+        self.service_status_filter = match &self.service_status_filter {
+            None => Some("ACTIVE".to_string()),
+            Some(status) if status == "ACTIVE" => Some("DRAINING".to_string()),
+            _ => None,
+        };
+        let filter_msg = match &self.service_status_filter {
+            None => "all statuses".to_string(),
+            Some(status) => status.clone(),
+        };
+        self.status_message = format!("Service status filter: {filter_msg}");
+    }
+
+    /// Cycles through task status filters (None -> RUNNING -> PENDING -> STOPPED -> None)
+    pub fn cycle_task_status_filter(&mut self) {
+        self.task_status_filter = match &self.task_status_filter {
+            None => Some("RUNNING".to_string()),
+            Some(status) if status == "RUNNING" => Some("PENDING".to_string()),
+            Some(status) if status == "PENDING" => Some("STOPPED".to_string()),
+            _ => None,
+        };
+        let filter_msg = match &self.task_status_filter {
+            None => "all statuses".to_string(),
+            Some(status) => status.clone(),
+        };
+        self.status_message = format!("Task status filter: {filter_msg}");
+    }
+
+    /// Cycles through launch type filters (None -> FARGATE -> EC2 -> EXTERNAL -> None)
+    pub fn cycle_launch_type_filter(&mut self) {
+        self.launch_type_filter = match &self.launch_type_filter {
+            None => Some("FARGATE".to_string()),
+            Some(lt) if lt == "FARGATE" => Some("EC2".to_string()),
+            Some(lt) if lt == "EC2" => Some("EXTERNAL".to_string()),
+            _ => None,
+        };
+        let filter_msg = match &self.launch_type_filter {
+            None => "all types".to_string(),
+            Some(lt) => lt.clone(),
+        };
+        self.status_message = format!("Launch type filter: {filter_msg}");
+    }
+
+    /// Clears all active filters
+    pub fn clear_all_filters(&mut self) {
+        self.service_status_filter = None;
+        self.task_status_filter = None;
+        self.launch_type_filter = None;
+        self.status_message = "All filters cleared".to_string();
+    }
+
+    /// Returns the statistics of a cluster, if DescribeClusters reported it.
+    pub fn cluster_info(&self, name: &str) -> Option<&ClusterInfo> {
+        self.cluster_details.iter().find(|info| info.name == name)
+    }
+
+    /// Returns whether any of `haystacks` matches the search query.
+    ///
+    /// In regex mode the query is a regular expression, and an invalid one matches
+    /// everything so a half-typed pattern doesn't empty the list; otherwise it is a
+    /// case-insensitive substring. An empty query matches everything.
+    fn matches_search(&self, haystacks: &[&str]) -> bool {
+        if self.search_query.is_empty() {
+            return true;
+        }
+        if !self.search_regex_mode {
+            let query_lower = self.search_query.to_lowercase();
+            return haystacks
+                .iter()
+                .any(|haystack| haystack.to_lowercase().contains(&query_lower));
+        }
+
+        // Filters call this for every row, so the regex is only compiled once per query
+        let mut cache = self.search_regex_cache.borrow_mut();
+        if cache
+            .as_ref()
+            .is_none_or(|(query, _)| *query != self.search_query)
+        {
+            *cache = Some((
+                self.search_query.clone(),
+                regex::Regex::new(&self.search_query).ok(),
+            ));
+        }
+        match cache.as_ref().and_then(|(_, regex)| regex.as_ref()) {
+            Some(regex) => haystacks.iter().any(|haystack| regex.is_match(haystack)),
+            None => true,
+        }
+    }
+
+    /// Returns filtered clusters based on search query and regex mode
+    pub fn get_filtered_clusters(&self) -> Vec<String> {
+        if self.search_query.is_empty() {
+            return self.clusters.clone();
+        }
+
+        if self.search_regex_mode {
+            // Use regex matching
+            if let Ok(re) = regex::Regex::new(&self.search_query) {
+                self.clusters
+                    .iter()
+                    .filter(|cluster| re.is_match(cluster))
+                    .cloned()
+                    .collect()
+            } else {
+                // Invalid regex, return all clusters
+                self.clusters.clone()
+            }
+        } else {
+            // Use simple substring matching (case-insensitive)
+            let query_lower = self.search_query.to_lowercase();
+            self.clusters
+                .iter()
+                .filter(|cluster| cluster.to_lowercase().contains(&query_lower))
+                .cloned()
+                .collect()
+        }
+    }
+
+    /// Returns filtered services based on search query, regex mode, and active filters
+    pub fn get_filtered_services(&self) -> Vec<ServiceInfo> {
+        let mut filtered = self.services.clone();
+
+        // Apply status filter
+        if let Some(ref status_filter) = self.service_status_filter {
+            filtered.retain(|s| s.status == *status_filter);
+        }
+
+        // Apply launch type filter
+        if let Some(ref lt_filter) = self.launch_type_filter {
+            filtered.retain(|s| s.launch_type == *lt_filter);
+        }
+
+        // Apply search query
+        if !self.search_query.is_empty() {
+            if self.search_regex_mode {
+                // Use regex matching
+                if let Ok(re) = regex::Regex::new(&self.search_query) {
+                    filtered.retain(|service| {
+                        re.is_match(&service.name)
+                            || re.is_match(&service.status)
+                            || re.is_match(&service.launch_type)
+                    });
+                }
+                // If regex is invalid, keep all results from previous filters
+            } else {
+                // Use simple substring matching (case-insensitive)
+                let query_lower = self.search_query.to_lowercase();
+                filtered.retain(|service| {
+                    service.name.to_lowercase().contains(&query_lower)
+                        || service.status.to_lowercase().contains(&query_lower)
+                        || service.launch_type.to_lowercase().contains(&query_lower)
+                });
+            }
+        }
+
+        filtered
+    }
+
+    /// Returns filtered tasks based on search query, regex mode, and active filters
+    pub fn get_filtered_tasks(&self) -> Vec<TaskInfo> {
+        let mut filtered = self.tasks.clone();
+
+        // Apply task status filter
+        if let Some(ref status_filter) = self.task_status_filter {
+            filtered.retain(|t| t.status == *status_filter);
+        }
+
+        // Apply search query
+        if !self.search_query.is_empty() {
+            if self.search_regex_mode {
+                // Use regex matching
+                if let Ok(re) = regex::Regex::new(&self.search_query) {
+                    filtered.retain(|task| {
+                        re.is_match(&task.task_id)
+                            || re.is_match(&task.status)
+                            || re.is_match(&task.desired_status)
+                    });
+                }
+                // If regex is invalid, keep all results from previous filters
+            } else {
+                // Use simple substring matching (case-insensitive)
+                let query_lower = self.search_query.to_lowercase();
+                filtered.retain(|task| {
+                    task.task_id.to_lowercase().contains(&query_lower)
+                        || task.status.to_lowercase().contains(&query_lower)
+                        || task.desired_status.to_lowercase().contains(&query_lower)
+                });
+            }
+        }
+
+        filtered
+    }
+
+    /// Returns filtered container instances based on the search query and regex mode.
+    ///
+    /// Matches EC2 instance IDs, container instance IDs, and statuses.
+    pub fn get_filtered_container_instances(&self) -> Vec<ContainerInstanceInfo> {
+        let mut filtered = self.container_instances.clone();
+        if self.search_query.is_empty() {
+            return filtered;
+        }
+
+        if self.search_regex_mode {
+            // Use regex matching
+            if let Ok(re) = regex::Regex::new(&self.search_query) {
+                filtered.retain(|instance| {
+                    re.is_match(&instance.ec2_instance_id)
+                        || re.is_match(instance.id())
+                        || re.is_match(&instance.status)
+                });
+            }
+            // If regex is invalid, keep all results
+        } else {
+            // Use simple substring matching (case-insensitive)
+            let query_lower = self.search_query.to_lowercase();
+            filtered.retain(|instance| {
+                instance
+                    .ec2_instance_id
+                    .to_lowercase()
+                    .contains(&query_lower)
+                    || instance.id().to_lowercase().contains(&query_lower)
+                    || instance.status.to_lowercase().contains(&query_lower)
+            });
+        }
+
+        filtered
+    }
+
+    /// Returns filtered stopped tasks based on the search query and regex mode.
+    ///
+    /// Matches task IDs, stop codes, stopped reasons, and container exit reasons.
+    pub fn get_filtered_stopped_tasks(&self) -> Vec<StoppedTaskInfo> {
+        let mut filtered = self.stopped_tasks.clone();
+        if self.search_query.is_empty() {
+            return filtered;
+        }
+
+        let fields = |task: &StoppedTaskInfo| {
+            let mut fields = vec![
+                task.task_id.clone(),
+                task.stop_code.clone(),
+                task.stopped_reason.clone(),
+            ];
+            fields.extend(task.containers.iter().map(|c| c.reason.clone()));
+            fields
+        };
+
+        if self.search_regex_mode {
+            // Use regex matching
+            if let Ok(re) = regex::Regex::new(&self.search_query) {
+                filtered.retain(|task| fields(task).iter().any(|f| re.is_match(f)));
+            }
+            // If regex is invalid, keep all results
+        } else {
+            // Use simple substring matching (case-insensitive)
+            let query_lower = self.search_query.to_lowercase();
+            filtered.retain(|task| {
+                fields(task)
+                    .iter()
+                    .any(|f| f.to_lowercase().contains(&query_lower))
+            });
+        }
+
+        filtered
+    }
+
+    /// Returns filtered task definition families based on search query and regex mode
+    pub fn get_filtered_task_definition_families(&self) -> Vec<String> {
+        if self.search_query.is_empty() {
+            return self.task_definition_families.clone();
+        }
+
+        if self.search_regex_mode {
+            // Use regex matching
+            if let Ok(re) = regex::Regex::new(&self.search_query) {
+                self.task_definition_families
+                    .iter()
+                    .filter(|family| re.is_match(family))
+                    .cloned()
+                    .collect()
+            } else {
+                // Invalid regex, return all families
+                self.task_definition_families.clone()
+            }
+        } else {
+            // Use simple substring matching (case-insensitive)
+            let query_lower = self.search_query.to_lowercase();
+            self.task_definition_families
+                .iter()
+                .filter(|family| family.to_lowercase().contains(&query_lower))
+                .cloned()
+                .collect()
+        }
+    }
+
+    /// Returns the revisions of the selected family filtered by search query and regex mode.
+    ///
+    /// Matches revision numbers, statuses, registering principals and container images.
+    pub fn get_filtered_task_definitions(&self) -> Vec<TaskDefinitionInfo> {
+        let mut filtered = self.task_definitions.clone();
+        filtered.retain(|revision| {
+            let family_revision = revision.family_revision();
+            let mut fields = vec![
+                family_revision.as_str(),
+                &revision.status,
+                revision.registered_by.as_deref().unwrap_or_default(),
+            ];
+            fields.extend(
+                revision
+                    .containers
+                    .iter()
+                    .map(|container| container.image.as_str()),
+            );
+            self.matches_search(&fields)
+        });
+        filtered
+    }
+
+    /// Returns service events filtered by search query and regex mode
+    pub fn get_filtered_service_events(&self) -> Vec<ServiceEvent> {
+        if self.search_query.is_empty() {
+            return self.service_events.clone();
+        }
+
+        if self.search_regex_mode {
+            // Use regex matching
+            if let Ok(re) = regex::Regex::new(&self.search_query) {
+                self.service_events
+                    .iter()
+                    .filter(|event| re.is_match(&event.message))
+                    .cloned()
+                    .collect()
+            } else {
+                // Invalid regex, return all events
+                self.service_events.clone()
+            }
+        } else {
+            // Use simple substring matching (case-insensitive)
+            let query_lower = self.search_query.to_lowercase();
+            self.service_events
+                .iter()
+                .filter(|event| event.message.to_lowercase().contains(&query_lower))
+                .cloned()
+                .collect()
+        }
+    }
+
+    // Modal management methods
+    pub fn show_profile_selector(&mut self) {
+        self.modal_state = ModalState::ProfileSelector;
+        self.modal_selected_index = 0;
+        // Try to find current profile in the list
+        if let Some(idx) = self
+            .available_profiles
+            .iter()
+            .position(|p| p == &self.current_profile)
+        {
+            self.modal_selected_index = idx;
+        }
+    }
+
+    pub fn show_region_selector(&mut self) {
+        self.modal_state = ModalState::RegionSelector;
+        self.modal_selected_index = 0;
+        // Try to find current region in the list
+        if let Some(idx) = self
+            .available_regions
+            .iter()
+            .position(|r| r == &self.current_region)
+        {
+            self.modal_selected_index = idx;
+        }
+    }
+
+    /// Shows the service editor modal for the currently selected service.
+    ///
+    /// Loads the current service configuration and available task definition revisions
+    /// in the background and opens the modal once they arrive.
+    /// Only works when a service is selected in the Services view.
+    pub fn show_service_editor(&mut self) {
+        // Block service editing in read-only mode
+        if self.config.behavior.read_only {
+            self.status_message = "Read-only mode enabled - cannot edit services".to_string();
+            return;
+        }
+
+        // Only allow editing when we have a selected cluster and service
+        let (Some(cluster), Some(service)) = (
+            self.selected_cluster.clone(),
+            self.get_filtered_services()
+                .get(self.selected_index)
+                .cloned(),
+        ) else {
+            self.status_message = "No service selected".to_string();
+            return;
+        };
+        self.selected_service = Some(service.name.clone());
+
+        let client = Arc::clone(&self.ecs_client);
+        let service_name = service.name.clone();
+        self.spawn_request(
+            format!("Loading service configuration: {service_name}"),
+            async move {
+                // Get full service details to extract task definition
+                let (details, _) = client.describe_service(&cluster, &service_name).await?;
+                let task_def = details_task_definition(&details);
+
+                // Load available task definition revisions for the family
+                let revisions = match task_def.as_deref().map(task_definition_family) {
+                    Some(family) if !family.is_empty() => {
+                        client.list_task_definition_revisions(family).await?
+                    }
+                    _ => Vec::new(),
+                };
+                let scaling = client.get_service_scaling(&cluster, &service_name).await;
+                Ok((task_def, revisions, scaling))
+            },
+            move |app, result| match result {
+                Ok((task_def, revisions, scaling)) => {
+                    // Initialize editor with current values
+                    app.service_editor_desired_count_input = service.desired_count.to_string();
+                    app.service_editor_editing_field = 0; // Start editing desired count
+                    app.service_editor_current_task_def = task_def.unwrap_or_default();
+                    app.service_editor_available_revisions = revisions;
+
+                    // Find current task def in the list and select it
+                    app.service_editor_selected_revision = app
+                        .service_editor_available_revisions
+                        .iter()
+                        .position(|r| r.contains(&app.service_editor_current_task_def))
+                        .unwrap_or(0);
+
+                    // The editor still works for desired count and task definition
+                    // when Application Auto Scaling can't be queried
+                    let scaling_error = scaling.as_ref().err().map(|e| e.to_string());
+                    app.service_editor_scaling = scaling.ok().flatten();
+                    if let Some(scaling) = &app.service_editor_scaling {
+                        app.service_editor_min_capacity_input = scaling.min_capacity.to_string();
+                        app.service_editor_max_capacity_input = scaling.max_capacity.to_string();
+                        app.service_editor_scaling_suspended = scaling.suspended;
+                    }
+
+                    app.modal_state = ModalState::ServiceEditor;
+                    app.modal_selected_index = 0;
+                    app.status_message = match scaling_error {
+                        Some(e) => format!(
+                            "Editing service: {} (auto scaling unavailable: {e})",
+                            service.name
+                        ),
+                        None => format!("Editing service: {}", service.name),
+                    };
+                }
+                Err(e) => app.status_message = format!("Error loading service configuration: {e}"),
+            },
+        );
+    }
+
+    /// Saves changes made in the service editor.
+    ///
+    /// Asks to confirm the modified desired count and/or task definition, which
+    /// are then applied to the service as a background action.
+    pub fn save_service_changes(&mut self) {
+        // Block service updates in read-only mode (defensive check)
+        if self.config.behavior.read_only {
+            self.status_message = "Read-only mode enabled - cannot save changes".to_string();
+            self.close_modal();
+            return;
+        }
+
+        let cluster = match &self.selected_cluster {
+            Some(c) => c.clone(),
+            None => {
+                self.status_message = "No cluster selected".to_string();
+                return;
+            }
+        };
+
+        let service = match &self.selected_service {
+            Some(s) => s.clone(),
+            None => {
+                self.status_message = "No service selected".to_string();
+                return;
+            }
+        };
+
+        // Validate auto scaling limits before closing the editor so they can be corrected
+        let scaling = match &self.service_editor_scaling {
+            Some(current) => {
+                let (Ok(min_capacity), Ok(max_capacity)) = (
+                    self.service_editor_min_capacity_input.parse::<i32>(),
+                    self.service_editor_max_capacity_input.parse::<i32>(),
+                ) else {
+                    self.status_message = "Enter a minimum and maximum capacity".to_string();
+                    return;
+                };
+                if min_capacity > max_capacity {
+                    self.status_message = format!(
+                        "Minimum capacity {min_capacity} exceeds maximum capacity {max_capacity}"
+                    );
+                    return;
+                }
+                let update = ScalingUpdate {
+                    min_capacity,
+                    max_capacity,
+                    suspended: self.service_editor_scaling_suspended,
+                };
+                let changed = (min_capacity, max_capacity, update.suspended)
+                    != (
+                        current.min_capacity,
+                        current.max_capacity,
+                        current.suspended,
+                    );
+                changed.then_some(update)
+            }
+            None => None,
+        };
+
+        self.close_modal();
+
+        // Parse desired count from input
+        let desired_count = self
+            .service_editor_desired_count_input
+            .parse::<i32>()
+            .unwrap_or(0);
+
+        // Get selected task definition revision
+        let task_def = self
+            .service_editor_available_revisions
+            .get(self.service_editor_selected_revision)
+            .cloned();
+
+        // Determine what changes to make
+        let current_service = self.services.iter().find(|s| s.name == service);
+        let count_changed = current_service
+            .map(|s| s.desired_count != desired_count)
+            .unwrap_or(false);
+        let task_def = task_def.filter(|td| !td.contains(&self.service_editor_current_task_def));
+
+        if !count_changed && task_def.is_none() && scaling.is_none() {
+            self.status_message = "No changes to apply".to_string();
+            return;
+        }
+
+        self.request_confirmation(PendingAction::UpdateService {
+            cluster,
+            service,
+            desired_count: count_changed.then_some(desired_count),
+            task_definition: task_def,
+            scaling,
+        });
+    }
+
+    /// Returns the number of fields in the service editor.
+    ///
+    /// The auto scaling fields are only shown when the service has a scalable target.
+    pub fn service_editor_field_count(&self) -> usize {
+        if self.service_editor_scaling.is_some() {
+            5
+        } else {
+            2
+        }
+    }
+
+    /// Moves the service editor to its next field, wrapping around.
+    pub fn service_editor_next_field(&mut self) {
+        self.service_editor_editing_field =
+            (self.service_editor_editing_field + 1) % self.service_editor_field_count();
+    }
+
+    /// Returns the numeric input buffer of the active service editor field, if any.
+    pub fn service_editor_input_mut(&mut self) -> Option<&mut String> {
+        match self.service_editor_editing_field {
+            0 => Some(&mut self.service_editor_desired_count_input),
+            2 => Some(&mut self.service_editor_min_capacity_input),
+            3 => Some(&mut self.service_editor_max_capacity_input),
+            _ => None,
+        }
+    }
+
+    /// Toggles whether saving the service editor suspends auto scaling.
+    pub fn toggle_service_editor_scaling_suspended(&mut self) {
+        if self.service_editor_scaling.is_some() && self.service_editor_editing_field == 4 {
+            self.service_editor_scaling_suspended = !self.service_editor_scaling_suspended;
+        }
+    }
+
+    /// Shows the port forwarding setup modal for the selected task.
+    ///
+    /// Allows user to specify local and remote ports for forwarding.
+    pub fn show_port_forwarding_setup(&mut self) {
+        // Only allow port forwarding when we're in the Tasks view with a cluster selected
+        if self.state != AppState::Tasks || self.selected_cluster.is_none() {
+            self.status_message = "No task selected".to_string();
+            return;
+        }
+        if self.show_stopped_tasks {
+            self.status_message = "Port forwarding is not available for stopped tasks".to_string();
+            return;
+        }
+
+        // Get the currently highlighted task
+        let filtered_tasks = self.get_filtered_tasks();
+        if self.selected_index >= filtered_tasks.len() {
+            self.status_message = "No task selected".to_string();
+            return;
+        }
+
+        // Store the selected task for port forwarding
+        self.selected_task = Some(filtered_tasks[self.selected_index].clone());
+
+        // Initialize with default ports (local=8080, remote=80)
+        self.port_forward_local_port = "8080".to_string();
+        self.port_forward_remote_port = "80".to_string();
+        self.port_forward_editing_field = 0; // Start editing local port
+
+        self.modal_state = ModalState::PortForwardingSetup;
+        self.modal_selected_index = 0;
+    }
+
+    /// Starts a port forwarding session using AWS SSM.
+    ///
+    /// Opens a local port that forwards to a remote port on the ECS task.
+    /// This runs in a blocking manner - the terminal is suspended while the session is active.
+    pub async fn start_port_forwarding(&mut self) -> Result<()> {
+        let cluster = self
+            .selected_cluster
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("No cluster selected"))?;
+
+        let task = self
+            .selected_task
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("No task selected"))?;
+
+        // Parse ports
+        let local_port = self
+            .port_forward_local_port
+            .parse::<u16>()
+            .context("Invalid local port number")?;
+
+        let remote_port = self
+            .port_forward_remote_port
+            .parse::<u16>()
+            .context("Invalid remote port number")?;
+
+        // Start port forwarding session (modal is closed by caller in main.rs)
+        self.ecs_client
+            .start_port_forwarding(
+                &cluster,
+                &task.task_arn,
+                local_port,
+                remote_port,
+                &self.current_region,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub fn close_modal(&mut self) {
+        // A diff opened from the service editor returns to the editor
+        let diff = self.task_definition_diff.take();
+        self.modal_state = if self.modal_state == ModalState::TaskDefinitionDiff
+            && diff.is_some_and(|diff| diff.from_service_editor)
+        {
+            ModalState::ServiceEditor
+        } else {
+            ModalState::None
+        };
+        self.modal_selected_index = 0;
+        self.confirmation = None;
+        self.log_detail = None;
+        self.log_export = None;
+        self.task_definition_editor = None;
+        self.definition_export = None;
+        self.task_definition_cleanup = None;
+    }
+
+    pub fn modal_next(&mut self) {
+        let len = match self.modal_state {
+            ModalState::LogEntry => {
+                // Scroll the log entry without wrapping around
+                let last = self.log_entry_detail_lines().len().saturating_sub(1);
+                self.modal_selected_index = (self.modal_selected_index + 1).min(last);
+                return;
+            }
+            ModalState::TaskDefinitionDiff => {
+                let last = self
+                    .task_definition_diff
+                    .as_ref()
+                    .map_or(0, |diff| diff.visible_fields().len().saturating_sub(1));
+                self.modal_selected_index = (self.modal_selected_index + 1).min(last);
+                return;
+            }
+            ModalState::ProfileSelector => self.available_profiles.len(),
+            ModalState::RegionSelector => self.available_regions.len(),
+            ModalState::ServiceEditor => self.service_editor_available_revisions.len(),
+            ModalState::PortForwardingSetup => 0, // Handled by field navigation
+            ModalState::Confirm => 0,
+            ModalState::LogExport => 0, // Handled by field navigation
+            ModalState::TaskDefinitionEditor => 0, // Handled by field navigation
+            ModalState::DefinitionExport => 0, // Handled by field navigation
+            ModalState::TaskDefinitionCleanup => 0, // Handled by field navigation
+            ModalState::LogBookmarks => self.log_bookmarks.len(),
+            ModalState::None => 0,
+        };
+        if len > 0 {
+            self.modal_selected_index = (self.modal_selected_index + 1) % len;
+        }
+    }
+
+    pub fn modal_previous(&mut self) {
+        let len = match self.modal_state {
+            ModalState::LogEntry | ModalState::TaskDefinitionDiff => {
+                self.modal_selected_index = self.modal_selected_index.saturating_sub(1);
+                return;
+            }
+            ModalState::ProfileSelector => self.available_profiles.len(),
+            ModalState::RegionSelector => self.available_regions.len(),
+            ModalState::ServiceEditor => self.service_editor_available_revisions.len(),
+            ModalState::PortForwardingSetup => 0, // Handled by field navigation
+            ModalState::Confirm => 0,
+            ModalState::LogExport => 0, // Handled by field navigation
+            ModalState::TaskDefinitionEditor => 0, // Handled by field navigation
+            ModalState::DefinitionExport => 0, // Handled by field navigation
+            ModalState::TaskDefinitionCleanup => 0, // Handled by field navigation
+            ModalState::LogBookmarks => self.log_bookmarks.len(),
+            ModalState::None => 0,
+        };
+        if len > 0 {
+            self.modal_selected_index = if self.modal_selected_index == 0 {
+                len - 1
+            } else {
+                self.modal_selected_index - 1
+            };
+        }
+    }
+
+    pub fn modal_select(&mut self) -> Result<()> {
+        match self.modal_state {
+            ModalState::ProfileSelector => {
+                if let Some(profile) = self.available_profiles.get(self.modal_selected_index) {
+                    self.switch_profile(profile.clone())?;
+                }
+            }
+            ModalState::RegionSelector => {
+                if let Some(region) = self.available_regions.get(self.modal_selected_index) {
+                    self.switch_region(region.clone())?;
+                }
+            }
+            ModalState::ServiceEditor => {
+                self.save_service_changes();
+            }
+            ModalState::PortForwardingSetup => {
+                // Port forwarding is handled directly in main.rs event loop
+                // because it needs to suspend/resume the TUI
+            }
+            ModalState::Confirm => {
+                if self
+                    .confirmation
+                    .as_ref()
+                    .is_some_and(|c| c.confirm_selected)
+                {
+                    self.confirm_action();
+                } else {
+                    self.close_modal();
+                }
+            }
+            ModalState::LogEntry | ModalState::TaskDefinitionDiff => self.close_modal(),
+            ModalState::LogExport => self.submit_log_export(),
+            ModalState::TaskDefinitionEditor => self.submit_task_definition_editor(),
+            ModalState::DefinitionExport => self.submit_definition_export(),
+            ModalState::TaskDefinitionCleanup => self.submit_task_definition_cleanup(),
+            ModalState::LogBookmarks => {
+                let index = self.modal_selected_index;
+                self.close_modal();
+                self.jump_to_log_bookmark(index);
+            }
+            ModalState::None => {}
+        }
+        Ok(())
+    }
+
+    // Profile and region switching
+    pub fn switch_profile(&mut self, profile: String) -> Result<()> {
+        self.close_modal();
+
+        // Update config and save
+        self.config.aws.profile = Some(profile.clone());
+        self.config.save()?;
+
+        // Reinitialize AWS client in the background
+        let region = self.current_region.clone();
+        let new_profile = profile.clone();
+        self.spawn_request(
+            format!("Switching to profile: {profile}"),
+            async move { EcsClient::new(Some(region), Some(new_profile)).await },
+            move |app, result| match result {
+                Ok(client) => {
+                    app.current_profile = profile;
+                    app.replace_client(client);
+                }
+                Err(e) => app.status_message = format!("Failed to switch profile: {e}"),
+            },
+        );
+
+        Ok(())
+    }
+
+    pub fn switch_region(&mut self, region: String) -> Result<()> {
+        self.close_modal();
+
+        // Update config and save
+        self.config.aws.region = Some(region.clone());
+        self.config.save()?;
+
+        // Reinitialize AWS client in the background
+        let profile = self.current_profile.clone();
+        let new_region = region.clone();
+        self.spawn_request(
+            format!("Switching to region: {region}"),
+            async move { EcsClient::new(Some(new_region), Some(profile)).await },
+            move |app, result| match result {
+                Ok(client) => {
+                    app.current_region = region;
+                    app.replace_client(client);
+                }
+                Err(e) => app.status_message = format!("Failed to switch region: {e}"),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Installs a freshly created AWS client and reloads from the Clusters view.
+    ///
+    /// All data from the previous profile/region is cleared.
+    fn replace_client(&mut self, client: EcsClient) {
+        self.ecs_client = Arc::new(client);
+
+        // Clear current data
+        self.clusters.clear();
+        self.cluster_details.clear();
+        self.services.clear();
+        self.tasks.clear();
+        self.selected_cluster = None;
+        self.selected_service = None;
+        self.selected_task = None;
+        self.details = None;
+        self.reset_logs();
+        self.show_service_logs = false;
+
+        // Reset to clusters view
+        self.state = AppState::Clusters;
+        self.selected_index = 0;
+
+        // Refresh data
+        self.refresh();
+    }
+}
+
+/// Extracts the family name from a task definition ARN or `family:revision` string.
+///
+/// Format: arn:aws:ecs:region:account:task-definition/family:revision
+fn task_definition_family(task_definition: &str) -> &str {
+    let family_part = task_definition
+        .split('/')
+        .next_back()
+        .unwrap_or(task_definition);
+    // Remove revision number (e.g., "my-task:1" -> "my-task")
+    family_part.split(':').next().unwrap_or(family_part)
+}
+
+/// Extracts the task definition from the formatted details of a service.
+fn details_task_definition(details: &str) -> Option<String> {
+    details
+        .lines()
+        .find(|l| l.starts_with("Task Definition:"))
+        .map(|l| l.trim_start_matches("Task Definition:").trim().to_string())
+}
+
+/// Shortens a task definition ARN to its `family:revision` form.
+fn short_task_definition(task_definition: &str) -> &str {
+    task_definition
+        .split('/')
+        .next_back()
+        .unwrap_or(task_definition)
+}
+
+/// Identifies a log entry across reloads, by its timestamp, container and first line.
+fn log_entry_key(entry: &LogEntry) -> (i64, String, String) {
+    (
+        entry.timestamp,
+        entry.container_name.clone(),
+        entry.message.clone(),
+    )
+}
+
+/// Groups multi-line log entries and applies the level, task and search filters of
+/// the Logs view to them.
+fn filter_log_entries(
+    logs: Vec<LogEntry>,
+    logs_config: &LogsConfig,
+    level_filter: Option<&LogLevel>,
+    task_filter: Option<&str>,
+    search_query: &str,
+) -> Vec<LogEntry> {
+    let mut logs = group_multiline(logs, logs_config);
+
+    // Apply task filter of service-wide logs
+    if let Some(task_id) = task_filter {
+        logs.retain(|log| log.task_id.as_deref() == Some(task_id));
+    }
+
+    // Apply log level filter
+    if let Some(level) = level_filter {
+        logs.retain(|log| &log.level == level);
+    }
+
+    // Apply search filter: field=value filters and free text
+    if !search_query.is_empty() {
+        let query = LogQuery::parse(search_query);
+        logs.retain(|log| query.matches(log));
+    }
+
+    logs
+}
+
+/// Describes task definition revisions, a few at a time, keeping their order.
+async fn describe_task_definitions(
+    client: Arc<dyn EcsBackend>,
+    arns: Vec<String>,
+) -> Result<Vec<TaskDefinitionInfo>> {
+    let mut revisions = Vec::with_capacity(arns.len());
+    for chunk in arns.chunks(TASK_DEFINITION_DESCRIBE_CONCURRENCY) {
+        let mut requests = tokio::task::JoinSet::new();
+        for (index, arn) in chunk.iter().cloned().enumerate() {
+            let client = Arc::clone(&client);
+            requests.spawn(async move { (index, client.describe_task_definition(&arn).await) });
+        }
+        let mut described = Vec::with_capacity(chunk.len());
+        while let Some(result) = requests.join_next().await {
+            let (index, revision) = result?;
+            described.push((index, revision?));
+        }
+        described.sort_by_key(|(index, _)| *index);
+        revisions.extend(described.into_iter().map(|(_, revision)| revision));
+    }
+    Ok(revisions)
+}
+
+/// Returns the index of the first of `revisions` (newest first) registered before
+/// `cutoff` (Unix seconds), or `revisions.len()` if none was.
+///
+/// Revision numbers grow with registration time, so the revisions are binary
+/// searched, describing only a few of them.
+async fn first_revision_registered_before(
+    client: &dyn EcsBackend,
+    revisions: &[String],
+    cutoff: i64,
+) -> Result<usize> {
+    let (mut low, mut high) = (0, revisions.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let registered_at = client
+            .describe_task_definition(&revisions[middle])
+            .await?
+            .registered_at;
+        // A revision without a registration time counts as old
+        if registered_at.is_none_or(|time| time < cutoff) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Deregisters a batch of task definition revisions one by one, then deletes the
+/// deregistered ones if `delete` is set. Failures are collected, not returned.
+async fn cleanup_task_definitions(
+    client: Arc<dyn EcsBackend>,
+    arns: Vec<String>,
+    delete: bool,
+) -> CleanupBatch {
+    let mut batch = CleanupBatch::default();
+    let mut deregistered = Vec::with_capacity(arns.len());
+    for arn in arns {
+        match client.deregister_task_definition(&arn).await {
+            Ok(()) => deregistered.push(arn),
+            Err(e) => batch.failures.push((arn, format!("{e:#}"))),
+        }
+    }
+    batch.deregistered = deregistered.len();
+    if delete && !deregistered.is_empty() {
+        match client.delete_task_definitions(&deregistered).await {
+            Ok(failures) => {
+                batch.deleted = deregistered.len().saturating_sub(failures.len());
+                batch.failures.extend(failures);
+            }
+            Err(e) => batch.failures.extend(
+                deregistered
+                    .into_iter()
+                    .map(|arn| (arn, format!("Deregistered but not deleted: {e:#}"))),
+            ),
+        }
+    }
+    batch
+}
+
+/// Reads the logs of every container of a task from `start_time` to `end_time`.
+///
+/// The first page at `start_time` is followed by newer events until `end_time`;
+/// the cursors of the last page read continue paging and tailing from there.
+async fn fetch_task_log_context(
+    client: Arc<dyn EcsBackend>,
+    cluster: &str,
+    task_arn: &str,
+    start_time: i64,
+    end_time: i64,
+) -> Result<LogPage> {
+    let mut page = client
+        .get_task_logs(cluster, task_arn, &LogPageRequest::At(start_time))
+        .await?;
+    let reached_end = page
+        .entries
+        .last()
+        .is_some_and(|log| log.timestamp >= end_time);
+    if !page.entries.is_empty() && !reached_end {
+        let newer = client
+            .get_task_logs(cluster, task_arn, &LogPageRequest::Newer(page.cursors))
+            .await?;
+        page.entries.extend(newer.entries);
+        page.entries.sort_by_key(|log| log.timestamp);
+        page.cursors = newer.cursors;
+    }
+    page.entries.retain(|log| log.timestamp <= end_time);
+    Ok(page)
+}
+
+/// Reads a task's logs from the latest page back to `start_time`, or to the beginning
+/// of every log stream, reading at most `MAX_EXPORT_PAGES` pages.
+async fn fetch_task_log_history(
+    client: Arc<dyn EcsBackend>,
+    cluster: &str,
+    task_arn: &str,
+    start_time: Option<i64>,
+) -> Result<Vec<LogEntry>> {
+    let mut page = client
+        .get_task_logs(cluster, task_arn, &LogPageRequest::Latest)
+        .await?;
+    let mut logs = Vec::new();
+    for _ in 0..MAX_EXPORT_PAGES {
+        // Pages end at their newest entry, so nothing older is in the range once
+        // a whole page is before its start
+        let before_start = start_time.is_some_and(|start_time| {
+            page.entries
+                .last()
+                .is_some_and(|log| log.timestamp < start_time)
+        });
+        logs.append(&mut page.entries);
+        if before_start || page.cursors.iter().all(|cursor| cursor.exhausted) {
+            break;
+        }
+        page = client
+            .get_task_logs(cluster, task_arn, &LogPageRequest::Older(page.cursors))
+            .await?;
+    }
+    logs.sort_by_key(|log| log.timestamp);
+    Ok(logs)
+}
+
+/// Parses a time typed to jump to in the Logs view into Unix milliseconds.
+///
+/// Accepts times relative to `now` ("-45s", "-30m", "-2h", "-1d") and absolute local
+/// times ("2026-10-14T09:00", "2026-10-14 09:00:30", "2026-10-14").
+fn parse_log_time(input: &str, now: chrono::DateTime<chrono::Local>) -> Result<i64> {
+    let input = input.trim();
+    let invalid = || anyhow::anyhow!("Invalid time '{input}' (try -30m, -2h or 2026-10-14T09:00)");
+
+    if let Some(relative) = input.strip_prefix('-') {
+        let unit = relative.chars().last().ok_or_else(invalid)?;
+        let amount: i64 = relative[..relative.len() - unit.len_utf8()]
+            .parse()
+            .map_err(|_| invalid())?;
+        // A signed amount ("--5m") would point into the future
+        if amount <= 0 {
+            return Err(invalid());
+        }
+        let unit_seconds = match unit {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => return Err(invalid()),
+        };
+        return amount
+            .checked_mul(unit_seconds)
+            .and_then(chrono::TimeDelta::try_seconds)
+            .and_then(|ago| now.checked_sub_signed(ago))
+            .map(|time| time.timestamp_millis())
+            .ok_or_else(invalid);
+    }
+
+    let naive = [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| chrono::NaiveDateTime::parse_from_str(input, format).ok())
+    .or_else(|| {
+        chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    })
+    .ok_or_else(invalid)?;
+    naive
+        .and_local_timezone(chrono::Local)
+        .earliest()
+        .map(|time| time.timestamp_millis())
+        .ok_or_else(|| anyhow::anyhow!("Time '{input}' does not exist in the local time zone"))
+}
+
+/// Reads available AWS profiles from ~/.aws/credentials
+fn list_aws_profiles() -> Result<Vec<String>> {
+    use std::fs;
+
+    let home_dir =
+        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Failed to determine home directory"))?;
+
+    let credentials_path = home_dir.join(".aws").join("credentials");
+
+    if !credentials_path.exists() {
+        return Ok(vec!["default".to_string()]);
+    }
+
+    let contents = fs::read_to_string(&credentials_path)?;
+    let mut profiles = Vec::new();
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            let profile_name = trimmed[1..trimmed.len() - 1].to_string();
+            profiles.push(profile_name);
+        }
+    }
+
+    if profiles.is_empty() {
+        profiles.push("default".to_string());
+    }
+
+    Ok(profiles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws_fake::{self, FakeBackend};
+    use crate::config::{AwsConfig, BehaviorConfig, Config, LogsConfig, MetricsConfig, UiConfig};
+    use crate::definition_export::DefinitionFormat;
+    use crate::log_export::{ExportField, ExportFormat};
+
+    // Helper function to create a test config
+    fn create_test_config() -> Config {
+        Config {
+            aws: AwsConfig {
+                region: None,
+                profile: None,
+            },
+            behavior: BehaviorConfig {
+                auto_refresh: true,
+                refresh_interval: 30,
+                default_view: "clusters".to_string(),
+                read_only: false,
+                production_patterns: Vec::new(),
+            },
+            ui: UiConfig {
+                theme: "dark".to_string(),
+                border_style: "normal".to_string(),
+                show_breadcrumbs: true,
+                min_terminal_width: 80,
+                min_terminal_height: 24,
+            },
+            logs: LogsConfig {
+                enable_search: true,
+                enable_filtering: true,
+                show_timestamps: true,
+                export_dir: "~/Downloads".to_string(),
+                max_buffer_lines: 10_000,
+                columns: Vec::new(),
+                multiline_pattern: crate::multiline::DEFAULT_CONTINUATION_PATTERN.to_string(),
+                context_minutes: 5,
+                saved_queries: Vec::new(),
+                multiline_patterns: std::collections::HashMap::new(),
+            },
+            metrics: MetricsConfig {
+                enabled: true,
+                time_range_minutes: 60,
+                refresh_interval: 60,
+                show_alarms: true,
+                show_charts: true,
+            },
+        }
+    }
+
+    // Helper function to create an App with canned data for testing
+    // The backend is an empty fake, so no test ever reaches AWS
+    fn create_test_app() -> App {
+        // `with_backend` starts loading the clusters, which needs a runtime even
+        // in sync tests; the load is cancelled before it can run
+        let runtime = match tokio::runtime::Handle::try_current() {
+            Ok(_) => None,
+            Err(_) => tokio::runtime::Builder::new_current_thread().build().ok(),
+        };
+        let _guard = runtime.as_ref().map(tokio::runtime::Runtime::enter);
+        let mut app = App::with_backend(
+            create_test_config(),
+            Arc::new(FakeBackend::new(Default::default())),
+        );
+        app.cancel_requests();
+
+        app.available_profiles = vec!["default".to_string()];
+        app.available_regions = vec!["us-east-1".to_string()];
+        app.status_message = "Ready".to_string();
+        app.clusters = vec![
+            "cluster-prod".to_string(),
+            "cluster-dev".to_string(),
+            "cluster-staging".to_string(),
+        ];
+        app.services = vec![
+            ServiceInfo {
+                name: "web-service".to_string(),
+                status: "ACTIVE".to_string(),
+                desired_count: 3,
+                running_count: 3,
+                pending_count: 0,
+                launch_type: "FARGATE".to_string(),
+            },
+            ServiceInfo {
+                name: "api-service".to_string(),
+                status: "ACTIVE".to_string(),
+                desired_count: 5,
+                running_count: 4,
+                pending_count: 1,
+                launch_type: "EC2".to_string(),
+            },
+            ServiceInfo {
+                name: "worker-service".to_string(),
+                status: "DRAINING".to_string(),
+                desired_count: 2,
+                running_count: 1,
+                pending_count: 0,
+                launch_type: "FARGATE".to_string(),
+            },
+        ];
+        app.tasks = vec![
+            TaskInfo {
+                task_arn: "arn:aws:ecs:us-east-1:123456789012:task/task-abc123".to_string(),
+                task_id: "task-abc123".to_string(),
+                status: "RUNNING".to_string(),
+                desired_status: "RUNNING".to_string(),
+                container_instance: "instance-1".to_string(),
+                cpu: "256".to_string(),
+                memory: "512".to_string(),
+            },
+            TaskInfo {
+                task_arn: "arn:aws:ecs:us-east-1:123456789012:task/task-def456".to_string(),
+                task_id: "task-def456".to_string(),
+                status: "PENDING".to_string(),
+                desired_status: "RUNNING".to_string(),
+                container_instance: "instance-2".to_string(),
+                cpu: "512".to_string(),
+                memory: "1024".to_string(),
+            },
+            TaskInfo {
+                task_arn: "arn:aws:ecs:us-east-1:123456789012:task/task-ghi789".to_string(),
+                task_id: "task-ghi789".to_string(),
+                status: "STOPPED".to_string(),
+                desired_status: "STOPPED".to_string(),
+                container_instance: "none".to_string(),
+                cpu: "256".to_string(),
+                memory: "512".to_string(),
+            },
+        ];
+        app
+    }
+
+    // Test search filtering
+    #[test]
+    fn test_get_filtered_clusters_empty_query() {
+        let app = create_test_app();
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 3);
+        assert_eq!(filtered, app.clusters);
+    }
+
+    #[test]
+    fn test_get_filtered_clusters_with_query() {
+        let mut app = create_test_app();
+        app.search_query = "prod".to_string();
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0], "cluster-prod");
+    }
+
+    #[test]
+    fn test_get_filtered_clusters_case_insensitive() {
+        let mut app = create_test_app();
+        app.search_query = "PROD".to_string();
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0], "cluster-prod");
+    }
+
+    #[test]
+    fn test_get_filtered_clusters_partial_match() {
+        let mut app = create_test_app();
+        app.search_query = "dev".to_string();
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0], "cluster-dev");
+    }
+
+    #[test]
+    fn test_get_filtered_clusters_no_match() {
+        let mut app = create_test_app();
+        app.search_query = "nonexistent".to_string();
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 0);
+    }
+
+    #[test]
+    fn test_get_filtered_services_empty_query() {
+        let app = create_test_app();
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 3);
+    }
+
+    #[test]
+    fn test_get_filtered_services_by_name() {
+        let mut app = create_test_app();
+        app.search_query = "web".to_string();
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "web-service");
+    }
+
+    #[test]
+    fn test_get_filtered_services_by_status() {
+        let mut app = create_test_app();
+        app.search_query = "DRAINING".to_string();
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "worker-service");
+    }
+
+    #[test]
+    fn test_get_filtered_services_by_launch_type() {
+        let mut app = create_test_app();
+        app.search_query = "FARGATE".to_string();
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().any(|s| s.name == "web-service"));
+        assert!(filtered.iter().any(|s| s.name == "worker-service"));
+    }
+
+    #[test]
+    fn test_get_filtered_tasks_empty_query() {
+        let app = create_test_app();
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 3);
+    }
+
+    #[test]
+    fn test_get_filtered_tasks_by_id() {
+        let mut app = create_test_app();
+        app.search_query = "abc123".to_string();
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].task_id, "task-abc123");
+    }
+
+    #[test]
+    fn test_get_filtered_tasks_by_status() {
+        let mut app = create_test_app();
+        app.search_query = "RUNNING".to_string();
+        let filtered = app.get_filtered_tasks();
+        // Should match 2 tasks: one with status=RUNNING and one with desired_status=RUNNING
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().any(|t| t.task_id == "task-abc123"));
+        assert!(filtered.iter().any(|t| t.task_id == "task-def456"));
+    }
+
+    #[test]
+    fn test_get_filtered_tasks_by_desired_status() {
+        let mut app = create_test_app();
+        app.search_query = "STOPPED".to_string();
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].task_id, "task-ghi789");
+    }
+
+    // Test navigation
+    #[test]
+    fn test_next_wraps_around() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+        app.selected_index = 2; // Last item
+        app.next();
+        assert_eq!(app.selected_index, 0); // Should wrap to first
+    }
+
+    #[test]
+    fn test_next_increments() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+        app.selected_index = 0;
+        app.next();
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_previous_wraps_around() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+        app.selected_index = 0; // First item
+        app.previous();
+        assert_eq!(app.selected_index, 2); // Should wrap to last
+    }
+
+    #[test]
+    fn test_previous_decrements() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+        app.selected_index = 2;
+        app.previous();
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_next_in_logs_scrolls_down() {
+        let mut app = create_test_app();
+        app.state = AppState::Logs;
+        app.logs = vec![
+            LogEntry::new(1000, "log1".to_string(), "container1".to_string()),
+            LogEntry::new(2000, "log2".to_string(), "container1".to_string()),
+        ];
+        app.log_scroll = 0;
+        app.auto_tail = true;
+
+        app.next();
+
+        assert_eq!(app.log_scroll, 1);
+        assert!(!app.auto_tail);
+    }
+
+    #[test]
+    fn test_previous_in_logs_scrolls_up() {
+        let mut app = create_test_app();
+        app.state = AppState::Logs;
+        app.log_scroll = 5;
+        app.auto_tail = true;
+
+        app.previous();
+
+        assert_eq!(app.log_scroll, 4);
+        assert!(!app.auto_tail);
+    }
+
+    #[test]
+    fn test_previous_in_logs_saturates_at_zero() {
+        let mut app = create_test_app();
+        app.state = AppState::Logs;
+        app.log_scroll = 0;
+
+        app.previous();
+
+        assert_eq!(app.log_scroll, 0);
+    }
+
+    // Test state transitions
+    #[test]
+    fn test_set_view_changes_state() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+        app.selected_index = 5;
+
+        app.set_view(AppState::Services);
+
+        assert_eq!(app.state, AppState::Services);
+        assert_eq!(app.previous_state, Some(AppState::Clusters));
+        assert_eq!(app.selected_index, 0); // Should reset index
+    }
+
+    #[test]
+    fn test_back_from_services_to_clusters() {
+        let mut app = create_test_app();
+        app.state = AppState::Services;
+        app.selected_service = Some("test-service".to_string());
+
+        app.back();
+
+        assert_eq!(app.state, AppState::Clusters);
+        assert_eq!(app.selected_service, None);
+    }
+
+    #[test]
+    fn test_back_from_tasks_to_services() {
+        let mut app = create_test_app();
+        app.state = AppState::Tasks;
+
+        app.back();
+
+        assert_eq!(app.state, AppState::Services);
+    }
+
+    #[test]
+    fn test_back_from_details_to_tasks() {
+        let mut app = create_test_app();
+        app.state = AppState::Details;
+        app.details = Some("test details".to_string());
+
+        app.back();
+
+        assert_eq!(app.state, AppState::Tasks);
+        assert_eq!(app.details, None);
+    }
+
+    #[test]
+    fn test_back_from_logs_to_tasks() {
+        let mut app = create_test_app();
+        app.state = AppState::Logs;
+        app.logs = vec![LogEntry::new(
+            1000,
+            "test".to_string(),
+            "container1".to_string(),
+        )];
+        app.log_scroll = 5;
+        app.auto_tail = false;
+
+        app.back();
+
+        assert_eq!(app.state, AppState::Tasks);
+        assert_eq!(app.logs.len(), 0);
+        assert_eq!(app.log_scroll, 0);
+        assert!(app.auto_tail);
+    }
+
+    #[test]
+    fn test_back_from_clusters_does_nothing() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+
+        app.back();
+
+        assert_eq!(app.state, AppState::Clusters);
+    }
+
+    // Test auto-tail toggle
+    #[test]
+    fn test_toggle_auto_tail_enables() {
+        let mut app = create_test_app();
+        app.auto_tail = false;
+        app.logs = vec![
+            LogEntry::new(1000, "log1".to_string(), "container1".to_string()),
+            LogEntry::new(2000, "log2".to_string(), "container1".to_string()),
+        ];
+
+        app.toggle_auto_tail();
+
+        assert!(app.auto_tail);
+        assert_eq!(app.log_scroll, 1); // Should scroll to last log (len - 1)
+        assert!(app.status_message.contains("enabled"));
+    }
+
+    #[test]
+    fn test_toggle_auto_tail_disables() {
+        let mut app = create_test_app();
+        app.auto_tail = true;
+
+        app.toggle_auto_tail();
+
+        assert!(!app.auto_tail);
+        assert!(app.status_message.contains("disabled"));
+    }
+
+    #[test]
+    fn test_toggle_auto_tail_with_empty_logs() {
+        let mut app = create_test_app();
+        app.auto_tail = false;
+        app.logs = vec![];
+
+        app.toggle_auto_tail();
+
+        assert!(app.auto_tail);
+        // Should not panic with empty logs
+    }
+
+    // Test search mode
+    #[test]
+    fn test_enter_search_mode() {
+        let mut app = create_test_app();
+        app.search_mode = false;
+        app.search_query = "old query".to_string();
+        app.selected_index = 5;
+
+        app.enter_search_mode();
+
+        assert!(app.search_mode);
+        assert_eq!(app.search_query, "");
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_exit_search_mode() {
+        let mut app = create_test_app();
+        app.search_mode = true;
+
+        app.exit_search_mode();
+
+        assert!(!app.search_mode);
+    }
+
+    #[test]
+    fn test_clear_search() {
+        let mut app = create_test_app();
+        app.search_mode = true;
+        app.search_query = "test query".to_string();
+        app.selected_index = 5;
+
+        app.clear_search();
+
+        assert!(!app.search_mode);
+        assert_eq!(app.search_query, "");
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_update_search() {
+        let mut app = create_test_app();
+        app.search_query = "test".to_string();
+        app.selected_index = 5;
+
+        app.update_search('!');
+
+        assert_eq!(app.search_query, "test!");
+        assert_eq!(app.selected_index, 0); // Should reset index
+    }
+
+    #[test]
+    fn test_update_search_multiple_chars() {
+        let mut app = create_test_app();
+        app.search_query = String::new();
+
+        app.update_search('h');
+        app.update_search('e');
+        app.update_search('l');
+        app.update_search('l');
+        app.update_search('o');
+
+        assert_eq!(app.search_query, "hello");
+    }
+
+    #[test]
+    fn test_delete_search_char() {
+        let mut app = create_test_app();
+        app.search_query = "test".to_string();
+        app.selected_index = 5;
+
+        app.delete_search_char();
+
+        assert_eq!(app.search_query, "tes");
+        assert_eq!(app.selected_index, 0); // Should reset index
+    }
+
+    #[test]
+    fn test_delete_search_char_empty() {
+        let mut app = create_test_app();
+        app.search_query = String::new();
+
+        app.delete_search_char();
+
+        assert_eq!(app.search_query, "");
+        // Should not panic with empty string
+    }
+
+    // Test help toggle
+    #[test]
+    fn test_toggle_help() {
+        let mut app = create_test_app();
+        app.show_help = false;
+
+        app.toggle_help();
+        assert!(app.show_help);
+
+        app.toggle_help();
+        assert!(!app.show_help);
+    }
+
+    // Test should_refresh
+    #[test]
+    fn test_should_refresh_logs_state() {
+        let mut app = create_test_app();
+        app.state = AppState::Logs;
+        app.auto_tail = true;
+        app.last_refresh = Instant::now() - Duration::from_secs(6);
+
+        assert!(app.should_refresh());
+    }
+
+    #[test]
+    fn test_should_refresh_logs_state_not_yet() {
+        let mut app = create_test_app();
+        app.state = AppState::Logs;
+        app.auto_tail = true;
+        app.last_refresh = Instant::now() - Duration::from_secs(3);
+
+        assert!(!app.should_refresh());
+    }
+
+    #[test]
+    fn test_should_refresh_other_state() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+        app.last_refresh = Instant::now() - Duration::from_secs(31);
+
+        assert!(app.should_refresh());
+    }
+
+    #[test]
+    fn test_should_refresh_other_state_not_yet() {
+        let mut app = create_test_app();
+        app.state = AppState::Services;
+        app.last_refresh = Instant::now() - Duration::from_secs(20);
+
+        assert!(!app.should_refresh());
+    }
+
+    #[test]
+    fn test_should_refresh_skips_task_definition_revisions() {
+        let mut app = create_test_app();
+        app.last_refresh = Instant::now() - Duration::from_secs(100);
+
+        app.state = AppState::TaskDefinitionRevisions;
+        assert!(!app.should_refresh());
+        app.state = AppState::TaskDefinitionDetail;
+        assert!(!app.should_refresh());
+        app.state = AppState::TaskDefinitions;
+        assert!(app.should_refresh());
+    }
+
+    #[test]
+    fn test_should_refresh_disabled_in_config() {
+        let mut app = create_test_app();
+        app.config.behavior.auto_refresh = false;
+        app.last_refresh = Instant::now() - Duration::from_secs(100);
+
+        assert!(!app.should_refresh());
+    }
+
+    // Test edge cases
+    #[test]
+    fn test_next_with_empty_list() {
+        let mut app = create_test_app();
+        app.state = AppState::Clusters;
+        app.clusters = vec![];
+        app.selected_index = 0;
+
+        app.next();
+
+        assert_eq!(app.selected_index, 0); // Should stay at 0
+    }
+
+    #[test]
+    fn test_previous_with_empty_list() {
+        let mut app = create_test_app();
+        app.state = AppState::Services;
+        app.services = vec![];
+        app.selected_index = 0;
+
+        app.previous();
+
+        assert_eq!(app.selected_index, 0); // Should stay at 0
+    }
+
+    #[test]
+    fn test_search_with_special_characters() {
+        let mut app = create_test_app();
+        app.clusters = vec![
+            "cluster-prod-1".to_string(),
+            "cluster_dev_2".to_string(),
+            "cluster.staging.3".to_string(),
+        ];
+        app.search_query = "-".to_string();
+
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0], "cluster-prod-1");
+    }
+
+    #[test]
+    fn test_search_with_underscore() {
+        let mut app = create_test_app();
+        app.clusters = vec![
+            "cluster-prod-1".to_string(),
+            "cluster_dev_2".to_string(),
+            "cluster.staging.3".to_string(),
+        ];
+        app.search_query = "_".to_string();
+
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0], "cluster_dev_2");
+    }
 
-        // Refresh data
-        self.refresh().await?;
-        self.loading = false;
-        self.status_message = format!("Switched to region: {}", self.current_region);
+    #[test]
+    fn test_search_with_dot() {
+        let mut app = create_test_app();
+        app.clusters = vec![
+            "cluster-prod-1".to_string(),
+            "cluster_dev_2".to_string(),
+            "cluster.staging.3".to_string(),
+        ];
+        app.search_query = ".".to_string();
 
-        Ok(())
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0], "cluster.staging.3");
     }
-}
 
-/// Reads available AWS profiles from ~/.aws/credentials
-fn list_aws_profiles() -> Result<Vec<String>> {
-    use std::fs;
+    // Test ServiceInfo and TaskInfo structures
+    #[test]
+    fn test_service_info_clone() {
+        let service = ServiceInfo {
+            name: "test".to_string(),
+            status: "ACTIVE".to_string(),
+            desired_count: 3,
+            running_count: 3,
+            pending_count: 0,
+            launch_type: "FARGATE".to_string(),
+        };
 
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Failed to determine home directory"))?;
+        let cloned = service.clone();
+        assert_eq!(service.name, cloned.name);
+        assert_eq!(service.status, cloned.status);
+        assert_eq!(service.desired_count, cloned.desired_count);
+    }
 
-    let credentials_path = home_dir.join(".aws").join("credentials");
+    #[test]
+    fn test_task_info_clone() {
+        let task = TaskInfo {
+            task_arn: "arn:test".to_string(),
+            task_id: "id123".to_string(),
+            status: "RUNNING".to_string(),
+            desired_status: "RUNNING".to_string(),
+            container_instance: "instance-1".to_string(),
+            cpu: "256".to_string(),
+            memory: "512".to_string(),
+        };
 
-    if !credentials_path.exists() {
-        return Ok(vec!["default".to_string()]);
+        let cloned = task.clone();
+        assert_eq!(task.task_arn, cloned.task_arn);
+        assert_eq!(task.task_id, cloned.task_id);
     }
 
-    let contents = fs::read_to_string(&credentials_path)?;
-    let mut profiles = Vec::new();
+    #[test]
+    fn test_log_entry_clone() {
+        let log = LogEntry::new(12345, "test message".to_string(), "container1".to_string());
 
-    for line in contents.lines() {
-        let trimmed = line.trim();
-        if trimmed.starts_with('[') && trimmed.ends_with(']') {
-            let profile_name = trimmed[1..trimmed.len() - 1].to_string();
-            profiles.push(profile_name);
-        }
+        let cloned = log.clone();
+        assert_eq!(log.timestamp, cloned.timestamp);
+        assert_eq!(log.message, cloned.message);
+        assert_eq!(log.container_name, cloned.container_name);
     }
 
-    if profiles.is_empty() {
-        profiles.push("default".to_string());
+    #[test]
+    fn test_app_state_equality() {
+        assert_eq!(AppState::Clusters, AppState::Clusters);
+        assert_ne!(AppState::Clusters, AppState::Services);
+        assert_eq!(AppState::Logs, AppState::Logs);
     }
 
-    Ok(profiles)
-}
+    #[test]
+    fn test_app_state_clone() {
+        let state = AppState::Tasks;
+        let cloned = state.clone();
+        assert_eq!(state, cloned);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::{AwsConfig, BehaviorConfig, Config, LogsConfig, MetricsConfig, UiConfig};
-    use std::mem::ManuallyDrop;
+    // Test log level parsing
+    #[test]
+    fn test_log_level_from_message_debug() {
+        let level = LogLevel::from_message("DEBUG: Starting process");
+        assert_eq!(level, LogLevel::Debug);
 
-    // Helper function to create a test config
-    fn create_test_config() -> Config {
-        Config {
-            aws: AwsConfig {
-                region: None,
-                profile: None,
-            },
-            behavior: BehaviorConfig {
-                auto_refresh: true,
-                refresh_interval: 30,
-                default_view: "clusters".to_string(),
-                read_only: false,
-            },
-            ui: UiConfig {
-                theme: "dark".to_string(),
-                border_style: "normal".to_string(),
-                show_breadcrumbs: true,
-                min_terminal_width: 80,
-                min_terminal_height: 24,
-            },
-            logs: LogsConfig {
-                enable_search: true,
-                enable_filtering: true,
-                show_timestamps: true,
-                export_dir: "~/Downloads".to_string(),
-            },
-            metrics: MetricsConfig {
-                enabled: true,
-                time_range_minutes: 60,
-                refresh_interval: 60,
-                show_alarms: true,
-                show_charts: true,
-            },
-        }
+        let level = LogLevel::from_message("[DBG] Message");
+        assert_eq!(level, LogLevel::Debug);
     }
 
-    // Helper function to create a mock App for testing
-    // We wrap in ManuallyDrop to avoid dropping the uninitialized EcsClient
-    // We use MaybeUninit to safely create an uninitialized EcsClient
-    fn create_test_app() -> ManuallyDrop<App> {
-        use std::mem::MaybeUninit;
+    #[test]
+    fn test_log_level_from_message_info() {
+        let level = LogLevel::from_message("INFO: Server started");
+        assert_eq!(level, LogLevel::Info);
 
-        let fake_client = MaybeUninit::<EcsClient>::uninit();
-        let theme = Theme::from_preset(ThemePreset::Dark);
-        ManuallyDrop::new(App {
-            state: AppState::Clusters,
-            previous_state: None,
-            show_help: false,
-            selected_index: 0,
-            ecs_client: unsafe { fake_client.assume_init() },
-            config: create_test_config(),
-            theme,
-            split_pane_mode: false,
-            current_profile: "default".to_string(),
-            current_region: "us-east-1".to_string(),
-            available_profiles: vec!["default".to_string()],
-            available_regions: vec!["us-east-1".to_string()],
-            modal_state: ModalState::None,
-            modal_selected_index: 0,
-            clusters: vec![
-                "cluster-prod".to_string(),
-                "cluster-dev".to_string(),
-                "cluster-staging".to_string(),
-            ],
-            services: vec![
-                ServiceInfo {
-                    name: "web-service".to_string(),
-                    status: "ACTIVE".to_string(),
-                    desired_count: 3,
-                    running_count: 3,
-                    pending_count: 0,
-                    launch_type: "FARGATE".to_string(),
-                },
-                ServiceInfo {
-                    name: "api-service".to_string(),
-                    status: "ACTIVE".to_string(),
-                    desired_count: 5,
-                    running_count: 4,
-                    pending_count: 1,
-                    launch_type: "EC2".to_string(),
-                },
-                ServiceInfo {
-                    name: "worker-service".to_string(),
-                    status: "DRAINING".to_string(),
-                    desired_count: 2,
-                    running_count: 1,
-                    pending_count: 0,
-                    launch_type: "FARGATE".to_string(),
-                },
-            ],
-            tasks: vec![
-                TaskInfo {
-                    task_arn: "arn:aws:ecs:us-east-1:123456789012:task/task-abc123".to_string(),
-                    task_id: "task-abc123".to_string(),
-                    status: "RUNNING".to_string(),
-                    desired_status: "RUNNING".to_string(),
-                    container_instance: "instance-1".to_string(),
-                    cpu: "256".to_string(),
-                    memory: "512".to_string(),
-                },
-                TaskInfo {
-                    task_arn: "arn:aws:ecs:us-east-1:123456789012:task/task-def456".to_string(),
-                    task_id: "task-def456".to_string(),
-                    status: "PENDING".to_string(),
-                    desired_status: "RUNNING".to_string(),
-                    container_instance: "instance-2".to_string(),
-                    cpu: "512".to_string(),
-                    memory: "1024".to_string(),
-                },
-                TaskInfo {
-                    task_arn: "arn:aws:ecs:us-east-1:123456789012:task/task-ghi789".to_string(),
-                    task_id: "task-ghi789".to_string(),
-                    status: "STOPPED".to_string(),
-                    desired_status: "STOPPED".to_string(),
-                    container_instance: "none".to_string(),
-                    cpu: "256".to_string(),
-                    memory: "512".to_string(),
-                },
-            ],
-            selected_cluster: None,
-            selected_service: None,
-            selected_task: None,
-            details: None,
-            details_json: None,
-            show_json_view: false,
-            details_scroll: 0,
-            logs: vec![],
-            log_scroll: 0,
-            auto_tail: true,
-            metrics: None,
-            metrics_scroll: 0,
-            task_definition_families: Vec::new(),
-            task_definitions: Vec::new(),
-            selected_task_definition_family: None,
-            selected_task_definition: None,
-            search_mode: false,
-            search_query: String::new(),
-            search_regex_mode: false,
-            service_status_filter: None,
-            task_status_filter: None,
-            launch_type_filter: None,
-            log_search_mode: false,
-            log_search_query: String::new(),
-            log_level_filter: None,
-            status_message: "Ready".to_string(),
-            loading: false,
-            last_refresh: Instant::now(),
-            auto_refresh_paused: false,
-            auto_refresh_pause_time: None,
-            // Service Editor
-            service_editor_desired_count_input: String::new(),
-            service_editor_selected_revision: 0,
-            service_editor_available_revisions: vec![],
-            service_editor_current_task_def: String::new(),
-            service_editor_editing_field: 0,
-            // Port Forwarding
-            port_forward_local_port: String::new(),
-            port_forward_remote_port: String::new(),
-            port_forward_editing_field: 0,
-        })
+        let level = LogLevel::from_message("[INF] Message");
+        assert_eq!(level, LogLevel::Info);
     }
 
-    // Test search filtering
     #[test]
-    fn test_get_filtered_clusters_empty_query() {
-        let app = create_test_app();
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 3);
-        assert_eq!(filtered, app.clusters);
+    fn test_log_level_from_message_warn() {
+        let level = LogLevel::from_message("WARN: Deprecated feature used");
+        assert_eq!(level, LogLevel::Warn);
+
+        let level = LogLevel::from_message("[WRN] Warning message");
+        assert_eq!(level, LogLevel::Warn);
     }
 
     #[test]
-    fn test_get_filtered_clusters_with_query() {
-        let mut app = create_test_app();
-        app.search_query = "prod".to_string();
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0], "cluster-prod");
+    fn test_log_level_from_message_error() {
+        let level = LogLevel::from_message("ERROR: Connection failed");
+        assert_eq!(level, LogLevel::Error);
+
+        let level = LogLevel::from_message("[ERR] Error occurred");
+        assert_eq!(level, LogLevel::Error);
     }
 
     #[test]
-    fn test_get_filtered_clusters_case_insensitive() {
-        let mut app = create_test_app();
-        app.search_query = "PROD".to_string();
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0], "cluster-prod");
+    fn test_log_level_from_message_fatal() {
+        let level = LogLevel::from_message("FATAL: Critical failure");
+        assert_eq!(level, LogLevel::Fatal);
+
+        let level = LogLevel::from_message("[FTL] Fatal error");
+        assert_eq!(level, LogLevel::Fatal);
     }
 
     #[test]
-    fn test_get_filtered_clusters_partial_match() {
-        let mut app = create_test_app();
-        app.search_query = "dev".to_string();
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0], "cluster-dev");
+    fn test_log_level_from_message_unknown() {
+        let level = LogLevel::from_message("Just a regular message");
+        assert_eq!(level, LogLevel::Unknown);
     }
 
     #[test]
-    fn test_get_filtered_clusters_no_match() {
-        let mut app = create_test_app();
-        app.search_query = "nonexistent".to_string();
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 0);
+    fn test_log_level_from_message_case_insensitive() {
+        let level = LogLevel::from_message("error: lowercase error");
+        assert_eq!(level, LogLevel::Error);
+
+        let level = LogLevel::from_message("WaRn: Mixed case");
+        assert_eq!(level, LogLevel::Warn);
     }
 
     #[test]
-    fn test_get_filtered_services_empty_query() {
-        let app = create_test_app();
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 3);
+    fn test_log_level_ignores_level_names_inside_words() {
+        let level = LogLevel::from_message("request done error_count=0 warnings_total=2");
+        assert_eq!(level, LogLevel::Unknown);
+
+        let level = LogLevel::from_message("level=warning retrying");
+        assert_eq!(level, LogLevel::Warn);
+    }
+
+    #[test]
+    fn test_json_log_level_comes_from_level_field() {
+        let entry = LogEntry::new(
+            1000,
+            r#"{"level":"info","msg":"processed","error_count":0}"#.to_string(),
+            "web".to_string(),
+        );
+        assert_eq!(entry.level, LogLevel::Info);
+        assert_eq!(entry.field("error_count").as_deref(), Some("0"));
+        assert_eq!(entry.field("container").as_deref(), Some("web"));
+
+        let entry = LogEntry::new(
+            1000,
+            r#"{"msg":"ERROR: connection refused"}"#.to_string(),
+            "web".to_string(),
+        );
+        assert_eq!(entry.level, LogLevel::Error);
     }
 
     #[test]
-    fn test_get_filtered_services_by_name() {
-        let mut app = create_test_app();
-        app.search_query = "web".to_string();
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "web-service");
+    fn test_log_entry_level_auto_parsed() {
+        let log = LogEntry::new(1000, "ERROR: Test".to_string(), "web".to_string());
+        assert_eq!(log.level, LogLevel::Error);
+
+        let log = LogEntry::new(2000, "INFO: Test".to_string(), "api".to_string());
+        assert_eq!(log.level, LogLevel::Info);
     }
 
+    // Test log level filtering
     #[test]
-    fn test_get_filtered_services_by_status() {
+    fn test_cycle_log_level_filter_from_none() {
         let mut app = create_test_app();
-        app.search_query = "DRAINING".to_string();
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "worker-service");
+        app.log_level_filter = None;
+
+        app.cycle_log_level_filter();
+        assert_eq!(app.log_level_filter, Some(LogLevel::Debug));
     }
 
     #[test]
-    fn test_get_filtered_services_by_launch_type() {
+    fn test_cycle_log_level_filter_sequence() {
         let mut app = create_test_app();
-        app.search_query = "FARGATE".to_string();
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered.iter().any(|s| s.name == "web-service"));
-        assert!(filtered.iter().any(|s| s.name == "worker-service"));
+
+        app.log_level_filter = None;
+        app.cycle_log_level_filter();
+        assert_eq!(app.log_level_filter, Some(LogLevel::Debug));
+
+        app.cycle_log_level_filter();
+        assert_eq!(app.log_level_filter, Some(LogLevel::Info));
+
+        app.cycle_log_level_filter();
+        assert_eq!(app.log_level_filter, Some(LogLevel::Warn));
+
+        app.cycle_log_level_filter();
+        assert_eq!(app.log_level_filter, Some(LogLevel::Error));
+
+        app.cycle_log_level_filter();
+        assert_eq!(app.log_level_filter, Some(LogLevel::Fatal));
+
+        app.cycle_log_level_filter();
+        assert_eq!(app.log_level_filter, None); // Wraps back to none
     }
 
     #[test]
-    fn test_get_filtered_tasks_empty_query() {
+    fn test_get_filtered_logs_empty() {
         let app = create_test_app();
-        let filtered = app.get_filtered_tasks();
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 0);
+    }
+
+    #[test]
+    fn test_get_filtered_logs_no_filter() {
+        let mut app = create_test_app();
+        app.logs = vec![
+            LogEntry::new(1000, "DEBUG: test1".to_string(), "web".to_string()),
+            LogEntry::new(2000, "INFO: test2".to_string(), "web".to_string()),
+            LogEntry::new(3000, "ERROR: test3".to_string(), "api".to_string()),
+        ];
+
+        let filtered = app.get_filtered_logs();
         assert_eq!(filtered.len(), 3);
     }
 
     #[test]
-    fn test_get_filtered_tasks_by_id() {
+    fn test_get_filtered_logs_by_level() {
         let mut app = create_test_app();
-        app.search_query = "abc123".to_string();
-        let filtered = app.get_filtered_tasks();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].task_id, "task-abc123");
+        app.logs = vec![
+            LogEntry::new(1000, "DEBUG: test1".to_string(), "web".to_string()),
+            LogEntry::new(2000, "INFO: test2".to_string(), "web".to_string()),
+            LogEntry::new(3000, "ERROR: test3".to_string(), "api".to_string()),
+            LogEntry::new(4000, "ERROR: test4".to_string(), "worker".to_string()),
+        ];
+        app.log_level_filter = Some(LogLevel::Error);
+
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().all(|log| log.level == LogLevel::Error));
     }
 
     #[test]
-    fn test_get_filtered_tasks_by_status() {
+    fn test_get_filtered_logs_by_search_text() {
         let mut app = create_test_app();
-        app.search_query = "RUNNING".to_string();
-        let filtered = app.get_filtered_tasks();
-        // Should match 2 tasks: one with status=RUNNING and one with desired_status=RUNNING
+        app.logs = vec![
+            LogEntry::new(1000, "User login successful".to_string(), "web".to_string()),
+            LogEntry::new(
+                2000,
+                "Database query executed".to_string(),
+                "api".to_string(),
+            ),
+            LogEntry::new(3000, "User logout".to_string(), "web".to_string()),
+        ];
+        app.log_search_query = "user".to_string();
+
+        let filtered = app.get_filtered_logs();
         assert_eq!(filtered.len(), 2);
-        assert!(filtered.iter().any(|t| t.task_id == "task-abc123"));
-        assert!(filtered.iter().any(|t| t.task_id == "task-def456"));
+        assert!(filtered
+            .iter()
+            .all(|log| log.message.to_lowercase().contains("user")));
     }
 
     #[test]
-    fn test_get_filtered_tasks_by_desired_status() {
+    fn test_get_filtered_logs_by_search_text_case_insensitive() {
         let mut app = create_test_app();
-        app.search_query = "STOPPED".to_string();
-        let filtered = app.get_filtered_tasks();
+        app.logs = vec![
+            LogEntry::new(1000, "USER action".to_string(), "web".to_string()),
+            LogEntry::new(2000, "system event".to_string(), "api".to_string()),
+        ];
+        app.log_search_query = "user".to_string();
+
+        let filtered = app.get_filtered_logs();
         assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].task_id, "task-ghi789");
     }
 
-    // Test navigation
     #[test]
-    fn test_next_wraps_around() {
+    fn test_get_filtered_logs_by_container_name() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
-        app.selected_index = 2; // Last item
-        app.next();
-        assert_eq!(app.selected_index, 0); // Should wrap to first
+        app.logs = vec![
+            LogEntry::new(1000, "message 1".to_string(), "web-server".to_string()),
+            LogEntry::new(2000, "message 2".to_string(), "api-server".to_string()),
+            LogEntry::new(3000, "message 3".to_string(), "web-worker".to_string()),
+        ];
+        app.log_search_query = "web".to_string();
+
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 2);
     }
 
     #[test]
-    fn test_next_increments() {
+    fn test_get_filtered_logs_level_and_search_combined() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
-        app.selected_index = 0;
-        app.next();
-        assert_eq!(app.selected_index, 1);
+        app.logs = vec![
+            LogEntry::new(1000, "ERROR: User not found".to_string(), "web".to_string()),
+            LogEntry::new(2000, "ERROR: Database error".to_string(), "api".to_string()),
+            LogEntry::new(3000, "INFO: User logged in".to_string(), "web".to_string()),
+            LogEntry::new(4000, "WARN: User timeout".to_string(), "web".to_string()),
+        ];
+        app.log_level_filter = Some(LogLevel::Error);
+        app.log_search_query = "user".to_string();
+
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 1); // Only ERROR logs containing "user"
+        assert_eq!(filtered[0].message, "ERROR: User not found");
     }
 
     #[test]
-    fn test_previous_wraps_around() {
+    fn test_get_filtered_logs_is_cached_until_logs_or_filters_change() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
-        app.selected_index = 0; // First item
-        app.previous();
-        assert_eq!(app.selected_index, 2); // Should wrap to last
+        let entry = |timestamp: i64, message: &str| {
+            LogEntry::new(timestamp, message.to_string(), "web".to_string())
+        };
+        app.logs = vec![entry(1000, "INFO started"), entry(2000, "ERROR failed")];
+
+        let filtered = app.get_filtered_logs();
+        assert!(Rc::ptr_eq(&filtered, &app.get_filtered_logs()));
+
+        app.log_search_query = "failed".to_string();
+        assert_eq!(app.get_filtered_logs().len(), 1);
+        app.log_search_query.clear();
+
+        app.append_log_page(LogPage {
+            entries: vec![entry(3000, "    at handler.js:10")],
+            cursors: Vec::new(),
+            unavailable: Vec::new(),
+        });
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[1].continuation.len(), 1);
+
+        // Multi-line settings regroup the entries
+        app.config.logs.multiline_pattern = "^$".to_string();
+        assert_eq!(app.get_filtered_logs().len(), 3);
     }
 
     #[test]
-    fn test_previous_decrements() {
+    fn test_get_filtered_logs_by_field_filters() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
-        app.selected_index = 2;
-        app.previous();
-        assert_eq!(app.selected_index, 1);
+        app.logs = vec![
+            LogEntry::new(
+                1000,
+                r#"{"level":"error","msg":"payment failed","http":{"status":502}}"#.to_string(),
+                "web".to_string(),
+            ),
+            LogEntry::new(
+                2000,
+                r#"{"level":"info","msg":"payment ok","http":{"status":200}}"#.to_string(),
+                "web".to_string(),
+            ),
+            LogEntry::new(
+                3000,
+                "ERROR: payment timeout".to_string(),
+                "api".to_string(),
+            ),
+        ];
+
+        app.log_search_query = "http.status=502 payment".to_string();
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].timestamp, 1000);
+
+        // Entries without the field do not have the excluded value
+        app.log_search_query = "http.status!=200".to_string();
+        let timestamps: Vec<i64> = app
+            .get_filtered_logs()
+            .iter()
+            .map(|l| l.timestamp)
+            .collect();
+        assert_eq!(timestamps, vec![1000, 3000]);
+
+        app.log_search_query = "level=error".to_string();
+        let timestamps: Vec<i64> = app
+            .get_filtered_logs()
+            .iter()
+            .map(|l| l.timestamp)
+            .collect();
+        assert_eq!(timestamps, vec![1000, 3000]);
     }
 
     #[test]
-    fn test_next_in_logs_scrolls_down() {
+    fn test_log_columns_and_entry_detail() {
         let mut app = create_test_app();
         app.state = AppState::Logs;
         app.logs = vec![
-            LogEntry::new(1000, "log1".to_string(), "container1".to_string()),
-            LogEntry::new(2000, "log2".to_string(), "container1".to_string()),
+            LogEntry::new(1000, "INFO: started".to_string(), "web".to_string()),
+            LogEntry::new(
+                2000,
+                r#"{"level":"warn","logger":"api","trace_id":"t-1","msg":"slow"}"#.to_string(),
+                "web".to_string(),
+            ),
         ];
-        app.log_scroll = 0;
+
+        app.enter_log_columns_mode();
+        for c in "level, trace_id,".chars() {
+            app.update_log_columns(c);
+        }
+        app.submit_log_columns();
+        assert!(!app.log_columns_mode);
+        assert_eq!(app.log_columns, vec!["level", "trace_id"]);
+        app.enter_log_columns_mode();
+        assert_eq!(app.log_columns_input, "level, trace_id");
+        app.cancel_log_columns();
+
+        // While tailing, Enter opens the newest entry
         app.auto_tail = true;
+        app.select();
+        assert_eq!(app.modal_state, ModalState::LogEntry);
+        let lines = app.log_entry_detail_lines();
+        assert!(lines.contains(&"Logger:    api".to_string()));
+        assert!(lines.contains(&"Trace ID:  t-1".to_string()));
+        assert!(lines.contains(&"  \"msg\": \"slow\",".to_string()));
 
-        app.next();
+        app.modal_next();
+        assert_eq!(app.modal_selected_index, 1);
+        app.close_modal();
+        assert!(app.log_detail.is_none());
 
-        assert_eq!(app.log_scroll, 1);
-        assert!(!app.auto_tail);
+        // Otherwise the entry at the scroll position
+        app.auto_tail = false;
+        app.log_scroll = 0;
+        app.select();
+        assert_eq!(
+            app.log_detail.as_ref().map(|entry| entry.message.as_str()),
+            Some("INFO: started")
+        );
     }
 
     #[test]
-    fn test_previous_in_logs_scrolls_up() {
+    fn test_multiline_log_entries_are_grouped_and_expandable() {
         let mut app = create_test_app();
         app.state = AppState::Logs;
-        app.log_scroll = 5;
-        app.auto_tail = true;
+        app.logs = vec![
+            LogEntry::new(1000, "ERROR Request failed".to_string(), "web".to_string()),
+            LogEntry::new(
+                1000,
+                "java.lang.IllegalStateException: pool closed".to_string(),
+                "web".to_string(),
+            ),
+            LogEntry::new(
+                1001,
+                "\tat com.example.Pool.get".to_string(),
+                "web".to_string(),
+            ),
+            LogEntry::new(2000, "INFO ok".to_string(), "web".to_string()),
+        ];
 
-        app.previous();
+        // Continuation lines share the entry's level and are searched with it
+        app.log_level_filter = Some(LogLevel::Error);
+        assert_eq!(app.get_filtered_logs().len(), 1);
+        app.log_level_filter = None;
+        app.log_search_query = "pool closed".to_string();
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].continuation.len(), 2);
+        app.log_search_query.clear();
 
-        assert_eq!(app.log_scroll, 4);
-        assert!(!app.auto_tail);
+        app.auto_tail = false;
+        app.log_scroll = 0;
+        app.toggle_log_expanded();
+        let filtered = app.get_filtered_logs();
+        assert!(app.is_log_expanded(&filtered[0]));
+        assert!(!app.is_log_expanded(&filtered[1]));
+        app.toggle_log_expanded();
+        assert!(!app.is_log_expanded(&filtered[0]));
+
+        app.toggle_all_logs_expanded();
+        assert!(app.is_log_expanded(&filtered[0]));
+        app.toggle_log_expanded();
+        assert!(!app.is_log_expanded(&filtered[0]));
+
+        // Single-line entries have nothing to expand
+        app.log_scroll = 1;
+        app.toggle_log_expanded();
+        assert_eq!(app.status_message, "Log entry has a single line");
+
+        app.log_scroll = 0;
+        app.select();
+        assert!(app
+            .log_entry_detail_lines()
+            .contains(&"\tat com.example.Pool.get".to_string()));
     }
 
     #[test]
-    fn test_previous_in_logs_saturates_at_zero() {
+    fn test_log_find_highlights_without_filtering_and_cycles_matches() {
         let mut app = create_test_app();
         app.state = AppState::Logs;
-        app.log_scroll = 0;
+        app.logs = vec![
+            LogEntry::new(
+                1000,
+                "INFO request timeout=5s".to_string(),
+                "web".to_string(),
+            ),
+            LogEntry::new(2000, "INFO ok".to_string(), "web".to_string()),
+            LogEntry::new(
+                3000,
+                "ERROR Timeout, timeout".to_string(),
+                "web".to_string(),
+            ),
+            LogEntry::new(4000, "INFO done".to_string(), "web".to_string()),
+        ];
+        app.auto_tail = true;
 
-        app.previous();
+        app.enter_log_find_mode();
+        for c in "TIMEOUT".chars() {
+            app.update_log_find(c);
+        }
+        assert_eq!(app.log_find_status().as_deref(), Some("Find: TIMEOUT_"));
+        app.submit_log_find();
+
+        // Nothing is filtered out; the newest match is selected
+        assert_eq!(app.get_filtered_logs().len(), 4);
+        assert!(!app.auto_tail);
+        assert_eq!(app.log_scroll, 2);
+        assert_eq!(app.status_message, "Match 2 of 2 for 'TIMEOUT'");
+        assert_eq!(
+            app.log_find_status().as_deref(),
+            Some("Find: TIMEOUT (2/2 entries, 3 matches)")
+        );
 
+        app.next_log_match();
         assert_eq!(app.log_scroll, 0);
+        app.previous_log_match();
+        assert_eq!(app.log_scroll, 2);
+        app.log_scroll = 3;
+        app.previous_log_match();
+        assert_eq!(app.log_scroll, 2);
+
+        // Regex mode matches the query as a case-sensitive regex
+        app.search_regex_mode = true;
+        app.log_find_query = "^INFO (ok|done)$".to_string();
+        app.next_log_match();
+        assert_eq!(app.log_scroll, 3);
+        app.next_log_match();
+        assert_eq!(app.log_scroll, 1);
+
+        app.log_find_query = "(".to_string();
+        app.submit_log_find();
+        assert_eq!(app.status_message, "Invalid regex: (");
+        assert_eq!(
+            app.log_find_status().as_deref(),
+            Some("Find regex: ( (invalid)")
+        );
+
+        app.cancel_log_find();
+        assert!(app.log_find_query.is_empty());
+        assert_eq!(app.log_find_status(), None);
     }
 
-    // Test state transitions
+    // Test log search mode
     #[test]
-    fn test_set_view_changes_state() {
+    fn test_enter_log_search_mode() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
-        app.selected_index = 5;
+        app.log_search_mode = false;
+        app.log_search_query = "old query".to_string();
 
-        app.set_view(AppState::Services);
+        app.enter_log_search_mode();
 
-        assert_eq!(app.state, AppState::Services);
-        assert_eq!(app.previous_state, Some(AppState::Clusters));
-        assert_eq!(app.selected_index, 0); // Should reset index
+        assert!(app.log_search_mode);
+        assert_eq!(app.log_search_query, "");
+        assert!(app.status_message.contains("Log search"));
     }
 
     #[test]
-    fn test_back_from_services_to_clusters() {
+    fn test_exit_log_search_mode() {
         let mut app = create_test_app();
-        app.state = AppState::Services;
-        app.selected_service = Some("test-service".to_string());
+        app.log_search_mode = true;
+        app.log_search_query = "test".to_string();
+        app.logs = vec![
+            LogEntry::new(1000, "test message".to_string(), "web".to_string()),
+            LogEntry::new(2000, "another message".to_string(), "web".to_string()),
+        ];
 
-        app.back();
+        app.exit_log_search_mode();
 
-        assert_eq!(app.state, AppState::Clusters);
-        assert_eq!(app.selected_service, None);
+        assert!(!app.log_search_mode);
+        assert!(app.status_message.contains("Found"));
     }
 
     #[test]
-    fn test_back_from_tasks_to_services() {
+    fn test_update_log_search() {
         let mut app = create_test_app();
-        app.state = AppState::Tasks;
+        app.log_search_query = "test".to_string();
 
-        app.back();
+        app.update_log_search('!');
 
-        assert_eq!(app.state, AppState::Services);
+        assert_eq!(app.log_search_query, "test!");
     }
 
     #[test]
-    fn test_back_from_details_to_tasks() {
+    fn test_delete_log_search_char() {
         let mut app = create_test_app();
-        app.state = AppState::Details;
-        app.details = Some("test details".to_string());
+        app.log_search_query = "test".to_string();
 
-        app.back();
+        app.delete_log_search_char();
 
-        assert_eq!(app.state, AppState::Tasks);
-        assert_eq!(app.details, None);
+        assert_eq!(app.log_search_query, "tes");
     }
 
     #[test]
-    fn test_back_from_logs_to_tasks() {
+    fn test_delete_log_search_char_empty() {
         let mut app = create_test_app();
-        app.state = AppState::Logs;
-        app.logs = vec![LogEntry::new(
-            1000,
-            "test".to_string(),
-            "container1".to_string(),
-        )];
-        app.log_scroll = 5;
-        app.auto_tail = false;
+        app.log_search_query = String::new();
 
-        app.back();
+        app.delete_log_search_char();
 
-        assert_eq!(app.state, AppState::Tasks);
-        assert_eq!(app.logs.len(), 0);
-        assert_eq!(app.log_scroll, 0);
-        assert!(app.auto_tail);
+        assert_eq!(app.log_search_query, "");
+        // Should not panic
     }
 
     #[test]
-    fn test_back_from_clusters_does_nothing() {
+    fn test_clear_log_search() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
+        app.log_search_mode = true;
+        app.log_search_query = "test query".to_string();
 
-        app.back();
+        app.clear_log_search();
 
-        assert_eq!(app.state, AppState::Clusters);
+        assert!(!app.log_search_mode);
+        assert_eq!(app.log_search_query, "");
+        assert!(app.status_message.contains("cleared"));
     }
 
-    // Test auto-tail toggle
+    // Test auto-tail behavior
     #[test]
-    fn test_toggle_auto_tail_enables() {
+    fn test_auto_tail_scrolls_to_end_on_new_logs() {
         let mut app = create_test_app();
-        app.auto_tail = false;
+        app.state = AppState::Logs;
+        app.auto_tail = true;
         app.logs = vec![
-            LogEntry::new(1000, "log1".to_string(), "container1".to_string()),
-            LogEntry::new(2000, "log2".to_string(), "container1".to_string()),
+            LogEntry::new(1000, "log1".to_string(), "web".to_string()),
+            LogEntry::new(2000, "log2".to_string(), "web".to_string()),
+            LogEntry::new(3000, "log3".to_string(), "web".to_string()),
         ];
+        app.log_scroll = 0;
 
-        app.toggle_auto_tail();
+        // Simulate what should_refresh does when auto_tail is true
+        if app.auto_tail && !app.logs.is_empty() {
+            app.log_scroll = app.logs.len().saturating_sub(1);
+        }
 
-        assert!(app.auto_tail);
-        assert_eq!(app.log_scroll, 1); // Should scroll to last log (len - 1)
-        assert!(app.status_message.contains("enabled"));
+        assert_eq!(app.log_scroll, 2); // Last log index
     }
 
     #[test]
-    fn test_toggle_auto_tail_disables() {
+    fn test_scrolling_disables_auto_tail() {
         let mut app = create_test_app();
+        app.state = AppState::Logs;
         app.auto_tail = true;
+        app.logs = vec![
+            LogEntry::new(1000, "log1".to_string(), "web".to_string()),
+            LogEntry::new(2000, "log2".to_string(), "web".to_string()),
+        ];
 
-        app.toggle_auto_tail();
+        app.next(); // Scroll down
 
         assert!(!app.auto_tail);
-        assert!(app.status_message.contains("disabled"));
     }
 
     #[test]
-    fn test_toggle_auto_tail_with_empty_logs() {
+    fn test_export_logs_generates_filename() {
+        let app = create_test_app();
+        // We can't fully test export without a real filesystem,
+        // but we can verify the method exists and basic logic
+
+        // This would normally fail without proper setup, so we just check the method exists
+        // In integration tests, we could verify actual file creation
+        assert_eq!(app.logs.len(), 0);
+    }
+
+    // Test edge cases
+    #[test]
+    fn test_log_level_filter_with_no_logs() {
         let mut app = create_test_app();
-        app.auto_tail = false;
         app.logs = vec![];
+        app.log_level_filter = Some(LogLevel::Error);
 
-        app.toggle_auto_tail();
-
-        assert!(app.auto_tail);
-        // Should not panic with empty logs
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 0);
     }
 
-    // Test search mode
     #[test]
-    fn test_enter_search_mode() {
+    fn test_log_search_with_no_logs() {
         let mut app = create_test_app();
-        app.search_mode = false;
-        app.search_query = "old query".to_string();
-        app.selected_index = 5;
-
-        app.enter_search_mode();
+        app.logs = vec![];
+        app.log_search_query = "test".to_string();
 
-        assert!(app.search_mode);
-        assert_eq!(app.search_query, "");
-        assert_eq!(app.selected_index, 0);
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 0);
     }
 
     #[test]
-    fn test_exit_search_mode() {
+    fn test_log_level_filter_no_matches() {
         let mut app = create_test_app();
-        app.search_mode = true;
-
-        app.exit_search_mode();
+        app.logs = vec![
+            LogEntry::new(1000, "INFO: test1".to_string(), "web".to_string()),
+            LogEntry::new(2000, "DEBUG: test2".to_string(), "web".to_string()),
+        ];
+        app.log_level_filter = Some(LogLevel::Error);
 
-        assert!(!app.search_mode);
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 0);
     }
 
     #[test]
-    fn test_clear_search() {
+    fn test_log_search_no_matches() {
         let mut app = create_test_app();
-        app.search_mode = true;
-        app.search_query = "test query".to_string();
-        app.selected_index = 5;
-
-        app.clear_search();
+        app.logs = vec![
+            LogEntry::new(1000, "message 1".to_string(), "web".to_string()),
+            LogEntry::new(2000, "message 2".to_string(), "api".to_string()),
+        ];
+        app.log_search_query = "nonexistent".to_string();
 
-        assert!(!app.search_mode);
-        assert_eq!(app.search_query, "");
-        assert_eq!(app.selected_index, 0);
+        let filtered = app.get_filtered_logs();
+        assert_eq!(filtered.len(), 0);
     }
 
     #[test]
-    fn test_update_search() {
+    fn test_multiple_containers_in_logs() {
         let mut app = create_test_app();
-        app.search_query = "test".to_string();
-        app.selected_index = 5;
+        app.logs = vec![
+            LogEntry::new(1000, "Web started".to_string(), "nginx".to_string()),
+            LogEntry::new(2000, "API ready".to_string(), "api".to_string()),
+            LogEntry::new(3000, "DB connected".to_string(), "postgres".to_string()),
+            LogEntry::new(4000, "Cache ready".to_string(), "redis".to_string()),
+        ];
 
-        app.update_search('!');
+        // Verify all container names are preserved
+        assert!(app.logs.iter().any(|l| l.container_name == "nginx"));
+        assert!(app.logs.iter().any(|l| l.container_name == "api"));
+        assert!(app.logs.iter().any(|l| l.container_name == "postgres"));
+        assert!(app.logs.iter().any(|l| l.container_name == "redis"));
+    }
 
-        assert_eq!(app.search_query, "test!");
-        assert_eq!(app.selected_index, 0); // Should reset index
+    #[test]
+    fn test_log_level_equality() {
+        assert_eq!(LogLevel::Error, LogLevel::Error);
+        assert_ne!(LogLevel::Error, LogLevel::Warn);
+        assert_eq!(LogLevel::Info, LogLevel::Info);
     }
 
+    // Tests for regex search functionality
     #[test]
-    fn test_update_search_multiple_chars() {
+    fn test_toggle_regex_mode() {
         let mut app = create_test_app();
-        app.search_query = String::new();
+        assert!(!app.search_regex_mode);
 
-        app.update_search('h');
-        app.update_search('e');
-        app.update_search('l');
-        app.update_search('l');
-        app.update_search('o');
+        app.toggle_regex_mode();
+        assert!(app.search_regex_mode);
+        assert!(app.status_message.contains("Regex mode: ON"));
 
-        assert_eq!(app.search_query, "hello");
+        app.toggle_regex_mode();
+        assert!(!app.search_regex_mode);
+        assert!(app.status_message.contains("Regex mode: OFF"));
     }
 
     #[test]
-    fn test_delete_search_char() {
+    fn test_search_with_regex_pattern() {
         let mut app = create_test_app();
-        app.search_query = "test".to_string();
-        app.selected_index = 5;
-
-        app.delete_search_char();
+        app.search_regex_mode = true;
+        app.search_query = "cluster-(prod|dev)".to_string();
 
-        assert_eq!(app.search_query, "tes");
-        assert_eq!(app.selected_index, 0); // Should reset index
+        let filtered = app.get_filtered_clusters();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.contains(&"cluster-prod".to_string()));
+        assert!(filtered.contains(&"cluster-dev".to_string()));
+        assert!(!filtered.contains(&"cluster-staging".to_string()));
     }
 
     #[test]
-    fn test_delete_search_char_empty() {
+    fn test_search_with_invalid_regex() {
         let mut app = create_test_app();
-        app.search_query = String::new();
-
-        app.delete_search_char();
+        app.search_regex_mode = true;
+        app.search_query = "[invalid(".to_string(); // Invalid regex
 
-        assert_eq!(app.search_query, "");
-        // Should not panic with empty string
+        let filtered = app.get_filtered_clusters();
+        // Should return all clusters when regex is invalid
+        assert_eq!(filtered.len(), 3);
     }
 
-    // Test help toggle
     #[test]
-    fn test_toggle_help() {
+    fn test_matches_search_follows_query_changes() {
         let mut app = create_test_app();
-        app.show_help = false;
+        app.search_regex_mode = true;
+        app.search_query = "^web".to_string();
+        assert!(app.matches_search(&["api", "web-service"]));
+        assert!(!app.matches_search(&["api-service"]));
 
-        app.toggle_help();
-        assert!(app.show_help);
+        // The compiled regex is replaced when the query changes
+        app.search_query = "^api".to_string();
+        assert!(app.matches_search(&["api-service"]));
+        assert!(!app.matches_search(&["web-service"]));
 
-        app.toggle_help();
-        assert!(!app.show_help);
+        app.search_regex_mode = false;
+        app.search_query = "SERVICE".to_string();
+        assert!(app.matches_search(&["web-service"]));
+        app.search_query.clear();
+        assert!(app.matches_search(&[]));
     }
 
-    // Test should_refresh
     #[test]
-    fn test_should_refresh_logs_state() {
+    fn test_regex_case_sensitive() {
         let mut app = create_test_app();
-        app.state = AppState::Logs;
-        app.auto_tail = true;
-        app.last_refresh = Instant::now() - Duration::from_secs(6);
+        app.search_regex_mode = true;
+        app.search_query = "PROD".to_string();
 
-        assert!(app.should_refresh());
+        let filtered = app.get_filtered_clusters();
+        // Regex is case-sensitive by default
+        assert_eq!(filtered.len(), 0);
     }
 
     #[test]
-    fn test_should_refresh_logs_state_not_yet() {
+    fn test_regex_with_services() {
         let mut app = create_test_app();
-        app.state = AppState::Logs;
-        app.auto_tail = true;
-        app.last_refresh = Instant::now() - Duration::from_secs(3);
+        app.search_regex_mode = true;
+        app.search_query = "^(web|api)-".to_string();
 
-        assert!(!app.should_refresh());
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().any(|s| s.name == "web-service"));
+        assert!(filtered.iter().any(|s| s.name == "api-service"));
     }
 
+    // Tests for service status filter
     #[test]
-    fn test_should_refresh_other_state() {
+    fn test_cycle_service_status_filter() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
-        app.last_refresh = Instant::now() - Duration::from_secs(31);
+        assert_eq!(app.service_status_filter, None);
 
-        assert!(app.should_refresh());
-    }
+        app.cycle_service_status_filter();
+        assert_eq!(app.service_status_filter, Some("ACTIVE".to_string()));
 
-    #[test]
-    fn test_should_refresh_other_state_not_yet() {
-        let mut app = create_test_app();
-        app.state = AppState::Services;
-        app.last_refresh = Instant::now() - Duration::from_secs(20);
+        app.cycle_service_status_filter();
+        assert_eq!(app.service_status_filter, Some("DRAINING".to_string()));
 
-        assert!(!app.should_refresh());
+        app.cycle_service_status_filter();
+        assert_eq!(app.service_status_filter, None);
     }
 
     #[test]
-    fn test_should_refresh_disabled_in_config() {
+    fn test_filter_services_by_status() {
         let mut app = create_test_app();
-        app.config.behavior.auto_refresh = false;
-        app.last_refresh = Instant::now() - Duration::from_secs(100);
+        app.service_status_filter = Some("ACTIVE".to_string());
 
-        assert!(!app.should_refresh());
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().all(|s| s.status == "ACTIVE"));
     }
 
-    // Test edge cases
     #[test]
-    fn test_next_with_empty_list() {
+    fn test_filter_services_by_status_draining() {
         let mut app = create_test_app();
-        app.state = AppState::Clusters;
-        app.clusters = vec![];
-        app.selected_index = 0;
-
-        app.next();
+        app.service_status_filter = Some("DRAINING".to_string());
 
-        assert_eq!(app.selected_index, 0); // Should stay at 0
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "worker-service");
     }
 
+    // Tests for launch type filter
     #[test]
-    fn test_previous_with_empty_list() {
+    fn test_cycle_launch_type_filter() {
         let mut app = create_test_app();
-        app.state = AppState::Services;
-        app.services = vec![];
-        app.selected_index = 0;
+        assert_eq!(app.launch_type_filter, None);
 
-        app.previous();
+        app.cycle_launch_type_filter();
+        assert_eq!(app.launch_type_filter, Some("FARGATE".to_string()));
 
-        assert_eq!(app.selected_index, 0); // Should stay at 0
+        app.cycle_launch_type_filter();
+        assert_eq!(app.launch_type_filter, Some("EC2".to_string()));
+
+        app.cycle_launch_type_filter();
+        assert_eq!(app.launch_type_filter, Some("EXTERNAL".to_string()));
+
+        app.cycle_launch_type_filter();
+        assert_eq!(app.launch_type_filter, None);
     }
 
     #[test]
-    fn test_search_with_special_characters() {
+    fn test_filter_services_by_launch_type() {
         let mut app = create_test_app();
-        app.clusters = vec![
-            "cluster-prod-1".to_string(),
-            "cluster_dev_2".to_string(),
-            "cluster.staging.3".to_string(),
-        ];
-        app.search_query = "-".to_string();
+        app.launch_type_filter = Some("FARGATE".to_string());
 
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0], "cluster-prod-1");
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().all(|s| s.launch_type == "FARGATE"));
     }
 
     #[test]
-    fn test_search_with_underscore() {
+    fn test_filter_services_by_launch_type_ec2() {
         let mut app = create_test_app();
-        app.clusters = vec![
-            "cluster-prod-1".to_string(),
-            "cluster_dev_2".to_string(),
-            "cluster.staging.3".to_string(),
-        ];
-        app.search_query = "_".to_string();
+        app.launch_type_filter = Some("EC2".to_string());
 
-        let filtered = app.get_filtered_clusters();
+        let filtered = app.get_filtered_services();
         assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0], "cluster_dev_2");
+        assert_eq!(filtered[0].name, "api-service");
     }
 
+    // Tests for task status filter
     #[test]
-    fn test_search_with_dot() {
+    fn test_cycle_task_status_filter() {
         let mut app = create_test_app();
-        app.clusters = vec![
-            "cluster-prod-1".to_string(),
-            "cluster_dev_2".to_string(),
-            "cluster.staging.3".to_string(),
-        ];
-        app.search_query = ".".to_string();
+        assert_eq!(app.task_status_filter, None);
 
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0], "cluster.staging.3");
-    }
+        app.cycle_task_status_filter();
+        assert_eq!(app.task_status_filter, Some("RUNNING".to_string()));
 
-    // Test ServiceInfo and TaskInfo structures
-    #[test]
-    fn test_service_info_clone() {
-        let service = ServiceInfo {
-            name: "test".to_string(),
-            status: "ACTIVE".to_string(),
-            desired_count: 3,
-            running_count: 3,
-            pending_count: 0,
-            launch_type: "FARGATE".to_string(),
-        };
+        app.cycle_task_status_filter();
+        assert_eq!(app.task_status_filter, Some("PENDING".to_string()));
 
-        let cloned = service.clone();
-        assert_eq!(service.name, cloned.name);
-        assert_eq!(service.status, cloned.status);
-        assert_eq!(service.desired_count, cloned.desired_count);
+        app.cycle_task_status_filter();
+        assert_eq!(app.task_status_filter, Some("STOPPED".to_string()));
+
+        app.cycle_task_status_filter();
+        assert_eq!(app.task_status_filter, None);
     }
 
     #[test]
-    fn test_task_info_clone() {
-        let task = TaskInfo {
-            task_arn: "arn:test".to_string(),
-            task_id: "id123".to_string(),
-            status: "RUNNING".to_string(),
-            desired_status: "RUNNING".to_string(),
-            container_instance: "instance-1".to_string(),
-            cpu: "256".to_string(),
-            memory: "512".to_string(),
-        };
+    fn test_filter_tasks_by_status() {
+        let mut app = create_test_app();
+        app.task_status_filter = Some("RUNNING".to_string());
 
-        let cloned = task.clone();
-        assert_eq!(task.task_arn, cloned.task_arn);
-        assert_eq!(task.task_id, cloned.task_id);
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].task_id, "task-abc123");
     }
 
     #[test]
-    fn test_log_entry_clone() {
-        let log = LogEntry::new(12345, "test message".to_string(), "container1".to_string());
+    fn test_filter_tasks_by_status_pending() {
+        let mut app = create_test_app();
+        app.task_status_filter = Some("PENDING".to_string());
 
-        let cloned = log.clone();
-        assert_eq!(log.timestamp, cloned.timestamp);
-        assert_eq!(log.message, cloned.message);
-        assert_eq!(log.container_name, cloned.container_name);
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].task_id, "task-def456");
     }
 
     #[test]
-    fn test_app_state_equality() {
-        assert_eq!(AppState::Clusters, AppState::Clusters);
-        assert_ne!(AppState::Clusters, AppState::Services);
-        assert_eq!(AppState::Logs, AppState::Logs);
-    }
+    fn test_filter_tasks_by_status_stopped() {
+        let mut app = create_test_app();
+        app.task_status_filter = Some("STOPPED".to_string());
 
-    #[test]
-    fn test_app_state_clone() {
-        let state = AppState::Tasks;
-        let cloned = state.clone();
-        assert_eq!(state, cloned);
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].task_id, "task-ghi789");
     }
 
-    // Test log level parsing
+    // Tests for multi-criteria filtering
     #[test]
-    fn test_log_level_from_message_debug() {
-        let level = LogLevel::from_message("DEBUG: Starting process");
-        assert_eq!(level, LogLevel::Debug);
+    fn test_multi_criteria_filter_services() {
+        let mut app = create_test_app();
+        app.service_status_filter = Some("ACTIVE".to_string());
+        app.launch_type_filter = Some("FARGATE".to_string());
 
-        let level = LogLevel::from_message("[DBG] Message");
-        assert_eq!(level, LogLevel::Debug);
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "web-service");
     }
 
     #[test]
-    fn test_log_level_from_message_info() {
-        let level = LogLevel::from_message("INFO: Server started");
-        assert_eq!(level, LogLevel::Info);
+    fn test_multi_criteria_filter_with_search() {
+        let mut app = create_test_app();
+        app.service_status_filter = Some("ACTIVE".to_string());
+        app.search_query = "web".to_string();
 
-        let level = LogLevel::from_message("[INF] Message");
-        assert_eq!(level, LogLevel::Info);
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "web-service");
     }
 
     #[test]
-    fn test_log_level_from_message_warn() {
-        let level = LogLevel::from_message("WARN: Deprecated feature used");
-        assert_eq!(level, LogLevel::Warn);
+    fn test_multi_criteria_filter_with_regex() {
+        let mut app = create_test_app();
+        app.service_status_filter = Some("ACTIVE".to_string());
+        app.search_regex_mode = true;
+        app.search_query = "^web".to_string();
 
-        let level = LogLevel::from_message("[WRN] Warning message");
-        assert_eq!(level, LogLevel::Warn);
+        let filtered = app.get_filtered_services();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "web-service");
     }
 
     #[test]
-    fn test_log_level_from_message_error() {
-        let level = LogLevel::from_message("ERROR: Connection failed");
-        assert_eq!(level, LogLevel::Error);
+    fn test_clear_all_filters() {
+        let mut app = create_test_app();
+        app.service_status_filter = Some("ACTIVE".to_string());
+        app.launch_type_filter = Some("FARGATE".to_string());
+        app.task_status_filter = Some("RUNNING".to_string());
 
-        let level = LogLevel::from_message("[ERR] Error occurred");
-        assert_eq!(level, LogLevel::Error);
+        app.clear_all_filters();
+
+        assert_eq!(app.service_status_filter, None);
+        assert_eq!(app.launch_type_filter, None);
+        assert_eq!(app.task_status_filter, None);
+        assert!(app.status_message.contains("cleared"));
     }
 
     #[test]
-    fn test_log_level_from_message_fatal() {
-        let level = LogLevel::from_message("FATAL: Critical failure");
-        assert_eq!(level, LogLevel::Fatal);
+    fn test_filter_tasks_with_regex() {
+        let mut app = create_test_app();
+        app.search_regex_mode = true;
+        app.search_query = "task-(abc|def)".to_string();
 
-        let level = LogLevel::from_message("[FTL] Fatal error");
-        assert_eq!(level, LogLevel::Fatal);
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.iter().any(|t| t.task_id == "task-abc123"));
+        assert!(filtered.iter().any(|t| t.task_id == "task-def456"));
     }
 
     #[test]
-    fn test_log_level_from_message_unknown() {
-        let level = LogLevel::from_message("Just a regular message");
-        assert_eq!(level, LogLevel::Unknown);
+    fn test_combined_task_filter_and_search() {
+        let mut app = create_test_app();
+        app.task_status_filter = Some("RUNNING".to_string());
+        app.search_query = "abc".to_string();
+
+        let filtered = app.get_filtered_tasks();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].task_id, "task-abc123");
     }
 
+    // Service Editor Tests
     #[test]
-    fn test_log_level_from_message_case_insensitive() {
-        let level = LogLevel::from_message("error: lowercase error");
-        assert_eq!(level, LogLevel::Error);
+    fn test_service_editor_modal_opens() {
+        let mut app = create_test_app();
+        app.modal_state = ModalState::ServiceEditor;
 
-        let level = LogLevel::from_message("WaRn: Mixed case");
-        assert_eq!(level, LogLevel::Warn);
+        assert_eq!(app.modal_state, ModalState::ServiceEditor);
     }
 
     #[test]
-    fn test_log_entry_level_auto_parsed() {
-        let log = LogEntry::new(1000, "ERROR: Test".to_string(), "web".to_string());
-        assert_eq!(log.level, LogLevel::Error);
+    fn test_service_editor_modal_closes() {
+        let mut app = create_test_app();
+        app.modal_state = ModalState::ServiceEditor;
 
-        let log = LogEntry::new(2000, "INFO: Test".to_string(), "api".to_string());
-        assert_eq!(log.level, LogLevel::Info);
+        app.close_modal();
+
+        assert_eq!(app.modal_state, ModalState::None);
+        assert_eq!(app.modal_selected_index, 0);
     }
 
-    // Test log level filtering
     #[test]
-    fn test_cycle_log_level_filter_from_none() {
+    fn test_service_editor_field_navigation_tab() {
         let mut app = create_test_app();
-        app.log_level_filter = None;
+        app.modal_state = ModalState::ServiceEditor;
+        app.service_editor_editing_field = 0;
 
-        app.cycle_log_level_filter();
-        assert_eq!(app.log_level_filter, Some(LogLevel::Debug));
+        // Tab should cycle through fields
+        app.service_editor_editing_field = (app.service_editor_editing_field + 1) % 2;
+        assert_eq!(app.service_editor_editing_field, 1);
+
+        app.service_editor_editing_field = (app.service_editor_editing_field + 1) % 2;
+        assert_eq!(app.service_editor_editing_field, 0);
     }
 
     #[test]
-    fn test_cycle_log_level_filter_sequence() {
+    fn test_service_editor_desired_count_input() {
         let mut app = create_test_app();
+        app.modal_state = ModalState::ServiceEditor;
+        app.service_editor_editing_field = 0;
 
-        app.log_level_filter = None;
-        app.cycle_log_level_filter();
-        assert_eq!(app.log_level_filter, Some(LogLevel::Debug));
-
-        app.cycle_log_level_filter();
-        assert_eq!(app.log_level_filter, Some(LogLevel::Info));
+        // Should accept numeric input
+        app.service_editor_desired_count_input.push('3');
+        assert_eq!(app.service_editor_desired_count_input, "3");
 
-        app.cycle_log_level_filter();
-        assert_eq!(app.log_level_filter, Some(LogLevel::Warn));
+        app.service_editor_desired_count_input.push('5');
+        assert_eq!(app.service_editor_desired_count_input, "35");
+    }
 
-        app.cycle_log_level_filter();
-        assert_eq!(app.log_level_filter, Some(LogLevel::Error));
+    #[test]
+    fn test_service_editor_desired_count_backspace() {
+        let mut app = create_test_app();
+        app.modal_state = ModalState::ServiceEditor;
+        app.service_editor_editing_field = 0;
+        app.service_editor_desired_count_input = "123".to_string();
 
-        app.cycle_log_level_filter();
-        assert_eq!(app.log_level_filter, Some(LogLevel::Fatal));
+        app.service_editor_desired_count_input.pop();
+        assert_eq!(app.service_editor_desired_count_input, "12");
 
-        app.cycle_log_level_filter();
-        assert_eq!(app.log_level_filter, None); // Wraps back to none
-    }
+        app.service_editor_desired_count_input.pop();
+        assert_eq!(app.service_editor_desired_count_input, "1");
 
-    #[test]
-    fn test_get_filtered_logs_empty() {
-        let app = create_test_app();
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 0);
+        app.service_editor_desired_count_input.pop();
+        assert_eq!(app.service_editor_desired_count_input, "");
     }
 
     #[test]
-    fn test_get_filtered_logs_no_filter() {
+    fn test_service_editor_revision_navigation_up() {
         let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "DEBUG: test1".to_string(), "web".to_string()),
-            LogEntry::new(2000, "INFO: test2".to_string(), "web".to_string()),
-            LogEntry::new(3000, "ERROR: test3".to_string(), "api".to_string()),
+        app.modal_state = ModalState::ServiceEditor;
+        app.service_editor_editing_field = 1;
+        app.service_editor_available_revisions = vec![
+            "task-def:1".to_string(),
+            "task-def:2".to_string(),
+            "task-def:3".to_string(),
         ];
+        app.service_editor_selected_revision = 2;
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 3);
+        // Navigate up
+        if app.service_editor_selected_revision > 0 {
+            app.service_editor_selected_revision -= 1;
+        }
+        assert_eq!(app.service_editor_selected_revision, 1);
+
+        if app.service_editor_selected_revision > 0 {
+            app.service_editor_selected_revision -= 1;
+        }
+        assert_eq!(app.service_editor_selected_revision, 0);
+
+        // Should not go below 0
+        if app.service_editor_selected_revision > 0 {
+            app.service_editor_selected_revision -= 1;
+        }
+        assert_eq!(app.service_editor_selected_revision, 0);
     }
 
     #[test]
-    fn test_get_filtered_logs_by_level() {
+    fn test_service_editor_revision_navigation_down() {
         let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "DEBUG: test1".to_string(), "web".to_string()),
-            LogEntry::new(2000, "INFO: test2".to_string(), "web".to_string()),
-            LogEntry::new(3000, "ERROR: test3".to_string(), "api".to_string()),
-            LogEntry::new(4000, "ERROR: test4".to_string(), "worker".to_string()),
+        app.modal_state = ModalState::ServiceEditor;
+        app.service_editor_editing_field = 1;
+        app.service_editor_available_revisions = vec![
+            "task-def:1".to_string(),
+            "task-def:2".to_string(),
+            "task-def:3".to_string(),
         ];
-        app.log_level_filter = Some(LogLevel::Error);
+        app.service_editor_selected_revision = 0;
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered.iter().all(|log| log.level == LogLevel::Error));
+        // Navigate down
+        if app.service_editor_selected_revision + 1 < app.service_editor_available_revisions.len() {
+            app.service_editor_selected_revision += 1;
+        }
+        assert_eq!(app.service_editor_selected_revision, 1);
+
+        if app.service_editor_selected_revision + 1 < app.service_editor_available_revisions.len() {
+            app.service_editor_selected_revision += 1;
+        }
+        assert_eq!(app.service_editor_selected_revision, 2);
+
+        // Should not go beyond length
+        if app.service_editor_selected_revision + 1 < app.service_editor_available_revisions.len() {
+            app.service_editor_selected_revision += 1;
+        }
+        assert_eq!(app.service_editor_selected_revision, 2);
     }
 
     #[test]
-    fn test_get_filtered_logs_by_search_text() {
+    fn test_service_editor_modal_next() {
         let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "User login successful".to_string(), "web".to_string()),
-            LogEntry::new(
-                2000,
-                "Database query executed".to_string(),
-                "api".to_string(),
-            ),
-            LogEntry::new(3000, "User logout".to_string(), "web".to_string()),
-        ];
-        app.log_search_query = "user".to_string();
+        app.modal_state = ModalState::ServiceEditor;
+        app.service_editor_available_revisions =
+            vec!["task-def:1".to_string(), "task-def:2".to_string()];
+        app.modal_selected_index = 0;
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered
-            .iter()
-            .all(|log| log.message.to_lowercase().contains("user")));
+        app.modal_next();
+        assert_eq!(app.modal_selected_index, 1);
+
+        app.modal_next();
+        assert_eq!(app.modal_selected_index, 0); // Wraps around
     }
 
     #[test]
-    fn test_get_filtered_logs_by_search_text_case_insensitive() {
+    fn test_service_editor_modal_previous() {
         let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "USER action".to_string(), "web".to_string()),
-            LogEntry::new(2000, "system event".to_string(), "api".to_string()),
-        ];
-        app.log_search_query = "user".to_string();
+        app.modal_state = ModalState::ServiceEditor;
+        app.service_editor_available_revisions =
+            vec!["task-def:1".to_string(), "task-def:2".to_string()];
+        app.modal_selected_index = 0;
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 1);
+        app.modal_previous();
+        assert_eq!(app.modal_selected_index, 1); // Wraps around
+
+        app.modal_previous();
+        assert_eq!(app.modal_selected_index, 0);
     }
 
     #[test]
-    fn test_get_filtered_logs_by_container_name() {
-        let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "message 1".to_string(), "web-server".to_string()),
-            LogEntry::new(2000, "message 2".to_string(), "api-server".to_string()),
-            LogEntry::new(3000, "message 3".to_string(), "web-worker".to_string()),
-        ];
-        app.log_search_query = "web".to_string();
+    fn test_service_editor_initial_state() {
+        let app = create_test_app();
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 2);
+        assert_eq!(app.service_editor_desired_count_input, "");
+        assert_eq!(app.service_editor_selected_revision, 0);
+        assert_eq!(app.service_editor_available_revisions.len(), 0);
+        assert_eq!(app.service_editor_current_task_def, "");
+        assert_eq!(app.service_editor_editing_field, 0);
     }
 
-    #[test]
-    fn test_get_filtered_logs_level_and_search_combined() {
-        let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "ERROR: User not found".to_string(), "web".to_string()),
-            LogEntry::new(2000, "ERROR: Database error".to_string(), "api".to_string()),
-            LogEntry::new(3000, "INFO: User logged in".to_string(), "web".to_string()),
-            LogEntry::new(4000, "WARN: User timeout".to_string(), "web".to_string()),
-        ];
-        app.log_level_filter = Some(LogLevel::Error);
-        app.log_search_query = "user".to_string();
+    // Service events tests
+    fn create_test_deployment(status: &str, rollout_state: &str) -> DeploymentInfo {
+        DeploymentInfo {
+            id: "ecs-svc/1".to_string(),
+            status: status.to_string(),
+            rollout_state: rollout_state.to_string(),
+            rollout_state_reason: String::new(),
+            task_definition: "arn:aws:ecs:us-east-1:123456789012:task-definition/web:3".to_string(),
+            desired_count: 2,
+            running_count: 1,
+            pending_count: 1,
+            failed_tasks: 0,
+            updated_at: 0,
+        }
+    }
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 1); // Only ERROR logs containing "user"
-        assert_eq!(filtered[0].message, "ERROR: User not found");
+    fn create_test_events() -> Vec<ServiceEvent> {
+        vec![
+            ServiceEvent {
+                created_at: 3000,
+                message: "(service web-service) has reached a steady state.".to_string(),
+            },
+            ServiceEvent {
+                created_at: 2000,
+                message: "(service web-service) has started 1 tasks: (task abc).".to_string(),
+            },
+            ServiceEvent {
+                created_at: 1000,
+                message: "(service web-service) was unable to place a task.".to_string(),
+            },
+        ]
     }
 
-    // Test log search mode
     #[test]
-    fn test_enter_log_search_mode() {
+    fn test_get_filtered_service_events_substring() {
         let mut app = create_test_app();
-        app.log_search_mode = false;
-        app.log_search_query = "old query".to_string();
-
-        app.enter_log_search_mode();
-
-        assert!(app.log_search_mode);
-        assert_eq!(app.log_search_query, "");
-        assert!(app.status_message.contains("Log search"));
+        app.service_events = create_test_events();
+        app.search_query = "STARTED".to_string();
+        let filtered = app.get_filtered_service_events();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].created_at, 2000);
     }
 
     #[test]
-    fn test_exit_log_search_mode() {
+    fn test_get_filtered_service_events_regex() {
         let mut app = create_test_app();
-        app.log_search_mode = true;
-        app.log_search_query = "test".to_string();
-        app.logs = vec![
-            LogEntry::new(1000, "test message".to_string(), "web".to_string()),
-            LogEntry::new(2000, "another message".to_string(), "web".to_string()),
-        ];
+        app.service_events = create_test_events();
+        app.search_regex_mode = true;
+        app.search_query = "steady|unable".to_string();
+        assert_eq!(app.get_filtered_service_events().len(), 2);
+    }
 
-        app.exit_log_search_mode();
+    #[test]
+    fn test_rollout_in_progress() {
+        let mut app = create_test_app();
+        assert!(!app.rollout_in_progress());
 
-        assert!(!app.log_search_mode);
-        assert!(app.status_message.contains("Found"));
+        app.service_deployments = vec![create_test_deployment("PRIMARY", "COMPLETED")];
+        assert!(!app.rollout_in_progress());
+
+        app.service_deployments = vec![
+            create_test_deployment("PRIMARY", "IN_PROGRESS"),
+            create_test_deployment("ACTIVE", "COMPLETED"),
+        ];
+        assert!(app.rollout_in_progress());
     }
 
     #[test]
-    fn test_update_log_search() {
+    fn test_should_refresh_service_events_during_rollout() {
         let mut app = create_test_app();
-        app.log_search_query = "test".to_string();
+        app.state = AppState::ServiceEvents;
+        app.last_refresh = Instant::now() - Duration::from_secs(6);
+        assert!(!app.should_refresh());
 
-        app.update_log_search('!');
+        app.service_deployments = vec![create_test_deployment("PRIMARY", "IN_PROGRESS")];
+        assert!(app.should_refresh());
+    }
 
-        assert_eq!(app.log_search_query, "test!");
+    #[test]
+    fn test_back_from_service_events_to_services() {
+        let mut app = create_test_app();
+        app.state = AppState::ServiceEvents;
+        app.service_events = create_test_events();
+        app.service_deployments = vec![create_test_deployment("PRIMARY", "COMPLETED")];
+
+        app.back();
+        assert_eq!(app.state, AppState::Services);
+        assert!(app.service_events.is_empty());
+        assert!(app.service_deployments.is_empty());
     }
 
     #[test]
-    fn test_delete_log_search_char() {
+    fn test_navigation_in_service_events() {
         let mut app = create_test_app();
-        app.log_search_query = "test".to_string();
+        app.state = AppState::ServiceEvents;
+        app.service_events = create_test_events();
 
-        app.delete_log_search_char();
+        app.next();
+        assert_eq!(app.selected_index, 1);
+        app.previous();
+        app.previous();
+        assert_eq!(app.selected_index, 2);
+    }
 
-        assert_eq!(app.log_search_query, "tes");
+    // Background request tests
+
+    /// Polls `process_responses` until at least one response was applied.
+    async fn wait_for_responses(app: &mut App) -> usize {
+        for _ in 0..100 {
+            let applied = app.process_responses();
+            if applied > 0 {
+                return applied;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        0
     }
 
-    #[test]
-    fn test_delete_log_search_char_empty() {
+    #[tokio::test]
+    async fn test_spawn_request_applies_result() {
         let mut app = create_test_app();
-        app.log_search_query = String::new();
+        app.spawn_request(
+            "Loading...",
+            async { Ok(vec!["cluster-new".to_string()]) },
+            |app, result: Result<Vec<String>>| {
+                if let Ok(clusters) = result {
+                    app.clusters = clusters;
+                }
+            },
+        );
+        assert!(app.loading);
+        assert_eq!(app.status_message, "Loading...");
 
-        app.delete_log_search_char();
+        assert_eq!(wait_for_responses(&mut app).await, 1);
+        assert_eq!(app.clusters, vec!["cluster-new".to_string()]);
+        assert!(!app.loading);
+        assert!(app.in_flight_request.is_none());
+    }
 
-        assert_eq!(app.log_search_query, "");
-        // Should not panic
+    #[tokio::test]
+    async fn test_spawn_request_supersedes_previous_request() {
+        let mut app = create_test_app();
+        app.spawn_request(
+            "Loading...",
+            async { Ok("first".to_string()) },
+            |app, result: Result<String>| {
+                if let Ok(message) = result {
+                    app.status_message = message;
+                }
+            },
+        );
+        app.spawn_request(
+            "Loading...",
+            async { Ok("second".to_string()) },
+            |app, result: Result<String>| {
+                if let Ok(message) = result {
+                    app.status_message = message;
+                }
+            },
+        );
+
+        wait_for_responses(&mut app).await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        app.process_responses();
+        assert_eq!(app.status_message, "second");
     }
 
-    #[test]
-    fn test_clear_log_search() {
+    #[tokio::test]
+    async fn test_cancel_requests_discards_result() {
         let mut app = create_test_app();
-        app.log_search_mode = true;
-        app.log_search_query = "test query".to_string();
+        app.spawn_request(
+            "Loading...",
+            async { Ok(vec!["cluster-new".to_string()]) },
+            |app, result: Result<Vec<String>>| {
+                if let Ok(clusters) = result {
+                    app.clusters = clusters;
+                }
+            },
+        );
+        app.cancel_requests();
+        assert!(!app.loading);
 
-        app.clear_log_search();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(app.process_responses(), 0);
+        assert_eq!(app.clusters.len(), 3);
+    }
 
-        assert!(!app.log_search_mode);
-        assert_eq!(app.log_search_query, "");
-        assert!(app.status_message.contains("cleared"));
+    #[tokio::test]
+    async fn test_back_cancels_in_flight_request() {
+        let mut app = create_test_app();
+        app.state = AppState::Services;
+        app.selected_cluster = Some("cluster-prod".to_string());
+        app.spawn_request(
+            "Loading services...",
+            async { Ok(Vec::<ServiceInfo>::new()) },
+            |app, result: Result<Vec<ServiceInfo>>| {
+                if let Ok(services) = result {
+                    app.services = services;
+                }
+            },
+        );
+
+        app.back();
+        assert_eq!(app.state, AppState::Clusters);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        app.process_responses();
+        assert_eq!(app.services.len(), 3);
     }
 
-    // Test auto-tail behavior
-    #[test]
-    fn test_auto_tail_scrolls_to_end_on_new_logs() {
+    #[tokio::test]
+    async fn test_spawn_action_survives_navigation() {
         let mut app = create_test_app();
-        app.state = AppState::Logs;
-        app.auto_tail = true;
-        app.logs = vec![
-            LogEntry::new(1000, "log1".to_string(), "web".to_string()),
-            LogEntry::new(2000, "log2".to_string(), "web".to_string()),
-            LogEntry::new(3000, "log3".to_string(), "web".to_string()),
-        ];
-        app.log_scroll = 0;
+        app.spawn_action(
+            "Restarting...",
+            async { Ok(()) },
+            |app, result: Result<()>| {
+                if result.is_ok() {
+                    app.status_message = "Restarted".to_string();
+                }
+            },
+        );
+        assert_eq!(app.pending_actions, 1);
 
-        // Simulate what should_refresh does when auto_tail is true
-        if app.auto_tail && !app.logs.is_empty() {
-            app.log_scroll = app.logs.len().saturating_sub(1);
-        }
+        app.cancel_requests();
+        assert!(app.loading);
 
-        assert_eq!(app.log_scroll, 2); // Last log index
+        assert_eq!(wait_for_responses(&mut app).await, 1);
+        assert_eq!(app.status_message, "Restarted");
+        assert_eq!(app.pending_actions, 0);
+        assert!(!app.loading);
     }
 
-    #[test]
-    fn test_scrolling_disables_auto_tail() {
+    #[tokio::test]
+    async fn test_should_refresh_false_while_request_in_flight() {
         let mut app = create_test_app();
-        app.state = AppState::Logs;
-        app.auto_tail = true;
-        app.logs = vec![
-            LogEntry::new(1000, "log1".to_string(), "web".to_string()),
-            LogEntry::new(2000, "log2".to_string(), "web".to_string()),
-        ];
+        app.state = AppState::Clusters;
+        app.last_refresh = Instant::now() - Duration::from_secs(60);
+        assert!(app.should_refresh());
 
-        app.next(); // Scroll down
+        app.spawn_request(
+            "Loading...",
+            std::future::pending::<Result<()>>(),
+            |_, _| {},
+        );
+        assert!(!app.should_refresh());
 
-        assert!(!app.auto_tail);
+        app.cancel_requests();
+        assert!(app.should_refresh());
     }
 
-    #[test]
-    fn test_export_logs_generates_filename() {
-        let app = create_test_app();
-        // We can't fully test export without a real filesystem,
-        // but we can verify the method exists and basic logic
+    // End-to-end tests against the in-memory AWS backend
 
-        // This would normally fail without proper setup, so we just check the method exists
-        // In integration tests, we could verify actual file creation
-        assert_eq!(app.logs.len(), 0);
+    /// Creates an app on top of the fixture backend, keeping a handle to the fake.
+    fn create_fake_app() -> (App, Arc<FakeBackend>) {
+        let backend = Arc::new(FakeBackend::with_fixtures());
+        let app = App::with_backend(create_test_config(), backend.clone());
+        (app, backend)
     }
 
-    // Test edge cases
-    #[test]
-    fn test_log_level_filter_with_no_logs() {
-        let mut app = create_test_app();
-        app.logs = vec![];
-        app.log_level_filter = Some(LogLevel::Error);
+    /// Applies background responses until no request or action is outstanding.
+    async fn settle(app: &mut App) {
+        for _ in 0..200 {
+            app.process_responses();
+            if app.in_flight_request.is_none() && app.pending_actions == 0 {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 0);
+    #[tokio::test]
+    async fn test_fake_backend_initial_refresh_loads_clusters() {
+        let (mut app, backend) = create_fake_app();
+        assert!(app.loading);
+
+        settle(&mut app).await;
+        assert_eq!(app.clusters, vec!["cluster-prod", "cluster-dev"]);
+        assert!(!app.loading);
+        assert_eq!(
+            backend.calls(),
+            vec![
+                "list_clusters",
+                "describe_clusters cluster-prod,cluster-dev"
+            ]
+        );
     }
 
-    #[test]
-    fn test_log_search_with_no_logs() {
-        let mut app = create_test_app();
-        app.logs = vec![];
-        app.log_search_query = "test".to_string();
+    #[tokio::test]
+    async fn test_fake_backend_loads_cluster_statistics() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+
+        let prod = app.cluster_info("cluster-prod");
+        assert!(prod.is_some());
+        if let Some(prod) = prod {
+            assert_eq!(prod.active_services, 2);
+            assert_eq!(prod.running_tasks, 3);
+            assert_eq!(prod.registered_container_instances, 2);
+            assert_eq!(prod.capacity_providers, vec!["FARGATE", "FARGATE_SPOT"]);
+            assert_eq!(
+                prod.strategy_summary(),
+                "FARGATE base 1 weight 1, FARGATE_SPOT weight 4"
+            );
+            assert_eq!(prod.container_insights, "enabled");
+            assert_eq!(
+                prod.tags,
+                vec![("env".to_string(), "production".to_string())]
+            );
+        }
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 0);
+        let dev = app.cluster_info("cluster-dev");
+        assert!(dev.is_some_and(|dev| dev.running_tasks == 0
+            && dev.registered_container_instances == 0
+            && dev.strategy_summary() == "none"));
     }
 
-    #[test]
-    fn test_log_level_filter_no_matches() {
-        let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "INFO: test1".to_string(), "web".to_string()),
-            LogEntry::new(2000, "DEBUG: test2".to_string(), "web".to_string()),
-        ];
-        app.log_level_filter = Some(LogLevel::Error);
+    #[tokio::test]
+    async fn test_fake_backend_cluster_statistics_follow_task_changes() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        let pending = TaskInfo {
+            task_arn: aws_fake::task_arn("task-worker-1"),
+            task_id: "task-worker-1".to_string(),
+            status: "PENDING".to_string(),
+            desired_status: "RUNNING".to_string(),
+            container_instance: String::new(),
+            cpu: "256".to_string(),
+            memory: "512".to_string(),
+        };
+        backend.state().tasks.insert(
+            ("cluster-dev".to_string(), "worker-service".to_string()),
+            vec![pending],
+        );
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 0);
+        app.refresh();
+        settle(&mut app).await;
+        assert!(app
+            .cluster_info("cluster-dev")
+            .is_some_and(|dev| dev.pending_tasks == 1 && dev.running_tasks == 0));
     }
 
-    #[test]
-    fn test_log_search_no_matches() {
-        let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "message 1".to_string(), "web".to_string()),
-            LogEntry::new(2000, "message 2".to_string(), "api".to_string()),
-        ];
-        app.log_search_query = "nonexistent".to_string();
+    #[tokio::test]
+    async fn test_fake_backend_navigate_to_task_details() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
 
-        let filtered = app.get_filtered_logs();
-        assert_eq!(filtered.len(), 0);
+        app.select();
+        assert_eq!(app.state, AppState::Services);
+        settle(&mut app).await;
+        assert_eq!(app.selected_cluster.as_deref(), Some("cluster-prod"));
+        assert_eq!(app.services.len(), 2);
+
+        app.select();
+        assert_eq!(app.state, AppState::Tasks);
+        settle(&mut app).await;
+        assert_eq!(app.selected_service.as_deref(), Some("web-service"));
+        assert_eq!(app.tasks.len(), 2);
+
+        app.select();
+        assert_eq!(app.state, AppState::Details);
+        settle(&mut app).await;
+        assert!(app
+            .details
+            .as_deref()
+            .is_some_and(|details| details.contains("task-web-1")));
+    }
+
+    #[tokio::test]
+    async fn test_revisions_and_cleanup_exclude_longer_family_names() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        {
+            let mut state = backend.state();
+            let arn = aws_fake::task_definition_arn("web-worker", 1);
+            let mut definition = state
+                .task_definition_details
+                .get(&aws_fake::task_definition_arn("web", 1))
+                .cloned()
+                .unwrap_or_default();
+            definition.arn = arn.clone();
+            definition.family = "web-worker".to_string();
+            state
+                .task_definition_details
+                .insert(arn.clone(), definition);
+            state
+                .task_definitions
+                .insert("web-worker".to_string(), vec![arn]);
+        }
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        app.selected_index = 1;
+        app.select();
+        settle(&mut app).await;
+        assert_eq!(app.selected_task_definition_family.as_deref(), Some("web"));
+        let revisions: Vec<i32> = app.task_definitions.iter().map(|r| r.revision).collect();
+        assert_eq!(revisions, vec![3, 2, 1]);
+
+        app.show_task_definition_cleanup();
+        if let Some(cleanup) = app.task_definition_cleanup.as_mut() {
+            cleanup.keep_latest = "0".to_string();
+            cleanup.skip_in_use = false;
+        }
+        app.modal_select()?;
+        settle(&mut app).await;
+        let plan = app
+            .task_definition_cleanup
+            .as_ref()
+            .and_then(|cleanup| cleanup.plan.clone())
+            .unwrap_or_default();
+        assert_eq!(plan.active, 3);
+        assert_eq!(plan.revisions.len(), 3);
+        assert!(!plan.revisions.iter().any(|arn| arn.contains("web-worker")));
+        Ok(())
     }
 
-    #[test]
-    fn test_multiple_containers_in_logs() {
-        let mut app = create_test_app();
-        app.logs = vec![
-            LogEntry::new(1000, "Web started".to_string(), "nginx".to_string()),
-            LogEntry::new(2000, "API ready".to_string(), "api".to_string()),
-            LogEntry::new(3000, "DB connected".to_string(), "postgres".to_string()),
-            LogEntry::new(4000, "Cache ready".to_string(), "redis".to_string()),
-        ];
+    #[tokio::test]
+    async fn test_task_definition_family_drills_into_revisions_and_detail() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        assert_eq!(app.task_definition_families, vec!["api", "web", "worker"]);
+
+        app.selected_index = 1;
+        app.select();
+        assert_eq!(app.state, AppState::TaskDefinitionRevisions);
+        settle(&mut app).await;
+        assert_eq!(app.selected_task_definition_family.as_deref(), Some("web"));
+        let revisions: Vec<i32> = app.task_definitions.iter().map(|r| r.revision).collect();
+        assert_eq!(revisions, vec![3, 2, 1]);
+        assert_eq!(app.status_message, "Loaded 3 revisions of web");
+
+        app.search_query = "1.2.0".to_string();
+        assert_eq!(app.get_filtered_task_definitions().len(), 1);
+        app.select();
+        assert_eq!(app.state, AppState::TaskDefinitionDetail);
+        let selected = app.selected_task_definition.clone();
+        assert!(selected
+            .as_ref()
+            .is_some_and(|r| r.revision == 2 && r.containers[0].secrets[0].name == "DB_PASSWORD"));
+
+        backend.state().calls.clear();
+        app.refresh();
+        settle(&mut app).await;
+        assert_eq!(
+            backend.calls(),
+            vec![format!(
+                "describe_task_definition {}",
+                aws_fake::task_definition_arn("web", 2)
+            )]
+        );
+        assert_eq!(app.selected_task_definition, selected);
 
-        // Verify all container names are preserved
-        assert!(app.logs.iter().any(|l| l.container_name == "nginx"));
-        assert!(app.logs.iter().any(|l| l.container_name == "api"));
-        assert!(app.logs.iter().any(|l| l.container_name == "postgres"));
-        assert!(app.logs.iter().any(|l| l.container_name == "redis"));
+        app.back();
+        assert_eq!(app.state, AppState::TaskDefinitionRevisions);
+        assert!(app.selected_task_definition.is_none());
+        assert_eq!(app.task_definitions.len(), 3);
+        app.back();
+        assert_eq!(app.state, AppState::TaskDefinitions);
+        assert!(app.task_definitions.is_empty());
     }
 
-    #[test]
-    fn test_log_level_equality() {
-        assert_eq!(LogLevel::Error, LogLevel::Error);
-        assert_ne!(LogLevel::Error, LogLevel::Warn);
-        assert_eq!(LogLevel::Info, LogLevel::Info);
+    #[tokio::test]
+    async fn test_fake_backend_back_then_refresh_reloads_parent_view() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.back();
+        app.refresh();
+        settle(&mut app).await;
+        assert_eq!(app.state, AppState::Clusters);
+        assert_eq!(
+            backend.calls(),
+            vec![
+                "list_clusters",
+                "describe_clusters cluster-prod,cluster-dev",
+                "list_services cluster-prod",
+                "list_clusters",
+                "describe_clusters cluster-prod,cluster-dev"
+            ]
+        );
     }
 
-    // Tests for regex search functionality
-    #[test]
-    fn test_toggle_regex_mode() {
-        let mut app = create_test_app();
-        assert!(!app.search_regex_mode);
+    #[tokio::test]
+    async fn test_fake_backend_restart_service() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.selected_index = 1;
+        app.execute_action();
+        app.confirm_action();
+        settle(&mut app).await;
+        assert!(backend
+            .calls()
+            .contains(&"restart_service cluster-prod/api-service".to_string()));
+        // The services list is reloaded after the restart
+        assert_eq!(
+            backend.calls().last().map(String::as_str),
+            Some("list_services cluster-prod")
+        );
+    }
 
-        app.toggle_regex_mode();
-        assert!(app.search_regex_mode);
-        assert!(app.status_message.contains("Regex mode: ON"));
+    #[tokio::test]
+    async fn test_fake_backend_stop_task_refreshes_tasks() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
 
-        app.toggle_regex_mode();
-        assert!(!app.search_regex_mode);
-        assert!(app.status_message.contains("Regex mode: OFF"));
+        app.execute_action();
+        app.confirm_action();
+        settle(&mut app).await;
+        assert_eq!(app.tasks[0].status, "STOPPED");
+        assert_eq!(app.tasks[1].status, "RUNNING");
     }
 
-    #[test]
-    fn test_search_with_regex_pattern() {
-        let mut app = create_test_app();
-        app.search_regex_mode = true;
-        app.search_query = "cluster-(prod|dev)".to_string();
+    #[tokio::test]
+    async fn test_actions_target_the_selected_filtered_row() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
 
-        let filtered = app.get_filtered_clusters();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered.contains(&"cluster-prod".to_string()));
-        assert!(filtered.contains(&"cluster-dev".to_string()));
-        assert!(!filtered.contains(&"cluster-staging".to_string()));
+        app.search_query = "api".to_string();
+        app.selected_index = 0;
+        app.execute_action();
+        app.confirm_action();
+        settle(&mut app).await;
+        assert!(backend
+            .calls()
+            .contains(&"restart_service cluster-prod/api-service".to_string()));
+
+        app.search_query.clear();
+        app.selected_index = 0;
+        app.select();
+        settle(&mut app).await;
+        app.search_query = "web-2".to_string();
+        app.execute_action();
+        app.confirm_action();
+        settle(&mut app).await;
+        assert_eq!(app.tasks[0].status, "RUNNING");
+        assert_eq!(app.tasks[1].status, "STOPPED");
+    }
+
+    #[tokio::test]
+    async fn test_fake_backend_read_only_blocks_actions() {
+        let (mut app, backend) = create_fake_app();
+        app.config.behavior.read_only = true;
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.execute_action();
+        settle(&mut app).await;
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("restart_service")));
+    }
+
+    #[tokio::test]
+    async fn test_action_waits_for_confirmation() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.execute_action();
+        assert_eq!(app.modal_state, ModalState::Confirm);
+        assert!(!app.confirmation_requires_name());
+        assert!(app.confirmation.as_ref().is_some_and(|c| c.changes[0]
+            == "Force new deployment of web-service in cluster cluster-prod"
+            && !c.confirm_selected));
+
+        // Enter on the preselected cancel button closes the dialog without acting
+        assert!(app.modal_select().is_ok());
+        settle(&mut app).await;
+        assert_eq!(app.modal_state, ModalState::None);
+        assert!(app.confirmation.is_none());
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("restart_service")));
     }
 
-    #[test]
-    fn test_search_with_invalid_regex() {
-        let mut app = create_test_app();
-        app.search_regex_mode = true;
-        app.search_query = "[invalid(".to_string(); // Invalid regex
+    #[tokio::test]
+    async fn test_production_resource_requires_typed_name() {
+        let (mut app, backend) = create_fake_app();
+        app.config.behavior.production_patterns = vec!["prod$".to_string()];
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
 
-        let filtered = app.get_filtered_clusters();
-        // Should return all clusters when regex is invalid
-        assert_eq!(filtered.len(), 3);
+        app.execute_action();
+        assert!(app.confirmation_requires_name());
+
+        app.confirm_action();
+        assert_eq!(app.modal_state, ModalState::Confirm);
+        assert_eq!(app.status_message, "Type 'web-service' to confirm");
+
+        for c in "web-servic".chars() {
+            app.update_confirm_input(c);
+        }
+        assert!(app.modal_select().is_ok());
+        assert_eq!(app.modal_state, ModalState::Confirm);
+
+        app.update_confirm_input('e');
+        assert!(app.modal_select().is_ok());
+        settle(&mut app).await;
+        assert_eq!(app.modal_state, ModalState::None);
+        assert!(backend
+            .calls()
+            .contains(&"restart_service cluster-prod/web-service".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_service_editor_confirmation_lists_changes() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.show_service_editor();
+        settle(&mut app).await;
+
+        app.service_editor_desired_count_input = "0".to_string();
+        app.service_editor_selected_revision = 1;
+        assert!(app.modal_select().is_ok());
+
+        assert_eq!(app.modal_state, ModalState::Confirm);
+        assert_eq!(
+            app.confirmation.as_ref().map(|c| c.changes.clone()),
+            Some(vec![
+                "desired 2 → 0 on web-service in cluster cluster-prod".to_string(),
+                "task definition web:3 → web:2 on web-service in cluster cluster-prod".to_string(),
+            ])
+        );
+
+        app.close_modal();
+        settle(&mut app).await;
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("update_service")));
+    }
+
+    #[tokio::test]
+    async fn test_stopped_tasks_mode_shows_exit_codes_and_logs() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.toggle_stopped_tasks();
+        settle(&mut app).await;
+        assert!(app.show_stopped_tasks);
+        assert!(backend
+            .calls()
+            .contains(&"list_stopped_tasks cluster-prod/web-service".to_string()));
+        assert_eq!(app.stopped_tasks.len(), 1);
+        assert_eq!(app.stopped_tasks[0].stop_code, "EssentialContainerExited");
+        assert_eq!(app.stopped_tasks[0].exit_codes(), "web:137");
+
+        // Stopped tasks cannot be stopped again
+        app.execute_action();
+        assert_eq!(app.modal_state, ModalState::None);
+
+        app.view_logs();
+        settle(&mut app).await;
+        assert_eq!(app.state, AppState::Logs);
+        assert_eq!(
+            app.selected_task.as_ref().map(|t| t.task_id.as_str()),
+            Some("task-web-0")
+        );
+        assert_eq!(app.logs.len(), 1);
+
+        // Going back returns to the stopped tasks
+        app.back();
+        assert!(app.show_stopped_tasks);
+    }
+
+    #[tokio::test]
+    async fn test_task_metrics_from_tasks_view() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.view_metrics();
+        assert_eq!(app.state, AppState::Metrics);
+        assert!(app.show_task_metrics);
+        settle(&mut app).await;
+        assert!(backend.calls().contains(&format!(
+            "get_task_metrics cluster-prod/{} 1h",
+            aws_fake::task_arn("task-web-1")
+        )));
+        assert!(app.task_metrics.as_ref().is_some_and(|metrics| {
+            metrics.task_id == "task-web-1"
+                && metrics.cpu_utilized.len() == 2
+                && metrics.containers.len() == 1
+        }));
+
+        app.cycle_metrics_time_range();
+        settle(&mut app).await;
+        assert!(app
+            .task_metrics
+            .as_ref()
+            .is_some_and(|metrics| metrics.time_range == TimeRange::SixHours));
+
+        app.refresh();
+        settle(&mut app).await;
+        assert!(backend.calls().contains(&format!(
+            "get_task_metrics cluster-prod/{} 6h",
+            aws_fake::task_arn("task-web-1")
+        )));
+
+        // Going back returns to the tasks, and service metrics still work from Services
+        app.back();
+        assert_eq!(app.state, AppState::Tasks);
+        assert!(!app.show_task_metrics);
+        assert!(app.task_metrics.is_none());
+        app.back();
+        app.view_metrics();
+        assert!(!app.show_task_metrics);
+        settle(&mut app).await;
+        assert!(app.metrics.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_task_metrics_of_the_selected_filtered_task() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.search_query = "web-2".to_string();
+        app.selected_index = 0;
+        app.view_metrics();
+        settle(&mut app).await;
+        assert!(backend.calls().contains(&format!(
+            "get_task_metrics cluster-prod/{} 1h",
+            aws_fake::task_arn("task-web-2")
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_task_metrics_for_stopped_task_without_insights() {
+        let (mut app, backend) = create_fake_app();
+        backend.state().task_insights = false;
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.toggle_stopped_tasks();
+        settle(&mut app).await;
+
+        app.view_metrics();
+        settle(&mut app).await;
+        assert!(app
+            .task_metrics
+            .as_ref()
+            .is_some_and(|metrics| metrics.task_id == "task-web-0" && !metrics.has_data()));
+        assert!(app.status_message.contains("No Container Insights metrics"));
+    }
+
+    #[tokio::test]
+    async fn test_stopped_task_search_matches_reasons() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.toggle_stopped_tasks();
+        settle(&mut app).await;
+
+        app.search_query = "outofmemory".to_string();
+        assert_eq!(app.get_filtered_stopped_tasks().len(), 1);
+        app.search_query = "TaskFailedToStart".to_string();
+        assert!(app.get_filtered_stopped_tasks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stopped_task_appears_after_stop() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.execute_action();
+        app.confirm_action();
+        settle(&mut app).await;
+        app.toggle_stopped_tasks();
+        settle(&mut app).await;
+        assert_eq!(app.stopped_tasks.len(), 2);
+        assert_eq!(app.stopped_tasks[0].task_id, "task-web-1");
+        assert_eq!(app.stopped_tasks[0].stop_code, "UserInitiated");
+    }
+
+    #[tokio::test]
+    async fn test_container_instances_drill_into_tasks() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+
+        app.view_container_instances();
+        settle(&mut app).await;
+        assert_eq!(app.state, AppState::ContainerInstances);
+        assert_eq!(app.selected_cluster.as_deref(), Some("cluster-prod"));
+        assert_eq!(app.container_instances.len(), 2);
+        assert_eq!(app.container_instances[0].id(), "ci-1");
+
+        app.select();
+        settle(&mut app).await;
+        assert_eq!(app.state, AppState::Tasks);
+        assert!(backend.calls().contains(&format!(
+            "list_container_instance_tasks cluster-prod/{}",
+            aws_fake::container_instance_arn("ci-1")
+        )));
+        assert_eq!(app.tasks.len(), 1);
+        assert_eq!(app.tasks[0].task_id, "task-api-1");
+
+        app.back();
+        assert_eq!(app.state, AppState::ContainerInstances);
+        assert!(app.selected_container_instance.is_none());
     }
 
-    #[test]
-    fn test_regex_case_sensitive() {
-        let mut app = create_test_app();
-        app.search_regex_mode = true;
-        app.search_query = "PROD".to_string();
+    #[tokio::test]
+    async fn test_drain_and_reactivate_container_instance() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.view_container_instances();
+        settle(&mut app).await;
 
-        let filtered = app.get_filtered_clusters();
-        // Regex is case-sensitive by default
-        assert_eq!(filtered.len(), 0);
+        app.execute_action();
+        assert_eq!(app.modal_state, ModalState::Confirm);
+        assert!(app
+            .confirmation
+            .as_ref()
+            .is_some_and(|c| c.title == "Drain Container Instance"
+                && c.changes[0]
+                    == "status ACTIVE → DRAINING on i-0aaa111 in cluster cluster-prod"));
+        app.confirm_action();
+        settle(&mut app).await;
+        assert!(backend.calls().contains(&format!(
+            "update_container_instance_state cluster-prod/{} DRAINING",
+            aws_fake::container_instance_arn("ci-1")
+        )));
+        assert_eq!(app.container_instances[0].status, "DRAINING");
+
+        app.execute_action();
+        assert!(app
+            .confirmation
+            .as_ref()
+            .is_some_and(|c| c.title == "Activate Container Instance"));
+        app.confirm_action();
+        settle(&mut app).await;
+        assert_eq!(app.container_instances[0].status, "ACTIVE");
     }
 
-    #[test]
-    fn test_regex_with_services() {
-        let mut app = create_test_app();
-        app.search_regex_mode = true;
-        app.search_query = "^(web|api)-".to_string();
+    #[tokio::test]
+    async fn test_read_only_blocks_container_instance_drain() {
+        let (mut app, backend) = create_fake_app();
+        app.config.behavior.read_only = true;
+        settle(&mut app).await;
+        app.view_container_instances();
+        settle(&mut app).await;
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered.iter().any(|s| s.name == "web-service"));
-        assert!(filtered.iter().any(|s| s.name == "api-service"));
+        app.execute_action();
+        assert_eq!(app.modal_state, ModalState::None);
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("update_container_instance_state")));
     }
 
-    // Tests for service status filter
-    #[test]
-    fn test_cycle_service_status_filter() {
-        let mut app = create_test_app();
-        assert_eq!(app.service_status_filter, None);
+    #[tokio::test]
+    async fn test_fake_backend_view_logs_and_metrics() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
 
-        app.cycle_service_status_filter();
-        assert_eq!(app.service_status_filter, Some("ACTIVE".to_string()));
+        app.view_logs();
+        assert_eq!(app.state, AppState::Logs);
+        settle(&mut app).await;
+        assert_eq!(app.logs.len(), 3);
+        assert_eq!(app.logs[2].level, LogLevel::Error);
 
-        app.cycle_service_status_filter();
-        assert_eq!(app.service_status_filter, Some("DRAINING".to_string()));
+        app.back();
+        app.back();
+        assert_eq!(app.state, AppState::Services);
+        app.view_metrics();
+        settle(&mut app).await;
+        assert_eq!(app.state, AppState::Metrics);
+        assert!(app
+            .metrics
+            .as_ref()
+            .is_some_and(|metrics| metrics.cpu_datapoints.len() == 2));
+    }
+
+    /// Opens the Logs view of `task-web-1` in `cluster-prod`.
+    async fn open_fake_logs(app: &mut App) {
+        settle(app).await;
+        app.select();
+        settle(app).await;
+        app.select();
+        settle(app).await;
+        app.view_logs();
+        settle(app).await;
+    }
+
+    #[tokio::test]
+    async fn test_logs_explain_containers_without_cloudwatch_logs() {
+        let (mut app, backend) = create_fake_app();
+        let sidecar = UnavailableLogs {
+            container_name: "datadog-agent".to_string(),
+            reason: "FireLens routes logs to the datadog output, not CloudWatch Logs".to_string(),
+        };
+        backend
+            .state()
+            .unavailable_logs
+            .insert(aws_fake::task_arn("task-web-1"), vec![sidecar.clone()]);
+        open_fake_logs(&mut app).await;
+
+        assert_eq!(app.logs.len(), 3);
+        assert_eq!(app.log_unavailable, vec![sidecar]);
+        assert!(app.status_message.ends_with(
+            "; no logs for datadog-agent: FireLens routes logs to the datadog output, not CloudWatch Logs"
+        ));
 
-        app.cycle_service_status_filter();
-        assert_eq!(app.service_status_filter, None);
+        app.back();
+        assert!(app.log_unavailable.is_empty());
     }
 
-    #[test]
-    fn test_filter_services_by_status() {
-        let mut app = create_test_app();
-        app.service_status_filter = Some("ACTIVE".to_string());
+    #[tokio::test]
+    async fn test_scrolling_up_at_top_loads_older_logs() {
+        let (mut app, backend) = create_fake_app();
+        backend.state().log_page_size = Some(2);
+        open_fake_logs(&mut app).await;
+        let timestamps = |app: &App| app.logs.iter().map(|l| l.timestamp).collect::<Vec<_>>();
+        assert_eq!(timestamps(&app), vec![2000, 3000]);
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered.iter().all(|s| s.status == "ACTIVE"));
+        // First press scrolls, the second one at the top loads the previous page
+        app.previous();
+        assert_eq!(app.log_scroll, 0);
+        app.previous();
+        settle(&mut app).await;
+        assert_eq!(timestamps(&app), vec![1000, 2000, 3000]);
+        assert_eq!(app.log_scroll, 1);
+        assert_eq!(app.status_message, "Loaded 1 older log entries");
+        assert!(!app.auto_tail);
+
+        app.previous();
+        app.previous();
+        settle(&mut app).await;
+        assert!(app.log_cursors.iter().all(|cursor| cursor.exhausted));
+        assert_eq!(app.status_message, "Reached the beginning of the logs");
+
+        // Nothing more is requested once every stream is exhausted
+        let requests = backend.calls().len();
+        app.previous();
+        assert_eq!(backend.calls().len(), requests);
+        assert!(backend.calls().contains(&format!(
+            "get_task_logs cluster-prod/{} older",
+            aws_fake::task_arn("task-web-1")
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_export_full_history_fetches_older_pages() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        backend.state().log_page_size = Some(2);
+        open_fake_logs(&mut app).await;
+        assert_eq!(app.logs.len(), 2);
+        let dir = std::env::temp_dir().join(format!("ecs-voyager-export-{}", std::process::id()));
+
+        app.show_log_export_dialog();
+        assert_eq!(app.modal_state, ModalState::LogExport);
+        if let Some(dialog) = &mut app.log_export {
+            dialog.change_field();
+            dialog.field = ExportField::FullHistory;
+            dialog.change_field();
+            dialog.path = dir.join("logs.jsonl").to_string_lossy().to_string();
+        }
+        app.modal_select()?;
+        assert_eq!(app.modal_state, ModalState::None);
+        settle(&mut app).await;
+
+        let content = std::fs::read_to_string(dir.join("logs.jsonl"))?;
+        let timestamps: Vec<i64> = content
+            .lines()
+            .map(|line| {
+                let entry: serde_json::Value = serde_json::from_str(line)?;
+                Ok(entry["timestamp"].as_i64().unwrap_or(0))
+            })
+            .collect::<Result<_>>()?;
+        assert_eq!(timestamps, vec![1000, 2000, 3000]);
+        assert!(app
+            .status_message
+            .starts_with("Exported 3 log entries (JSON Lines)"));
+        assert!(backend.calls().contains(&format!(
+            "get_task_logs cluster-prod/{} older",
+            aws_fake::task_arn("task-web-1")
+        )));
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
     }
 
-    #[test]
-    fn test_filter_services_by_status_draining() {
-        let mut app = create_test_app();
-        app.service_status_filter = Some("DRAINING".to_string());
+    #[tokio::test]
+    async fn test_export_loaded_logs_applies_filters() -> Result<()> {
+        let (mut app, _backend) = create_fake_app();
+        open_fake_logs(&mut app).await;
+        app.log_level_filter = Some(LogLevel::Error);
+        let path = std::env::temp_dir().join(format!("ecs-voyager-{}.csv", std::process::id()));
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "worker-service");
+        app.show_log_export_dialog();
+        if let Some(dialog) = &mut app.log_export {
+            dialog.format = ExportFormat::Csv;
+            dialog.path = path.to_string_lossy().to_string();
+        }
+        app.submit_log_export();
+
+        let content = std::fs::read_to_string(&path)?;
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.lines().all(|line| !line.contains(",INFO,")));
+
+        // Without the filters every loaded entry is exported
+        app.show_log_export_dialog();
+        if let Some(dialog) = &mut app.log_export {
+            dialog.format = ExportFormat::Csv;
+            dialog.apply_filters = false;
+            dialog.path = path.to_string_lossy().to_string();
+        }
+        app.submit_log_export();
+        let content = std::fs::read_to_string(&path)?;
+        assert_eq!(content.lines().count(), 4);
+        std::fs::remove_file(&path)?;
+        Ok(())
     }
 
-    // Tests for launch type filter
-    #[test]
-    fn test_cycle_launch_type_filter() {
-        let mut app = create_test_app();
-        assert_eq!(app.launch_type_filter, None);
+    #[tokio::test]
+    async fn test_older_logs_respect_buffer_cap() {
+        let (mut app, backend) = create_fake_app();
+        app.config.logs.max_buffer_lines = 2;
+        backend.state().log_page_size = Some(2);
+        open_fake_logs(&mut app).await;
 
-        app.cycle_launch_type_filter();
-        assert_eq!(app.launch_type_filter, Some("FARGATE".to_string()));
+        app.log_scroll = 0;
+        app.previous();
+        settle(&mut app).await;
+        let timestamps: Vec<i64> = app.logs.iter().map(|l| l.timestamp).collect();
+        assert_eq!(timestamps, vec![1000, 2000]);
+        assert_eq!(
+            app.status_message,
+            "Loaded 1 older log entries (buffer full, dropped 1 newest)"
+        );
 
-        app.cycle_launch_type_filter();
-        assert_eq!(app.launch_type_filter, Some("EC2".to_string()));
+        // Tailing again reloads the latest page instead of appending after the gap
+        assert!(app.log_tail_detached);
+        app.toggle_auto_tail();
+        settle(&mut app).await;
+        let timestamps: Vec<i64> = app.logs.iter().map(|l| l.timestamp).collect();
+        assert_eq!(timestamps, vec![2000, 3000]);
+        assert!(!app.log_tail_detached);
+    }
+
+    /// Refreshes the Logs view and waits for the tailing poll to be applied.
+    async fn poll_fake_logs(app: &mut App) {
+        app.refresh();
+        for _ in 0..200 {
+            app.process_responses();
+            if !app.log_poll_in_flight {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
 
-        app.cycle_launch_type_filter();
-        assert_eq!(app.launch_type_filter, Some("EXTERNAL".to_string()));
+    #[tokio::test]
+    async fn test_tailing_appends_only_new_log_events() {
+        let (mut app, backend) = create_fake_app();
+        open_fake_logs(&mut app).await;
+        assert_eq!(app.logs.len(), 3);
+
+        let task_arn = aws_fake::task_arn("task-web-1");
+        backend
+            .state()
+            .logs
+            .entry(task_arn.clone())
+            .or_default()
+            .push(LogEntry::new(
+                4000,
+                "INFO Request served".to_string(),
+                "web".to_string(),
+            ));
+        poll_fake_logs(&mut app).await;
+        let timestamps: Vec<i64> = app.logs.iter().map(|l| l.timestamp).collect();
+        assert_eq!(timestamps, vec![1000, 2000, 3000, 4000]);
+        assert_eq!(app.log_scroll, 3);
+        assert!(!app.loading);
+        assert_eq!(
+            backend.calls().last(),
+            Some(&format!("get_task_logs cluster-prod/{task_arn} newer"))
+        );
 
-        app.cycle_launch_type_filter();
-        assert_eq!(app.launch_type_filter, None);
+        // Polling again without new events leaves the buffer unchanged
+        poll_fake_logs(&mut app).await;
+        assert_eq!(app.logs.len(), 4);
     }
 
-    #[test]
-    fn test_filter_services_by_launch_type() {
-        let mut app = create_test_app();
-        app.launch_type_filter = Some("FARGATE".to_string());
+    #[tokio::test]
+    async fn test_log_poll_is_ignored_after_leaving_logs() {
+        let (mut app, backend) = create_fake_app();
+        open_fake_logs(&mut app).await;
+        backend
+            .state()
+            .logs
+            .entry(aws_fake::task_arn("task-web-1"))
+            .or_default()
+            .push(LogEntry::new(4000, "late".to_string(), "web".to_string()));
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered.iter().all(|s| s.launch_type == "FARGATE"));
+        app.refresh();
+        app.back();
+        for _ in 0..200 {
+            app.process_responses();
+            if !app.log_poll_in_flight {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert!(!app.log_poll_in_flight);
+        assert!(app.logs.is_empty());
     }
 
-    #[test]
-    fn test_filter_services_by_launch_type_ec2() {
-        let mut app = create_test_app();
-        app.launch_type_filter = Some("EC2".to_string());
+    /// Seeds recent logs for the running and stopped tasks of `web-service` and opens
+    /// its service-wide Logs view. Returns the current time in Unix milliseconds.
+    async fn poll_insights_now(app: &mut App) {
+        if let Some(run) = app.insights_run.as_mut() {
+            run.last_poll = None;
+        }
+        app.poll_insights_query();
+        for _ in 0..200 {
+            app.process_responses();
+            if !app
+                .insights_run
+                .as_ref()
+                .is_some_and(|run| run.poll_in_flight)
+            {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "api-service");
+    #[tokio::test]
+    async fn test_log_insights_runs_query_over_service_log_groups() {
+        let (mut app, backend) = create_fake_app();
+        {
+            let mut state = backend.state();
+            state.insights_running_polls = 1;
+            state.insights_results = vec![vec![
+                (
+                    "@timestamp".to_string(),
+                    "2026-10-14 09:00:00.000".to_string(),
+                ),
+                ("@message".to_string(), "ERROR Request failed".to_string()),
+                ("@ptr".to_string(), "ptr-1".to_string()),
+            ]];
+        }
+        open_fake_service_logs(&mut app, &backend).await;
+
+        app.view_log_insights();
+        settle(&mut app).await;
+        assert_eq!(app.state, AppState::LogInsights);
+        assert!(app.insights_editing);
+        assert_eq!(app.insights_query, DEFAULT_INSIGHTS_QUERY);
+        assert_eq!(app.insights_log_groups, vec!["/ecs/web"]);
+
+        app.run_insights_query();
+        settle(&mut app).await;
+        assert!(!app.insights_editing);
+        assert!(backend.calls().contains(&format!(
+            "start_insights_query /ecs/web 3600000 {DEFAULT_INSIGHTS_QUERY}"
+        )));
+
+        poll_insights_now(&mut app).await;
+        assert!(app
+            .insights_results()
+            .is_some_and(|results| !results.is_finished()));
+        assert!(app.status_message.contains("running"));
+
+        poll_insights_now(&mut app).await;
+        let results = app.insights_results().cloned().unwrap_or_default();
+        assert_eq!(results.status, "Complete");
+        assert_eq!(results.fields, vec!["@timestamp", "@message"]);
+        assert_eq!(results.rows.len(), 1);
+        assert!(app.status_message.starts_with("Query complete"));
+
+        // Finished queries are not polled again
+        let polls = |backend: &FakeBackend| {
+            backend
+                .calls()
+                .iter()
+                .filter(|c| c.starts_with("get_insights_query_results"))
+                .count()
+        };
+        let before = polls(&backend);
+        poll_insights_now(&mut app).await;
+        assert_eq!(polls(&backend), before);
+        assert!(!app.should_refresh());
     }
 
-    // Tests for task status filter
-    #[test]
-    fn test_cycle_task_status_filter() {
-        let mut app = create_test_app();
-        assert_eq!(app.task_status_filter, None);
+    #[tokio::test]
+    async fn test_task_log_insights_is_limited_to_task_streams() {
+        let (mut app, backend) = create_fake_app();
+        open_fake_logs(&mut app).await;
+        app.view_log_insights();
+        settle(&mut app).await;
+        assert_eq!(app.insights_log_groups, vec!["/ecs/web"]);
+
+        app.exit_insights_edit_mode();
+        app.cycle_insights_time_range();
+        assert_eq!(app.insights_time_range, TimeRange::SixHours);
+        app.insights_query.clear();
+        for c in "stats count(*)".chars() {
+            app.update_insights_query(c);
+        }
+        app.select();
+        settle(&mut app).await;
 
-        app.cycle_task_status_filter();
-        assert_eq!(app.task_status_filter, Some("RUNNING".to_string()));
+        assert!(backend.calls().contains(
+            &"start_insights_query /ecs/web 21600000 filter @logStream like /task-web-1/ | stats count(*)"
+                .to_string()
+        ));
+    }
 
-        app.cycle_task_status_filter();
-        assert_eq!(app.task_status_filter, Some("PENDING".to_string()));
+    #[tokio::test]
+    async fn test_log_insights_saved_queries_and_back_stops_query() {
+        let (mut app, backend) = create_fake_app();
+        backend.state().insights_running_polls = 5;
+        app.config.logs.saved_queries = vec![
+            SavedQuery {
+                name: "Errors".to_string(),
+                query: "filter @message like /ERROR/".to_string(),
+            },
+            SavedQuery {
+                name: "Count".to_string(),
+                query: "stats count(*)".to_string(),
+            },
+        ];
+        open_fake_service_logs(&mut app, &backend).await;
+        app.view_log_insights();
+        settle(&mut app).await;
+        assert_eq!(app.insights_query, "filter @message like /ERROR/");
+
+        app.load_next_saved_query();
+        app.load_next_saved_query();
+        assert_eq!(app.insights_query, "stats count(*)");
+        assert!(app.status_message.contains("2/2: Count"));
+        app.load_next_saved_query();
+        assert_eq!(app.insights_query, "filter @message like /ERROR/");
+
+        app.run_insights_query();
+        settle(&mut app).await;
+        poll_insights_now(&mut app).await;
+        let query_id = app
+            .insights_run
+            .as_ref()
+            .map(|run| run.query_id.clone())
+            .unwrap_or_default();
 
-        app.cycle_task_status_filter();
-        assert_eq!(app.task_status_filter, Some("STOPPED".to_string()));
+        app.back();
+        settle(&mut app).await;
+        for _ in 0..200 {
+            if backend
+                .calls()
+                .contains(&format!("stop_insights_query {query_id}"))
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(app.state, AppState::Logs);
+        assert!(app.insights_run.is_none());
+        assert!(backend
+            .calls()
+            .contains(&format!("stop_insights_query {query_id}")));
+    }
+
+    #[tokio::test]
+    async fn test_log_insights_without_log_groups_does_not_run() {
+        let (mut app, backend) = create_fake_app();
+        open_fake_logs(&mut app).await;
+        backend.state().service_task_definitions.clear();
+        app.view_log_insights();
+        settle(&mut app).await;
+        assert!(app.insights_log_groups.is_empty());
+        assert!(app.status_message.contains("awslogs"));
+
+        app.run_insights_query();
+        settle(&mut app).await;
+        assert!(app.insights_run.is_none());
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|c| c.starts_with("start_insights_query")));
+    }
 
-        app.cycle_task_status_filter();
-        assert_eq!(app.task_status_filter, None);
+    async fn open_fake_service_logs(app: &mut App, backend: &FakeBackend) -> i64 {
+        let now = chrono::Utc::now().timestamp_millis();
+        {
+            let mut state = backend.state();
+            for (task_id, offset, message) in [
+                ("task-web-0", 240_000, "ERROR OutOfMemoryError"),
+                ("task-web-1", 180_000, "INFO started"),
+                ("task-web-2", 120_000, "INFO started"),
+                ("task-web-1", 60_000, "WARN slow request"),
+            ] {
+                state
+                    .logs
+                    .entry(aws_fake::task_arn(task_id))
+                    .or_default()
+                    .push(LogEntry::new(
+                        now - offset,
+                        message.to_string(),
+                        "web".to_string(),
+                    ));
+            }
+            // Outside of the latest window
+            state
+                .logs
+                .entry(aws_fake::task_arn("task-web-2"))
+                .or_default()
+                .push(LogEntry::new(
+                    now - SERVICE_LOG_WINDOW_MS - 60_000,
+                    "INFO warming up".to_string(),
+                    "web".to_string(),
+                ));
+        }
+        settle(app).await;
+        app.select();
+        settle(app).await;
+        app.view_logs();
+        settle(app).await;
+        now
+    }
+
+    #[tokio::test]
+    async fn test_service_logs_interleave_all_tasks() {
+        let (mut app, backend) = create_fake_app();
+        open_fake_service_logs(&mut app, &backend).await;
+        assert_eq!(app.state, AppState::Logs);
+        assert!(app.show_service_logs);
+        assert_eq!(app.selected_service.as_deref(), Some("web-service"));
+        assert!(backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("get_service_logs cluster-prod/web-service ")));
+
+        let tasks: Vec<&str> = app
+            .logs
+            .iter()
+            .filter_map(|log| log.task_id.as_deref())
+            .collect();
+        assert_eq!(
+            tasks,
+            vec!["task-web-0", "task-web-1", "task-web-2", "task-web-1"]
+        );
+        assert_eq!(
+            app.log_task_ids(),
+            vec!["task-web-0", "task-web-1", "task-web-2"]
+        );
+
+        // The task filter cycles through the tasks and back to all of them
+        app.cycle_log_task_filter();
+        assert_eq!(app.log_task_filter.as_deref(), Some("task-web-0"));
+        app.cycle_log_task_filter();
+        assert_eq!(app.log_task_filter.as_deref(), Some("task-web-1"));
+        assert_eq!(app.get_filtered_logs().len(), 2);
+        app.cycle_log_task_filter();
+        app.cycle_log_task_filter();
+        assert_eq!(app.log_task_filter, None);
+        assert_eq!(app.get_filtered_logs().len(), 4);
+
+        app.back();
+        assert_eq!(app.state, AppState::Services);
+        assert!(!app.show_service_logs);
+        assert!(app.logs.is_empty());
     }
 
-    #[test]
-    fn test_filter_tasks_by_status() {
-        let mut app = create_test_app();
-        app.task_status_filter = Some("RUNNING".to_string());
+    #[tokio::test]
+    async fn test_service_logs_open_the_selected_filtered_service() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
 
-        let filtered = app.get_filtered_tasks();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].task_id, "task-abc123");
+        app.search_query = "api".to_string();
+        app.selected_index = 0;
+        app.view_logs();
+        settle(&mut app).await;
+        assert_eq!(app.selected_service.as_deref(), Some("api-service"));
+        assert!(backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("get_service_logs cluster-prod/api-service ")));
     }
 
-    #[test]
-    fn test_filter_tasks_by_status_pending() {
-        let mut app = create_test_app();
-        app.task_status_filter = Some("PENDING".to_string());
+    #[tokio::test]
+    async fn test_service_logs_read_every_filter_page_of_the_window() {
+        let (mut app, backend) = create_fake_app();
+        let now = chrono::Utc::now().timestamp_millis();
+        {
+            let mut state = backend.state();
+            // One event per page plus an empty page after each: far more than 10 pages
+            state.filter_page_size = Some(1);
+            state
+                .logs
+                .entry(aws_fake::task_arn("task-web-2"))
+                .or_default()
+                .extend((0..12).map(|second| {
+                    LogEntry::new(
+                        now - 50_000 + second * 1000,
+                        format!("INFO request {second}"),
+                        "web".to_string(),
+                    )
+                }));
+        }
+        open_fake_service_logs(&mut app, &backend).await;
 
-        let filtered = app.get_filtered_tasks();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].task_id, "task-def456");
+        assert_eq!(app.logs.len(), 16);
+        assert_eq!(
+            app.logs.last().map(|log| log.message.as_str()),
+            Some("INFO request 11")
+        );
     }
 
-    #[test]
-    fn test_filter_tasks_by_status_stopped() {
-        let mut app = create_test_app();
-        app.task_status_filter = Some("STOPPED".to_string());
+    #[tokio::test]
+    async fn test_service_logs_stop_reading_at_the_buffer_size() {
+        let (mut app, backend) = create_fake_app();
+        app.config.logs.max_buffer_lines = 3;
+        backend.state().filter_page_size = Some(1);
+        open_fake_service_logs(&mut app, &backend).await;
 
-        let filtered = app.get_filtered_tasks();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].task_id, "task-ghi789");
+        assert_eq!(app.logs.len(), 3);
+        assert_eq!(
+            app.logs.last().map(|log| log.message.as_str()),
+            Some("INFO started")
+        );
+        assert!(app.status_message.contains("stopped at the first 3 events"));
+    }
+
+    #[tokio::test]
+    async fn test_service_logs_tail_and_page_back() {
+        let (mut app, backend) = create_fake_app();
+        let now = open_fake_service_logs(&mut app, &backend).await;
+        assert_eq!(app.logs.len(), 4);
+
+        backend
+            .state()
+            .logs
+            .entry(aws_fake::task_arn("task-web-2"))
+            .or_default()
+            .push(LogEntry::new(
+                now,
+                "INFO request".to_string(),
+                "web".to_string(),
+            ));
+        poll_fake_logs(&mut app).await;
+        poll_fake_logs(&mut app).await;
+        assert_eq!(app.logs.len(), 5);
+        assert_eq!(app.logs[4].message, "INFO request");
+        assert!(!app.loading);
+
+        // Scrolling up at the top loads the window before the oldest one
+        app.log_scroll = 0;
+        app.previous();
+        settle(&mut app).await;
+        assert_eq!(app.logs.len(), 6);
+        assert_eq!(app.logs[0].message, "INFO warming up");
+        assert_eq!(app.status_message, "Loaded 1 older log entries");
+
+        app.previous();
+        app.previous();
+        settle(&mut app).await;
+        assert_eq!(app.logs.len(), 6);
+        assert!(app.status_message.starts_with("No log events between"));
     }
 
-    // Tests for multi-criteria filtering
     #[test]
-    fn test_multi_criteria_filter_services() {
+    fn test_append_log_page_skips_duplicate_events() {
         let mut app = create_test_app();
-        app.service_status_filter = Some("ACTIVE".to_string());
-        app.launch_type_filter = Some("FARGATE".to_string());
+        app.state = AppState::Logs;
+        app.logs = vec![
+            LogEntry::new(1000, "a".to_string(), "web".to_string()),
+            LogEntry::new(2000, "b".to_string(), "web".to_string()),
+        ];
+        let with_id = |timestamp, message: &str, id: &str| LogEntry {
+            event_id: Some(id.to_string()),
+            ..LogEntry::new(timestamp, message.to_string(), "web".to_string())
+        };
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "web-service");
+        app.append_log_page(LogPage {
+            entries: vec![
+                LogEntry::new(2000, "b".to_string(), "web".to_string()),
+                LogEntry::new(2000, "b".to_string(), "api".to_string()),
+                with_id(3000, "c", "event-1"),
+                with_id(3000, "c", "event-1"),
+                with_id(3000, "c", "event-2"),
+            ],
+            cursors: Vec::new(),
+            unavailable: Vec::new(),
+        });
+
+        let events: Vec<(i64, &str, Option<&str>)> = app
+            .logs
+            .iter()
+            .map(|l| {
+                (
+                    l.timestamp,
+                    l.container_name.as_str(),
+                    l.event_id.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (1000, "web", None),
+                (2000, "web", None),
+                (2000, "api", None),
+                (3000, "web", Some("event-1")),
+                (3000, "web", Some("event-2")),
+            ]
+        );
+        assert_eq!(app.log_scroll, 4);
     }
 
     #[test]
-    fn test_multi_criteria_filter_with_search() {
+    fn test_append_log_page_keeps_repeated_lines() {
         let mut app = create_test_app();
-        app.service_status_filter = Some("ACTIVE".to_string());
-        app.search_query = "web".to_string();
+        app.state = AppState::Logs;
+        let retry = || LogEntry::new(1000, "WARN retrying".to_string(), "web".to_string());
+        let with_id = |id: &str| LogEntry {
+            event_id: Some(id.to_string()),
+            ..LogEntry::new(2000, "GET /health 200".to_string(), "web".to_string())
+        };
+        app.logs = vec![retry(), with_id("event-1")];
+
+        // The poll overlaps the buffered retry, then the same line is logged twice more
+        app.append_log_page(LogPage {
+            entries: vec![
+                retry(),
+                retry(),
+                retry(),
+                with_id("event-1"),
+                with_id("event-2"),
+                LogEntry::new(2000, "GET /health 200".to_string(), "web".to_string()),
+            ],
+            cursors: Vec::new(),
+            unavailable: Vec::new(),
+        });
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "web-service");
+        let messages: Vec<(&str, Option<&str>)> = app
+            .logs
+            .iter()
+            .map(|l| (l.message.as_str(), l.event_id.as_deref()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                ("WARN retrying", None),
+                ("WARN retrying", None),
+                ("WARN retrying", None),
+                ("GET /health 200", Some("event-1")),
+                ("GET /health 200", Some("event-2")),
+                ("GET /health 200", None),
+            ]
+        );
     }
 
-    #[test]
-    fn test_multi_criteria_filter_with_regex() {
-        let mut app = create_test_app();
-        app.service_status_filter = Some("ACTIVE".to_string());
-        app.search_regex_mode = true;
-        app.search_query = "^web".to_string();
+    #[tokio::test]
+    async fn test_jump_to_relative_time_in_logs() {
+        let (mut app, backend) = create_fake_app();
+        let now = chrono::Local::now().timestamp_millis();
+        backend.state().logs.insert(
+            aws_fake::task_arn("task-web-1"),
+            vec![
+                LogEntry::new(now - 3 * 3_600_000, "old".to_string(), "web".to_string()),
+                LogEntry::new(now - 3_600_000, "before".to_string(), "web".to_string()),
+                LogEntry::new(now - 600_000, "after".to_string(), "web".to_string()),
+            ],
+        );
+        open_fake_logs(&mut app).await;
+        assert_eq!(app.logs.len(), 3);
 
-        let filtered = app.get_filtered_services();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "web-service");
+        app.enter_log_jump_mode();
+        for c in "-30m".chars() {
+            app.update_log_jump(c);
+        }
+        app.submit_log_jump();
+        settle(&mut app).await;
+        assert!(!app.log_jump_mode);
+        assert!(!app.auto_tail);
+        assert!(app
+            .log_start_time
+            .is_some_and(|start| (start - (now - 1_800_000)).abs() < 60_000));
+        assert_eq!(app.logs.len(), 1);
+        assert_eq!(app.logs[0].message, "after");
+
+        // Scrolling up pages back from the jumped-to time
+        app.previous();
+        settle(&mut app).await;
+        let messages: Vec<&str> = app.logs.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(messages, vec!["old", "before", "after"]);
+
+        // Logs that are not tailed are not auto-refreshed; tailing returns to the latest
+        app.last_refresh = Instant::now() - Duration::from_secs(120);
+        assert!(!app.should_refresh());
+        app.toggle_auto_tail();
+        settle(&mut app).await;
+        assert_eq!(app.log_start_time, None);
+        assert!(backend
+            .calls()
+            .last()
+            .is_some_and(|call| call.ends_with(" latest")));
+    }
+
+    #[tokio::test]
+    async fn test_log_bookmarks_and_context_around_an_entry() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        let base = 1_791_968_400_000;
+        let minute = 60_000;
+        backend.state().logs.insert(
+            aws_fake::task_arn("task-web-1"),
+            vec![
+                LogEntry::new(base - 20 * minute, "early".to_string(), "web".to_string()),
+                LogEntry::new(base - 4 * minute, "before".to_string(), "web".to_string()),
+                LogEntry::new(base, "target".to_string(), "web".to_string()),
+                LogEntry::new(base + minute, "sidecar".to_string(), "envoy".to_string()),
+                LogEntry::new(base + 4 * minute, "after".to_string(), "web".to_string()),
+                LogEntry::new(base + 20 * minute, "late".to_string(), "web".to_string()),
+            ],
+        );
+        open_fake_logs(&mut app).await;
+        let messages = |app: &App| {
+            app.logs
+                .iter()
+                .map(|l| l.message.clone())
+                .collect::<Vec<_>>()
+        };
+        let selected = |app: &App| {
+            app.selected_log_entry()
+                .map(|entry| entry.message)
+                .unwrap_or_default()
+        };
+        assert_eq!(app.logs.len(), 6);
+
+        // Bookmark "early" and "target"
+        app.auto_tail = false;
+        app.log_scroll = 0;
+        app.toggle_log_bookmark();
+        app.log_scroll = 2;
+        app.toggle_log_bookmark();
+        assert_eq!(app.log_bookmarks.len(), 2);
+        assert!(app.is_log_bookmarked(&app.logs[2]));
+
+        // Context reads forward from 5 minutes before the entry to 5 minutes after it
+        backend.state().log_page_size = Some(2);
+        app.show_log_context(false);
+        settle(&mut app).await;
+        assert_eq!(messages(&app), vec!["before", "target", "sidecar", "after"]);
+        assert_eq!(selected(&app), "target");
+        assert!(!app.auto_tail);
+        assert_eq!(app.log_start_time, Some(base - 5 * minute));
+        assert!(backend
+            .calls()
+            .iter()
+            .any(|call| call.ends_with(&format!(" at {}", base - 5 * minute))));
+
+        // Bookmarks survive reloads; jumping to one outside the buffer loads its context
+        assert_eq!(app.log_bookmarks.len(), 2);
+        app.previous_log_bookmark();
+        settle(&mut app).await;
+        assert_eq!(selected(&app), "early");
+        assert_eq!(messages(&app), vec!["early"]);
+
+        app.show_log_bookmarks();
+        assert_eq!(app.modal_state, ModalState::LogBookmarks);
+        app.modal_next();
+        app.modal_select()?;
+        settle(&mut app).await;
+        assert_eq!(app.modal_state, ModalState::None);
+        assert_eq!(selected(&app), "target");
+
+        // Context of all tasks switches to service-wide logs
+        app.show_log_context(true);
+        settle(&mut app).await;
+        assert!(app.show_service_logs);
+        assert_eq!(app.log_task_filter, None);
+        assert!(backend.calls().iter().any(|call| call
+            == &format!(
+                "get_service_logs cluster-prod/web-service {}-{}",
+                base - 5 * minute,
+                base + 5 * minute
+            )));
+
+        // Going back returns to the tasks the logs were opened from
+        app.back();
+        assert_eq!(app.state, AppState::Tasks);
+        assert!(!app.show_service_logs);
+        assert!(app.log_bookmarks.is_empty());
+        Ok(())
     }
 
     #[test]
-    fn test_clear_all_filters() {
+    fn test_submit_log_jump_rejects_invalid_time() {
         let mut app = create_test_app();
-        app.service_status_filter = Some("ACTIVE".to_string());
-        app.launch_type_filter = Some("FARGATE".to_string());
-        app.task_status_filter = Some("RUNNING".to_string());
-
-        app.clear_all_filters();
-
-        assert_eq!(app.service_status_filter, None);
-        assert_eq!(app.launch_type_filter, None);
-        assert_eq!(app.task_status_filter, None);
-        assert!(app.status_message.contains("cleared"));
+        app.state = AppState::Logs;
+        app.enter_log_jump_mode();
+        app.update_log_jump('x');
+        app.submit_log_jump();
+        assert!(!app.log_jump_mode);
+        assert_eq!(app.log_start_time, None);
+        assert!(app.status_message.starts_with("Invalid time 'x'"));
     }
 
     #[test]
-    fn test_filter_tasks_with_regex() {
-        let mut app = create_test_app();
-        app.search_regex_mode = true;
-        app.search_query = "task-(abc|def)".to_string();
+    fn test_parse_log_time() {
+        let now = chrono::Local::now();
+        let millis = now.timestamp_millis();
+        assert_eq!(parse_log_time("-30m", now).ok(), Some(millis - 1_800_000));
+        assert_eq!(parse_log_time("-45s", now).ok(), Some(millis - 45_000));
+        assert_eq!(parse_log_time(" -2h ", now).ok(), Some(millis - 7_200_000));
+        assert_eq!(parse_log_time("-1d", now).ok(), Some(millis - 86_400_000));
+
+        let local = |date: &str| {
+            chrono::NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .and_then(|naive| naive.and_local_timezone(chrono::Local).earliest())
+                .map(|time| time.timestamp_millis())
+        };
+        let nine = local("2026-10-14 09:00:00");
+        assert!(nine.is_some());
+        assert_eq!(parse_log_time("2026-10-14T09:00", now).ok(), nine);
+        assert_eq!(parse_log_time("2026-10-14 09:00", now).ok(), nine);
+        assert_eq!(
+            parse_log_time("2026-10-14T09:00:30", now).ok(),
+            local("2026-10-14 09:00:30")
+        );
+        assert_eq!(
+            parse_log_time("2026-10-14", now).ok(),
+            local("2026-10-14 00:00:00")
+        );
 
-        let filtered = app.get_filtered_tasks();
-        assert_eq!(filtered.len(), 2);
-        assert!(filtered.iter().any(|t| t.task_id == "task-abc123"));
-        assert!(filtered.iter().any(|t| t.task_id == "task-def456"));
+        for invalid in ["", "-", "-30", "-30x", "-m", "yesterday", "2026-13-01"] {
+            assert!(parse_log_time(invalid, now).is_err(), "{invalid}");
+        }
+
+        // Out-of-range and non-positive amounts are rejected rather than panicking
+        for invalid in [
+            "-9999999999999999s",
+            "-999999999d",
+            "-9223372036854775807m",
+            "--5m",
+            "-+-5m",
+            "-0h",
+        ] {
+            assert!(parse_log_time(invalid, now).is_err(), "{invalid}");
+        }
     }
 
-    #[test]
-    fn test_combined_task_filter_and_search() {
-        let mut app = create_test_app();
-        app.task_status_filter = Some("RUNNING".to_string());
-        app.search_query = "abc".to_string();
+    #[tokio::test]
+    async fn test_fake_backend_failure_is_reported_in_status() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
 
-        let filtered = app.get_filtered_tasks();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].task_id, "task-abc123");
+        backend.fail_with("AccessDeniedException");
+        app.select();
+        settle(&mut app).await;
+        assert_eq!(app.state, AppState::Services);
+        assert!(app.services.is_empty());
+        assert!(app.status_message.contains("AccessDeniedException"));
+        assert!(!app.loading);
     }
 
-    // Service Editor Tests
-    #[test]
-    fn test_service_editor_modal_opens() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
+    #[tokio::test]
+    async fn test_fake_backend_service_editor_saves_changes() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
 
+        app.show_service_editor();
+        settle(&mut app).await;
         assert_eq!(app.modal_state, ModalState::ServiceEditor);
+        assert_eq!(app.service_editor_available_revisions.len(), 3);
+
+        app.service_editor_desired_count_input = "4".to_string();
+        app.service_editor_selected_revision = 1;
+        assert!(app.modal_select().is_ok());
+        assert_eq!(app.modal_state, ModalState::Confirm);
+        app.confirm_action();
+        settle(&mut app).await;
+
+        let calls = backend.calls();
+        assert!(
+            calls.contains(&"update_service_desired_count cluster-prod/web-service 4".to_string())
+        );
+        assert!(calls.contains(&format!(
+            "update_service_task_definition cluster-prod/web-service {}",
+            aws_fake::task_definition_arn("web", 2)
+        )));
+        assert_eq!(app.services[0].desired_count, 4);
     }
 
-    #[test]
-    fn test_service_editor_modal_closes() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
+    #[tokio::test]
+    async fn test_service_editor_opens_the_selected_filtered_service() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.search_query = "api".to_string();
+        app.selected_index = 0;
+        app.show_service_editor();
+        settle(&mut app).await;
+        assert_eq!(app.modal_state, ModalState::ServiceEditor);
+        assert_eq!(app.selected_service.as_deref(), Some("api-service"));
+        assert!(backend
+            .calls()
+            .contains(&"describe_service cluster-prod/api-service".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_task_definition_diff_from_browser_and_service_editor() -> Result<()> {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        app.selected_index = 1;
+        app.select();
+        settle(&mut app).await;
+
+        // Without a marked revision the selected one is compared with the one before it
+        app.show_task_definition_diff();
+        assert_eq!(app.modal_state, ModalState::TaskDefinitionDiff);
+        let diff = app.task_definition_diff.clone();
+        assert!(diff.as_ref().is_some_and(|diff| diff.old.revision == 2
+            && diff.new.revision == 3
+            && diff.summary() == (0, 0, 1)));
+        app.toggle_diff_unchanged();
+        app.modal_next();
+        assert_eq!(app.modal_selected_index, 1);
+        app.close_modal();
+        assert_eq!(app.modal_state, ModalState::None);
 
+        // A marked newer revision is shown on the right
+        app.mark_task_definition_diff_base();
+        app.selected_index = 2;
+        app.show_task_definition_diff();
+        assert!(app
+            .task_definition_diff
+            .as_ref()
+            .is_some_and(|diff| diff.old.revision == 1 && diff.new.revision == 3));
         app.close_modal();
 
-        assert_eq!(app.modal_state, ModalState::None);
-        assert_eq!(app.modal_selected_index, 0);
+        // From the editor the running revision is compared with the selected one
+        app.set_view(AppState::Clusters);
+        app.refresh();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.show_service_editor();
+        settle(&mut app).await;
+        app.show_service_editor_diff();
+        assert_eq!(app.modal_state, ModalState::ServiceEditor);
+        assert_eq!(
+            app.status_message,
+            "The selected revision is the one the service runs"
+        );
+
+        app.service_editor_selected_revision = 2;
+        app.show_service_editor_diff();
+        settle(&mut app).await;
+        assert_eq!(app.modal_state, ModalState::TaskDefinitionDiff);
+        assert!(app.task_definition_diff.as_ref().is_some_and(|diff| {
+            diff.from_service_editor
+                && diff.old.family_revision() == "web:3"
+                && diff.new.family_revision() == "web:1"
+        }));
+        app.modal_select()?;
+        assert_eq!(app.modal_state, ModalState::ServiceEditor);
+        assert!(app.task_definition_diff.is_none());
+        Ok(())
     }
 
-    #[test]
-    fn test_service_editor_field_navigation_tab() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
-        app.service_editor_editing_field = 0;
+    #[tokio::test]
+    async fn test_register_task_definition_from_browser() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        app.selected_index = 1;
+        app.select();
+        settle(&mut app).await;
+
+        // Without a selected service the new revision can't be deployed
+        app.show_task_definition_editor();
+        assert_eq!(app.modal_state, ModalState::TaskDefinitionEditor);
+        let Some(editor) = app.task_definition_editor.as_mut() else {
+            unreachable!("the register dialog should be open");
+        };
+        assert!(editor.deploy_target.is_none());
+        editor.field = 1;
+        editor.insert_char('0');
+        app.modal_select()?;
+        assert_eq!(app.modal_state, ModalState::TaskDefinitionEditor);
+        assert!(app
+            .task_definition_editor
+            .as_ref()
+            .is_some_and(|editor| editor.problems.contains(
+                &"Fargate does not support 256 CPU units with 5120 MiB of memory".to_string()
+            )));
+
+        if let Some(editor) = app.task_definition_editor.as_mut() {
+            editor.delete_char();
+            editor.field = 2;
+            for c in "-rc1".chars() {
+                editor.insert_char(c);
+            }
+        }
+        app.modal_select()?;
+        assert_eq!(app.modal_state, ModalState::Confirm);
+        let changes = app
+            .confirmation
+            .as_ref()
+            .map(|confirmation| confirmation.changes.clone())
+            .unwrap_or_default();
+        assert_eq!(
+            changes,
+            vec![
+                "Register a new revision of web copied from web:3".to_string(),
+                "web › image: 123456789012.dkr.ecr.us-east-1.amazonaws.com/web:1.3.0 → 123456789012.dkr.ecr.us-east-1.amazonaws.com/web:1.3.0-rc1".to_string(),
+            ]
+        );
 
-        // Tab should cycle through fields
-        app.service_editor_editing_field = (app.service_editor_editing_field + 1) % 2;
-        assert_eq!(app.service_editor_editing_field, 1);
+        app.confirm_action();
+        settle(&mut app).await;
+        assert!(backend
+            .calls()
+            .contains(&"register_task_definition web".to_string()));
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("update_service_task_definition")));
+        assert_eq!(app.task_definitions.len(), 4);
+        assert_eq!(app.task_definitions[0].family_revision(), "web:4");
+        assert!(app.task_definitions[0].containers[0]
+            .image
+            .ends_with("web:1.3.0-rc1"));
+        Ok(())
+    }
 
-        app.service_editor_editing_field = (app.service_editor_editing_field + 1) % 2;
-        assert_eq!(app.service_editor_editing_field, 0);
+    #[tokio::test]
+    async fn test_register_task_definition_and_deploy_from_service_editor() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.show_service_editor();
+        settle(&mut app).await;
+
+        app.register_from_service_editor();
+        settle(&mut app).await;
+        assert_eq!(app.modal_state, ModalState::TaskDefinitionEditor);
+        let deploy_to = app
+            .task_definition_editor
+            .as_ref()
+            .and_then(|editor| editor.deploy_to());
+        assert_eq!(
+            deploy_to,
+            Some(("cluster-prod".to_string(), "web-service".to_string()))
+        );
+
+        // JSON edited in $EDITOR replaces the definition; invalid JSON is kept to fix
+        app.apply_task_definition_json(Ok("{\"family\": \"web\", \"revision\": 9}".to_string()));
+        assert!(app
+            .task_definition_editor
+            .as_ref()
+            .is_some_and(|editor| editor.draft_json.is_some()));
+        let mut edited = app
+            .task_definition_editor
+            .as_ref()
+            .map(|editor| editor.source.clone())
+            .unwrap_or_default();
+        edited.containers[0].stop_timeout = Some(30);
+        app.apply_task_definition_json(Ok(edited.to_json()?));
+        assert!(app
+            .task_definition_editor
+            .as_ref()
+            .is_some_and(|editor| editor.draft_json.is_none()));
+
+        app.submit_task_definition_editor();
+        assert_eq!(
+            app.confirmation.as_ref().map(|c| c.changes.clone()),
+            Some(vec![
+                "Register a new revision of web copied from web:3".to_string(),
+                "+ web › stop timeout: 30s".to_string(),
+                "Deploy the new revision to web-service in cluster cluster-prod".to_string(),
+            ])
+        );
+        app.confirm_action();
+        settle(&mut app).await;
+
+        assert!(backend.calls().contains(&format!(
+            "update_service_task_definition cluster-prod/web-service {}",
+            aws_fake::task_definition_arn("web", 4)
+        )));
+        assert!(app.rollout_monitor.is_some());
+        Ok(())
     }
 
-    #[test]
-    fn test_service_editor_desired_count_input() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
-        app.service_editor_editing_field = 0;
+    #[tokio::test]
+    async fn test_register_task_definition_blocked_in_read_only_mode() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
 
-        // Should accept numeric input
-        app.service_editor_desired_count_input.push('3');
-        assert_eq!(app.service_editor_desired_count_input, "3");
+        app.config.behavior.read_only = true;
+        app.show_task_definition_editor();
+        assert_eq!(app.modal_state, ModalState::None);
+        assert_eq!(
+            app.status_message,
+            "Read-only mode enabled - cannot register task definitions"
+        );
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("register_task_definition")));
+    }
+
+    #[tokio::test]
+    async fn test_export_task_definition_for_registration() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        app.selected_index = 1;
+        app.select();
+        settle(&mut app).await;
+        let path = std::env::temp_dir().join(format!("ecs-voyager-td-{}.json", std::process::id()));
+
+        app.show_definition_export_dialog();
+        assert_eq!(app.modal_state, ModalState::DefinitionExport);
+        if let Some(dialog) = &mut app.definition_export {
+            assert!(dialog.path.ends_with("/task-definition-web-3.json"));
+            dialog.path = path.to_string_lossy().to_string();
+        }
+        app.modal_select()?;
+        assert_eq!(app.modal_state, ModalState::None);
+        settle(&mut app).await;
 
-        app.service_editor_desired_count_input.push('5');
-        assert_eq!(app.service_editor_desired_count_input, "35");
+        let exported: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        assert_eq!(exported["family"], "web");
+        assert!(exported["containerDefinitions"].is_array());
+        for field in ["taskDefinitionArn", "revision", "status", "compatibilities"] {
+            assert!(exported.get(field).is_none(), "{field} was exported");
+        }
+        assert!(app
+            .status_message
+            .starts_with("Exported task definition web:3 (JSON) to: "));
+        assert!(backend.calls().contains(&format!(
+            "describe_task_definition {}",
+            aws_fake::task_definition_arn("web", 3)
+        )));
+        std::fs::remove_file(&path)?;
+        Ok(())
     }
 
-    #[test]
-    fn test_service_editor_desired_count_backspace() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
-        app.service_editor_editing_field = 0;
-        app.service_editor_desired_count_input = "123".to_string();
+    #[tokio::test]
+    async fn test_export_service_as_yaml() -> Result<()> {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        let path =
+            std::env::temp_dir().join(format!("ecs-voyager-svc-{}.yaml", std::process::id()));
+
+        app.show_definition_export_dialog();
+        if let Some(dialog) = &mut app.definition_export {
+            dialog.change_field();
+            assert_eq!(dialog.format, DefinitionFormat::Yaml);
+            dialog.path = path.to_string_lossy().to_string();
+        }
+        app.submit_definition_export();
+        settle(&mut app).await;
 
-        app.service_editor_desired_count_input.pop();
-        assert_eq!(app.service_editor_desired_count_input, "12");
+        let content = std::fs::read_to_string(&path)?;
+        assert!(
+            content.contains("cluster: arn:aws:ecs:us-east-1:123456789012:cluster/cluster-prod\n")
+        );
+        assert!(content.contains("serviceName: web-service\n"));
+        assert!(!content.contains("runningCount"));
+        assert!(!content.contains("serviceArn"));
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
 
-        app.service_editor_desired_count_input.pop();
-        assert_eq!(app.service_editor_desired_count_input, "1");
+    #[tokio::test]
+    async fn test_cleanup_previews_then_deregisters_and_deletes_revisions() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        app.selected_index = 1;
+        app.select();
+        settle(&mut app).await;
+
+        app.show_task_definition_cleanup();
+        assert_eq!(app.modal_state, ModalState::TaskDefinitionCleanup);
+        let Some(cleanup) = app.task_definition_cleanup.as_mut() else {
+            unreachable!("cleanup dialog is open");
+        };
+        assert_eq!(cleanup.family, "web");
+        cleanup.keep_latest = "0".to_string();
+        cleanup.delete = true;
+
+        // web:3 is old enough but runs in web-service
+        app.modal_select()?;
+        settle(&mut app).await;
+        let plan = app
+            .task_definition_cleanup
+            .as_ref()
+            .and_then(|cleanup| cleanup.plan.clone())
+            .unwrap_or_default();
+        assert_eq!(
+            plan.revisions,
+            vec![
+                aws_fake::task_definition_arn("web", 1),
+                aws_fake::task_definition_arn("web", 2)
+            ]
+        );
+        assert_eq!(plan.in_use, vec![aws_fake::task_definition_arn("web", 3)]);
+        assert!(backend
+            .calls()
+            .contains(&"list_task_definitions_in_use".to_string()));
+
+        app.modal_select()?;
+        assert_eq!(app.modal_state, ModalState::Confirm);
+        assert_eq!(
+            app.confirmation.as_ref().map(|c| c.title.as_str()),
+            Some("Delete Task Definitions")
+        );
+        app.confirm_action();
+        assert_eq!(app.modal_state, ModalState::TaskDefinitionCleanup);
+        settle(&mut app).await;
 
-        app.service_editor_desired_count_input.pop();
-        assert_eq!(app.service_editor_desired_count_input, "");
+        let progress = app
+            .task_definition_cleanup
+            .as_ref()
+            .and_then(|cleanup| cleanup.progress.clone())
+            .unwrap_or_default();
+        assert_eq!((progress.processed, progress.total), (2, 2));
+        assert_eq!((progress.deregistered, progress.deleted), (2, 2));
+        assert!(progress.failures.is_empty());
+        assert_eq!(
+            app.toast.as_ref().map(|toast| toast.message.as_str()),
+            Some("Deregistered 2 of 2 revisions of web and deleted 2")
+        );
+        assert!(backend.calls().contains(&format!(
+            "delete_task_definitions {},{}",
+            aws_fake::task_definition_arn("web", 1),
+            aws_fake::task_definition_arn("web", 2)
+        )));
+        assert_eq!(
+            backend.state().task_definitions.get("web"),
+            Some(&vec![aws_fake::task_definition_arn("web", 3)])
+        );
+
+        // Enter closes the dialog once the cleanup is done
+        app.modal_select()?;
+        assert_eq!(app.modal_state, ModalState::None);
+        assert!(app.task_definition_cleanup.is_none());
+        Ok(())
     }
 
-    #[test]
-    fn test_service_editor_revision_navigation_up() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
-        app.service_editor_editing_field = 1;
-        app.service_editor_available_revisions = vec![
-            "task-def:1".to_string(),
-            "task-def:2".to_string(),
-            "task-def:3".to_string(),
-        ];
-        app.service_editor_selected_revision = 2;
+    #[tokio::test]
+    async fn test_cleanup_keeps_latest_revisions_and_is_blocked_in_read_only_mode() -> Result<()> {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.set_view(AppState::TaskDefinitions);
+        app.refresh();
+        settle(&mut app).await;
+        app.selected_index = 1;
 
-        // Navigate up
-        if app.service_editor_selected_revision > 0 {
-            app.service_editor_selected_revision -= 1;
-        }
-        assert_eq!(app.service_editor_selected_revision, 1);
+        app.config.behavior.read_only = true;
+        app.show_task_definition_cleanup();
+        assert_eq!(app.modal_state, ModalState::None);
+        assert_eq!(
+            app.status_message,
+            "Read-only mode enabled - cannot clean up task definitions"
+        );
 
-        if app.service_editor_selected_revision > 0 {
-            app.service_editor_selected_revision -= 1;
+        app.config.behavior.read_only = false;
+        app.show_task_definition_cleanup();
+        if let Some(cleanup) = app.task_definition_cleanup.as_mut() {
+            cleanup.keep_latest = "2".to_string();
+            cleanup.skip_in_use = false;
         }
-        assert_eq!(app.service_editor_selected_revision, 0);
+        app.modal_select()?;
+        settle(&mut app).await;
+        let plan = app
+            .task_definition_cleanup
+            .as_ref()
+            .and_then(|cleanup| cleanup.plan.clone())
+            .unwrap_or_default();
+        assert_eq!(
+            plan.revisions,
+            vec![aws_fake::task_definition_arn("web", 1)]
+        );
+        assert!(!backend
+            .calls()
+            .contains(&"list_task_definitions_in_use".to_string()));
+
+        // Changing a rule discards the preview
+        if let Some(cleanup) = app.task_definition_cleanup.as_mut() {
+            cleanup.field = crate::task_definition_cleanup::CleanupField::KeepLatest;
+            cleanup.delete_char();
+            assert!(cleanup.plan.is_none());
+        }
+        Ok(())
+    }
 
-        // Should not go below 0
-        if app.service_editor_selected_revision > 0 {
-            app.service_editor_selected_revision -= 1;
+    #[tokio::test]
+    async fn test_service_editor_updates_auto_scaling() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.show_service_editor();
+        settle(&mut app).await;
+        assert_eq!(app.service_editor_field_count(), 5);
+        assert_eq!(app.service_editor_min_capacity_input, "2");
+        assert_eq!(app.service_editor_max_capacity_input, "6");
+
+        // Tab reaches the capacity fields, then wraps around to desired count
+        app.service_editor_next_field();
+        app.service_editor_next_field();
+        if let Some(input) = app.service_editor_input_mut() {
+            input.clear();
+            input.push('3');
         }
-        assert_eq!(app.service_editor_selected_revision, 0);
+        app.service_editor_next_field();
+        if let Some(input) = app.service_editor_input_mut() {
+            input.push('0');
+        }
+        app.service_editor_next_field();
+        app.toggle_service_editor_scaling_suspended();
+        assert!(app.service_editor_scaling_suspended);
+        app.service_editor_next_field();
+        assert_eq!(app.service_editor_editing_field, 0);
+
+        assert!(app.modal_select().is_ok());
+        assert_eq!(
+            app.confirmation.as_ref().map(|c| c.changes.clone()),
+            Some(vec![
+                "auto scaling min 2 → 3, max 6 → 60 on web-service in cluster cluster-prod"
+                    .to_string(),
+                "Suspend auto scaling of web-service in cluster cluster-prod".to_string(),
+            ])
+        );
+        app.confirm_action();
+        settle(&mut app).await;
+        assert!(backend.calls().contains(
+            &"update_service_scaling cluster-prod/web-service 3-60 suspended".to_string()
+        ));
+        let scaling = backend
+            .state()
+            .scaling
+            .get(&("cluster-prod".to_string(), "web-service".to_string()))
+            .map(|scaling| {
+                (
+                    scaling.min_capacity,
+                    scaling.max_capacity,
+                    scaling.suspended,
+                )
+            });
+        assert_eq!(scaling, Some((3, 60, true)));
     }
 
-    #[test]
-    fn test_service_editor_revision_navigation_down() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
-        app.service_editor_editing_field = 1;
-        app.service_editor_available_revisions = vec![
-            "task-def:1".to_string(),
-            "task-def:2".to_string(),
-            "task-def:3".to_string(),
-        ];
-        app.service_editor_selected_revision = 0;
+    #[tokio::test]
+    async fn test_service_editor_rejects_min_above_max() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.show_service_editor();
+        settle(&mut app).await;
 
-        // Navigate down
-        if app.service_editor_selected_revision + 1 < app.service_editor_available_revisions.len() {
-            app.service_editor_selected_revision += 1;
-        }
-        assert_eq!(app.service_editor_selected_revision, 1);
+        app.service_editor_min_capacity_input = "8".to_string();
+        assert!(app.modal_select().is_ok());
+        assert_eq!(app.modal_state, ModalState::ServiceEditor);
+        assert!(app.status_message.contains("exceeds maximum capacity 6"));
+        assert!(!backend
+            .calls()
+            .iter()
+            .any(|call| call.starts_with("update_service_scaling")));
+    }
 
-        if app.service_editor_selected_revision + 1 < app.service_editor_available_revisions.len() {
-            app.service_editor_selected_revision += 1;
+    #[tokio::test]
+    async fn test_service_editor_without_scalable_target() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+        app.next();
+        app.show_service_editor();
+        settle(&mut app).await;
+
+        assert_eq!(app.selected_service.as_deref(), Some("api-service"));
+        assert!(app.service_editor_scaling.is_none());
+        assert_eq!(app.service_editor_field_count(), 2);
+        app.service_editor_next_field();
+        assert!(app.service_editor_input_mut().is_none());
+        app.service_editor_next_field();
+        assert_eq!(app.service_editor_editing_field, 0);
+    }
+
+    #[tokio::test]
+    async fn test_service_details_include_auto_scaling() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.describe();
+        settle(&mut app).await;
+        let details = app.details.clone().unwrap_or_default();
+        assert!(details.contains("Auto Scaling:"));
+        assert!(details.contains("Capacity: min 2, max 6"));
+        assert!(details.contains(
+            "web-cpu-target (TargetTrackingScaling): ECSServiceAverageCPUUtilization target 60"
+        ));
+        assert!(details.contains("[Successful] Setting desired count to 2."));
+
+        app.set_view(AppState::Services);
+        app.next();
+        app.describe();
+        settle(&mut app).await;
+        assert!(app
+            .details
+            .as_deref()
+            .is_some_and(|details| details.contains("Auto Scaling:\n  Not configured")));
+    }
+
+    #[tokio::test]
+    async fn test_fake_backend_service_events_show_restart_rollout() {
+        let (mut app, _backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.view_service_events();
+        assert_eq!(app.state, AppState::ServiceEvents);
+        settle(&mut app).await;
+        assert_eq!(app.selected_service.as_deref(), Some("web-service"));
+        assert_eq!(app.service_events.len(), 1);
+        assert!(!app.rollout_in_progress());
+
+        app.back();
+        app.execute_action();
+        app.confirm_action();
+        settle(&mut app).await;
+        app.view_service_events();
+        settle(&mut app).await;
+        assert_eq!(app.service_deployments.len(), 2);
+        assert_eq!(app.service_deployments[0].rollout_state, "IN_PROGRESS");
+        assert!(app.rollout_in_progress());
+    }
+
+    #[tokio::test]
+    async fn test_service_events_open_the_selected_filtered_service() {
+        let (mut app, backend) = create_fake_app();
+        settle(&mut app).await;
+        app.select();
+        settle(&mut app).await;
+
+        app.search_query = "api".to_string();
+        app.selected_index = 0;
+        app.view_service_events();
+        settle(&mut app).await;
+        assert_eq!(app.selected_service.as_deref(), Some("api-service"));
+        assert!(backend
+            .calls()
+            .contains(&"get_service_events cluster-prod/api-service".to_string()));
+    }
+
+    /// Forces a rollout poll and applies its response.
+    async fn poll_rollout_now(app: &mut App) {
+        if let Some(monitor) = app.rollout_monitor.as_mut() {
+            monitor.last_poll = None;
         }
-        assert_eq!(app.service_editor_selected_revision, 2);
+        app.poll_rollout();
+        for _ in 0..200 {
+            app.process_responses();
+            if !app
+                .rollout_monitor
+                .as_ref()
+                .is_some_and(|m| m.poll_in_flight)
+            {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
 
-        // Should not go beyond length
-        if app.service_editor_selected_revision + 1 < app.service_editor_available_revisions.len() {
-            app.service_editor_selected_revision += 1;
+    /// Restarts web-service on cluster-prod and waits for the action to finish.
+    async fn restart_web_service(app: &mut App) {
+        settle(app).await;
+        app.select();
+        settle(app).await;
+        app.execute_action();
+        app.confirm_action();
+        settle(app).await;
+    }
+
+    fn web_service_deployments(backend: &FakeBackend) -> Vec<DeploymentInfo> {
+        backend
+            .state()
+            .deployments
+            .get(&("cluster-prod".to_string(), "web-service".to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn set_primary_rollout(backend: &FakeBackend, rollout_state: &str, running: i32, reason: &str) {
+        let mut state = backend.state();
+        if let Some(primary) = state
+            .deployments
+            .get_mut(&("cluster-prod".to_string(), "web-service".to_string()))
+            .and_then(|deployments| deployments.first_mut())
+        {
+            primary.rollout_state = rollout_state.to_string();
+            primary.rollout_state_reason = reason.to_string();
+            primary.running_count = running;
         }
-        assert_eq!(app.service_editor_selected_revision, 2);
     }
 
-    #[test]
-    fn test_service_editor_modal_next() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
-        app.service_editor_available_revisions =
-            vec!["task-def:1".to_string(), "task-def:2".to_string()];
-        app.modal_selected_index = 0;
+    #[tokio::test]
+    async fn test_restart_starts_rollout_monitor() {
+        let (mut app, backend) = create_fake_app();
+        restart_web_service(&mut app).await;
 
-        app.modal_next();
-        assert_eq!(app.modal_selected_index, 1);
+        assert!(app
+            .rollout_monitor
+            .as_ref()
+            .is_some_and(|m| m.service == "web-service" && m.cluster == "cluster-prod"));
+        assert!(app
+            .active_toast()
+            .is_some_and(|t| t.toast_type == ToastType::Info));
+
+        poll_rollout_now(&mut app).await;
+        let expected = web_service_deployments(&backend)
+            .first()
+            .map(|d| d.id.clone());
+        assert!(expected.is_some());
+        assert_eq!(
+            app.rollout_monitor
+                .as_ref()
+                .and_then(|m| m.deployment_id.clone()),
+            expected
+        );
+        assert!(backend
+            .calls()
+            .contains(&"get_service_events cluster-prod/web-service".to_string()));
+    }
 
-        app.modal_next();
-        assert_eq!(app.modal_selected_index, 0); // Wraps around
+    #[tokio::test]
+    async fn test_rollout_monitor_reports_completion() {
+        let (mut app, backend) = create_fake_app();
+        restart_web_service(&mut app).await;
+        poll_rollout_now(&mut app).await;
+        assert!(app.rollout_monitor.is_some());
+
+        set_primary_rollout(&backend, "COMPLETED", 1, "ECS deployment completed.");
+        poll_rollout_now(&mut app).await;
+
+        assert!(app.rollout_monitor.is_none());
+        assert!(app.active_toast().is_some_and(
+            |t| t.toast_type == ToastType::Success && t.message.contains("web-service")
+        ));
     }
 
-    #[test]
-    fn test_service_editor_modal_previous() {
-        let mut app = create_test_app();
-        app.modal_state = ModalState::ServiceEditor;
-        app.service_editor_available_revisions =
-            vec!["task-def:1".to_string(), "task-def:2".to_string()];
-        app.modal_selected_index = 0;
+    #[tokio::test]
+    async fn test_rollout_monitor_reports_circuit_breaker_rollback() {
+        let (mut app, backend) = create_fake_app();
+        backend.state().circuit_breaker_rollback = true;
+        restart_web_service(&mut app).await;
+        poll_rollout_now(&mut app).await;
+        assert!(app
+            .rollout_monitor
+            .as_ref()
+            .is_some_and(|m| m.circuit_breaker_enabled && m.circuit_breaker_rollback));
 
-        app.modal_previous();
-        assert_eq!(app.modal_selected_index, 1); // Wraps around
+        set_primary_rollout(
+            &backend,
+            "FAILED",
+            0,
+            "ECS deployment circuit breaker: tasks failed to start.",
+        );
+        poll_rollout_now(&mut app).await;
 
-        app.modal_previous();
-        assert_eq!(app.modal_selected_index, 0);
+        assert!(app.rollout_monitor.is_none());
+        assert!(
+            app.active_toast().is_some_and(
+                |t| t.toast_type == ToastType::Error && t.message.contains("rolled back")
+            )
+        );
     }
 
-    #[test]
-    fn test_service_editor_initial_state() {
-        let app = create_test_app();
+    #[tokio::test]
+    async fn test_rollout_poll_not_repeated_before_interval() {
+        let (mut app, backend) = create_fake_app();
+        restart_web_service(&mut app).await;
+        poll_rollout_now(&mut app).await;
 
-        assert_eq!(app.service_editor_desired_count_input, "");
-        assert_eq!(app.service_editor_selected_revision, 0);
-        assert_eq!(app.service_editor_available_revisions.len(), 0);
-        assert_eq!(app.service_editor_current_task_def, "");
-        assert_eq!(app.service_editor_editing_field, 0);
+        app.poll_rollout();
+        app.poll_rollout();
+        let polls = backend
+            .calls()
+            .iter()
+            .filter(|c| c.starts_with("get_service_events"))
+            .count();
+        assert_eq!(polls, 1);
     }
 }
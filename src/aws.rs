@@ -2,13 +2,41 @@
 //!
 //! This module provides a client wrapper for AWS ECS and CloudWatch Logs services,
 //! with methods for listing clusters, services, tasks, and retrieving logs.
-
-use crate::app::{LogEntry, ServiceInfo, TaskInfo};
+//!
+//! The app talks to AWS through the [`EcsBackend`] trait. [`EcsClient`] is the
+//! implementation backed by the AWS SDK; tests use the in-memory fake from the
+//! `aws_fake` module instead.
+
+use crate::app::{
+    CapacityProviderStrategyItem, ClusterInfo, ContainerExit, ContainerInstanceInfo,
+    DeploymentInfo, LogEntry, ScalingActivityInfo, ScalingPolicyInfo, ScalingUpdate,
+    ServiceActivity, ServiceEvent, ServiceInfo, ServiceScaling, StoppedTaskInfo, TaskInfo,
+};
+use crate::ecs_json;
+use crate::task_definition::{
+    ContainerDefinitionInfo, ContainerDependencyInfo, EfsVolumeInfo, EnvironmentVariable,
+    HealthCheckInfo, LogConfigurationInfo, MountPointInfo, PortMappingInfo, RuntimePlatformInfo,
+    SecretReference, TaskDefinitionInfo, VolumeInfo,
+};
 use anyhow::{Context, Result};
+use async_trait::async_trait;
+use aws_sdk_applicationautoscaling::Client as AutoScalingClient;
 use aws_sdk_cloudwatch::Client as CloudWatchClient;
+use aws_sdk_cloudwatchlogs::error::{DisplayErrorContext, SdkError};
+use aws_sdk_cloudwatchlogs::operation::get_log_events::GetLogEventsError;
+use aws_sdk_cloudwatchlogs::types::OutputLogEvent;
 use aws_sdk_cloudwatchlogs::Client as LogsClient;
+use aws_sdk_ecs::types::{
+    ApplicationProtocol, Compatibility, ContainerCondition, ContainerDefinition,
+    ContainerDependency, CpuArchitecture, DesiredStatus, EfsAuthorizationConfig,
+    EfsAuthorizationConfigIam, EfsTransitEncryption, EfsVolumeConfiguration, EphemeralStorage,
+    HealthCheck, HostVolumeProperties, KeyValuePair, LogConfiguration, LogDriver, MountPoint,
+    NetworkMode, OsFamily, PortMapping, RuntimePlatform, Secret, ServiceField, TaskDefinition,
+    TaskDefinitionField, TransportProtocol, Volume,
+};
 use aws_sdk_ecs::Client;
 use aws_sdk_ssm::Client as SsmClient;
+use std::collections::{HashMap, HashSet};
 
 /// Client for interacting with AWS ECS, CloudWatch Logs, and CloudWatch Metrics.
 ///
@@ -23,6 +51,8 @@ pub struct EcsClient {
     metrics_client: CloudWatchClient,
     /// AWS Systems Manager SDK client (for ECS Exec and port forwarding)
     ssm_client: SsmClient,
+    /// AWS Application Auto Scaling SDK client (for service auto scaling)
+    autoscaling_client: AutoScalingClient,
 }
 
 /// Represents a CloudWatch metric datapoint.
@@ -137,6 +167,166 @@ pub struct Metrics {
     pub service_name: String,
 }
 
+/// Container Insights metrics of a single container of a task.
+#[derive(Debug, Clone)]
+pub struct ContainerMetrics {
+    /// Container name from the task definition
+    pub name: String,
+    /// CPU units used by the container
+    pub cpu_utilized: Vec<MetricDatapoint>,
+    /// Memory (MiB) used by the container
+    pub memory_utilized: Vec<MetricDatapoint>,
+}
+
+/// Container Insights metrics of a single task and its containers.
+///
+/// Task-level metrics require Container Insights with enhanced observability on the
+/// cluster; every series is empty otherwise.
+#[derive(Debug, Clone)]
+pub struct TaskMetrics {
+    /// CPU units used by the task
+    pub cpu_utilized: Vec<MetricDatapoint>,
+    /// CPU units reserved by the task
+    pub cpu_reserved: Option<f64>,
+    /// Memory (MiB) used by the task
+    pub memory_utilized: Vec<MetricDatapoint>,
+    /// Memory (MiB) reserved by the task
+    pub memory_reserved: Option<f64>,
+    /// Bytes per second received over the network
+    pub network_rx: Vec<MetricDatapoint>,
+    /// Bytes per second transmitted over the network
+    pub network_tx: Vec<MetricDatapoint>,
+    /// Bytes read from storage
+    pub storage_read: Vec<MetricDatapoint>,
+    /// Bytes written to storage
+    pub storage_write: Vec<MetricDatapoint>,
+    /// Per-container CPU and memory, sorted by container name
+    pub containers: Vec<ContainerMetrics>,
+    /// Time range for these metrics
+    pub time_range: TimeRange,
+    /// Cluster name
+    pub cluster_name: String,
+    /// Task ID (last segment of the task ARN)
+    pub task_id: String,
+}
+
+impl TaskMetrics {
+    /// Creates task metrics without any datapoints.
+    pub fn empty(cluster_name: &str, task_id: &str, time_range: TimeRange) -> Self {
+        Self {
+            cpu_utilized: Vec::new(),
+            cpu_reserved: None,
+            memory_utilized: Vec::new(),
+            memory_reserved: None,
+            network_rx: Vec::new(),
+            network_tx: Vec::new(),
+            storage_read: Vec::new(),
+            storage_write: Vec::new(),
+            containers: Vec::new(),
+            time_range,
+            cluster_name: cluster_name.to_string(),
+            task_id: task_id.to_string(),
+        }
+    }
+
+    /// Returns whether Container Insights reported any datapoint for the task.
+    pub fn has_data(&self) -> bool {
+        [
+            &self.cpu_utilized,
+            &self.memory_utilized,
+            &self.network_rx,
+            &self.network_tx,
+            &self.storage_read,
+            &self.storage_write,
+        ]
+        .iter()
+        .any(|series| !series.is_empty())
+            || !self.containers.is_empty()
+    }
+}
+
+/// CloudWatch namespace of ECS Container Insights metrics.
+const CONTAINER_INSIGHTS_NAMESPACE: &str = "ECS/ContainerInsights";
+
+/// The [`TaskMetrics`] series a Container Insights metric fills.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TaskMetricSeries {
+    CpuUtilized,
+    CpuReserved,
+    MemoryUtilized,
+    MemoryReserved,
+    NetworkRx,
+    NetworkTx,
+    StorageRead,
+    StorageWrite,
+    ContainerCpu(String),
+    ContainerMemory(String),
+}
+
+impl TaskMetricSeries {
+    /// Maps a metric name and its `ContainerName` dimension to a series.
+    ///
+    /// Container-level metrics are published both with and without a `Container`
+    /// name prefix depending on the observability level, so both are accepted.
+    fn from_metric(name: &str, container: Option<&str>) -> Option<Self> {
+        match container {
+            Some(container) => match name.strip_prefix("Container").unwrap_or(name) {
+                "CpuUtilized" => Some(Self::ContainerCpu(container.to_string())),
+                "MemoryUtilized" => Some(Self::ContainerMemory(container.to_string())),
+                _ => None,
+            },
+            None => match name {
+                "CpuUtilized" => Some(Self::CpuUtilized),
+                "CpuReserved" => Some(Self::CpuReserved),
+                "MemoryUtilized" => Some(Self::MemoryUtilized),
+                "MemoryReserved" => Some(Self::MemoryReserved),
+                "NetworkRxBytes" => Some(Self::NetworkRx),
+                "NetworkTxBytes" => Some(Self::NetworkTx),
+                "StorageReadBytes" => Some(Self::StorageRead),
+                "StorageWriteBytes" => Some(Self::StorageWrite),
+                _ => None,
+            },
+        }
+    }
+
+    /// Stores datapoints in the matching field of `metrics`.
+    fn store(self, metrics: &mut TaskMetrics, datapoints: Vec<MetricDatapoint>) {
+        let latest = datapoints.last().and_then(|dp| dp.average);
+        match self {
+            Self::CpuUtilized => metrics.cpu_utilized = datapoints,
+            Self::CpuReserved => metrics.cpu_reserved = latest,
+            Self::MemoryUtilized => metrics.memory_utilized = datapoints,
+            Self::MemoryReserved => metrics.memory_reserved = latest,
+            Self::NetworkRx => metrics.network_rx = datapoints,
+            Self::NetworkTx => metrics.network_tx = datapoints,
+            Self::StorageRead => metrics.storage_read = datapoints,
+            Self::StorageWrite => metrics.storage_write = datapoints,
+            Self::ContainerCpu(name) => {
+                Self::container_entry(metrics, name).cpu_utilized = datapoints;
+            }
+            Self::ContainerMemory(name) => {
+                Self::container_entry(metrics, name).memory_utilized = datapoints;
+            }
+        }
+    }
+
+    /// Returns the metrics of a container, adding it if not seen yet.
+    fn container_entry(metrics: &mut TaskMetrics, name: String) -> &mut ContainerMetrics {
+        let index = match metrics.containers.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                metrics.containers.push(ContainerMetrics {
+                    name,
+                    cpu_utilized: Vec::new(),
+                    memory_utilized: Vec::new(),
+                });
+                metrics.containers.len() - 1
+            }
+        };
+        &mut metrics.containers[index]
+    }
+}
+
 /// Session information returned by ECS ExecuteCommand API.
 ///
 /// Contains the credentials needed to establish a connection to a container
@@ -151,790 +341,3246 @@ pub struct Session {
     pub token_value: String,
 }
 
-impl EcsClient {
-    /// Creates a new ECS client with optional region and profile configuration.
-    ///
-    /// # Arguments
-    /// * `region` - Optional AWS region override (e.g., "us-east-1")
-    /// * `profile` - Optional AWS profile name from ~/.aws/credentials
-    ///
-    /// # Returns
-    /// Returns a new `EcsClient` instance configured with the specified options,
-    /// or an error if AWS SDK initialization fails.
-    ///
-    /// # Errors
-    /// This function will return an error if:
-    /// - AWS credentials cannot be resolved
-    /// - The specified profile doesn't exist
-    /// - The specified region is invalid
-    pub async fn new(region: Option<String>, profile: Option<String>) -> Result<Self> {
-        let mut config_loader = aws_config::from_env();
+/// Maximum number of events requested per log stream and page.
+pub const LOG_PAGE_SIZE: i32 = 500;
 
-        // Set region if provided
-        if let Some(region_str) = region {
-            config_loader = config_loader.region(aws_config::Region::new(region_str));
-        }
+/// Maximum number of pages read per log stream when polling for new events.
+const MAX_TAIL_PAGES: usize = 10;
 
-        // Set profile if provided
-        if let Some(profile_name) = profile {
-            config_loader = config_loader.profile_name(profile_name);
-        }
+/// Read position in the CloudWatch Logs stream of one container of a task.
+///
+/// Returned with every page of logs and passed back to read the page before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogStreamCursor {
+    /// Container name from the task definition
+    pub container_name: String,
+    /// CloudWatch Logs group name
+    pub log_group: String,
+    /// CloudWatch Logs stream name
+    pub log_stream: String,
+    /// Only events before this time (Unix milliseconds) are read; `None` for no limit
+    pub end_time: Option<i64>,
+    /// `nextBackwardToken` of the last page read, `None` before the first one
+    pub backward_token: Option<String>,
+    /// `nextForwardToken` of the newest page read; `None` to read from the start of the stream
+    pub forward_token: Option<String>,
+    /// Whether there are no older events in the stream
+    pub exhausted: bool,
+}
 
-        let config = config_loader.load().await;
-        let client = Client::new(&config);
-        let logs_client = LogsClient::new(&config);
-        let metrics_client = CloudWatchClient::new(&config);
-        let ssm_client = SsmClient::new(&config);
-        Ok(Self {
-            client,
-            logs_client,
-            metrics_client,
-            ssm_client,
-        })
+/// Which page of a task's logs to read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogPageRequest {
+    /// The most recent events of every container
+    Latest,
+    /// The first events at or after a point in time (Unix milliseconds)
+    At(i64),
+    /// The events before the given cursors
+    Older(Vec<LogStreamCursor>),
+    /// The events after the given cursors, used to tail logs
+    Newer(Vec<LogStreamCursor>),
+}
+
+/// A page of log events across all containers of a task.
+#[derive(Debug, Clone)]
+pub struct LogPage {
+    /// Log entries sorted by timestamp
+    pub entries: Vec<LogEntry>,
+    /// Cursors to read the pages before and after it, one per container log stream
+    pub cursors: Vec<LogStreamCursor>,
+    /// Containers whose logs could not be read, with the reason why
+    pub unavailable: Vec<UnavailableLogs>,
+}
+
+/// Log events of all tasks of a service within a time window.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceLogs {
+    /// Log entries sorted by timestamp
+    pub entries: Vec<LogEntry>,
+    /// Whether reading stopped at the event limit before the end of the window
+    pub truncated: bool,
+}
+
+/// A container whose logs cannot be read from CloudWatch Logs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnavailableLogs {
+    /// Container name from the task definition
+    pub container_name: String,
+    /// Why the container's logs cannot be shown
+    pub reason: String,
+}
+
+impl LogPage {
+    /// Returns whether every log stream has been read back to its first event.
+    pub fn reached_start(&self) -> bool {
+        self.cursors.iter().all(|cursor| cursor.exhausted)
     }
+}
 
-    /// Lists all ECS clusters in the configured region.
-    ///
-    /// Returns cluster names extracted from the full ARNs. If no clusters exist,
-    /// returns an empty vector.
-    ///
-    /// # Returns
-    /// A vector of cluster names (not full ARNs)
-    ///
-    /// # Errors
-    /// This function will return an error if:
-    /// - The AWS API call fails due to network or permission issues
-    /// - The response cannot be parsed
-    pub async fn list_clusters(&self) -> Result<Vec<String>> {
-        let resp = self.client.list_clusters().send().await?;
+/// Status and results of a CloudWatch Logs Insights query, partial while it runs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InsightsQueryResults {
+    /// Query status: Scheduled, Running, Complete, Failed, Cancelled, Timeout or Unknown
+    pub status: String,
+    /// Result field names in the order they first appear, without `@ptr`
+    pub fields: Vec<String>,
+    /// One value per field for every result row, empty where a row lacks the field
+    pub rows: Vec<Vec<String>>,
+    /// Number of log events that matched the query so far
+    pub records_matched: f64,
+    /// Number of log events scanned so far
+    pub records_scanned: f64,
+}
 
-        let cluster_arns = resp.cluster_arns();
-        let clusters = cluster_arns
-            .iter()
-            .map(|arn| {
-                // Extract cluster name from ARN
-                arn.split('/').next_back().unwrap_or(arn).to_string()
+impl InsightsQueryResults {
+    /// Builds results from rows of (field, value) pairs, aligning the values in columns.
+    pub fn from_rows(status: String, results: Vec<Vec<(String, String)>>) -> Self {
+        let mut fields: Vec<String> = Vec::new();
+        for (field, _) in results.iter().flatten() {
+            if field != "@ptr" && !fields.contains(field) {
+                fields.push(field.clone());
+            }
+        }
+        let rows = results
+            .into_iter()
+            .map(|row| {
+                fields
+                    .iter()
+                    .map(|field| {
+                        row.iter()
+                            .find(|(name, _)| name == field)
+                            .map(|(_, value)| value.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect()
             })
             .collect();
+        Self {
+            status,
+            fields,
+            rows,
+            ..Self::default()
+        }
+    }
 
-        Ok(clusters)
+    /// Returns whether the query stopped running, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        !matches!(self.status.as_str(), "Scheduled" | "Running")
     }
+}
 
-    /// Lists all services in a specific ECS cluster.
-    ///
-    /// First retrieves service ARNs, then fetches detailed information for each service
-    /// including status, task counts, and launch type.
-    ///
-    /// # Arguments
-    /// * `cluster` - The cluster name or ARN
-    ///
-    /// # Returns
-    /// A vector of `ServiceInfo` structs containing service details, or an empty vector
-    /// if no services exist in the cluster
-    ///
-    /// # Errors
-    /// This function will return an error if:
-    /// - The AWS ListServices or DescribeServices API calls fail
-    /// - The cluster doesn't exist
-    /// - Insufficient permissions to access the cluster or services
-    pub async fn list_services(&self, cluster: &str) -> Result<Vec<ServiceInfo>> {
-        let resp = self.client.list_services().cluster(cluster).send().await?;
+/// A container that sends its logs to CloudWatch Logs, with the awslogs driver or
+/// through a FireLens log router.
+///
+/// Log group and stream names are templates that may contain the FireLens variables
+/// `$(ecs_task_id)`, `$(ecs_task_arn)`, `$(ecs_cluster)`, `$(container_name)` and `$(tag)`.
+#[derive(Debug, Clone, PartialEq)]
+struct CloudWatchLogsContainer {
+    /// Container name from the task definition
+    name: String,
+    /// Log group name template
+    log_group: String,
+    /// Log stream name templates; the first one that can be resolved is used
+    stream_templates: Vec<String>,
+}
 
-        let service_arns = resp.service_arns();
+impl CloudWatchLogsContainer {
+    /// Returns the container's log group, `None` if its template cannot be resolved.
+    fn log_group(&self, context: LogTemplateContext) -> Option<String> {
+        resolve_log_template(&self.log_group, &self.name, context)
+    }
 
-        if service_arns.is_empty() {
-            return Ok(Vec::new());
-        }
+    /// Returns the name of the container's log stream, `None` if no template can be resolved.
+    fn stream_name(&self, context: LogTemplateContext) -> Option<String> {
+        self.stream_templates
+            .iter()
+            .find_map(|template| resolve_log_template(template, &self.name, context))
+    }
 
-        // Describe services to get detailed info
-        let describe_resp = self
-            .client
-            .describe_services()
-            .cluster(cluster)
-            .set_services(Some(service_arns.to_vec()))
-            .send()
-            .await?;
+    /// Returns the prefix shared by the container's log streams across tasks.
+    fn stream_prefix(&self, cluster: &str) -> String {
+        let context = LogTemplateContext {
+            cluster,
+            task_id: None,
+            task_arn: None,
+        };
+        self.stream_templates
+            .first()
+            .map(|template| expand_log_template(template, &self.name, context).0)
+            .unwrap_or_default()
+    }
+}
 
-        let services = describe_resp
-            .services()
-            .iter()
-            .map(|s| {
-                let name = s.service_name().unwrap_or("unknown").to_string();
-                let status = s.status().unwrap_or("unknown").to_string();
-                let desired_count = s.desired_count();
-                let running_count = s.running_count();
-                let pending_count = s.pending_count();
-                let launch_type = s
-                    .launch_type()
-                    .map(|lt| lt.as_str().to_string())
-                    .unwrap_or_else(|| "unknown".to_string());
+/// Where a container of a task definition sends its logs.
+#[derive(Debug, Clone, PartialEq)]
+enum ContainerLogSource {
+    /// The container's logs can be read from CloudWatch Logs
+    CloudWatch(CloudWatchLogsContainer),
+    /// The container's logs cannot be read, with the reason why
+    Unavailable(UnavailableLogs),
+}
 
-                ServiceInfo {
-                    name,
-                    status,
-                    desired_count,
-                    running_count,
-                    pending_count,
-                    launch_type,
-                }
+impl ContainerLogSource {
+    /// Works out where a container sends its logs from its log configuration.
+    ///
+    /// FireLens outputs are understood when they use the `cloudwatch` or
+    /// `cloudwatch_logs` Fluent Bit plugin in `region`, the region logs are read from.
+    fn from_container(container: &ContainerDefinition, region: Option<&str>) -> Self {
+        let name = container.name().unwrap_or("unknown").to_string();
+        let unavailable = |reason: String| {
+            ContainerLogSource::Unavailable(UnavailableLogs {
+                container_name: name.clone(),
+                reason,
             })
-            .collect();
+        };
+        let Some(log_config) = container.log_configuration() else {
+            return unavailable("no log configuration in the task definition".to_string());
+        };
+        let option = |key: &str| {
+            log_config
+                .options()
+                .and_then(|options| options.get(key))
+                .filter(|value| !value.is_empty())
+                .cloned()
+        };
 
-        Ok(services)
+        match log_config.log_driver().as_str() {
+            "awslogs" => {
+                let Some(log_group) = option("awslogs-group") else {
+                    return unavailable("awslogs log driver without an awslogs-group".to_string());
+                };
+                let prefix = option("awslogs-stream-prefix").unwrap_or_else(|| "ecs".to_string());
+                ContainerLogSource::CloudWatch(CloudWatchLogsContainer {
+                    stream_templates: vec![format!("{prefix}/{name}/$(ecs_task_id)")],
+                    name,
+                    log_group,
+                })
+            }
+            "awsfirelens" => {
+                let Some(output) = option("Name") else {
+                    return unavailable(
+                        "FireLens output is defined in a Fluent Bit config file, not in the task definition"
+                            .to_string(),
+                    );
+                };
+                if !matches!(
+                    output.to_lowercase().as_str(),
+                    "cloudwatch" | "cloudwatch_logs"
+                ) {
+                    return unavailable(format!(
+                        "FireLens routes logs to the {output} output, not CloudWatch Logs"
+                    ));
+                }
+                if let (Some(target), Some(current)) = (option("region"), region) {
+                    if target != current {
+                        return unavailable(format!(
+                            "FireLens sends logs to CloudWatch Logs in {target}; switch to that region to read them"
+                        ));
+                    }
+                }
+                let Some(log_group) = option("log_group_name") else {
+                    return unavailable(
+                        "FireLens CloudWatch output without a log_group_name".to_string(),
+                    );
+                };
+                // Fluent Bit falls back to the prefix followed by the tag, which FireLens
+                // sets to <container>-firelens-<task ID>
+                let stream_templates: Vec<String> = [
+                    option("log_stream_name"),
+                    option("log_stream_prefix").map(|prefix| format!("{prefix}$(tag)")),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+                if stream_templates.is_empty() {
+                    return unavailable(
+                        "FireLens CloudWatch output without a log_stream_name or log_stream_prefix"
+                            .to_string(),
+                    );
+                }
+                ContainerLogSource::CloudWatch(CloudWatchLogsContainer {
+                    name,
+                    log_group,
+                    stream_templates,
+                })
+            }
+            driver => unavailable(format!(
+                "the {driver} log driver does not send logs to CloudWatch Logs"
+            )),
+        }
     }
+}
 
-    /// Lists all tasks for a specific service in a cluster.
-    ///
-    /// Retrieves task ARNs for the service, then fetches detailed information including
-    /// status, resource allocation, and container instance details.
-    ///
-    /// # Arguments
-    /// * `cluster` - The cluster name or ARN
-    /// * `service` - The service name or ARN
-    ///
-    /// # Returns
-    /// A vector of `TaskInfo` structs containing task details, or an empty vector
-    /// if no tasks are running for the service
-    ///
-    /// # Errors
-    /// This function will return an error if:
-    /// - The AWS ListTasks or DescribeTasks API calls fail
-    /// - The cluster or service doesn't exist
-    /// - Insufficient permissions to access tasks
-    pub async fn list_tasks(&self, cluster: &str, service: &str) -> Result<Vec<TaskInfo>> {
-        let resp = self
-            .client
-            .list_tasks()
-            .cluster(cluster)
-            .service_name(service)
-            .send()
-            .await?;
+/// The task that log group and stream name templates are resolved for.
+#[derive(Debug, Clone, Copy)]
+struct LogTemplateContext<'a> {
+    /// Cluster name or ARN
+    cluster: &'a str,
+    /// Task ID, `None` for names shared by all tasks
+    task_id: Option<&'a str>,
+    /// Task ARN, when known
+    task_arn: Option<&'a str>,
+}
 
-        let task_arns = resp.task_arns();
+/// Expands the `$(...)` variables of a FireLens log group or stream name template.
+///
+/// Returns the expanded text up to the first variable that cannot be resolved, and
+/// whether the whole template was expanded.
+fn expand_log_template(
+    template: &str,
+    container_name: &str,
+    context: LogTemplateContext,
+) -> (String, bool) {
+    let cluster = context
+        .cluster
+        .split('/')
+        .next_back()
+        .unwrap_or(context.cluster);
+    let tag = context
+        .task_id
+        .map(|task_id| format!("{container_name}-firelens-{task_id}"));
+
+    let mut expanded = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("$(") {
+        expanded.push_str(&rest[..start]);
+        let Some(length) = rest[start..].find(')') else {
+            return (expanded, false);
+        };
+        let value = match &rest[start + 2..start + length] {
+            "ecs_task_id" => context.task_id,
+            "ecs_task_arn" => context.task_arn,
+            "ecs_cluster" => Some(cluster),
+            "container_name" => Some(container_name),
+            "tag" | "tag[0]" => tag.as_deref(),
+            _ => None,
+        };
+        let Some(value) = value else {
+            return (expanded, false);
+        };
+        expanded.push_str(value);
+        rest = &rest[start + length + 1..];
+    }
+    expanded.push_str(rest);
+    (expanded, true)
+}
 
-        if task_arns.is_empty() {
-            return Ok(Vec::new());
+/// Converts a GetLogEvents error into a readable one, explaining a missing log stream.
+fn log_events_error(err: SdkError<GetLogEventsError>, cursor: &LogStreamCursor) -> anyhow::Error {
+    if err
+        .as_service_error()
+        .is_some_and(GetLogEventsError::is_resource_not_found_exception)
+    {
+        anyhow::anyhow!(
+            "log stream {} not found in log group {} (the container may not have logged yet)",
+            cursor.log_stream,
+            cursor.log_group
+        )
+    } else {
+        anyhow::anyhow!("{}", DisplayErrorContext(err))
+    }
+}
+
+/// Reads the pages of a paginated call, following its `nextToken` until the last page
+/// or until `limit` items are read.
+///
+/// Paging stops when the token runs out, not at a page count, since pages may be empty
+/// and still carry a token. It also stops if a token repeats, so a misbehaving API
+/// can't page forever.
+///
+/// # Arguments
+/// * `limit` - Most items to return
+/// * `fetch_page` - Reads the page at a token (`None` for the first page), returning
+///   its items and the token of the next page
+///
+/// # Returns
+/// The items, and whether items were left unread because of the limit
+pub async fn read_pages<T, F, Fut>(limit: usize, mut fetch_page: F) -> Result<(Vec<T>, bool)>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<(Vec<T>, Option<String>)>>,
+{
+    let mut items = Vec::new();
+    let mut seen_tokens = HashSet::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let (page, token) = fetch_page(next_token).await?;
+        items.extend(page);
+        if items.len() > limit {
+            items.truncate(limit);
+            return Ok((items, true));
         }
+        match token {
+            Some(token) if seen_tokens.insert(token.clone()) => {
+                if items.len() == limit {
+                    return Ok((items, true));
+                }
+                next_token = Some(token);
+            }
+            _ => return Ok((items, false)),
+        }
+    }
+}
 
-        // Describe tasks to get detailed info
-        let describe_resp = self
-            .client
-            .describe_tasks()
-            .cluster(cluster)
-            .set_tasks(Some(task_arns.to_vec()))
-            .send()
-            .await?;
+/// Returns whether a task definition ARN is a revision of exactly `family`, not of a
+/// longer family name starting with it.
+pub fn is_family_revision(arn: &str, family: &str) -> bool {
+    arn.rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once(':'))
+        .is_some_and(|(name, _)| name == family)
+}
 
-        let tasks = describe_resp
-            .tasks()
+/// Resolves a FireLens log group or stream name template, `None` if a variable is unknown.
+fn resolve_log_template(
+    template: &str,
+    container_name: &str,
+    context: LogTemplateContext,
+) -> Option<String> {
+    let (expanded, complete) = expand_log_template(template, container_name, context);
+    complete.then_some(expanded)
+}
+
+/// Converts a task definition returned by DescribeTaskDefinition into a `TaskDefinitionInfo`.
+fn task_definition_info(task_definition: &TaskDefinition) -> TaskDefinitionInfo {
+    let secrets = |secrets: &[aws_sdk_ecs::types::Secret]| {
+        secrets
             .iter()
-            .map(|t| {
-                let task_arn = t.task_arn().unwrap_or("unknown").to_string();
+            .map(|secret| SecretReference {
+                name: secret.name().to_string(),
+                value_from: secret.value_from().to_string(),
+            })
+            .collect::<Vec<_>>()
+    };
+    let containers = task_definition
+        .container_definitions()
+        .iter()
+        .map(|container| ContainerDefinitionInfo {
+            name: container.name().unwrap_or("unknown").to_string(),
+            image: container.image().unwrap_or_default().to_string(),
+            cpu: container.cpu(),
+            memory: container.memory(),
+            memory_reservation: container.memory_reservation(),
+            essential: container.essential(),
+            command: container.command().to_vec(),
+            entry_point: container.entry_point().to_vec(),
+            working_directory: container.working_directory().map(str::to_string),
+            user: container.user().map(str::to_string),
+            port_mappings: container
+                .port_mappings()
+                .iter()
+                .map(|port| PortMappingInfo {
+                    container_port: port.container_port(),
+                    host_port: port.host_port(),
+                    protocol: port
+                        .protocol()
+                        .map(|protocol| protocol.as_str().to_string()),
+                    name: port.name().map(str::to_string),
+                    app_protocol: port
+                        .app_protocol()
+                        .map(|protocol| protocol.as_str().to_string()),
+                })
+                .collect(),
+            environment: container
+                .environment()
+                .iter()
+                .map(|variable| EnvironmentVariable {
+                    name: variable.name().unwrap_or_default().to_string(),
+                    value: variable.value().unwrap_or_default().to_string(),
+                })
+                .collect(),
+            secrets: secrets(container.secrets()),
+            health_check: container.health_check().map(|check| HealthCheckInfo {
+                command: check.command().to_vec(),
+                interval: check.interval(),
+                timeout: check.timeout(),
+                retries: check.retries(),
+                start_period: check.start_period(),
+            }),
+            mount_points: container
+                .mount_points()
+                .iter()
+                .map(|mount| MountPointInfo {
+                    source_volume: mount.source_volume().unwrap_or_default().to_string(),
+                    container_path: mount.container_path().unwrap_or_default().to_string(),
+                    read_only: mount.read_only(),
+                })
+                .collect(),
+            log_configuration: container.log_configuration().map(|log_config| {
+                LogConfigurationInfo {
+                    log_driver: log_config.log_driver().as_str().to_string(),
+                    options: log_config
+                        .options()
+                        .map(|options| {
+                            options
+                                .iter()
+                                .map(|(key, value)| (key.clone(), value.clone()))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    secret_options: secrets(log_config.secret_options()),
+                }
+            }),
+            depends_on: container
+                .depends_on()
+                .iter()
+                .map(|dependency| ContainerDependencyInfo {
+                    container_name: dependency.container_name().to_string(),
+                    condition: dependency.condition().as_str().to_string(),
+                })
+                .collect(),
+            readonly_root_filesystem: container.readonly_root_filesystem(),
+            docker_labels: container
+                .docker_labels()
+                .map(|labels| {
+                    labels
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            stop_timeout: container.stop_timeout(),
+            start_timeout: container.start_timeout(),
+        })
+        .collect();
+    let volumes = task_definition
+        .volumes()
+        .iter()
+        .map(|volume| VolumeInfo {
+            name: volume.name().unwrap_or_default().to_string(),
+            host_source_path: volume
+                .host()
+                .and_then(|host| host.source_path())
+                .map(str::to_string),
+            efs: volume.efs_volume_configuration().map(|efs| EfsVolumeInfo {
+                file_system_id: efs.file_system_id().to_string(),
+                root_directory: efs.root_directory().map(str::to_string),
+                transit_encryption: efs
+                    .transit_encryption()
+                    .map(|encryption| encryption.as_str().to_string()),
+                access_point_id: efs
+                    .authorization_config()
+                    .and_then(|auth| auth.access_point_id())
+                    .map(str::to_string),
+                iam: efs
+                    .authorization_config()
+                    .and_then(|auth| auth.iam())
+                    .map(|iam| iam.as_str().to_string()),
+            }),
+        })
+        .collect();
+
+    TaskDefinitionInfo {
+        arn: task_definition
+            .task_definition_arn()
+            .unwrap_or_default()
+            .to_string(),
+        family: task_definition.family().unwrap_or_default().to_string(),
+        revision: task_definition.revision(),
+        status: task_definition
+            .status()
+            .map(|status| status.as_str().to_string())
+            .unwrap_or_else(|| "UNKNOWN".to_string()),
+        registered_at: task_definition.registered_at().map(|time| time.secs()),
+        registered_by: task_definition.registered_by().map(str::to_string),
+        compatibilities: task_definition
+            .compatibilities()
+            .iter()
+            .map(|compatibility| compatibility.as_str().to_string())
+            .collect(),
+        requires_compatibilities: task_definition
+            .requires_compatibilities()
+            .iter()
+            .map(|compatibility| compatibility.as_str().to_string())
+            .collect(),
+        task_role_arn: task_definition.task_role_arn().map(str::to_string),
+        execution_role_arn: task_definition.execution_role_arn().map(str::to_string),
+        network_mode: task_definition
+            .network_mode()
+            .map(|mode| mode.as_str().to_string()),
+        cpu: task_definition.cpu().map(str::to_string),
+        memory: task_definition.memory().map(str::to_string),
+        runtime_platform: task_definition
+            .runtime_platform()
+            .map(|platform| RuntimePlatformInfo {
+                cpu_architecture: platform
+                    .cpu_architecture()
+                    .map(|architecture| architecture.as_str().to_string()),
+                operating_system_family: platform
+                    .operating_system_family()
+                    .map(|family| family.as_str().to_string()),
+            }),
+        ephemeral_storage_gib: task_definition
+            .ephemeral_storage()
+            .map(|storage| storage.size_in_gib()),
+        containers,
+        volumes,
+    }
+}
+
+/// Returns `None` for an empty list, which the ECS API treats as unset.
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    (!values.is_empty()).then_some(values)
+}
+
+/// Builds the container definition of a revision about to be registered.
+///
+/// Settings the model covers are taken from `container`; every other setting (such
+/// as Linux parameters, ulimits or the FireLens configuration) is kept from the
+/// source revision's container of the same name, and the port range of a port
+/// mapping from the source mapping of the same container port.
+fn sdk_container_definition(
+    container: &ContainerDefinitionInfo,
+    source: Option<&ContainerDefinition>,
+) -> Result<ContainerDefinition> {
+    let secrets = |secrets: &[SecretReference]| {
+        secrets
+            .iter()
+            .map(|secret| {
+                Secret::builder()
+                    .name(&secret.name)
+                    .value_from(&secret.value_from)
+                    .build()
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    let mut definition = source
+        .cloned()
+        .unwrap_or_else(|| ContainerDefinition::builder().build());
+    definition.name = Some(container.name.clone());
+    definition.image = Some(container.image.clone());
+    definition.cpu = container.cpu;
+    definition.memory = container.memory;
+    definition.memory_reservation = container.memory_reservation;
+    definition.essential = container.essential;
+    definition.command = non_empty(container.command.clone());
+    definition.entry_point = non_empty(container.entry_point.clone());
+    definition.working_directory = container.working_directory.clone();
+    definition.user = container.user.clone();
+    definition.port_mappings = non_empty(
+        container
+            .port_mappings
+            .iter()
+            .map(|port| {
+                let mut mapping = source
+                    .and_then(|source| {
+                        source
+                            .port_mappings()
+                            .iter()
+                            .find(|mapping| mapping.container_port() == port.container_port)
+                    })
+                    .cloned()
+                    .unwrap_or_else(|| PortMapping::builder().build());
+                mapping.container_port = port.container_port;
+                mapping.host_port = port.host_port;
+                mapping.protocol = port.protocol.as_deref().map(TransportProtocol::from);
+                mapping.name = port.name.clone();
+                mapping.app_protocol = port.app_protocol.as_deref().map(ApplicationProtocol::from);
+                mapping
+            })
+            .collect(),
+    );
+    definition.environment = non_empty(
+        container
+            .environment
+            .iter()
+            .map(|variable| {
+                KeyValuePair::builder()
+                    .name(&variable.name)
+                    .value(&variable.value)
+                    .build()
+            })
+            .collect(),
+    );
+    definition.secrets = non_empty(secrets(&container.secrets)?);
+    definition.health_check = container
+        .health_check
+        .as_ref()
+        .map(|check| {
+            HealthCheck::builder()
+                .set_command(Some(check.command.clone()))
+                .set_interval(check.interval)
+                .set_timeout(check.timeout)
+                .set_retries(check.retries)
+                .set_start_period(check.start_period)
+                .build()
+        })
+        .transpose()?;
+    definition.mount_points = non_empty(
+        container
+            .mount_points
+            .iter()
+            .map(|mount| {
+                MountPoint::builder()
+                    .source_volume(&mount.source_volume)
+                    .container_path(&mount.container_path)
+                    .set_read_only(mount.read_only)
+                    .build()
+            })
+            .collect(),
+    );
+    definition.log_configuration = container
+        .log_configuration
+        .as_ref()
+        .map(|log_config| {
+            LogConfiguration::builder()
+                .log_driver(LogDriver::from(log_config.log_driver.as_str()))
+                .set_options((!log_config.options.is_empty()).then(|| {
+                    log_config
+                        .options
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone()))
+                        .collect()
+                }))
+                .set_secret_options(non_empty(secrets(&log_config.secret_options)?))
+                .build()
+                .map_err(anyhow::Error::from)
+        })
+        .transpose()?;
+    definition.depends_on = non_empty(
+        container
+            .depends_on
+            .iter()
+            .map(|dependency| {
+                ContainerDependency::builder()
+                    .container_name(&dependency.container_name)
+                    .condition(ContainerCondition::from(dependency.condition.as_str()))
+                    .build()
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    );
+    definition.readonly_root_filesystem = container.readonly_root_filesystem;
+    definition.docker_labels = (!container.docker_labels.is_empty()).then(|| {
+        container
+            .docker_labels
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    });
+    definition.stop_timeout = container.stop_timeout;
+    definition.start_timeout = container.start_timeout;
+    Ok(definition)
+}
+
+/// Builds a volume of a revision about to be registered.
+///
+/// Docker volume and FSx for Windows File Server settings, which the model doesn't
+/// cover, are kept from the source revision's volume of the same name.
+fn sdk_volume(volume: &VolumeInfo, source: Option<&Volume>) -> Result<Volume> {
+    let mut definition = source.cloned().unwrap_or_else(|| Volume::builder().build());
+    definition.name = Some(volume.name.clone());
+    definition.host = match &volume.host_source_path {
+        Some(path) => Some(HostVolumeProperties::builder().source_path(path).build()),
+        // A host volume without a source path is managed by Docker
+        None => definition.host.filter(|host| host.source_path().is_none()),
+    };
+    definition.efs_volume_configuration = volume
+        .efs
+        .as_ref()
+        .map(|efs| {
+            let authorization = (efs.access_point_id.is_some() || efs.iam.is_some()).then(|| {
+                EfsAuthorizationConfig::builder()
+                    .set_access_point_id(efs.access_point_id.clone())
+                    .set_iam(efs.iam.as_deref().map(EfsAuthorizationConfigIam::from))
+                    .build()
+            });
+            EfsVolumeConfiguration::builder()
+                .file_system_id(&efs.file_system_id)
+                .set_root_directory(efs.root_directory.clone())
+                .set_transit_encryption(
+                    efs.transit_encryption
+                        .as_deref()
+                        .map(EfsTransitEncryption::from),
+                )
+                .set_authorization_config(authorization)
+                .build()
+        })
+        .transpose()?;
+    Ok(definition)
+}
+
+/// Operations the app performs against AWS.
+///
+/// Implemented by [`EcsClient`] for real AWS accounts and by an in-memory fake in
+/// tests, so that navigation, actions and refresh logic can run without network
+/// access. See the `EcsClient` implementation for the details of each call.
+#[async_trait]
+pub trait EcsBackend: Send + Sync {
+    /// Lists cluster names in the configured region.
+    async fn list_clusters(&self) -> Result<Vec<String>>;
+
+    /// Describes clusters with task/service statistics, capacity providers, settings and tags.
+    async fn describe_clusters(&self, clusters: &[String]) -> Result<Vec<ClusterInfo>>;
+
+    /// Lists the services of a cluster.
+    async fn list_services(&self, cluster: &str) -> Result<Vec<ServiceInfo>>;
+
+    /// Lists the tasks of a service.
+    async fn list_tasks(&self, cluster: &str, service: &str) -> Result<Vec<TaskInfo>>;
+
+    /// Lists recently stopped tasks of a service with stop reasons and exit codes, newest first.
+    async fn list_stopped_tasks(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<Vec<StoppedTaskInfo>>;
+
+    /// Lists the EC2 container instances of a cluster with their capacity and agent status.
+    async fn list_container_instances(&self, cluster: &str) -> Result<Vec<ContainerInstanceInfo>>;
+
+    /// Lists the tasks placed on a container instance.
+    async fn list_container_instance_tasks(
+        &self,
+        cluster: &str,
+        container_instance_arn: &str,
+    ) -> Result<Vec<TaskInfo>>;
+
+    /// Sets a container instance to ACTIVE or DRAINING.
+    async fn update_container_instance_state(
+        &self,
+        cluster: &str,
+        container_instance_arn: &str,
+        status: &str,
+    ) -> Result<()>;
+
+    /// Describes a service, returning formatted text and a JSON representation.
+    async fn describe_service(&self, cluster: &str, service: &str) -> Result<(String, String)>;
+
+    /// Fetches the deployments, recent event stream and circuit breaker settings of a service.
+    async fn get_service_events(&self, cluster: &str, service: &str) -> Result<ServiceActivity>;
+
+    /// Describes a task, returning formatted text and a JSON representation.
+    async fn describe_task(&self, cluster: &str, task_arn: &str) -> Result<(String, String)>;
+
+    /// Forces a new deployment of a service.
+    async fn restart_service(&self, cluster: &str, service: &str) -> Result<()>;
+
+    /// Sets the desired task count of a service.
+    async fn update_service_desired_count(
+        &self,
+        cluster: &str,
+        service: &str,
+        desired_count: i32,
+    ) -> Result<()>;
+
+    /// Points a service at a different task definition revision.
+    async fn update_service_task_definition(
+        &self,
+        cluster: &str,
+        service: &str,
+        task_definition: &str,
+    ) -> Result<()>;
+
+    /// Fetches the scalable target, scaling policies and recent activities of a service.
+    async fn get_service_scaling(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<Option<ServiceScaling>>;
+
+    /// Sets the auto scaling capacity limits of a service and suspends or resumes scaling.
+    async fn update_service_scaling(
+        &self,
+        cluster: &str,
+        service: &str,
+        update: ScalingUpdate,
+    ) -> Result<()>;
+
+    /// Lists the revision ARNs of a task definition family, newest first.
+    async fn list_task_definition_revisions(&self, family: &str) -> Result<Vec<String>>;
+
+    /// Lists task definition family names.
+    async fn list_task_definition_families(&self) -> Result<Vec<String>>;
+
+    /// Describes a task definition revision.
+    async fn describe_task_definition(&self, task_definition: &str) -> Result<TaskDefinitionInfo>;
+
+    /// Describes a task definition revision and its tags as an API-shaped JSON document.
+    async fn describe_task_definition_document(
+        &self,
+        task_definition: &str,
+    ) -> Result<serde_json::Value>;
+
+    /// Describes a service as an API-shaped JSON document.
+    async fn describe_service_document(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<serde_json::Value>;
+
+    /// Registers a new task definition revision from an edited copy of an existing one.
+    async fn register_task_definition(
+        &self,
+        source: &str,
+        definition: &TaskDefinitionInfo,
+    ) -> Result<TaskDefinitionInfo>;
+
+    /// Lists the task definitions used by the services of every cluster, including
+    /// those of deployments in progress.
+    async fn list_task_definitions_in_use(&self) -> Result<HashSet<String>>;
+
+    /// Deregisters a task definition revision, marking it INACTIVE.
+    async fn deregister_task_definition(&self, task_definition: &str) -> Result<()>;
+
+    /// Deletes INACTIVE task definition revisions, returning those that could not be
+    /// deleted with the reason.
+    async fn delete_task_definitions(
+        &self,
+        task_definitions: &[String],
+    ) -> Result<Vec<(String, String)>>;
+
+    /// Stops a running task.
+    async fn stop_task(&self, cluster: &str, task_arn: &str) -> Result<()>;
+
+    /// Fetches a page of CloudWatch logs of all containers of a task.
+    async fn get_task_logs(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        request: &LogPageRequest,
+    ) -> Result<LogPage>;
+
+    /// Fetches the CloudWatch logs of all running and recently stopped tasks of a service
+    /// within a time window, reading at most `limit` log events.
+    async fn get_service_logs(
+        &self,
+        cluster: &str,
+        service: &str,
+        start_time: i64,
+        end_time: Option<i64>,
+        limit: usize,
+    ) -> Result<ServiceLogs>;
+
+    /// Lists the CloudWatch log groups the containers of a service's task definition log to.
+    async fn get_service_log_groups(&self, cluster: &str, service: &str) -> Result<Vec<String>>;
+
+    /// Lists the CloudWatch log groups the containers of a task log to.
+    async fn get_task_log_groups(&self, cluster: &str, task_arn: &str) -> Result<Vec<String>>;
+
+    /// Starts a CloudWatch Logs Insights query over a time window, returning its query ID.
+    async fn start_insights_query(
+        &self,
+        log_groups: &[String],
+        query: &str,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<String>;
+
+    /// Fetches the status and the results so far of a Logs Insights query.
+    async fn get_insights_query_results(&self, query_id: &str) -> Result<InsightsQueryResults>;
+
+    /// Stops a running Logs Insights query.
+    async fn stop_insights_query(&self, query_id: &str) -> Result<()>;
+
+    /// Fetches the CloudWatch alarms monitoring a service.
+    async fn get_service_alarms(
+        &self,
+        cluster_name: &str,
+        service_name: &str,
+    ) -> Result<Vec<CloudWatchAlarm>>;
+
+    /// Fetches CPU and memory metrics and alarms of a service.
+    async fn get_service_metrics(
+        &self,
+        cluster_name: &str,
+        service_name: &str,
+        time_range: TimeRange,
+    ) -> Result<Metrics>;
+
+    /// Fetches Container Insights CPU, memory, network and storage metrics of a task and its containers.
+    async fn get_task_metrics(
+        &self,
+        cluster_name: &str,
+        task_arn: &str,
+        time_range: TimeRange,
+    ) -> Result<TaskMetrics>;
+
+    /// Checks whether ECS Exec is enabled on a task.
+    async fn check_task_exec_enabled(&self, cluster: &str, task_arn: &str) -> Result<bool>;
+
+    /// Starts an ECS ExecuteCommand session on a task container.
+    async fn execute_command(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        container_name: Option<String>,
+        command: &str,
+    ) -> Result<Session>;
+
+    /// Runs an interactive ECS Exec shell until the session ends.
+    async fn start_exec_session(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        container_name: Option<String>,
+        region: &str,
+    ) -> Result<()>;
+
+    /// Runs an SSM port forwarding session until it ends.
+    async fn start_port_forwarding(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        local_port: u16,
+        remote_port: u16,
+        region: &str,
+    ) -> Result<()>;
+}
+
+impl EcsClient {
+    /// Creates a new ECS client with optional region and profile configuration.
+    ///
+    /// # Arguments
+    /// * `region` - Optional AWS region override (e.g., "us-east-1")
+    /// * `profile` - Optional AWS profile name from ~/.aws/credentials
+    ///
+    /// # Returns
+    /// Returns a new `EcsClient` instance configured with the specified options,
+    /// or an error if AWS SDK initialization fails.
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - AWS credentials cannot be resolved
+    /// - The specified profile doesn't exist
+    /// - The specified region is invalid
+    pub async fn new(region: Option<String>, profile: Option<String>) -> Result<Self> {
+        let mut config_loader = aws_config::from_env();
+
+        // Set region if provided
+        if let Some(region_str) = region {
+            config_loader = config_loader.region(aws_config::Region::new(region_str));
+        }
+
+        // Set profile if provided
+        if let Some(profile_name) = profile {
+            config_loader = config_loader.profile_name(profile_name);
+        }
+
+        let config = config_loader.load().await;
+        let client = Client::new(&config);
+        let logs_client = LogsClient::new(&config);
+        let metrics_client = CloudWatchClient::new(&config);
+        let ssm_client = SsmClient::new(&config);
+        let autoscaling_client = AutoScalingClient::new(&config);
+        Ok(Self {
+            client,
+            logs_client,
+            metrics_client,
+            ssm_client,
+            autoscaling_client,
+        })
+    }
+
+    /// Fetches a page of log events from a specific CloudWatch Logs stream.
+    ///
+    /// Reads up to `LOG_PAGE_SIZE` events: the most recent ones, the first ones at or
+    /// after a point in time, or the ones before the cursor's `nextBackwardToken`.
+    /// Newer events are read from the cursor's `nextForwardToken` until the end of the
+    /// stream, at most `MAX_TAIL_PAGES` pages at a time.
+    /// This is a helper method used by `get_task_logs`.
+    ///
+    /// # Arguments
+    /// * `cursor` - The log stream and position to read from
+    /// * `request` - Which page to read
+    ///
+    /// # Returns
+    /// The `LogEntry` structs of the page and the cursor to read the pages around it
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS GetLogEvents API call fails
+    /// - The log group or stream doesn't exist
+    /// - Insufficient permissions to read logs
+    async fn fetch_logs_from_stream(
+        &self,
+        cursor: &LogStreamCursor,
+        request: &LogPageRequest,
+    ) -> Result<(Vec<LogEntry>, LogStreamCursor)> {
+        let mut next = cursor.clone();
+        let query = self
+            .logs_client
+            .get_log_events()
+            .log_group_name(&cursor.log_group)
+            .log_stream_name(&cursor.log_stream)
+            .limit(LOG_PAGE_SIZE);
+
+        if let LogPageRequest::Newer(_) = request {
+            let mut logs = Vec::new();
+            for _ in 0..MAX_TAIL_PAGES {
+                let resp = query
+                    .clone()
+                    .set_next_token(next.forward_token.clone())
+                    .start_from_head(true)
+                    .send()
+                    .await
+                    .map_err(|e| log_events_error(e, cursor))?;
+                logs.extend(Self::log_entries(resp.events(), &cursor.container_name));
+
+                // GetLogEvents returns the token it was given at the end of the stream
+                let token = resp.next_forward_token().map(str::to_string);
+                let at_end =
+                    resp.events().is_empty() || token.is_none() || token == next.forward_token;
+                next.forward_token = token.or(next.forward_token);
+                if at_end {
+                    break;
+                }
+            }
+            return Ok((logs, next));
+        }
+
+        let query = match request {
+            LogPageRequest::At(start_time) => {
+                next.end_time = Some(*start_time);
+                next.backward_token = None;
+                query.start_time(*start_time).start_from_head(true)
+            }
+            LogPageRequest::Older(_) => query
+                .set_end_time(cursor.end_time)
+                .set_next_token(cursor.backward_token.clone())
+                .start_from_head(false),
+            _ => {
+                next.end_time = None;
+                query.start_from_head(false)
+            }
+        };
+
+        let resp = query
+            .send()
+            .await
+            .map_err(|e| log_events_error(e, cursor))?;
+        let logs = Self::log_entries(resp.events(), &cursor.container_name);
+
+        let token = resp.next_backward_token().map(str::to_string);
+        match request {
+            LogPageRequest::Older(_) => {
+                // GetLogEvents returns the token it was given once the stream is exhausted
+                next.exhausted = logs.is_empty() || token == cursor.backward_token;
+                next.backward_token = token;
+            }
+            // Tokens are tied to the time window, so paging back from a point in
+            // time starts with a fresh backward read ending at that time
+            LogPageRequest::At(_) => {
+                next.forward_token = resp.next_forward_token().map(str::to_string);
+            }
+            _ => {
+                next.backward_token = token;
+                next.forward_token = resp.next_forward_token().map(str::to_string);
+            }
+        }
+
+        Ok((logs, next))
+    }
+
+    /// Converts CloudWatch Logs events of a container into log entries.
+    fn log_entries(events: &[OutputLogEvent], container_name: &str) -> Vec<LogEntry> {
+        events
+            .iter()
+            .filter_map(|event| match (event.timestamp(), event.message()) {
+                (Some(timestamp), Some(message)) => Some(LogEntry::new(
+                    timestamp,
+                    message.to_string(),
+                    container_name.to_string(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Finds the CloudWatch Logs streams of the containers of a task.
+    ///
+    /// Containers using the `awslogs` log driver, or a FireLens log router with a
+    /// CloudWatch output, have a stream whose name is resolved from the task.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `task_arn` - The full task ARN
+    ///
+    /// # Returns
+    /// A cursor at the end of each container log stream, and the containers whose
+    /// logs cannot be read with the reason why
+    ///
+    /// # Errors
+    /// This function will return an error if the AWS DescribeTasks or
+    /// DescribeTaskDefinition API calls fail.
+    async fn task_log_streams(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+    ) -> Result<(Vec<LogStreamCursor>, Vec<UnavailableLogs>)> {
+        // First, describe the task to get the task definition and container details
+        let task_resp = self
+            .client
+            .describe_tasks()
+            .cluster(cluster)
+            .tasks(task_arn)
+            .send()
+            .await?;
+
+        let Some(task_def_arn) = task_resp
+            .tasks()
+            .first()
+            .and_then(|task| task.task_definition_arn())
+        else {
+            return Ok((Vec::new(), Vec::new()));
+        };
+
+        // Extract task ID from ARN for log stream name
+        let task_id = task_arn.split('/').next_back().unwrap_or(task_arn);
+        let context = LogTemplateContext {
+            cluster,
+            task_id: Some(task_id),
+            task_arn: Some(task_arn),
+        };
+
+        let mut cursors = Vec::new();
+        let mut unavailable = Vec::new();
+        for source in self.container_log_sources(task_def_arn).await? {
+            let container = match source {
+                ContainerLogSource::CloudWatch(container) => container,
+                ContainerLogSource::Unavailable(logs) => {
+                    unavailable.push(logs);
+                    continue;
+                }
+            };
+            match (container.log_group(context), container.stream_name(context)) {
+                (Some(log_group), Some(log_stream)) => cursors.push(LogStreamCursor {
+                    container_name: container.name,
+                    log_group,
+                    log_stream,
+                    end_time: None,
+                    backward_token: None,
+                    forward_token: None,
+                    exhausted: false,
+                }),
+                _ => unavailable.push(UnavailableLogs {
+                    reason: format!(
+                        "log group '{}' or stream '{}' uses variables only Fluent Bit can resolve",
+                        container.log_group,
+                        container.stream_templates.join("' / '")
+                    ),
+                    container_name: container.name,
+                }),
+            }
+        }
+        Ok((cursors, unavailable))
+    }
+
+    /// Works out where the containers of a task definition send their logs.
+    ///
+    /// # Arguments
+    /// * `task_definition` - The task definition ARN or `family:revision`
+    ///
+    /// # Returns
+    /// One `ContainerLogSource` per container: the log group and stream templates of
+    /// containers logging to CloudWatch Logs, or why the logs of the others cannot be read
+    ///
+    /// # Errors
+    /// This function will return an error if the AWS DescribeTaskDefinition API call fails.
+    async fn container_log_sources(
+        &self,
+        task_definition: &str,
+    ) -> Result<Vec<ContainerLogSource>> {
+        let task_def_resp = self
+            .client
+            .describe_task_definition()
+            .task_definition(task_definition)
+            .send()
+            .await?;
+
+        let region = self
+            .logs_client
+            .config()
+            .region()
+            .map(|region| region.as_ref());
+        Ok(task_def_resp
+            .task_definition()
+            .map(|task_definition| {
+                task_definition
+                    .container_definitions()
+                    .iter()
+                    .map(|container| ContainerLogSource::from_container(container, region))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Returns the containers of a task definition that log to CloudWatch Logs.
+    ///
+    /// # Errors
+    /// This function will return an error if the AWS DescribeTaskDefinition API call fails.
+    async fn cloudwatch_logs_containers(
+        &self,
+        task_definition: &str,
+    ) -> Result<Vec<CloudWatchLogsContainer>> {
+        Ok(self
+            .container_log_sources(task_definition)
+            .await?
+            .into_iter()
+            .filter_map(|source| match source {
+                ContainerLogSource::CloudWatch(container) => Some(container),
+                ContainerLogSource::Unavailable(_) => None,
+            })
+            .collect())
+    }
+
+    /// Returns the task definition ARN a service runs.
+    ///
+    /// # Errors
+    /// This function will return an error if the AWS DescribeServices API call fails
+    /// or the service doesn't exist.
+    async fn service_task_definition(&self, cluster: &str, service: &str) -> Result<String> {
+        let resp = self
+            .client
+            .describe_services()
+            .cluster(cluster)
+            .services(service)
+            .send()
+            .await?;
+        resp.services()
+            .first()
+            .and_then(|svc| svc.task_definition())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("Service not found: {service}"))
+    }
+
+    /// Lists the IDs of the running and recently stopped tasks of a service.
+    ///
+    /// ECS keeps stopped tasks listed for about an hour after they stop.
+    ///
+    /// # Errors
+    /// This function will return an error if the AWS ListTasks API call fails.
+    async fn service_task_ids(&self, cluster: &str, service: &str) -> Result<HashSet<String>> {
+        let mut task_ids = HashSet::new();
+        for status in [DesiredStatus::Running, DesiredStatus::Stopped] {
+            let mut next_token: Option<String> = None;
+            loop {
+                let resp = self
+                    .client
+                    .list_tasks()
+                    .cluster(cluster)
+                    .service_name(service)
+                    .desired_status(status.clone())
+                    .set_next_token(next_token)
+                    .send()
+                    .await?;
+                task_ids.extend(
+                    resp.task_arns()
+                        .iter()
+                        .filter_map(|arn| arn.split('/').next_back().map(str::to_string)),
+                );
+                next_token = resp.next_token().map(String::from);
+                if next_token.is_none() {
+                    break;
+                }
+            }
+        }
+        Ok(task_ids)
+    }
+
+    /// Describes tasks and maps them to `TaskInfo` structs.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `task_arns` - ARNs of the tasks to describe (at most 100)
+    ///
+    /// # Errors
+    /// Returns an error if the AWS DescribeTasks API call fails
+    async fn describe_task_infos(
+        &self,
+        cluster: &str,
+        task_arns: &[String],
+    ) -> Result<Vec<TaskInfo>> {
+        // Describe tasks to get detailed info
+        let describe_resp = self
+            .client
+            .describe_tasks()
+            .cluster(cluster)
+            .set_tasks(Some(task_arns.to_vec()))
+            .send()
+            .await?;
+
+        let tasks = describe_resp
+            .tasks()
+            .iter()
+            .map(|t| {
+                let task_arn = t.task_arn().unwrap_or("unknown").to_string();
+                let task_id = task_arn
+                    .split('/')
+                    .next_back()
+                    .unwrap_or("unknown")
+                    .to_string();
+                let status = t.last_status().unwrap_or("unknown").to_string();
+                let desired_status = t.desired_status().unwrap_or("unknown").to_string();
+                let container_instance = t
+                    .container_instance_arn()
+                    .and_then(|ci| ci.split('/').next_back())
+                    .unwrap_or("none")
+                    .to_string();
+                let cpu = t.cpu().unwrap_or("unknown").to_string();
+                let memory = t.memory().unwrap_or("unknown").to_string();
+
+                TaskInfo {
+                    task_arn,
+                    task_id,
+                    status,
+                    desired_status,
+                    container_instance,
+                    cpu,
+                    memory,
+                }
+            })
+            .collect();
+
+        Ok(tasks)
+    }
+
+    /// Helper function to create a CloudWatch Dimension with required name and value.
+    ///
+    /// Both name and value are required by the CloudWatch API, even though the SDK
+    /// allows them to be optional. This helper ensures they are always set.
+    fn create_dimension(name: &str, value: &str) -> aws_sdk_cloudwatch::types::Dimension {
+        aws_sdk_cloudwatch::types::Dimension::builder()
+            .name(name)
+            .value(value)
+            .build()
+    }
+
+    /// Describes a scaling policy in one line.
+    ///
+    /// Target tracking policies show their metric and target value; step scaling
+    /// policies show their adjustment type and steps, e.g. "ChangeInCapacity: [0, 10) +1".
+    fn scaling_policy_summary(
+        policy: &aws_sdk_applicationautoscaling::types::ScalingPolicy,
+    ) -> String {
+        if let Some(config) = policy.target_tracking_scaling_policy_configuration() {
+            let metric = match (
+                config.predefined_metric_specification(),
+                config.customized_metric_specification(),
+            ) {
+                (Some(predefined), _) => predefined.predefined_metric_type().as_str().to_string(),
+                (None, Some(custom)) => format!(
+                    "{}/{}",
+                    custom.namespace().unwrap_or("custom"),
+                    custom.metric_name().unwrap_or("metric")
+                ),
+                (None, None) => "unknown metric".to_string(),
+            };
+            let mut summary = format!("{metric} target {}", config.target_value());
+            if config.disable_scale_in() == Some(true) {
+                summary.push_str(", scale-in disabled");
+            }
+            return summary;
+        }
+
+        if let Some(config) = policy.step_scaling_policy_configuration() {
+            let bound = |value: Option<f64>, unbounded: &str| {
+                value.map_or(unbounded.to_string(), |v| v.to_string())
+            };
+            let steps: Vec<String> = config
+                .step_adjustments()
+                .iter()
+                .map(|step| {
+                    format!(
+                        "[{}, {}) {:+}",
+                        bound(step.metric_interval_lower_bound(), "-∞"),
+                        bound(step.metric_interval_upper_bound(), "∞"),
+                        step.scaling_adjustment()
+                    )
+                })
+                .collect();
+            return format!(
+                "{}: {}",
+                config
+                    .adjustment_type()
+                    .map(|t| t.as_str())
+                    .unwrap_or("ChangeInCapacity"),
+                steps.join(", ")
+            );
+        }
+
+        policy.policy_type().as_str().to_string()
+    }
+}
+
+#[async_trait]
+impl EcsBackend for EcsClient {
+    /// Lists all ECS clusters in the configured region.
+    ///
+    /// Returns cluster names extracted from the full ARNs. If no clusters exist,
+    /// returns an empty vector.
+    ///
+    /// # Returns
+    /// A vector of cluster names (not full ARNs)
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS API call fails due to network or permission issues
+    /// - The response cannot be parsed
+    async fn list_clusters(&self) -> Result<Vec<String>> {
+        let resp = self.client.list_clusters().send().await?;
+
+        let cluster_arns = resp.cluster_arns();
+        let clusters = cluster_arns
+            .iter()
+            .map(|arn| {
+                // Extract cluster name from ARN
+                arn.split('/').next_back().unwrap_or(arn).to_string()
+            })
+            .collect();
+
+        Ok(clusters)
+    }
+
+    /// Describes clusters with their statistics and configuration.
+    ///
+    /// Requests the SETTINGS and TAGS fields in addition to the counts DescribeClusters
+    /// always returns, so the result includes the Container Insights setting and tags.
+    ///
+    /// # Arguments
+    /// * `clusters` - Cluster names or ARNs
+    ///
+    /// # Returns
+    /// A vector of `ClusterInfo` structs in the order AWS returns them. Clusters that
+    /// could not be described are omitted.
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeClusters API call fails
+    /// - Insufficient permissions to describe clusters
+    async fn describe_clusters(&self, clusters: &[String]) -> Result<Vec<ClusterInfo>> {
+        let mut infos = Vec::new();
+        // DescribeClusters accepts at most 100 clusters per call, and describes the
+        // `default` cluster when given none
+        for chunk in clusters.chunks(100) {
+            let resp = self
+                .client
+                .describe_clusters()
+                .set_clusters(Some(chunk.to_vec()))
+                .include(aws_sdk_ecs::types::ClusterField::Settings)
+                .include(aws_sdk_ecs::types::ClusterField::Tags)
+                .send()
+                .await?;
+
+            for cluster in resp.clusters() {
+                let container_insights = cluster
+                    .settings()
+                    .iter()
+                    .find(|setting| {
+                        setting.name()
+                            == Some(&aws_sdk_ecs::types::ClusterSettingName::ContainerInsights)
+                    })
+                    .and_then(|setting| setting.value())
+                    .unwrap_or_default()
+                    .to_string();
+
+                infos.push(ClusterInfo {
+                    name: cluster.cluster_name().unwrap_or("unknown").to_string(),
+                    status: cluster.status().unwrap_or("unknown").to_string(),
+                    active_services: cluster.active_services_count(),
+                    running_tasks: cluster.running_tasks_count(),
+                    pending_tasks: cluster.pending_tasks_count(),
+                    registered_container_instances: cluster.registered_container_instances_count(),
+                    capacity_providers: cluster.capacity_providers().to_vec(),
+                    default_strategy: cluster
+                        .default_capacity_provider_strategy()
+                        .iter()
+                        .map(|item| CapacityProviderStrategyItem {
+                            capacity_provider: item.capacity_provider().to_string(),
+                            weight: item.weight(),
+                            base: item.base(),
+                        })
+                        .collect(),
+                    container_insights,
+                    tags: cluster
+                        .tags()
+                        .iter()
+                        .map(|tag| {
+                            (
+                                tag.key().unwrap_or_default().to_string(),
+                                tag.value().unwrap_or_default().to_string(),
+                            )
+                        })
+                        .collect(),
+                });
+            }
+        }
+
+        Ok(infos)
+    }
+
+    /// Lists all services in a specific ECS cluster.
+    ///
+    /// First retrieves service ARNs, then fetches detailed information for each service
+    /// including status, task counts, and launch type.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    ///
+    /// # Returns
+    /// A vector of `ServiceInfo` structs containing service details, or an empty vector
+    /// if no services exist in the cluster
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS ListServices or DescribeServices API calls fail
+    /// - The cluster doesn't exist
+    /// - Insufficient permissions to access the cluster or services
+    async fn list_services(&self, cluster: &str) -> Result<Vec<ServiceInfo>> {
+        let resp = self.client.list_services().cluster(cluster).send().await?;
+
+        let service_arns = resp.service_arns();
+
+        if service_arns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Describe services to get detailed info
+        let describe_resp = self
+            .client
+            .describe_services()
+            .cluster(cluster)
+            .set_services(Some(service_arns.to_vec()))
+            .send()
+            .await?;
+
+        let services = describe_resp
+            .services()
+            .iter()
+            .map(|s| {
+                let name = s.service_name().unwrap_or("unknown").to_string();
+                let status = s.status().unwrap_or("unknown").to_string();
+                let desired_count = s.desired_count();
+                let running_count = s.running_count();
+                let pending_count = s.pending_count();
+                let launch_type = s
+                    .launch_type()
+                    .map(|lt| lt.as_str().to_string())
+                    .unwrap_or_else(|| "unknown".to_string());
+
+                ServiceInfo {
+                    name,
+                    status,
+                    desired_count,
+                    running_count,
+                    pending_count,
+                    launch_type,
+                }
+            })
+            .collect();
+
+        Ok(services)
+    }
+
+    /// Lists all tasks for a specific service in a cluster.
+    ///
+    /// Retrieves task ARNs for the service, then fetches detailed information including
+    /// status, resource allocation, and container instance details.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name or ARN
+    ///
+    /// # Returns
+    /// A vector of `TaskInfo` structs containing task details, or an empty vector
+    /// if no tasks are running for the service
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS ListTasks or DescribeTasks API calls fail
+    /// - The cluster or service doesn't exist
+    /// - Insufficient permissions to access tasks
+    async fn list_tasks(&self, cluster: &str, service: &str) -> Result<Vec<TaskInfo>> {
+        let resp = self
+            .client
+            .list_tasks()
+            .cluster(cluster)
+            .service_name(service)
+            .send()
+            .await?;
+
+        let task_arns = resp.task_arns();
+
+        if task_arns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.describe_task_infos(cluster, task_arns).await
+    }
+
+    /// Lists recently stopped tasks of a service.
+    ///
+    /// Queries tasks with desired status STOPPED (ECS keeps them for about an hour),
+    /// then describes them to get stop reasons, stop codes, timestamps, and the
+    /// exit code and reason of every container.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name or ARN
+    ///
+    /// # Returns
+    /// A vector of `StoppedTaskInfo` structs, most recently stopped first
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS ListTasks or DescribeTasks API calls fail
+    /// - The cluster or service doesn't exist
+    /// - Insufficient permissions to access tasks
+    async fn list_stopped_tasks(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<Vec<StoppedTaskInfo>> {
+        let resp = self
+            .client
+            .list_tasks()
+            .cluster(cluster)
+            .service_name(service)
+            .desired_status(DesiredStatus::Stopped)
+            .send()
+            .await?;
+
+        let task_arns = resp.task_arns();
+
+        if task_arns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let describe_resp = self
+            .client
+            .describe_tasks()
+            .cluster(cluster)
+            .set_tasks(Some(task_arns.to_vec()))
+            .send()
+            .await?;
+
+        let mut tasks: Vec<StoppedTaskInfo> = describe_resp
+            .tasks()
+            .iter()
+            .map(|t| {
+                let task_arn = t.task_arn().unwrap_or("unknown").to_string();
                 let task_id = task_arn
                     .split('/')
                     .next_back()
                     .unwrap_or("unknown")
                     .to_string();
-                let status = t.last_status().unwrap_or("unknown").to_string();
-                let desired_status = t.desired_status().unwrap_or("unknown").to_string();
-                let container_instance = t
-                    .container_instance_arn()
-                    .and_then(|ci| ci.split('/').next_back())
-                    .unwrap_or("none")
-                    .to_string();
-                let cpu = t.cpu().unwrap_or("unknown").to_string();
-                let memory = t.memory().unwrap_or("unknown").to_string();
+                let containers = t
+                    .containers()
+                    .iter()
+                    .map(|c| ContainerExit {
+                        name: c.name().unwrap_or("unknown").to_string(),
+                        exit_code: c.exit_code(),
+                        reason: c.reason().unwrap_or("").to_string(),
+                    })
+                    .collect();
+
+                StoppedTaskInfo {
+                    task_arn,
+                    task_id,
+                    last_status: t.last_status().unwrap_or("unknown").to_string(),
+                    stopped_reason: t.stopped_reason().unwrap_or("").to_string(),
+                    stop_code: t
+                        .stop_code()
+                        .map(|code| code.as_str().to_string())
+                        .unwrap_or_default(),
+                    started_at: t.started_at().map(|d| d.secs()),
+                    stopping_at: t.stopping_at().map(|d| d.secs()),
+                    stopped_at: t.stopped_at().map(|d| d.secs()),
+                    containers,
+                }
+            })
+            .collect();
+
+        tasks.sort_by_key(|t| std::cmp::Reverse(t.stopped_at));
+
+        Ok(tasks)
+    }
+
+    /// Lists the EC2 container instances registered to a cluster.
+    ///
+    /// Describes every instance to get its EC2 instance ID, status, agent connection
+    /// and version, registered and remaining CPU/memory, and task counts.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    ///
+    /// # Returns
+    /// A vector of `ContainerInstanceInfo` structs, or an empty vector for clusters
+    /// without EC2 capacity (e.g., Fargate-only clusters)
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS ListContainerInstances or DescribeContainerInstances API calls fail
+    /// - The cluster doesn't exist
+    /// - Insufficient permissions to access container instances
+    async fn list_container_instances(&self, cluster: &str) -> Result<Vec<ContainerInstanceInfo>> {
+        let mut arns = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let resp = self
+                .client
+                .list_container_instances()
+                .cluster(cluster)
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            arns.extend(resp.container_instance_arns().iter().cloned());
+            next_token = resp.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        let mut instances = Vec::new();
+        // DescribeContainerInstances accepts at most 100 instances per call
+        for chunk in arns.chunks(100) {
+            let resp = self
+                .client
+                .describe_container_instances()
+                .cluster(cluster)
+                .set_container_instances(Some(chunk.to_vec()))
+                .send()
+                .await?;
+
+            for ci in resp.container_instances() {
+                let resource = |resources: &[aws_sdk_ecs::types::Resource], name: &str| {
+                    resources
+                        .iter()
+                        .find(|r| r.name() == Some(name))
+                        .map(|r| r.integer_value())
+                        .unwrap_or(0)
+                };
+
+                instances.push(ContainerInstanceInfo {
+                    arn: ci.container_instance_arn().unwrap_or("unknown").to_string(),
+                    ec2_instance_id: ci.ec2_instance_id().unwrap_or("unknown").to_string(),
+                    status: ci.status().unwrap_or("unknown").to_string(),
+                    agent_connected: ci.agent_connected(),
+                    agent_version: ci
+                        .version_info()
+                        .and_then(|v| v.agent_version())
+                        .unwrap_or("unknown")
+                        .to_string(),
+                    registered_cpu: resource(ci.registered_resources(), "CPU"),
+                    remaining_cpu: resource(ci.remaining_resources(), "CPU"),
+                    registered_memory: resource(ci.registered_resources(), "MEMORY"),
+                    remaining_memory: resource(ci.remaining_resources(), "MEMORY"),
+                    running_tasks: ci.running_tasks_count(),
+                    pending_tasks: ci.pending_tasks_count(),
+                });
+            }
+        }
+
+        Ok(instances)
+    }
+
+    /// Lists the tasks placed on a container instance.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `container_instance_arn` - The container instance ARN or ID
+    ///
+    /// # Errors
+    /// This function will return an error if the AWS ListTasks or DescribeTasks API calls fail
+    async fn list_container_instance_tasks(
+        &self,
+        cluster: &str,
+        container_instance_arn: &str,
+    ) -> Result<Vec<TaskInfo>> {
+        let resp = self
+            .client
+            .list_tasks()
+            .cluster(cluster)
+            .container_instance(container_instance_arn)
+            .send()
+            .await?;
+
+        let task_arns = resp.task_arns();
+
+        if task_arns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.describe_task_infos(cluster, task_arns).await
+    }
+
+    /// Sets the status of a container instance.
+    ///
+    /// Setting an instance to DRAINING stops new tasks from being placed on it and
+    /// lets services replace its tasks elsewhere; ACTIVE returns it to service.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `container_instance_arn` - The container instance ARN or ID
+    /// * `status` - The new status, "ACTIVE" or "DRAINING"
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS UpdateContainerInstancesState API call fails
+    /// - ECS reports a failure for the instance
+    async fn update_container_instance_state(
+        &self,
+        cluster: &str,
+        container_instance_arn: &str,
+        status: &str,
+    ) -> Result<()> {
+        let resp = self
+            .client
+            .update_container_instances_state()
+            .cluster(cluster)
+            .container_instances(container_instance_arn)
+            .status(aws_sdk_ecs::types::ContainerInstanceStatus::from(status))
+            .send()
+            .await?;
+
+        if let Some(failure) = resp.failures().first() {
+            anyhow::bail!(
+                "Failed to update container instance: {}",
+                failure.reason().unwrap_or("unknown reason")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves detailed information about a specific service.
+    ///
+    /// Fetches comprehensive service details including ARN, status, task counts,
+    /// launch type, and task definition ARN, formatting them as human-readable text.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name or ARN
+    ///
+    /// # Returns
+    /// A formatted string containing service details for display in the UI
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeServices API call fails
+    /// - The service doesn't exist in the specified cluster
+    /// - Insufficient permissions to describe the service
+    async fn describe_service(&self, cluster: &str, service: &str) -> Result<(String, String)> {
+        let resp = self
+            .client
+            .describe_services()
+            .cluster(cluster)
+            .services(service)
+            .send()
+            .await?;
+
+        let mut output = String::new();
+        output.push_str(&format!("Cluster: {cluster}\n\n"));
+
+        let mut json_documents = Vec::new();
+
+        for svc in resp.services() {
+            // Formatted text view
+            output.push_str(&format!(
+                "Service Name: {}\n",
+                svc.service_name().unwrap_or("N/A")
+            ));
+            output.push_str(&format!(
+                "Service ARN: {}\n",
+                svc.service_arn().unwrap_or("N/A")
+            ));
+            output.push_str(&format!("Status: {}\n", svc.status().unwrap_or("N/A")));
+            output.push_str(&format!("Desired Count: {}\n", svc.desired_count()));
+            output.push_str(&format!("Running Count: {}\n", svc.running_count()));
+            output.push_str(&format!("Pending Count: {}\n", svc.pending_count()));
+            output.push_str(&format!(
+                "Launch Type: {}\n",
+                svc.launch_type().map(|lt| lt.as_str()).unwrap_or("N/A")
+            ));
+            output.push_str(&format!(
+                "Task Definition: {}\n",
+                svc.task_definition().unwrap_or("N/A")
+            ));
+
+            // Add deployment info
+            let deployments = svc.deployments();
+            if !deployments.is_empty() {
+                output.push_str("\nDeployments:\n");
+                for deployment in deployments {
+                    output.push_str(&format!(
+                        "  - Status: {}\n",
+                        deployment.status().unwrap_or("N/A")
+                    ));
+                    output.push_str(&format!(
+                        "    Running Count: {}\n",
+                        deployment.running_count()
+                    ));
+                    output.push_str(&format!(
+                        "    Desired Count: {}\n",
+                        deployment.desired_count()
+                    ));
+                }
+            }
+
+            // Add network configuration
+            if let Some(network_config) = svc.network_configuration() {
+                output.push_str("\nNetwork Configuration:\n");
+                if let Some(awsvpc) = network_config.awsvpc_configuration() {
+                    output.push_str(&format!("  Subnets: {}\n", awsvpc.subnets().join(", ")));
+                    output.push_str(&format!(
+                        "  Assign Public IP: {}\n",
+                        awsvpc
+                            .assign_public_ip()
+                            .map(|ip| ip.as_str())
+                            .unwrap_or("N/A")
+                    ));
+                    let sgs = awsvpc.security_groups();
+                    if !sgs.is_empty() {
+                        output.push_str(&format!("  Security Groups: {}\n", sgs.join(", ")));
+                    }
+                }
+            }
+
+            output.push('\n');
+
+            json_documents.push(ecs_json::service_document(svc));
+        }
+
+        let json_output = serde_json::to_string_pretty(&json_documents)?;
+        Ok((output, json_output))
+    }
+
+    /// Fetches the deployments, recent event stream and circuit breaker settings of a service.
+    ///
+    /// ECS keeps the last 100 service events, which are returned newest first.
+    /// Deployments are returned in API order, which lists the PRIMARY
+    /// deployment first.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name or ARN
+    ///
+    /// # Returns
+    /// A `ServiceActivity` snapshot of the service
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeServices API call fails
+    /// - The service doesn't exist
+    async fn get_service_events(&self, cluster: &str, service: &str) -> Result<ServiceActivity> {
+        let resp = self
+            .client
+            .describe_services()
+            .cluster(cluster)
+            .services(service)
+            .send()
+            .await?;
+
+        let svc = resp
+            .services()
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("Service not found: {service}"))?;
+
+        let deployments = svc
+            .deployments()
+            .iter()
+            .map(|deployment| DeploymentInfo {
+                id: deployment.id().unwrap_or("N/A").to_string(),
+                status: deployment.status().unwrap_or("N/A").to_string(),
+                rollout_state: deployment
+                    .rollout_state()
+                    .map(|state| state.as_str().to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+                rollout_state_reason: deployment
+                    .rollout_state_reason()
+                    .unwrap_or_default()
+                    .to_string(),
+                task_definition: deployment.task_definition().unwrap_or("N/A").to_string(),
+                desired_count: deployment.desired_count(),
+                running_count: deployment.running_count(),
+                pending_count: deployment.pending_count(),
+                failed_tasks: deployment.failed_tasks(),
+                updated_at: deployment.updated_at().map(|t| t.secs()).unwrap_or(0),
+            })
+            .collect();
+
+        let events = svc
+            .events()
+            .iter()
+            .map(|event| ServiceEvent {
+                created_at: event.created_at().map(|t| t.secs()).unwrap_or(0),
+                message: event.message().unwrap_or_default().to_string(),
+            })
+            .collect();
+
+        let circuit_breaker = svc
+            .deployment_configuration()
+            .and_then(|config| config.deployment_circuit_breaker());
+
+        Ok(ServiceActivity {
+            deployments,
+            events,
+            circuit_breaker_enabled: circuit_breaker.is_some_and(|cb| cb.enable()),
+            circuit_breaker_rollback: circuit_breaker.is_some_and(|cb| cb.rollback()),
+        })
+    }
+
+    /// Retrieves detailed information about a specific task.
+    ///
+    /// Fetches comprehensive task details including ARN, task definition, status,
+    /// resource allocation, launch type, and container information, formatting them
+    /// as human-readable text.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `task_arn` - The full task ARN
+    ///
+    /// # Returns
+    /// A formatted string containing task and container details for display in the UI
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeTasks API call fails
+    /// - The task doesn't exist in the specified cluster
+    /// - Insufficient permissions to describe the task
+    async fn describe_task(&self, cluster: &str, task_arn: &str) -> Result<(String, String)> {
+        let resp = self
+            .client
+            .describe_tasks()
+            .cluster(cluster)
+            .tasks(task_arn)
+            .send()
+            .await?;
+
+        // Format the response manually since AWS types don't implement Serialize
+        let mut output = String::new();
+        output.push_str(&format!("Cluster: {cluster}\n\n"));
+
+        let mut json_parts = Vec::new();
+
+        for task in resp.tasks() {
+            output.push_str(&format!("Task ARN: {}\n", task.task_arn().unwrap_or("N/A")));
+            output.push_str(&format!(
+                "Task Definition: {}\n",
+                task.task_definition_arn().unwrap_or("N/A")
+            ));
+            output.push_str(&format!(
+                "Last Status: {}\n",
+                task.last_status().unwrap_or("N/A")
+            ));
+            output.push_str(&format!(
+                "Desired Status: {}\n",
+                task.desired_status().unwrap_or("N/A")
+            ));
+            output.push_str(&format!("CPU: {}\n", task.cpu().unwrap_or("N/A")));
+            output.push_str(&format!("Memory: {}\n", task.memory().unwrap_or("N/A")));
+            output.push_str(&format!(
+                "Launch Type: {}\n",
+                task.launch_type().map(|lt| lt.as_str()).unwrap_or("N/A")
+            ));
+
+            output.push_str("\nContainers:\n");
+            let mut container_jsons = Vec::new();
+            for container in task.containers() {
+                output.push_str(&format!(
+                    "  - Name: {}\n",
+                    container.name().unwrap_or("N/A")
+                ));
+                output.push_str(&format!(
+                    "    Image: {}\n",
+                    container.image().unwrap_or("N/A")
+                ));
+                output.push_str(&format!(
+                    "    Last Status: {}\n",
+                    container.last_status().unwrap_or("N/A")
+                ));
+                if let Some(exit_code) = container.exit_code() {
+                    output.push_str(&format!("    Exit Code: {exit_code}\n"));
+                }
+
+                // Build container JSON
+                let exit_code_json = container
+                    .exit_code()
+                    .map(|ec| format!(r#", "exitCode": {ec}"#))
+                    .unwrap_or_default();
+                container_jsons.push(format!(
+                    r#"{{
+      "name": "{}",
+      "image": "{}",
+      "lastStatus": "{}"{exit_code_json}
+    }}"#,
+                    container.name().unwrap_or("N/A"),
+                    container.image().unwrap_or("N/A"),
+                    container.last_status().unwrap_or("N/A")
+                ));
+            }
+            output.push('\n');
+
+            // Build task JSON
+            json_parts.push(format!(
+                r#"{{
+  "taskArn": "{}",
+  "taskDefinitionArn": "{}",
+  "lastStatus": "{}",
+  "desiredStatus": "{}",
+  "cpu": "{}",
+  "memory": "{}",
+  "launchType": "{}",
+  "containers": [
+{}
+  ]
+}}"#,
+                task.task_arn().unwrap_or("N/A"),
+                task.task_definition_arn().unwrap_or("N/A"),
+                task.last_status().unwrap_or("N/A"),
+                task.desired_status().unwrap_or("N/A"),
+                task.cpu().unwrap_or("N/A"),
+                task.memory().unwrap_or("N/A"),
+                task.launch_type().map(|lt| lt.as_str()).unwrap_or("N/A"),
+                container_jsons.join(",\n")
+            ));
+        }
+
+        let json_output = format!("[{}]", json_parts.join(",\n"));
+        Ok((output, json_output))
+    }
+
+    /// Forces a new deployment of a service, restarting all tasks.
+    ///
+    /// Uses the UpdateService API with `force_new_deployment` set to true,
+    /// which causes ECS to start new tasks and stop old ones, effectively
+    /// restarting the service.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name or ARN
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS UpdateService API call fails
+    /// - The service is in a state that prevents updates
+    /// - Insufficient permissions to update the service
+    async fn restart_service(&self, cluster: &str, service: &str) -> Result<()> {
+        self.client
+            .update_service()
+            .cluster(cluster)
+            .service(service)
+            .force_new_deployment(true)
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Updates the desired count for a service.
+    ///
+    /// Changes the number of tasks that should be running for the service.
+    /// This is useful for scaling a service up or down.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name or ARN
+    /// * `desired_count` - The new desired task count
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS UpdateService API call fails
+    /// - The service doesn't exist
+    /// - The desired count is invalid (negative)
+    /// - Insufficient permissions to update the service
+    async fn update_service_desired_count(
+        &self,
+        cluster: &str,
+        service: &str,
+        desired_count: i32,
+    ) -> Result<()> {
+        self.client
+            .update_service()
+            .cluster(cluster)
+            .service(service)
+            .desired_count(desired_count)
+            .send()
+            .await
+            .context("Failed to update service desired count")?;
+
+        Ok(())
+    }
+
+    /// Updates the task definition for a service.
+    ///
+    /// Changes the service to use a different task definition revision.
+    /// This triggers a new deployment with the updated task definition.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name or ARN
+    /// * `task_definition` - The task definition ARN or family:revision
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS UpdateService API call fails
+    /// - The service or task definition doesn't exist
+    /// - Insufficient permissions to update the service
+    async fn update_service_task_definition(
+        &self,
+        cluster: &str,
+        service: &str,
+        task_definition: &str,
+    ) -> Result<()> {
+        self.client
+            .update_service()
+            .cluster(cluster)
+            .service(service)
+            .task_definition(task_definition)
+            .send()
+            .await
+            .context("Failed to update service task definition")?;
 
-                TaskInfo {
-                    task_arn,
-                    task_id,
-                    status,
-                    desired_status,
-                    container_instance,
-                    cpu,
-                    memory,
-                }
-            })
-            .collect();
+        Ok(())
+    }
 
-        Ok(tasks)
+    /// Fetches the Application Auto Scaling configuration of a service.
+    ///
+    /// Looks up the service's `ecs:service:DesiredCount` scalable target, then its
+    /// scaling policies and the ten most recent scaling activities.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name
+    /// * `service` - The service name
+    ///
+    /// # Returns
+    /// Returns `None` if the service has no scalable target registered
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeScalableTargets, DescribeScalingPolicies or
+    ///   DescribeScalingActivities API calls fail
+    /// - Insufficient permissions to read Application Auto Scaling
+    async fn get_service_scaling(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<Option<ServiceScaling>> {
+        use aws_sdk_applicationautoscaling::types::{ScalableDimension, ServiceNamespace};
+
+        let resource_id = format!("service/{cluster}/{service}");
+        let targets = self
+            .autoscaling_client
+            .describe_scalable_targets()
+            .service_namespace(ServiceNamespace::Ecs)
+            .resource_ids(&resource_id)
+            .scalable_dimension(ScalableDimension::EcsServiceDesiredCount)
+            .send()
+            .await
+            .context("Failed to describe scalable targets")?;
+        let Some(target) = targets.scalable_targets().first() else {
+            return Ok(None);
+        };
+
+        let policies = self
+            .autoscaling_client
+            .describe_scaling_policies()
+            .service_namespace(ServiceNamespace::Ecs)
+            .resource_id(&resource_id)
+            .scalable_dimension(ScalableDimension::EcsServiceDesiredCount)
+            .send()
+            .await
+            .context("Failed to describe scaling policies")?;
+
+        let activities = self
+            .autoscaling_client
+            .describe_scaling_activities()
+            .service_namespace(ServiceNamespace::Ecs)
+            .resource_id(&resource_id)
+            .scalable_dimension(ScalableDimension::EcsServiceDesiredCount)
+            .max_results(10)
+            .send()
+            .await
+            .context("Failed to describe scaling activities")?;
+
+        // Scaling counts as suspended only when every kind of scaling is
+        let suspended = target.suspended_state().is_some_and(|state| {
+            state.dynamic_scaling_in_suspended() == Some(true)
+                && state.dynamic_scaling_out_suspended() == Some(true)
+                && state.scheduled_scaling_suspended() == Some(true)
+        });
+
+        Ok(Some(ServiceScaling {
+            min_capacity: target.min_capacity(),
+            max_capacity: target.max_capacity(),
+            suspended,
+            policies: policies
+                .scaling_policies()
+                .iter()
+                .map(|policy| ScalingPolicyInfo {
+                    name: policy.policy_name().to_string(),
+                    policy_type: policy.policy_type().as_str().to_string(),
+                    summary: Self::scaling_policy_summary(policy),
+                })
+                .collect(),
+            activities: activities
+                .scaling_activities()
+                .iter()
+                .map(|activity| ScalingActivityInfo {
+                    start_time: activity.start_time().secs(),
+                    status: activity.status_code().as_str().to_string(),
+                    description: activity.description().to_string(),
+                    cause: activity.cause().to_string(),
+                })
+                .collect(),
+        }))
     }
 
-    /// Retrieves detailed information about a specific service.
+    /// Updates the scalable target of a service.
     ///
-    /// Fetches comprehensive service details including ARN, status, task counts,
-    /// launch type, and task definition ARN, formatting them as human-readable text.
+    /// Re-registers the service's `ecs:service:DesiredCount` scalable target with
+    /// new minimum and maximum capacity, suspending or resuming dynamic scale-in,
+    /// scale-out and scheduled scaling together.
+    ///
+    /// # Arguments
+    /// * `cluster` - The cluster name
+    /// * `service` - The service name
+    /// * `update` - The new capacity limits and suspension state
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS RegisterScalableTarget API call fails
+    /// - Minimum capacity exceeds maximum capacity
+    /// - Insufficient permissions to register scalable targets
+    async fn update_service_scaling(
+        &self,
+        cluster: &str,
+        service: &str,
+        update: ScalingUpdate,
+    ) -> Result<()> {
+        use aws_sdk_applicationautoscaling::types::{
+            ScalableDimension, ServiceNamespace, SuspendedState,
+        };
+
+        self.autoscaling_client
+            .register_scalable_target()
+            .service_namespace(ServiceNamespace::Ecs)
+            .resource_id(format!("service/{cluster}/{service}"))
+            .scalable_dimension(ScalableDimension::EcsServiceDesiredCount)
+            .min_capacity(update.min_capacity)
+            .max_capacity(update.max_capacity)
+            .suspended_state(
+                SuspendedState::builder()
+                    .dynamic_scaling_in_suspended(update.suspended)
+                    .dynamic_scaling_out_suspended(update.suspended)
+                    .scheduled_scaling_suspended(update.suspended)
+                    .build(),
+            )
+            .send()
+            .await
+            .context("Failed to update service auto scaling")?;
+
+        Ok(())
+    }
+
+    /// Lists all revisions of a task definition family.
+    ///
+    /// Retrieves all task definition revisions for the specified family,
+    /// sorted by revision number (newest first). The family prefix ListTaskDefinitions
+    /// filters by also matches longer family names, so only revisions of exactly
+    /// this family are kept.
+    ///
+    /// # Arguments
+    /// * `family` - The task definition family name
+    ///
+    /// # Returns
+    /// A vector of task definition ARNs, sorted by revision (newest first)
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS ListTaskDefinitions API call fails
+    /// - Insufficient permissions to list task definitions
+    async fn list_task_definition_revisions(&self, family: &str) -> Result<Vec<String>> {
+        let mut arns = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let resp = self
+                .client
+                .list_task_definitions()
+                .family_prefix(family)
+                .sort(aws_sdk_ecs::types::SortOrder::Desc)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .context("Failed to list task definition revisions")?;
+            arns.extend(
+                resp.task_definition_arns()
+                    .iter()
+                    .filter(|arn| is_family_revision(arn, family))
+                    .cloned(),
+            );
+            next_token = resp.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+        Ok(arns)
+    }
+
+    /// Lists all task definition families in the account.
+    ///
+    /// Returns a list of task definition family names (not ARNs).
+    /// Each family represents a logical grouping of task definition revisions.
+    ///
+    /// # Returns
+    /// A vector of task definition family names sorted alphabetically
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS API call fails
+    /// - Insufficient permissions to list task definition families
+    async fn list_task_definition_families(&self) -> Result<Vec<String>> {
+        let resp = self
+            .client
+            .list_task_definition_families()
+            .send()
+            .await
+            .context("Failed to list task definition families")?;
+
+        let mut families: Vec<String> = resp.families().to_vec();
+        families.sort();
+        Ok(families)
+    }
+
+    /// Describes a task definition revision.
+    ///
+    /// # Arguments
+    /// * `task_definition` - The task definition ARN or `family:revision`
+    ///
+    /// # Returns
+    /// The revision's metadata and settings, including its container definitions
+    /// and volumes
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeTaskDefinition API call fails
+    /// - The task definition doesn't exist
+    async fn describe_task_definition(&self, task_definition: &str) -> Result<TaskDefinitionInfo> {
+        let resp = self
+            .client
+            .describe_task_definition()
+            .task_definition(task_definition)
+            .send()
+            .await
+            .with_context(|| format!("Failed to describe task definition {task_definition}"))?;
+
+        resp.task_definition()
+            .map(task_definition_info)
+            .ok_or_else(|| anyhow::anyhow!("Task definition not found: {task_definition}"))
+    }
+
+    /// Describes a task definition revision and its tags as an API-shaped JSON document.
+    ///
+    /// # Arguments
+    /// * `task_definition` - The task definition ARN or `family:revision`
+    ///
+    /// # Returns
+    /// The document DescribeTaskDefinition returns for the revision, with its `tags`
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeTaskDefinition API call fails
+    /// - The task definition doesn't exist
+    async fn describe_task_definition_document(
+        &self,
+        task_definition: &str,
+    ) -> Result<serde_json::Value> {
+        let resp = self
+            .client
+            .describe_task_definition()
+            .task_definition(task_definition)
+            .include(TaskDefinitionField::Tags)
+            .send()
+            .await
+            .with_context(|| format!("Failed to describe task definition {task_definition}"))?;
+
+        resp.task_definition()
+            .map(|definition| ecs_json::task_definition_document(definition, resp.tags()))
+            .ok_or_else(|| anyhow::anyhow!("Task definition not found: {task_definition}"))
+    }
+
+    /// Describes a service as an API-shaped JSON document.
     ///
     /// # Arguments
     /// * `cluster` - The cluster name or ARN
     /// * `service` - The service name or ARN
     ///
     /// # Returns
-    /// A formatted string containing service details for display in the UI
+    /// The document DescribeServices returns for the service, with its `tags`
     ///
     /// # Errors
     /// This function will return an error if:
     /// - The AWS DescribeServices API call fails
-    /// - The service doesn't exist in the specified cluster
-    /// - Insufficient permissions to describe the service
-    pub async fn describe_service(&self, cluster: &str, service: &str) -> Result<(String, String)> {
+    /// - The service doesn't exist
+    async fn describe_service_document(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<serde_json::Value> {
         let resp = self
             .client
             .describe_services()
             .cluster(cluster)
             .services(service)
+            .include(ServiceField::Tags)
             .send()
-            .await?;
+            .await
+            .with_context(|| format!("Failed to describe service {service}"))?;
 
-        // Format the response manually since AWS types don't implement Serialize
-        let mut output = String::new();
-        output.push_str(&format!("Cluster: {cluster}\n\n"));
+        resp.services()
+            .first()
+            .map(ecs_json::service_document)
+            .ok_or_else(|| anyhow::anyhow!("Service not found: {service}"))
+    }
 
-        let mut json_parts = Vec::new();
+    /// Registers a new task definition revision from an edited copy of an existing one.
+    ///
+    /// The settings of `definition` replace those of the source revision. Settings
+    /// the model doesn't cover are copied from the source: container settings such as
+    /// Linux parameters and ulimits (matched by container name), volume drivers,
+    /// placement constraints, the proxy configuration, PID and IPC modes, and tags.
+    ///
+    /// # Arguments
+    /// * `source` - ARN of the revision the definition was copied from
+    /// * `definition` - Settings of the new revision
+    ///
+    /// # Returns
+    /// The registered revision
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS DescribeTaskDefinition or RegisterTaskDefinition API calls fail
+    /// - A secret, health check, log configuration, dependency or EFS volume lacks a
+    ///   required field
+    async fn register_task_definition(
+        &self,
+        source: &str,
+        definition: &TaskDefinitionInfo,
+    ) -> Result<TaskDefinitionInfo> {
+        let described = self
+            .client
+            .describe_task_definition()
+            .task_definition(source)
+            .include(TaskDefinitionField::Tags)
+            .send()
+            .await
+            .with_context(|| format!("Failed to describe task definition {source}"))?;
+        let source_definition = described
+            .task_definition()
+            .ok_or_else(|| anyhow::anyhow!("Task definition not found: {source}"))?;
 
-        for svc in resp.services() {
-            // Formatted text view
-            output.push_str(&format!(
-                "Service Name: {}\n",
-                svc.service_name().unwrap_or("N/A")
-            ));
-            output.push_str(&format!(
-                "Service ARN: {}\n",
-                svc.service_arn().unwrap_or("N/A")
-            ));
-            output.push_str(&format!("Status: {}\n", svc.status().unwrap_or("N/A")));
-            output.push_str(&format!("Desired Count: {}\n", svc.desired_count()));
-            output.push_str(&format!("Running Count: {}\n", svc.running_count()));
-            output.push_str(&format!("Pending Count: {}\n", svc.pending_count()));
-            output.push_str(&format!(
-                "Launch Type: {}\n",
-                svc.launch_type().map(|lt| lt.as_str()).unwrap_or("N/A")
-            ));
-            output.push_str(&format!(
-                "Task Definition: {}\n",
-                svc.task_definition().unwrap_or("N/A")
-            ));
+        let containers = definition
+            .containers
+            .iter()
+            .map(|container| {
+                let source_container = source_definition
+                    .container_definitions()
+                    .iter()
+                    .find(|source| source.name() == Some(container.name.as_str()));
+                sdk_container_definition(container, source_container)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let volumes = definition
+            .volumes
+            .iter()
+            .map(|volume| {
+                let source_volume = source_definition
+                    .volumes()
+                    .iter()
+                    .find(|source| source.name() == Some(volume.name.as_str()));
+                sdk_volume(volume, source_volume)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let runtime_platform = definition.runtime_platform.as_ref().map(|platform| {
+            RuntimePlatform::builder()
+                .set_cpu_architecture(
+                    platform
+                        .cpu_architecture
+                        .as_deref()
+                        .map(CpuArchitecture::from),
+                )
+                .set_operating_system_family(
+                    platform
+                        .operating_system_family
+                        .as_deref()
+                        .map(OsFamily::from),
+                )
+                .build()
+        });
 
-            // Add deployment info
-            let deployments = svc.deployments();
-            if !deployments.is_empty() {
-                output.push_str("\nDeployments:\n");
-                for deployment in deployments {
-                    output.push_str(&format!(
-                        "  - Status: {}\n",
-                        deployment.status().unwrap_or("N/A")
-                    ));
-                    output.push_str(&format!(
-                        "    Running Count: {}\n",
-                        deployment.running_count()
-                    ));
-                    output.push_str(&format!(
-                        "    Desired Count: {}\n",
-                        deployment.desired_count()
-                    ));
+        let resp = self
+            .client
+            .register_task_definition()
+            .family(&definition.family)
+            .set_task_role_arn(definition.task_role_arn.clone())
+            .set_execution_role_arn(definition.execution_role_arn.clone())
+            .set_network_mode(definition.network_mode.as_deref().map(NetworkMode::from))
+            .set_requires_compatibilities(non_empty(
+                definition
+                    .requires_compatibilities
+                    .iter()
+                    .map(|compatibility| Compatibility::from(compatibility.as_str()))
+                    .collect(),
+            ))
+            .set_cpu(definition.cpu.clone())
+            .set_memory(definition.memory.clone())
+            .set_runtime_platform(runtime_platform)
+            .set_ephemeral_storage(
+                definition
+                    .ephemeral_storage_gib
+                    .map(|size| EphemeralStorage::builder().size_in_gib(size).build()),
+            )
+            .set_container_definitions(Some(containers))
+            .set_volumes(non_empty(volumes))
+            // Settings the model doesn't cover are kept from the source revision
+            .set_placement_constraints(non_empty(
+                source_definition.placement_constraints().to_vec(),
+            ))
+            .set_proxy_configuration(source_definition.proxy_configuration().cloned())
+            .set_pid_mode(source_definition.pid_mode().cloned())
+            .set_ipc_mode(source_definition.ipc_mode().cloned())
+            .set_inference_accelerators(non_empty(
+                source_definition.inference_accelerators().to_vec(),
+            ))
+            .set_enable_fault_injection(source_definition.enable_fault_injection())
+            .set_tags(non_empty(described.tags().to_vec()))
+            .send()
+            .await
+            .with_context(|| format!("Failed to register task definition {}", definition.family))?;
+
+        resp.task_definition()
+            .map(task_definition_info)
+            .ok_or_else(|| anyhow::anyhow!("RegisterTaskDefinition returned no task definition"))
+    }
+
+    /// Lists the task definitions used by the services of every cluster in the region.
+    ///
+    /// Both the task definition of each service and those of its deployments are
+    /// included, so revisions still being rolled out or rolled back count as in use.
+    ///
+    /// # Returns
+    /// The set of task definition ARNs in use
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS ListClusters, ListServices or DescribeServices API calls fail
+    /// - Insufficient permissions to list clusters or services
+    async fn list_task_definitions_in_use(&self) -> Result<HashSet<String>> {
+        let mut clusters = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let resp = self
+                .client
+                .list_clusters()
+                .set_next_token(next_token)
+                .send()
+                .await
+                .context("Failed to list clusters")?;
+            clusters.extend(resp.cluster_arns().iter().cloned());
+            next_token = resp.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        let mut in_use = HashSet::new();
+        for cluster in &clusters {
+            let mut service_arns = Vec::new();
+            let mut next_token: Option<String> = None;
+            loop {
+                let resp = self
+                    .client
+                    .list_services()
+                    .cluster(cluster)
+                    .set_next_token(next_token)
+                    .send()
+                    .await
+                    .with_context(|| format!("Failed to list the services of {cluster}"))?;
+                service_arns.extend(resp.service_arns().iter().cloned());
+                next_token = resp.next_token().map(String::from);
+                if next_token.is_none() {
+                    break;
                 }
             }
 
-            // Add network configuration
-            if let Some(network_config) = svc.network_configuration() {
-                output.push_str("\nNetwork Configuration:\n");
-                if let Some(awsvpc) = network_config.awsvpc_configuration() {
-                    output.push_str(&format!("  Subnets: {}\n", awsvpc.subnets().join(", ")));
-                    output.push_str(&format!(
-                        "  Assign Public IP: {}\n",
-                        awsvpc
-                            .assign_public_ip()
-                            .map(|ip| ip.as_str())
-                            .unwrap_or("N/A")
-                    ));
-                    let sgs = awsvpc.security_groups();
-                    if !sgs.is_empty() {
-                        output.push_str(&format!("  Security Groups: {}\n", sgs.join(", ")));
-                    }
+            // DescribeServices accepts at most 10 services per call
+            for chunk in service_arns.chunks(10) {
+                let resp = self
+                    .client
+                    .describe_services()
+                    .cluster(cluster)
+                    .set_services(Some(chunk.to_vec()))
+                    .send()
+                    .await
+                    .with_context(|| format!("Failed to describe the services of {cluster}"))?;
+                for service in resp.services() {
+                    in_use.extend(service.task_definition().map(String::from));
+                    in_use.extend(
+                        service
+                            .deployments()
+                            .iter()
+                            .filter_map(|deployment| deployment.task_definition())
+                            .map(String::from),
+                    );
                 }
             }
-
-            output.push('\n');
-
-            // Build JSON representation
-            json_parts.push(format!(
-                r#"{{
-  "serviceName": "{}",
-  "serviceArn": "{}",
-  "status": "{}",
-  "desiredCount": {},
-  "runningCount": {},
-  "pendingCount": {},
-  "launchType": "{}",
-  "taskDefinition": "{}"
-}}"#,
-                svc.service_name().unwrap_or("N/A"),
-                svc.service_arn().unwrap_or("N/A"),
-                svc.status().unwrap_or("N/A"),
-                svc.desired_count(),
-                svc.running_count(),
-                svc.pending_count(),
-                svc.launch_type().map(|lt| lt.as_str()).unwrap_or("N/A"),
-                svc.task_definition().unwrap_or("N/A")
-            ));
         }
 
-        let json_output = format!("[{}]", json_parts.join(",\n"));
-        Ok((output, json_output))
+        Ok(in_use)
     }
 
-    /// Retrieves detailed information about a specific task.
+    /// Deregisters a task definition revision.
     ///
-    /// Fetches comprehensive task details including ARN, task definition, status,
-    /// resource allocation, launch type, and container information, formatting them
-    /// as human-readable text.
+    /// The revision becomes INACTIVE: running tasks and services keep using it, but
+    /// no new task or service can be started from it.
     ///
     /// # Arguments
-    /// * `cluster` - The cluster name or ARN
-    /// * `task_arn` - The full task ARN
-    ///
-    /// # Returns
-    /// A formatted string containing task and container details for display in the UI
+    /// * `task_definition` - ARN or family:revision of the revision
     ///
     /// # Errors
     /// This function will return an error if:
-    /// - The AWS DescribeTasks API call fails
-    /// - The task doesn't exist in the specified cluster
-    /// - Insufficient permissions to describe the task
-    pub async fn describe_task(&self, cluster: &str, task_arn: &str) -> Result<(String, String)> {
-        let resp = self
-            .client
-            .describe_tasks()
-            .cluster(cluster)
-            .tasks(task_arn)
+    /// - The AWS DeregisterTaskDefinition API call fails
+    /// - The revision doesn't exist or is already INACTIVE
+    async fn deregister_task_definition(&self, task_definition: &str) -> Result<()> {
+        self.client
+            .deregister_task_definition()
+            .task_definition(task_definition)
             .send()
-            .await?;
-
-        // Format the response manually since AWS types don't implement Serialize
-        let mut output = String::new();
-        output.push_str(&format!("Cluster: {cluster}\n\n"));
-
-        let mut json_parts = Vec::new();
-
-        for task in resp.tasks() {
-            output.push_str(&format!("Task ARN: {}\n", task.task_arn().unwrap_or("N/A")));
-            output.push_str(&format!(
-                "Task Definition: {}\n",
-                task.task_definition_arn().unwrap_or("N/A")
-            ));
-            output.push_str(&format!(
-                "Last Status: {}\n",
-                task.last_status().unwrap_or("N/A")
-            ));
-            output.push_str(&format!(
-                "Desired Status: {}\n",
-                task.desired_status().unwrap_or("N/A")
-            ));
-            output.push_str(&format!("CPU: {}\n", task.cpu().unwrap_or("N/A")));
-            output.push_str(&format!("Memory: {}\n", task.memory().unwrap_or("N/A")));
-            output.push_str(&format!(
-                "Launch Type: {}\n",
-                task.launch_type().map(|lt| lt.as_str()).unwrap_or("N/A")
-            ));
-
-            output.push_str("\nContainers:\n");
-            let mut container_jsons = Vec::new();
-            for container in task.containers() {
-                output.push_str(&format!(
-                    "  - Name: {}\n",
-                    container.name().unwrap_or("N/A")
-                ));
-                output.push_str(&format!(
-                    "    Image: {}\n",
-                    container.image().unwrap_or("N/A")
-                ));
-                output.push_str(&format!(
-                    "    Last Status: {}\n",
-                    container.last_status().unwrap_or("N/A")
-                ));
-                if let Some(exit_code) = container.exit_code() {
-                    output.push_str(&format!("    Exit Code: {exit_code}\n"));
-                }
-
-                // Build container JSON
-                let exit_code_json = container
-                    .exit_code()
-                    .map(|ec| format!(r#", "exitCode": {ec}"#))
-                    .unwrap_or_default();
-                container_jsons.push(format!(
-                    r#"{{
-      "name": "{}",
-      "image": "{}",
-      "lastStatus": "{}"{exit_code_json}
-    }}"#,
-                    container.name().unwrap_or("N/A"),
-                    container.image().unwrap_or("N/A"),
-                    container.last_status().unwrap_or("N/A")
-                ));
-            }
-            output.push('\n');
-
-            // Build task JSON
-            json_parts.push(format!(
-                r#"{{
-  "taskArn": "{}",
-  "taskDefinitionArn": "{}",
-  "lastStatus": "{}",
-  "desiredStatus": "{}",
-  "cpu": "{}",
-  "memory": "{}",
-  "launchType": "{}",
-  "containers": [
-{}
-  ]
-}}"#,
-                task.task_arn().unwrap_or("N/A"),
-                task.task_definition_arn().unwrap_or("N/A"),
-                task.last_status().unwrap_or("N/A"),
-                task.desired_status().unwrap_or("N/A"),
-                task.cpu().unwrap_or("N/A"),
-                task.memory().unwrap_or("N/A"),
-                task.launch_type().map(|lt| lt.as_str()).unwrap_or("N/A"),
-                container_jsons.join(",\n")
-            ));
-        }
+            .await
+            .with_context(|| format!("Failed to deregister task definition {task_definition}"))?;
 
-        let json_output = format!("[{}]", json_parts.join(",\n"));
-        Ok((output, json_output))
+        Ok(())
     }
 
-    /// Forces a new deployment of a service, restarting all tasks.
-    ///
-    /// Uses the UpdateService API with `force_new_deployment` set to true,
-    /// which causes ECS to start new tasks and stop old ones, effectively
-    /// restarting the service.
+    /// Deletes INACTIVE task definition revisions permanently.
     ///
     /// # Arguments
-    /// * `cluster` - The cluster name or ARN
-    /// * `service` - The service name or ARN
+    /// * `task_definitions` - ARNs of the revisions, at most 10
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// The revisions that could not be deleted, with the reason ECS gave
     ///
     /// # Errors
     /// This function will return an error if:
-    /// - The AWS UpdateService API call fails
-    /// - The service is in a state that prevents updates
-    /// - Insufficient permissions to update the service
-    pub async fn restart_service(&self, cluster: &str, service: &str) -> Result<()> {
-        self.client
-            .update_service()
-            .cluster(cluster)
-            .service(service)
-            .force_new_deployment(true)
+    /// - The AWS DeleteTaskDefinitions API call fails
+    /// - More than 10 revisions are given
+    async fn delete_task_definitions(
+        &self,
+        task_definitions: &[String],
+    ) -> Result<Vec<(String, String)>> {
+        let resp = self
+            .client
+            .delete_task_definitions()
+            .set_task_definitions(Some(task_definitions.to_vec()))
             .send()
-            .await?;
+            .await
+            .context("Failed to delete task definitions")?;
 
-        Ok(())
+        Ok(resp
+            .failures()
+            .iter()
+            .map(|failure| {
+                (
+                    failure.arn().unwrap_or_default().to_string(),
+                    failure
+                        .reason()
+                        .or(failure.detail())
+                        .unwrap_or("unknown reason")
+                        .to_string(),
+                )
+            })
+            .collect())
     }
 
-    /// Updates the desired count for a service.
+    /// Stops a specific task in a cluster.
     ///
-    /// Changes the number of tasks that should be running for the service.
-    /// This is useful for scaling a service up or down.
+    /// Sends a stop request to ECS, which will terminate the task containers.
+    /// The reason "Stopped via ecs-voyager" is included in the stop request for auditing.
     ///
     /// # Arguments
     /// * `cluster` - The cluster name or ARN
-    /// * `service` - The service name or ARN
-    /// * `desired_count` - The new desired task count
+    /// * `task_arn` - The full task ARN
     ///
     /// # Returns
     /// Returns `Ok(())` on success
     ///
     /// # Errors
     /// This function will return an error if:
-    /// - The AWS UpdateService API call fails
-    /// - The service doesn't exist
-    /// - The desired count is invalid (negative)
-    /// - Insufficient permissions to update the service
-    pub async fn update_service_desired_count(
-        &self,
-        cluster: &str,
-        service: &str,
-        desired_count: i32,
-    ) -> Result<()> {
+    /// - The AWS StopTask API call fails
+    /// - The task doesn't exist or is already stopped
+    /// - Insufficient permissions to stop tasks
+    async fn stop_task(&self, cluster: &str, task_arn: &str) -> Result<()> {
         self.client
-            .update_service()
+            .stop_task()
             .cluster(cluster)
-            .service(service)
-            .desired_count(desired_count)
+            .task(task_arn)
+            .reason("Stopped via ecs-voyager")
             .send()
-            .await
-            .context("Failed to update service desired count")?;
+            .await?;
 
         Ok(())
     }
 
-    /// Updates the task definition for a service.
+    /// Retrieves a page of CloudWatch Logs for all containers in a task.
     ///
-    /// Changes the service to use a different task definition revision.
-    /// This triggers a new deployment with the updated task definition.
+    /// This method:
+    /// 1. Finds the log stream of each container from the task definition (awslogs, or
+    ///    FireLens with a CloudWatch output), or reuses the streams of the cursors when
+    ///    reading older or newer events
+    /// 2. Fetches up to `LOG_PAGE_SIZE` events from each stream (newer events are
+    ///    read to the end of the stream)
+    /// 3. Combines and sorts all logs by timestamp
+    ///
+    /// Containers whose logs cannot be located or read are listed in the page with the
+    /// reason why, so the other containers' logs are still shown.
     ///
     /// # Arguments
     /// * `cluster` - The cluster name or ARN
-    /// * `service` - The service name or ARN
-    /// * `task_definition` - The task definition ARN or family:revision
+    /// * `task_arn` - The full task ARN
+    /// * `request` - The most recent page, the page starting at a point in time, or
+    ///   the events before or after previously returned cursors
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// A `LogPage` with the entries sorted by timestamp (empty if no logs are
+    /// available), the cursors to read the pages around it and the containers
+    /// without logs
     ///
     /// # Errors
-    /// This function will return an error if:
-    /// - The AWS UpdateService API call fails
-    /// - The service or task definition doesn't exist
-    /// - Insufficient permissions to update the service
-    pub async fn update_service_task_definition(
+    /// This function will return an error if the AWS DescribeTasks or
+    /// DescribeTaskDefinition API calls fail. CloudWatch Logs errors, such as a missing
+    /// log stream or insufficient permissions, are reported per container in the page.
+    async fn get_task_logs(
         &self,
         cluster: &str,
-        service: &str,
-        task_definition: &str,
-    ) -> Result<()> {
-        self.client
-            .update_service()
-            .cluster(cluster)
-            .service(service)
-            .task_definition(task_definition)
-            .send()
-            .await
-            .context("Failed to update service task definition")?;
+        task_arn: &str,
+        request: &LogPageRequest,
+    ) -> Result<LogPage> {
+        let (streams, mut unavailable) = match request {
+            LogPageRequest::Older(cursors) | LogPageRequest::Newer(cursors) => {
+                (cursors.clone(), Vec::new())
+            }
+            _ => self.task_log_streams(cluster, task_arn).await?,
+        };
 
-        Ok(())
+        let mut all_logs = Vec::new();
+        let mut cursors = Vec::with_capacity(streams.len());
+
+        for stream in streams {
+            if stream.exhausted && matches!(request, LogPageRequest::Older(_)) {
+                cursors.push(stream);
+                continue;
+            }
+
+            match self.fetch_logs_from_stream(&stream, request).await {
+                Ok((mut logs, cursor)) => {
+                    all_logs.append(&mut logs);
+                    cursors.push(cursor);
+                }
+                Err(e) => {
+                    // Keep the cursor so the stream is read again once it exists
+                    unavailable.push(UnavailableLogs {
+                        container_name: stream.container_name.clone(),
+                        reason: e.to_string(),
+                    });
+                    cursors.push(stream);
+                }
+            }
+        }
+
+        // Sort logs by timestamp
+        all_logs.sort_by_key(|log| log.timestamp);
+
+        Ok(LogPage {
+            entries: all_logs,
+            cursors,
+            unavailable,
+        })
     }
 
-    /// Lists all revisions of a task definition family.
+    /// Retrieves CloudWatch Logs of every task of a service, interleaved by timestamp.
     ///
-    /// Retrieves all task definition revisions for the specified family,
-    /// sorted by revision number (newest first).
+    /// This method:
+    /// 1. Describes the service to find its task definition, and the log group and
+    ///    stream names of each container logging to CloudWatch Logs (awslogs or FireLens)
+    /// 2. Lists the running and recently stopped tasks of the service
+    /// 3. Runs FilterLogEvents on each container's stream prefix within the time window,
+    ///    keeping the events of the service's tasks
+    ///
+    /// Every page of the window is read, up to `limit` events: FilterLogEvents may return
+    /// empty pages that still carry a `nextToken`, and the newest events come last.
     ///
     /// # Arguments
-    /// * `family` - The task definition family name
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name
+    /// * `start_time` - Start of the window (Unix milliseconds, inclusive)
+    /// * `end_time` - End of the window (Unix milliseconds, exclusive), `None` for now
+    /// * `limit` - Most log events to read, across all containers
     ///
     /// # Returns
-    /// A vector of task definition ARNs, sorted by revision (newest first)
+    /// `LogEntry` structs with their task ID and event ID, sorted by timestamp, and
+    /// whether events were left unread because of the limit
     ///
     /// # Errors
     /// This function will return an error if:
-    /// - The AWS ListTaskDefinitions API call fails
-    /// - Insufficient permissions to list task definitions
-    pub async fn list_task_definition_revisions(&self, family: &str) -> Result<Vec<String>> {
-        let resp = self
-            .client
-            .list_task_definitions()
-            .family_prefix(family)
-            .sort(aws_sdk_ecs::types::SortOrder::Desc)
-            .send()
-            .await
-            .context("Failed to list task definition revisions")?;
+    /// - The AWS DescribeServices, DescribeTaskDefinition or ListTasks API calls fail
+    /// - The service doesn't exist
+    /// - The AWS FilterLogEvents API call fails, e.g. when the log group doesn't exist
+    /// - Insufficient permissions to access logs
+    async fn get_service_logs(
+        &self,
+        cluster: &str,
+        service: &str,
+        start_time: i64,
+        end_time: Option<i64>,
+        limit: usize,
+    ) -> Result<ServiceLogs> {
+        let task_definition = self.service_task_definition(cluster, service).await?;
+        let containers = self.cloudwatch_logs_containers(&task_definition).await?;
+        if containers.is_empty() {
+            return Ok(ServiceLogs::default());
+        }
+        let task_ids = self.service_task_ids(cluster, service).await?;
+        let shared = LogTemplateContext {
+            cluster,
+            task_id: None,
+            task_arn: None,
+        };
+
+        let mut logs = Vec::new();
+        // Events read, including those of other tasks sharing the stream prefix
+        let mut read = 0;
+        let mut truncated = false;
+        for container in &containers {
+            let Some(log_group) = container.log_group(shared) else {
+                continue;
+            };
+            if read == limit {
+                truncated = true;
+                break;
+            }
+            // The stream of each task, to tell which task an event comes from
+            let task_streams: HashMap<String, &str> = task_ids
+                .iter()
+                .filter_map(|task_id| {
+                    let context = LogTemplateContext {
+                        task_id: Some(task_id),
+                        ..shared
+                    };
+                    Some((container.stream_name(context)?, task_id.as_str()))
+                })
+                .collect();
+
+            let (events, container_truncated) = read_pages(limit - read, |next_token| {
+                let request = self
+                    .logs_client
+                    .filter_log_events()
+                    .log_group_name(&log_group)
+                    .log_stream_name_prefix(container.stream_prefix(cluster))
+                    .start_time(start_time)
+                    .set_end_time(end_time)
+                    .set_next_token(next_token);
+                async move {
+                    let resp = request.send().await?;
+                    Ok((resp.events().to_vec(), resp.next_token().map(String::from)))
+                }
+            })
+            .await?;
+            read += events.len();
+            truncated |= container_truncated;
+
+            for event in &events {
+                let (Some(timestamp), Some(message), Some(stream)) =
+                    (event.timestamp(), event.message(), event.log_stream_name())
+                else {
+                    continue;
+                };
+                let Some(task_id) = task_streams.get(stream) else {
+                    continue;
+                };
+                logs.push(LogEntry {
+                    event_id: event.event_id().map(str::to_string),
+                    task_id: Some(task_id.to_string()),
+                    ..LogEntry::new(timestamp, message.to_string(), container.name.clone())
+                });
+            }
+        }
 
-        Ok(resp.task_definition_arns().to_vec())
+        logs.sort_by_key(|log| log.timestamp);
+        Ok(ServiceLogs {
+            entries: logs,
+            truncated,
+        })
     }
 
-    /// Lists all task definition families in the account.
+    /// Lists the CloudWatch log groups of a service's containers.
     ///
-    /// Returns a list of task definition family names (not ARNs).
-    /// Each family represents a logical grouping of task definition revisions.
+    /// # Arguments
+    /// * `cluster` - The cluster name or ARN
+    /// * `service` - The service name
     ///
     /// # Returns
-    /// A vector of task definition family names sorted alphabetically
+    /// The distinct log groups of the containers of the service's current task
+    /// definition that log to CloudWatch Logs (awslogs or FireLens); empty if none does
     ///
     /// # Errors
     /// This function will return an error if:
-    /// - The AWS API call fails
-    /// - Insufficient permissions to list task definition families
-    pub async fn list_task_definition_families(&self) -> Result<Vec<String>> {
-        let resp = self
-            .client
-            .list_task_definition_families()
-            .send()
-            .await
-            .context("Failed to list task definition families")?;
-
-        let mut families: Vec<String> = resp.families().to_vec();
-        families.sort();
-        Ok(families)
+    /// - The AWS DescribeServices or DescribeTaskDefinition API calls fail
+    /// - The service doesn't exist
+    async fn get_service_log_groups(&self, cluster: &str, service: &str) -> Result<Vec<String>> {
+        let task_definition = self.service_task_definition(cluster, service).await?;
+        let context = LogTemplateContext {
+            cluster,
+            task_id: None,
+            task_arn: None,
+        };
+        let mut log_groups: Vec<String> = Vec::new();
+        for container in self.cloudwatch_logs_containers(&task_definition).await? {
+            if let Some(log_group) = container.log_group(context) {
+                if !log_groups.contains(&log_group) {
+                    log_groups.push(log_group);
+                }
+            }
+        }
+        Ok(log_groups)
     }
 
-    /// Stops a specific task in a cluster.
-    ///
-    /// Sends a stop request to ECS, which will terminate the task containers.
-    /// The reason "Stopped via ecs-voyager" is included in the stop request for auditing.
+    /// Lists the CloudWatch log groups of a task's containers.
     ///
     /// # Arguments
     /// * `cluster` - The cluster name or ARN
-    /// * `task_arn` - The full task ARN
+    /// * `task_arn` - The full ARN of the task
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// The distinct log groups of the containers of the task that log to CloudWatch
+    /// Logs (awslogs or FireLens); empty if none does
     ///
     /// # Errors
-    /// This function will return an error if:
-    /// - The AWS StopTask API call fails
-    /// - The task doesn't exist or is already stopped
-    /// - Insufficient permissions to stop tasks
-    pub async fn stop_task(&self, cluster: &str, task_arn: &str) -> Result<()> {
-        self.client
-            .stop_task()
-            .cluster(cluster)
-            .task(task_arn)
-            .reason("Stopped via ecs-voyager")
-            .send()
-            .await?;
-
-        Ok(())
+    /// This function will return an error if the AWS DescribeTasks or
+    /// DescribeTaskDefinition API calls fail.
+    async fn get_task_log_groups(&self, cluster: &str, task_arn: &str) -> Result<Vec<String>> {
+        let mut log_groups: Vec<String> = Vec::new();
+        let (streams, _) = self.task_log_streams(cluster, task_arn).await?;
+        for stream in streams {
+            if !log_groups.contains(&stream.log_group) {
+                log_groups.push(stream.log_group);
+            }
+        }
+        Ok(log_groups)
     }
 
-    /// Retrieves CloudWatch Logs for all containers in a task.
-    ///
-    /// This method:
-    /// 1. Describes the task to get the task definition ARN
-    /// 2. Describes the task definition to get log configuration
-    /// 3. For each container with awslogs configuration, fetches log events
-    /// 4. Combines and sorts all logs by timestamp
+    /// Starts a CloudWatch Logs Insights query.
     ///
-    /// Only works with tasks that have CloudWatch Logs (awslogs) configured.
+    /// The query runs asynchronously in CloudWatch Logs; poll its results with
+    /// `get_insights_query_results`.
     ///
     /// # Arguments
-    /// * `cluster` - The cluster name or ARN
-    /// * `task_arn` - The full task ARN
+    /// * `log_groups` - The log groups to query
+    /// * `query` - The Logs Insights query string
+    /// * `start_time` - Start of the window (Unix milliseconds)
+    /// * `end_time` - End of the window (Unix milliseconds)
     ///
     /// # Returns
-    /// A vector of `LogEntry` structs sorted by timestamp, or an empty vector if
-    /// no logs are available
+    /// The ID of the started query
     ///
     /// # Errors
     /// This function will return an error if:
-    /// - The AWS DescribeTasks or DescribeTaskDefinition API calls fail
-    /// - The task doesn't exist
-    /// - CloudWatch Logs API calls fail (log streams not found are handled gracefully)
-    /// - Insufficient permissions to access logs
-    pub async fn get_task_logs(&self, cluster: &str, task_arn: &str) -> Result<Vec<LogEntry>> {
-        // First, describe the task to get the task definition and container details
-        let task_resp = self
-            .client
-            .describe_tasks()
-            .cluster(cluster)
-            .tasks(task_arn)
+    /// - The AWS StartQuery API call fails, e.g. for a malformed query or a missing log group
+    /// - Too many queries are already running in the account
+    /// - Insufficient permissions to query logs
+    async fn start_insights_query(
+        &self,
+        log_groups: &[String],
+        query: &str,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<String> {
+        let resp = self
+            .logs_client
+            .start_query()
+            .set_log_group_names(Some(log_groups.to_vec()))
+            .query_string(query)
+            // StartQuery takes seconds since the epoch
+            .start_time(start_time / 1000)
+            .end_time(end_time / 1000)
             .send()
             .await?;
-
-        let mut all_logs = Vec::new();
-
-        if let Some(task) = task_resp.tasks().first() {
-            // Get the task definition to find log configuration
-            if let Some(task_def_arn) = task.task_definition_arn() {
-                let task_def_resp = self
-                    .client
-                    .describe_task_definition()
-                    .task_definition(task_def_arn)
-                    .send()
-                    .await?;
-
-                if let Some(task_definition) = task_def_resp.task_definition() {
-                    // Extract task ID from ARN for log stream name
-                    let task_id = task_arn.split('/').next_back().unwrap_or(task_arn);
-
-                    // Iterate through containers to get logs from each
-                    for container_def in task_definition.container_definitions() {
-                        let container_name = container_def.name().unwrap_or("unknown");
-
-                        // Check if container has CloudWatch Logs configured
-                        if let Some(log_config) = container_def.log_configuration() {
-                            if log_config.log_driver().as_str() == "awslogs" {
-                                if let Some(options) = log_config.options() {
-                                    // Get log group and stream prefix
-                                    if let Some(log_group) = options.get("awslogs-group") {
-                                        let stream_prefix = options
-                                            .get("awslogs-stream-prefix")
-                                            .map(|s| s.as_str())
-                                            .unwrap_or("ecs");
-
-                                        // Construct log stream name
-                                        let log_stream =
-                                            format!("{stream_prefix}/{container_name}/{task_id}");
-
-                                        // Fetch logs from CloudWatch Logs
-                                        match self
-                                            .fetch_logs_from_stream(
-                                                log_group,
-                                                &log_stream,
-                                                container_name,
-                                            )
-                                            .await
-                                        {
-                                            Ok(mut logs) => all_logs.append(&mut logs),
-                                            Err(e) => {
-                                                // Log stream might not exist yet or other error - continue with other containers
-                                                eprintln!("Failed to fetch logs for container {container_name}: {e}");
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        // Sort logs by timestamp
-        all_logs.sort_by_key(|log| log.timestamp);
-
-        Ok(all_logs)
+        resp.query_id()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("StartQuery returned no query ID"))
     }
 
-    /// Fetches log events from a specific CloudWatch Logs stream.
+    /// Fetches the status and results of a CloudWatch Logs Insights query.
     ///
-    /// Retrieves the most recent 100 log events from the specified log stream.
-    /// This is a helper method used by `get_task_logs`.
+    /// Results are partial while the query is still running.
     ///
     /// # Arguments
-    /// * `log_group` - The CloudWatch Logs group name
-    /// * `log_stream` - The CloudWatch Logs stream name
-    /// * `container_name` - The container name to associate with log entries
+    /// * `query_id` - The ID returned by `start_insights_query`
     ///
     /// # Returns
-    /// A vector of `LogEntry` structs from this log stream
+    /// `InsightsQueryResults` with the rows aligned to the result fields and the
+    /// scan statistics
     ///
     /// # Errors
-    /// This function will return an error if:
-    /// - The AWS GetLogEvents API call fails
-    /// - The log group or stream doesn't exist
-    /// - Insufficient permissions to read logs
-    async fn fetch_logs_from_stream(
-        &self,
-        log_group: &str,
-        log_stream: &str,
-        container_name: &str,
-    ) -> Result<Vec<LogEntry>> {
-        let mut logs = Vec::new();
-
-        // Get the last 100 log events (you can adjust this or add pagination)
+    /// This function will return an error if the AWS GetQueryResults API call fails.
+    async fn get_insights_query_results(&self, query_id: &str) -> Result<InsightsQueryResults> {
         let resp = self
             .logs_client
-            .get_log_events()
-            .log_group_name(log_group)
-            .log_stream_name(log_stream)
-            .limit(100)
-            .start_from_head(false) // Get most recent logs first
+            .get_query_results()
+            .query_id(query_id)
             .send()
             .await?;
 
-        for event in resp.events() {
-            if let (Some(timestamp), Some(message)) = (event.timestamp(), event.message()) {
-                logs.push(LogEntry::new(
-                    timestamp,
-                    message.to_string(),
-                    container_name.to_string(),
-                ));
-            }
+        let rows = resp
+            .results()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter_map(|field| {
+                        Some((field.field()?.to_string(), field.value()?.to_string()))
+                    })
+                    .collect()
+            })
+            .collect();
+        let status = resp
+            .status()
+            .map(|status| status.as_str().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let mut results = InsightsQueryResults::from_rows(status, rows);
+        if let Some(statistics) = resp.statistics() {
+            results.records_matched = statistics.records_matched();
+            results.records_scanned = statistics.records_scanned();
         }
+        Ok(results)
+    }
 
-        Ok(logs)
+    /// Stops a running CloudWatch Logs Insights query.
+    ///
+    /// # Arguments
+    /// * `query_id` - The ID returned by `start_insights_query`
+    ///
+    /// # Errors
+    /// This function will return an error if the AWS StopQuery API call fails, e.g.
+    /// when the query already finished.
+    async fn stop_insights_query(&self, query_id: &str) -> Result<()> {
+        self.logs_client
+            .stop_query()
+            .query_id(query_id)
+            .send()
+            .await?;
+        Ok(())
     }
 
     /// Fetches CloudWatch alarms for an ECS service.
@@ -953,7 +3599,7 @@ impl EcsClient {
     /// This function will return an error if:
     /// - The AWS DescribeAlarms API call fails
     /// - Insufficient permissions to read alarms
-    pub async fn get_service_alarms(
+    async fn get_service_alarms(
         &self,
         cluster_name: &str,
         service_name: &str,
@@ -1004,17 +3650,6 @@ impl EcsClient {
         Ok(alarms)
     }
 
-    /// Helper function to create a CloudWatch Dimension with required name and value.
-    ///
-    /// Both name and value are required by the CloudWatch API, even though the SDK
-    /// allows them to be optional. This helper ensures they are always set.
-    fn create_dimension(name: &str, value: &str) -> aws_sdk_cloudwatch::types::Dimension {
-        aws_sdk_cloudwatch::types::Dimension::builder()
-            .name(name)
-            .value(value)
-            .build()
-    }
-
     /// Fetches CloudWatch metrics for an ECS service.
     ///
     /// Retrieves CPU and Memory utilization metrics for the specified service
@@ -1032,7 +3667,7 @@ impl EcsClient {
     /// This function will return an error if:
     /// - The AWS GetMetricStatistics API call fails
     /// - Insufficient permissions to read metrics
-    pub async fn get_service_metrics(
+    async fn get_service_metrics(
         &self,
         cluster_name: &str,
         service_name: &str,
@@ -1161,6 +3796,168 @@ impl EcsClient {
         })
     }
 
+    /// Fetches Container Insights metrics for a single ECS task.
+    ///
+    /// Lists the metrics Container Insights publishes for the task to discover their
+    /// dimension sets, then fetches the task's CPU, memory, network and storage
+    /// metrics and the per-container CPU and memory in batched GetMetricData calls.
+    /// Uses one-minute periods for the 1h range and five-minute periods otherwise.
+    ///
+    /// # Arguments
+    /// * `cluster_name` - Name of the ECS cluster
+    /// * `task_arn` - The full task ARN
+    /// * `time_range` - Time range for metrics (1h, 6h, 24h, 7d)
+    ///
+    /// # Returns
+    /// Returns `TaskMetrics` with every series empty if the cluster doesn't publish
+    /// task-level metrics (Container Insights with enhanced observability disabled)
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - The AWS ListMetrics or GetMetricData API calls fail
+    /// - Insufficient permissions to read metrics
+    async fn get_task_metrics(
+        &self,
+        cluster_name: &str,
+        task_arn: &str,
+        time_range: TimeRange,
+    ) -> Result<TaskMetrics> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let task_id = task_arn.split('/').next_back().unwrap_or(task_arn);
+        let end_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("Failed to get current time")?
+            .as_secs() as i64;
+        let start_time = end_time - (time_range.minutes() as i64 * 60);
+
+        // Discover the dimension sets the task's metrics are published under
+        let mut available = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let resp = self
+                .metrics_client
+                .list_metrics()
+                .namespace(CONTAINER_INSIGHTS_NAMESPACE)
+                .dimensions(
+                    aws_sdk_cloudwatch::types::DimensionFilter::builder()
+                        .name("ClusterName")
+                        .value(cluster_name)
+                        .build(),
+                )
+                .dimensions(
+                    aws_sdk_cloudwatch::types::DimensionFilter::builder()
+                        .name("TaskId")
+                        .value(task_id)
+                        .build(),
+                )
+                .set_next_token(next_token)
+                .send()
+                .await
+                .context("Failed to list Container Insights metrics")?;
+            available.extend(resp.metrics().iter().cloned());
+            next_token = resp.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // A metric can be published under several dimension sets; query each series once
+        let mut wanted: Vec<(TaskMetricSeries, aws_sdk_cloudwatch::types::Metric)> = Vec::new();
+        for metric in available {
+            let container = metric
+                .dimensions()
+                .iter()
+                .find(|d| d.name() == Some("ContainerName"))
+                .and_then(|d| d.value());
+            let series = metric
+                .metric_name()
+                .and_then(|name| TaskMetricSeries::from_metric(name, container));
+            if let Some(series) = series {
+                if !wanted.iter().any(|(existing, _)| *existing == series) {
+                    wanted.push((series, metric));
+                }
+            }
+        }
+
+        let period = if time_range == TimeRange::OneHour {
+            60
+        } else {
+            300
+        };
+        let mut series_datapoints: Vec<Vec<MetricDatapoint>> = vec![Vec::new(); wanted.len()];
+        // GetMetricData accepts at most 500 queries per call
+        for (chunk_index, chunk) in wanted.chunks(500).enumerate() {
+            let queries: Vec<aws_sdk_cloudwatch::types::MetricDataQuery> = chunk
+                .iter()
+                .enumerate()
+                .map(|(i, (_, metric))| {
+                    aws_sdk_cloudwatch::types::MetricDataQuery::builder()
+                        .id(format!("m{}", chunk_index * 500 + i))
+                        .metric_stat(
+                            aws_sdk_cloudwatch::types::MetricStat::builder()
+                                .metric(metric.clone())
+                                .period(period)
+                                .stat("Average")
+                                .build(),
+                        )
+                        .build()
+                })
+                .collect();
+
+            let mut next_token: Option<String> = None;
+            loop {
+                let resp = self
+                    .metrics_client
+                    .get_metric_data()
+                    .set_metric_data_queries(Some(queries.clone()))
+                    .start_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(
+                        start_time,
+                    ))
+                    .end_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(
+                        end_time,
+                    ))
+                    .set_next_token(next_token)
+                    .send()
+                    .await
+                    .context("Failed to fetch Container Insights metrics from CloudWatch")?;
+
+                for result in resp.metric_data_results() {
+                    let index = result
+                        .id()
+                        .and_then(|id| id.strip_prefix('m'))
+                        .and_then(|index| index.parse::<usize>().ok());
+                    if let Some(datapoints) = index.and_then(|i| series_datapoints.get_mut(i)) {
+                        datapoints.extend(result.timestamps().iter().zip(result.values()).map(
+                            |(timestamp, value)| MetricDatapoint {
+                                timestamp: timestamp.secs(),
+                                average: Some(*value),
+                                maximum: Some(*value),
+                                minimum: Some(*value),
+                                sum: None,
+                                sample_count: None,
+                            },
+                        ));
+                    }
+                }
+
+                next_token = resp.next_token().map(String::from);
+                if next_token.is_none() {
+                    break;
+                }
+            }
+        }
+
+        let mut metrics = TaskMetrics::empty(cluster_name, task_id, time_range);
+        for ((series, _), mut datapoints) in wanted.into_iter().zip(series_datapoints) {
+            datapoints.sort_by_key(|dp| dp.timestamp);
+            series.store(&mut metrics, datapoints);
+        }
+        metrics.containers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(metrics)
+    }
+
     /// Checks if a task has ECS Exec enabled.
     ///
     /// Queries the task details to determine if ExecuteCommand capability is enabled.
@@ -1178,7 +3975,7 @@ impl EcsClient {
     /// - The AWS DescribeTasks API call fails
     /// - The task doesn't exist
     /// - Insufficient permissions to describe the task
-    pub async fn check_task_exec_enabled(&self, cluster: &str, task_arn: &str) -> Result<bool> {
+    async fn check_task_exec_enabled(&self, cluster: &str, task_arn: &str) -> Result<bool> {
         let resp = self
             .client
             .describe_tasks()
@@ -1216,7 +4013,7 @@ impl EcsClient {
     /// - The task doesn't exist or has no containers
     /// - Insufficient IAM permissions for ECS Exec
     /// - The specified container doesn't exist
-    pub async fn execute_command(
+    async fn execute_command(
         &self,
         cluster: &str,
         task_arn: &str,
@@ -1309,7 +4106,7 @@ impl EcsClient {
     /// - session-manager-plugin is not installed
     /// - ExecuteCommand API call fails (see execute_command errors)
     /// - Plugin process fails to start or exits with error
-    pub async fn start_exec_session(
+    async fn start_exec_session(
         &self,
         cluster: &str,
         task_arn: &str,
@@ -1387,7 +4184,7 @@ impl EcsClient {
     /// - SSM start_session API call fails
     /// - Plugin process fails to start or exits with error
     /// - Invalid port numbers provided
-    pub async fn start_port_forwarding(
+    async fn start_port_forwarding(
         &self,
         cluster: &str,
         task_arn: &str,
@@ -1532,6 +4329,85 @@ impl EcsClient {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use aws_sdk_ecs::types::{LogConfiguration, LogDriver};
+
+    #[test]
+    fn test_is_family_revision_matches_exact_family() {
+        let arn = "arn:aws:ecs:us-east-1:123456789012:task-definition/web-worker:7";
+        assert!(is_family_revision(arn, "web-worker"));
+        assert!(!is_family_revision(arn, "web"));
+        assert!(is_family_revision("web:3", "web"));
+        assert!(!is_family_revision("web", "web"));
+    }
+
+    #[tokio::test]
+    async fn test_read_pages_follows_tokens_past_empty_pages() {
+        // 25 pages, most of them empty, with the newest events on the last page
+        let pages: Vec<(Vec<i64>, Option<String>)> = (0..25)
+            .map(|page| {
+                let events = if page % 10 == 0 || page == 24 {
+                    vec![page]
+                } else {
+                    Vec::new()
+                };
+                let token = (page < 24).then(|| format!("token-{}", page + 1));
+                (events, token)
+            })
+            .collect();
+        let mut requested = Vec::new();
+
+        let (events, truncated) = read_pages(10, |token| {
+            requested.push(token.clone());
+            let index = token
+                .and_then(|token| token.strip_prefix("token-")?.parse::<usize>().ok())
+                .unwrap_or(0);
+            let page = pages[index].clone();
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(events, vec![0, 10, 20, 24]);
+        assert!(!truncated);
+        assert_eq!(requested.len(), 25);
+        assert_eq!(requested[0], None);
+    }
+
+    #[tokio::test]
+    async fn test_read_pages_stops_on_a_repeated_token() {
+        let mut calls = 0;
+        let (events, truncated) = read_pages(10, |_| {
+            calls += 1;
+            async { Ok((vec![1], Some("same".to_string()))) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(events, vec![1, 1]);
+        assert!(!truncated);
+        assert_eq!(calls, 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_pages_stops_at_the_limit() {
+        let mut calls = 0;
+        let (events, truncated) = read_pages(5, |token| {
+            calls += 1;
+            let page = token.map_or(0, |token| token.len());
+            async move { Ok((vec![page; 2], Some("x".repeat(page + 1)))) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(events, vec![0, 0, 1, 1, 2]);
+        assert!(truncated);
+        assert_eq!(calls, 3);
+
+        // Reaching the limit on the last page reads everything
+        let (events, truncated) = read_pages(2, |_| async { Ok((vec![1, 2], None)) })
+            .await
+            .unwrap();
+        assert_eq!(events, vec![1, 2]);
+        assert!(!truncated);
+    }
 
     // Test ARN parsing/extraction logic
     #[test]
@@ -1775,7 +4651,7 @@ mod tests {
 
     #[test]
     fn test_metric_datapoint_sorting_by_timestamp() {
-        let mut datapoints = vec![
+        let mut datapoints = [
             MetricDatapoint {
                 timestamp: 3000,
                 average: Some(50.0),
@@ -1962,7 +4838,7 @@ mod tests {
 
     #[test]
     fn test_metrics_with_multiple_alarms() {
-        let alarms = vec![
+        let alarms = [
             CloudWatchAlarm {
                 name: "CPUAlarm".to_string(),
                 description: Some("High CPU".to_string()),
@@ -2409,4 +5285,389 @@ mod tests {
         assert_eq!(sessions.len(), 2);
         assert_ne!(sessions[0].session_id, sessions[1].session_id);
     }
+
+    #[test]
+    fn test_task_metric_series_from_metric() {
+        assert_eq!(
+            TaskMetricSeries::from_metric("CpuUtilized", None),
+            Some(TaskMetricSeries::CpuUtilized)
+        );
+        assert_eq!(
+            TaskMetricSeries::from_metric("NetworkTxBytes", None),
+            Some(TaskMetricSeries::NetworkTx)
+        );
+        assert_eq!(
+            TaskMetricSeries::from_metric("ContainerCpuUtilized", Some("web")),
+            Some(TaskMetricSeries::ContainerCpu("web".to_string()))
+        );
+        assert_eq!(
+            TaskMetricSeries::from_metric("MemoryUtilized", Some("web")),
+            Some(TaskMetricSeries::ContainerMemory("web".to_string()))
+        );
+        assert_eq!(TaskMetricSeries::from_metric("TaskCount", None), None);
+        assert_eq!(
+            TaskMetricSeries::from_metric("NetworkRxBytes", Some("web")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_task_metric_series_store_groups_containers() {
+        let datapoint = |value: f64| MetricDatapoint {
+            timestamp: 1000,
+            average: Some(value),
+            maximum: Some(value),
+            minimum: Some(value),
+            sum: None,
+            sample_count: None,
+        };
+        let mut metrics = TaskMetrics::empty("cluster", "task-1", TimeRange::OneHour);
+        assert!(!metrics.has_data());
+
+        TaskMetricSeries::CpuReserved.store(&mut metrics, vec![datapoint(512.0)]);
+        TaskMetricSeries::ContainerCpu("web".to_string()).store(&mut metrics, vec![datapoint(1.0)]);
+        TaskMetricSeries::ContainerMemory("web".to_string())
+            .store(&mut metrics, vec![datapoint(2.0)]);
+        TaskMetricSeries::ContainerCpu("sidecar".to_string())
+            .store(&mut metrics, vec![datapoint(3.0)]);
+
+        assert_eq!(metrics.cpu_reserved, Some(512.0));
+        assert_eq!(metrics.containers.len(), 2);
+        assert_eq!(metrics.containers[0].name, "web");
+        assert_eq!(metrics.containers[0].cpu_utilized.len(), 1);
+        assert_eq!(metrics.containers[0].memory_utilized.len(), 1);
+        assert!(metrics.has_data());
+    }
+    #[test]
+    fn test_insights_results_align_fields_in_columns() {
+        let pair = |field: &str, value: &str| (field.to_string(), value.to_string());
+        let results = InsightsQueryResults::from_rows(
+            "Running".to_string(),
+            vec![
+                vec![
+                    pair("@timestamp", "2026-10-14 09:00:00.000"),
+                    pair("@message", "started"),
+                    pair("@ptr", "abc"),
+                ],
+                vec![pair("@message", "failed"), pair("status", "500")],
+            ],
+        );
+
+        assert_eq!(results.fields, vec!["@timestamp", "@message", "status"]);
+        assert_eq!(
+            results.rows,
+            vec![
+                vec!["2026-10-14 09:00:00.000", "started", ""],
+                vec!["", "failed", "500"],
+            ]
+        );
+        assert!(!results.is_finished());
+        assert!(InsightsQueryResults::from_rows("Failed".to_string(), Vec::new()).is_finished());
+    }
+
+    fn container_with_logs(driver: LogDriver, options: &[(&str, &str)]) -> ContainerDefinition {
+        let mut log_configuration = LogConfiguration::builder().log_driver(driver);
+        for (key, value) in options {
+            log_configuration = log_configuration.options(*key, *value);
+        }
+        ContainerDefinition::builder()
+            .name("web")
+            .log_configuration(log_configuration.build().unwrap())
+            .build()
+    }
+
+    fn unavailable_reason(source: ContainerLogSource) -> String {
+        match source {
+            ContainerLogSource::Unavailable(unavailable) => unavailable.reason,
+            ContainerLogSource::CloudWatch(container) => {
+                format!("expected no logs, found {}", container.log_group)
+            }
+        }
+    }
+
+    #[test]
+    fn test_firelens_cloudwatch_output_resolves_log_stream() {
+        let context = LogTemplateContext {
+            cluster: "arn:aws:ecs:us-east-1:123456789012:cluster/prod",
+            task_id: Some("abc123"),
+            task_arn: None,
+        };
+        let container = container_with_logs(
+            LogDriver::Awsfirelens,
+            &[
+                ("Name", "cloudwatch_logs"),
+                ("region", "us-east-1"),
+                ("log_group_name", "/firelens/$(ecs_cluster)"),
+                ("log_stream_prefix", "app-"),
+            ],
+        );
+        let ContainerLogSource::CloudWatch(source) =
+            ContainerLogSource::from_container(&container, Some("us-east-1"))
+        else {
+            unreachable!("FireLens CloudWatch output should be readable");
+        };
+
+        assert_eq!(source.log_group(context).as_deref(), Some("/firelens/prod"));
+        assert_eq!(
+            source.stream_name(context).as_deref(),
+            Some("app-web-firelens-abc123")
+        );
+        assert_eq!(source.stream_prefix(context.cluster), "app-");
+    }
+
+    #[test]
+    fn test_firelens_log_stream_name_takes_precedence_over_prefix() {
+        let container = container_with_logs(
+            LogDriver::Awsfirelens,
+            &[
+                ("Name", "cloudwatch"),
+                ("log_group_name", "/firelens/web"),
+                ("log_stream_name", "web/$(ecs_task_id)"),
+                ("log_stream_prefix", "ignored-"),
+            ],
+        );
+        let ContainerLogSource::CloudWatch(source) =
+            ContainerLogSource::from_container(&container, Some("us-east-1"))
+        else {
+            unreachable!("FireLens CloudWatch output should be readable");
+        };
+        let context = LogTemplateContext {
+            cluster: "prod",
+            task_id: Some("abc123"),
+            task_arn: None,
+        };
+
+        assert_eq!(source.stream_name(context).as_deref(), Some("web/abc123"));
+        assert_eq!(source.stream_prefix("prod"), "web/");
+    }
+
+    #[test]
+    fn test_containers_without_cloudwatch_logs_explain_why() {
+        let datadog = container_with_logs(LogDriver::Awsfirelens, &[("Name", "datadog")]);
+        assert!(
+            unavailable_reason(ContainerLogSource::from_container(&datadog, None))
+                .contains("datadog output")
+        );
+
+        let other_region = container_with_logs(
+            LogDriver::Awsfirelens,
+            &[
+                ("Name", "cloudwatch"),
+                ("region", "eu-west-1"),
+                ("log_group_name", "/firelens/web"),
+                ("log_stream_prefix", "app-"),
+            ],
+        );
+        assert!(unavailable_reason(ContainerLogSource::from_container(
+            &other_region,
+            Some("us-east-1")
+        ))
+        .contains("eu-west-1"));
+
+        let splunk = container_with_logs(LogDriver::Splunk, &[]);
+        assert_eq!(
+            unavailable_reason(ContainerLogSource::from_container(&splunk, None)),
+            "the splunk log driver does not send logs to CloudWatch Logs"
+        );
+
+        let no_logs = ContainerDefinition::builder().name("web").build();
+        assert_eq!(
+            unavailable_reason(ContainerLogSource::from_container(&no_logs, None)),
+            "no log configuration in the task definition"
+        );
+    }
+
+    #[test]
+    fn test_sdk_container_definition_keeps_unmodeled_settings() {
+        use aws_sdk_ecs::types::{LinuxParameters, Ulimit, UlimitName};
+
+        let source = ContainerDefinition::builder()
+            .name("web")
+            .image("nginx:1.26")
+            .linux_parameters(
+                LinuxParameters::builder()
+                    .init_process_enabled(true)
+                    .build(),
+            )
+            .ulimits(
+                Ulimit::builder()
+                    .name(UlimitName::Nofile)
+                    .soft_limit(1024)
+                    .hard_limit(4096)
+                    .build()
+                    .unwrap(),
+            )
+            .environment(KeyValuePair::builder().name("MODE").value("prod").build())
+            .build();
+        let task_definition = TaskDefinition::builder()
+            .container_definitions(source.clone())
+            .build();
+        let mut container = task_definition_info(&task_definition).containers.remove(0);
+        container.image = "nginx:1.27".to_string();
+        container.environment.clear();
+        container.secrets.push(SecretReference {
+            name: "TOKEN".to_string(),
+            value_from: "arn:aws:ssm:us-east-1:123456789012:parameter/token".to_string(),
+        });
+
+        let registered = sdk_container_definition(&container, Some(&source)).unwrap();
+        assert_eq!(registered.image(), Some("nginx:1.27"));
+        assert!(registered.environment().is_empty());
+        assert_eq!(registered.secrets()[0].name(), "TOKEN");
+        assert_eq!(registered.linux_parameters(), source.linux_parameters());
+        assert_eq!(registered.ulimits(), source.ulimits());
+
+        // A Docker-managed host volume stays one
+        let volume = sdk_volume(
+            &VolumeInfo {
+                name: "cache".to_string(),
+                ..Default::default()
+            },
+            Some(
+                &Volume::builder()
+                    .name("cache")
+                    .host(HostVolumeProperties::builder().build())
+                    .build(),
+            ),
+        )
+        .unwrap();
+        assert!(volume
+            .host()
+            .is_some_and(|host| host.source_path().is_none()));
+    }
+
+    #[test]
+    fn test_task_definition_info_converts_containers_and_volumes() {
+        use aws_sdk_ecs::types::{
+            Compatibility, ContainerCondition, ContainerDependency, EfsTransitEncryption,
+            EfsVolumeConfiguration, HealthCheck, KeyValuePair, MountPoint, NetworkMode,
+            PortMapping, Secret, TaskDefinitionStatus, TransportProtocol, Volume,
+        };
+
+        let container = ContainerDefinition::builder()
+            .name("web")
+            .image("nginx:1.27")
+            .cpu(128)
+            .memory_reservation(256)
+            .port_mappings(
+                PortMapping::builder()
+                    .container_port(80)
+                    .host_port(80)
+                    .protocol(TransportProtocol::Tcp)
+                    .build(),
+            )
+            .environment(KeyValuePair::builder().name("MODE").value("prod").build())
+            .secrets(
+                Secret::builder()
+                    .name("API_KEY")
+                    .value_from("arn:aws:secretsmanager:us-east-1:123456789012:secret:api")
+                    .build()
+                    .unwrap(),
+            )
+            .health_check(
+                HealthCheck::builder()
+                    .command("CMD-SHELL")
+                    .command("curl -f http://localhost/")
+                    .retries(3)
+                    .build()
+                    .unwrap(),
+            )
+            .mount_points(
+                MountPoint::builder()
+                    .source_volume("shared")
+                    .container_path("/data")
+                    .read_only(true)
+                    .build(),
+            )
+            .depends_on(
+                ContainerDependency::builder()
+                    .container_name("init")
+                    .condition(ContainerCondition::Success)
+                    .build()
+                    .unwrap(),
+            )
+            .log_configuration(
+                LogConfiguration::builder()
+                    .log_driver(LogDriver::Awslogs)
+                    .options("awslogs-group", "/ecs/web")
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        let task_definition = TaskDefinition::builder()
+            .task_definition_arn("arn:aws:ecs:us-east-1:123456789012:task-definition/web:7")
+            .family("web")
+            .revision(7)
+            .status(TaskDefinitionStatus::Active)
+            .registered_at(aws_sdk_ecs::primitives::DateTime::from_secs(1_700_000_000))
+            .compatibilities(Compatibility::Ec2)
+            .compatibilities(Compatibility::Fargate)
+            .network_mode(NetworkMode::Awsvpc)
+            .cpu("256")
+            .memory("512")
+            .task_role_arn("arn:aws:iam::123456789012:role/web-task")
+            .container_definitions(container)
+            .volumes(
+                Volume::builder()
+                    .name("shared")
+                    .efs_volume_configuration(
+                        EfsVolumeConfiguration::builder()
+                            .file_system_id("fs-123")
+                            .transit_encryption(EfsTransitEncryption::Enabled)
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        let info = task_definition_info(&task_definition);
+        assert_eq!(info.family_revision(), "web:7");
+        assert_eq!(info.status, "ACTIVE");
+        assert_eq!(info.registered_at, Some(1_700_000_000));
+        assert_eq!(info.compatibilities, vec!["EC2", "FARGATE"]);
+        assert_eq!(info.network_mode.as_deref(), Some("awsvpc"));
+        assert_eq!(info.execution_role_arn, None);
+
+        let web = &info.containers[0];
+        assert_eq!(web.image, "nginx:1.27");
+        assert_eq!(web.memory_reservation, Some(256));
+        assert_eq!(web.port_mappings[0].protocol.as_deref(), Some("tcp"));
+        assert_eq!(web.environment[0].value, "prod");
+        assert_eq!(web.secrets[0].name, "API_KEY");
+        assert_eq!(
+            web.health_check.as_ref().map(|check| check.command.len()),
+            Some(2)
+        );
+        assert_eq!(web.mount_points[0].read_only, Some(true));
+        assert_eq!(web.depends_on[0].condition, "SUCCESS");
+        assert_eq!(
+            web.log_configuration
+                .as_ref()
+                .map(|logs| logs.options["awslogs-group"].as_str()),
+            Some("/ecs/web")
+        );
+        let efs = info.volumes[0].efs.as_ref().unwrap();
+        assert_eq!(efs.file_system_id, "fs-123");
+        assert_eq!(efs.transit_encryption.as_deref(), Some("ENABLED"));
+    }
+
+    #[test]
+    fn test_expand_log_template_stops_at_unknown_variables() {
+        let context = LogTemplateContext {
+            cluster: "prod",
+            task_id: None,
+            task_arn: None,
+        };
+
+        assert_eq!(
+            expand_log_template("$(ecs_cluster)/$(container_name)", "web", context),
+            ("prod/web".to_string(), true)
+        );
+        assert_eq!(
+            expand_log_template("logs/$(ecs_task_id)/out", "web", context),
+            ("logs/".to_string(), false)
+        );
+        assert_eq!(resolve_log_template("$(unknown)", "web", context), None);
+    }
 }
@@ -0,0 +1,1343 @@
+//! In-memory fake of the AWS backend for offline testing.
+//!
+//! `FakeBackend` implements [`EcsBackend`] on top of plain collections seeded from
+//! fixtures. Mutating calls update those collections, and every call is recorded so
+//! tests can assert which AWS operations the app performed.
+
+use crate::app::{
+    CapacityProviderStrategyItem, ClusterInfo, ContainerExit, ContainerInstanceInfo,
+    DeploymentInfo, LogEntry, ScalingActivityInfo, ScalingPolicyInfo, ScalingUpdate,
+    ServiceActivity, ServiceEvent, ServiceInfo, ServiceScaling, StoppedTaskInfo, TaskInfo,
+};
+use crate::aws::{
+    is_family_revision, read_pages, CloudWatchAlarm, ContainerMetrics, EcsBackend,
+    InsightsQueryResults, LogPage, LogPageRequest, LogStreamCursor, MetricDatapoint, Metrics,
+    ServiceLogs, Session, TaskMetrics, TimeRange, UnavailableLogs, LOG_PAGE_SIZE,
+};
+use crate::task_definition::{
+    ContainerDefinitionInfo, EnvironmentVariable, HealthCheckInfo, LogConfigurationInfo,
+    PortMappingInfo, SecretReference, TaskDefinitionInfo,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard};
+
+/// Data served by the fake backend.
+#[derive(Debug, Default, Clone)]
+pub struct FakeState {
+    /// Cluster names
+    pub clusters: Vec<String>,
+    /// Capacity providers, settings and tags keyed by cluster name; the counts are
+    /// computed from the other collections when described
+    pub cluster_configs: HashMap<String, ClusterInfo>,
+    /// Services keyed by cluster name
+    pub services: HashMap<String, Vec<ServiceInfo>>,
+    /// Tasks keyed by (cluster, service)
+    pub tasks: HashMap<(String, String), Vec<TaskInfo>>,
+    /// Container instances keyed by cluster
+    pub container_instances: HashMap<String, Vec<ContainerInstanceInfo>>,
+    /// Stopped tasks keyed by (cluster, service), most recently stopped first
+    pub stopped_tasks: HashMap<(String, String), Vec<StoppedTaskInfo>>,
+    /// Task definition ARN used by each (cluster, service)
+    pub service_task_definitions: HashMap<(String, String), String>,
+    /// Deployments keyed by (cluster, service), PRIMARY first
+    pub deployments: HashMap<(String, String), Vec<DeploymentInfo>>,
+    /// Service events keyed by (cluster, service), newest first
+    pub service_events: HashMap<(String, String), Vec<ServiceEvent>>,
+    /// Whether services report the deployment circuit breaker with rollback enabled
+    pub circuit_breaker_rollback: bool,
+    /// Auto scaling configuration keyed by (cluster, service); services without an
+    /// entry have no scalable target
+    pub scaling: HashMap<(String, String), ServiceScaling>,
+    /// Task definition revision ARNs keyed by family, newest first
+    pub task_definitions: HashMap<String, Vec<String>>,
+    /// Task definition revisions keyed by ARN
+    pub task_definition_details: HashMap<String, TaskDefinitionInfo>,
+    /// Log entries keyed by task ARN
+    pub logs: HashMap<String, Vec<LogEntry>>,
+    /// Containers without readable logs keyed by task ARN, with the reason why
+    pub unavailable_logs: HashMap<String, Vec<UnavailableLogs>>,
+    /// Log events returned per container and page; `None` for `LOG_PAGE_SIZE`
+    pub log_page_size: Option<usize>,
+    /// Service log events per FilterLogEvents page, each page followed by an empty
+    /// one carrying a token; `None` to return the whole window at once
+    pub filter_page_size: Option<usize>,
+    /// Rows of (field, value) pairs returned by every Logs Insights query
+    pub insights_results: Vec<Vec<(String, String)>>,
+    /// Number of result polls that report a query as still running before it completes
+    pub insights_running_polls: usize,
+    /// CPU datapoints returned for every service
+    pub cpu_datapoints: Vec<MetricDatapoint>,
+    /// Memory datapoints returned for every service
+    pub memory_datapoints: Vec<MetricDatapoint>,
+    /// Whether tasks report Container Insights metrics (enhanced observability enabled)
+    pub task_insights: bool,
+    /// Alarms returned for every service
+    pub alarms: Vec<CloudWatchAlarm>,
+    /// Whether ECS Exec is reported as enabled on tasks
+    pub exec_enabled: bool,
+    /// Every call made against the backend, e.g. `"restart_service cluster-prod/web-service"`
+    pub calls: Vec<String>,
+    /// When set, every call fails with this message
+    pub failure: Option<String>,
+}
+
+/// In-memory implementation of [`EcsBackend`].
+pub struct FakeBackend {
+    state: Mutex<FakeState>,
+}
+
+impl FakeBackend {
+    /// Creates a fake backend serving the given state.
+    pub fn new(state: FakeState) -> Self {
+        Self {
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Creates a fake backend seeded with a small production-like account.
+    ///
+    /// `cluster-prod` runs `web-service` (two tasks, plus `task-web-0` that was
+    /// OOM-killed) and `api-service` (one task, placed on container instance `ci-1`
+    /// of the two EC2 instances) with a FARGATE/FARGATE_SPOT strategy and Container
+    /// Insights enabled; `cluster-dev` runs `worker-service` with no tasks.
+    pub fn with_fixtures() -> Self {
+        let mut state = FakeState {
+            clusters: vec!["cluster-prod".to_string(), "cluster-dev".to_string()],
+            exec_enabled: true,
+            task_insights: true,
+            ..FakeState::default()
+        };
+
+        state.cluster_configs.insert(
+            "cluster-prod".to_string(),
+            ClusterInfo {
+                capacity_providers: vec!["FARGATE".to_string(), "FARGATE_SPOT".to_string()],
+                default_strategy: vec![
+                    CapacityProviderStrategyItem {
+                        capacity_provider: "FARGATE".to_string(),
+                        weight: 1,
+                        base: 1,
+                    },
+                    CapacityProviderStrategyItem {
+                        capacity_provider: "FARGATE_SPOT".to_string(),
+                        weight: 4,
+                        base: 0,
+                    },
+                ],
+                container_insights: "enabled".to_string(),
+                tags: vec![("env".to_string(), "production".to_string())],
+                ..fixture_cluster("cluster-prod")
+            },
+        );
+        state.cluster_configs.insert(
+            "cluster-dev".to_string(),
+            ClusterInfo {
+                container_insights: "disabled".to_string(),
+                ..fixture_cluster("cluster-dev")
+            },
+        );
+
+        state.services.insert(
+            "cluster-prod".to_string(),
+            vec![
+                fixture_service("web-service", 2, "FARGATE"),
+                fixture_service("api-service", 1, "EC2"),
+            ],
+        );
+        state.services.insert(
+            "cluster-dev".to_string(),
+            vec![fixture_service("worker-service", 0, "FARGATE")],
+        );
+
+        state.tasks.insert(
+            key("cluster-prod", "web-service"),
+            vec![fixture_task("task-web-1"), fixture_task("task-web-2")],
+        );
+        state.tasks.insert(
+            key("cluster-prod", "api-service"),
+            vec![TaskInfo {
+                container_instance: "ci-1".to_string(),
+                ..fixture_task("task-api-1")
+            }],
+        );
+        state.container_instances.insert(
+            "cluster-prod".to_string(),
+            vec![
+                fixture_container_instance("ci-1", "i-0aaa111", 1),
+                fixture_container_instance("ci-2", "i-0bbb222", 0),
+            ],
+        );
+        state
+            .tasks
+            .insert(key("cluster-dev", "worker-service"), Vec::new());
+
+        state.stopped_tasks.insert(
+            key("cluster-prod", "web-service"),
+            vec![StoppedTaskInfo {
+                task_arn: task_arn("task-web-0"),
+                task_id: "task-web-0".to_string(),
+                last_status: "STOPPED".to_string(),
+                stopped_reason: "Essential container in task exited".to_string(),
+                stop_code: "EssentialContainerExited".to_string(),
+                started_at: Some(500),
+                stopping_at: Some(900),
+                stopped_at: Some(950),
+                containers: vec![ContainerExit {
+                    name: "web".to_string(),
+                    exit_code: Some(137),
+                    reason: "OutOfMemoryError: Container killed due to memory usage".to_string(),
+                }],
+            }],
+        );
+
+        state.scaling.insert(
+            key("cluster-prod", "web-service"),
+            ServiceScaling {
+                min_capacity: 2,
+                max_capacity: 6,
+                suspended: false,
+                policies: vec![ScalingPolicyInfo {
+                    name: "web-cpu-target".to_string(),
+                    policy_type: "TargetTrackingScaling".to_string(),
+                    summary: "ECSServiceAverageCPUUtilization target 60".to_string(),
+                }],
+                activities: vec![ScalingActivityInfo {
+                    start_time: 900,
+                    status: "Successful".to_string(),
+                    description: "Setting desired count to 2.".to_string(),
+                    cause: "monitor alarm web-cpu-target-AlarmLow in state ALARM triggered policy web-cpu-target".to_string(),
+                }],
+            },
+        );
+
+        for (cluster, service, family) in [
+            ("cluster-prod", "web-service", "web"),
+            ("cluster-prod", "api-service", "api"),
+            ("cluster-dev", "worker-service", "worker"),
+        ] {
+            let revisions: Vec<String> = (1..=3)
+                .rev()
+                .map(|revision| task_definition_arn(family, revision))
+                .collect();
+            for revision in 1..=3 {
+                state.task_definition_details.insert(
+                    task_definition_arn(family, revision),
+                    fixture_task_definition(family, revision),
+                );
+            }
+            state.deployments.insert(
+                key(cluster, service),
+                vec![fixture_deployment(1, &revisions[0], "COMPLETED")],
+            );
+            state.service_events.insert(
+                key(cluster, service),
+                vec![ServiceEvent {
+                    created_at: 1000,
+                    message: format!("(service {service}) has reached a steady state."),
+                }],
+            );
+            state
+                .service_task_definitions
+                .insert(key(cluster, service), revisions[0].clone());
+            state.task_definitions.insert(family.to_string(), revisions);
+        }
+
+        state.logs.insert(
+            task_arn("task-web-0"),
+            vec![LogEntry::new(
+                800,
+                "ERROR java.lang.OutOfMemoryError: Java heap space".to_string(),
+                "web".to_string(),
+            )],
+        );
+        state.logs.insert(
+            task_arn("task-web-1"),
+            vec![
+                LogEntry::new(1000, "INFO Server started".to_string(), "web".to_string()),
+                LogEntry::new(2000, "WARN Slow request".to_string(), "web".to_string()),
+                LogEntry::new(3000, "ERROR Request failed".to_string(), "web".to_string()),
+            ],
+        );
+
+        state.cpu_datapoints = vec![fixture_datapoint(1000, 20.0), fixture_datapoint(2000, 40.0)];
+        state.memory_datapoints =
+            vec![fixture_datapoint(1000, 50.0), fixture_datapoint(2000, 55.0)];
+
+        Self::new(state)
+    }
+
+    /// Locks and returns the fake's state for inspection or modification.
+    pub fn state(&self) -> MutexGuard<'_, FakeState> {
+        // A panicking test must not poison the fake for the assertions that follow
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the calls made against the backend so far.
+    pub fn calls(&self) -> Vec<String> {
+        self.state().calls.clone()
+    }
+
+    /// Makes every subsequent call fail with the given message.
+    pub fn fail_with(&self, message: &str) {
+        self.state().failure = Some(message.to_string());
+    }
+
+    /// Records a call and returns the locked state, or the configured failure.
+    fn record(&self, call: String) -> Result<MutexGuard<'_, FakeState>> {
+        let mut state = self.state();
+        state.calls.push(call);
+        if let Some(message) = &state.failure {
+            anyhow::bail!("{message}");
+        }
+        Ok(state)
+    }
+}
+
+#[async_trait]
+impl EcsBackend for FakeBackend {
+    async fn list_clusters(&self) -> Result<Vec<String>> {
+        let state = self.record("list_clusters".to_string())?;
+        Ok(state.clusters.clone())
+    }
+
+    async fn describe_clusters(&self, clusters: &[String]) -> Result<Vec<ClusterInfo>> {
+        let state = self.record(format!("describe_clusters {}", clusters.join(",")))?;
+        Ok(clusters
+            .iter()
+            .filter(|name| state.clusters.contains(name))
+            .map(|name| {
+                let services = state.services.get(name).map(Vec::as_slice).unwrap_or(&[]);
+                let count_tasks = |status: &str| {
+                    services
+                        .iter()
+                        .filter_map(|svc| state.tasks.get(&key(name, &svc.name)))
+                        .flatten()
+                        .filter(|task| task.status == status)
+                        .count() as i32
+                };
+                ClusterInfo {
+                    active_services: services.iter().filter(|svc| svc.status == "ACTIVE").count()
+                        as i32,
+                    running_tasks: count_tasks("RUNNING"),
+                    pending_tasks: count_tasks("PENDING"),
+                    registered_container_instances: state
+                        .container_instances
+                        .get(name)
+                        .map_or(0, |instances| instances.len() as i32),
+                    ..state
+                        .cluster_configs
+                        .get(name)
+                        .cloned()
+                        .unwrap_or_else(|| fixture_cluster(name))
+                }
+            })
+            .collect())
+    }
+
+    async fn list_services(&self, cluster: &str) -> Result<Vec<ServiceInfo>> {
+        let state = self.record(format!("list_services {cluster}"))?;
+        state
+            .services
+            .get(cluster)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Cluster not found: {cluster}"))
+    }
+
+    async fn list_tasks(&self, cluster: &str, service: &str) -> Result<Vec<TaskInfo>> {
+        let state = self.record(format!("list_tasks {cluster}/{service}"))?;
+        state
+            .tasks
+            .get(&key(cluster, service))
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Service not found: {service}"))
+    }
+
+    async fn list_stopped_tasks(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<Vec<StoppedTaskInfo>> {
+        let state = self.record(format!("list_stopped_tasks {cluster}/{service}"))?;
+        find_service(&state, cluster, service)?;
+        Ok(state
+            .stopped_tasks
+            .get(&key(cluster, service))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_container_instances(&self, cluster: &str) -> Result<Vec<ContainerInstanceInfo>> {
+        let state = self.record(format!("list_container_instances {cluster}"))?;
+        Ok(state
+            .container_instances
+            .get(cluster)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_container_instance_tasks(
+        &self,
+        cluster: &str,
+        container_instance_arn: &str,
+    ) -> Result<Vec<TaskInfo>> {
+        let state = self.record(format!(
+            "list_container_instance_tasks {cluster}/{container_instance_arn}"
+        ))?;
+        let instance_id = container_instance_arn
+            .split('/')
+            .next_back()
+            .unwrap_or(container_instance_arn);
+        Ok(state
+            .tasks
+            .iter()
+            .filter(|((task_cluster, _), _)| task_cluster == cluster)
+            .flat_map(|(_, tasks)| tasks.iter())
+            .filter(|task| task.container_instance == instance_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_container_instance_state(
+        &self,
+        cluster: &str,
+        container_instance_arn: &str,
+        status: &str,
+    ) -> Result<()> {
+        let mut state = self.record(format!(
+            "update_container_instance_state {cluster}/{container_instance_arn} {status}"
+        ))?;
+        let instance = state
+            .container_instances
+            .get_mut(cluster)
+            .and_then(|instances| {
+                instances
+                    .iter_mut()
+                    .find(|i| i.arn == container_instance_arn)
+            })
+            .ok_or_else(|| {
+                anyhow::anyhow!("Container instance not found: {container_instance_arn}")
+            })?;
+        instance.status = status.to_string();
+        Ok(())
+    }
+
+    async fn describe_service(&self, cluster: &str, service: &str) -> Result<(String, String)> {
+        let state = self.record(format!("describe_service {cluster}/{service}"))?;
+        let info = find_service(&state, cluster, service)?;
+        let task_definition = state
+            .service_task_definitions
+            .get(&key(cluster, service))
+            .cloned()
+            .unwrap_or_default();
+
+        let formatted = format!(
+            "Service: {}\nStatus: {}\nTask Definition: {task_definition}\nDesired: {}\nRunning: {}\nPending: {}\nLaunch Type: {}",
+            info.name,
+            info.status,
+            info.desired_count,
+            info.running_count,
+            info.pending_count,
+            info.launch_type
+        );
+        let json = serde_json::json!({
+            "serviceName": info.name,
+            "status": info.status,
+            "taskDefinition": task_definition,
+            "desiredCount": info.desired_count,
+            "runningCount": info.running_count,
+            "pendingCount": info.pending_count,
+            "launchType": info.launch_type,
+        });
+        Ok((formatted, serde_json::to_string_pretty(&json)?))
+    }
+
+    async fn get_service_events(&self, cluster: &str, service: &str) -> Result<ServiceActivity> {
+        let state = self.record(format!("get_service_events {cluster}/{service}"))?;
+        find_service(&state, cluster, service)?;
+        let service_key = key(cluster, service);
+        Ok(ServiceActivity {
+            deployments: state
+                .deployments
+                .get(&service_key)
+                .cloned()
+                .unwrap_or_default(),
+            events: state
+                .service_events
+                .get(&service_key)
+                .cloned()
+                .unwrap_or_default(),
+            circuit_breaker_enabled: state.circuit_breaker_rollback,
+            circuit_breaker_rollback: state.circuit_breaker_rollback,
+        })
+    }
+
+    async fn describe_task(&self, cluster: &str, task_arn: &str) -> Result<(String, String)> {
+        let state = self.record(format!("describe_task {cluster}/{task_arn}"))?;
+        let task = find_task(&state, cluster, task_arn)?;
+
+        let formatted = format!(
+            "Task: {}\nStatus: {}\nDesired Status: {}\nCPU: {}\nMemory: {}",
+            task.task_id, task.status, task.desired_status, task.cpu, task.memory
+        );
+        let json = serde_json::json!({
+            "taskArn": task.task_arn,
+            "lastStatus": task.status,
+            "desiredStatus": task.desired_status,
+            "cpu": task.cpu,
+            "memory": task.memory,
+        });
+        Ok((formatted, serde_json::to_string_pretty(&json)?))
+    }
+
+    async fn restart_service(&self, cluster: &str, service: &str) -> Result<()> {
+        let mut state = self.record(format!("restart_service {cluster}/{service}"))?;
+        find_service(&state, cluster, service)?;
+
+        // A forced deployment starts a new PRIMARY rollout of the same task definition
+        let task_definition = state
+            .service_task_definitions
+            .get(&key(cluster, service))
+            .cloned()
+            .unwrap_or_default();
+        let deployments = state.deployments.entry(key(cluster, service)).or_default();
+        for deployment in deployments.iter_mut() {
+            deployment.status = "ACTIVE".to_string();
+        }
+        let number = deployments.len() + 1;
+        deployments.insert(
+            0,
+            fixture_deployment(number, &task_definition, "IN_PROGRESS"),
+        );
+        Ok(())
+    }
+
+    async fn update_service_desired_count(
+        &self,
+        cluster: &str,
+        service: &str,
+        desired_count: i32,
+    ) -> Result<()> {
+        let mut state = self.record(format!(
+            "update_service_desired_count {cluster}/{service} {desired_count}"
+        ))?;
+        let info = state
+            .services
+            .get_mut(cluster)
+            .and_then(|services| services.iter_mut().find(|s| s.name == service))
+            .ok_or_else(|| anyhow::anyhow!("Service not found: {service}"))?;
+        info.desired_count = desired_count;
+        Ok(())
+    }
+
+    async fn update_service_task_definition(
+        &self,
+        cluster: &str,
+        service: &str,
+        task_definition: &str,
+    ) -> Result<()> {
+        let mut state = self.record(format!(
+            "update_service_task_definition {cluster}/{service} {task_definition}"
+        ))?;
+        find_service(&state, cluster, service)?;
+        state
+            .service_task_definitions
+            .insert(key(cluster, service), task_definition.to_string());
+        Ok(())
+    }
+
+    async fn get_service_scaling(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<Option<ServiceScaling>> {
+        let state = self.record(format!("get_service_scaling {cluster}/{service}"))?;
+        find_service(&state, cluster, service)?;
+        Ok(state.scaling.get(&key(cluster, service)).cloned())
+    }
+
+    async fn update_service_scaling(
+        &self,
+        cluster: &str,
+        service: &str,
+        update: ScalingUpdate,
+    ) -> Result<()> {
+        let mut state = self.record(format!(
+            "update_service_scaling {cluster}/{service} {}-{}{}",
+            update.min_capacity,
+            update.max_capacity,
+            if update.suspended { " suspended" } else { "" }
+        ))?;
+        find_service(&state, cluster, service)?;
+        if update.min_capacity > update.max_capacity {
+            anyhow::bail!("Minimum capacity cannot be greater than maximum capacity");
+        }
+        let scaling = state
+            .scaling
+            .entry(key(cluster, service))
+            .or_insert_with(|| ServiceScaling {
+                min_capacity: 0,
+                max_capacity: 0,
+                suspended: false,
+                policies: Vec::new(),
+                activities: Vec::new(),
+            });
+        scaling.min_capacity = update.min_capacity;
+        scaling.max_capacity = update.max_capacity;
+        scaling.suspended = update.suspended;
+        Ok(())
+    }
+
+    async fn list_task_definition_revisions(&self, family: &str) -> Result<Vec<String>> {
+        let state = self.record(format!("list_task_definition_revisions {family}"))?;
+        // Like ListTaskDefinitions, the family is matched as a prefix, then narrowed
+        // down to the family itself
+        let mut families: Vec<&String> = state
+            .task_definitions
+            .keys()
+            .filter(|name| name.starts_with(family))
+            .collect();
+        families.sort();
+        Ok(families
+            .into_iter()
+            .flat_map(|name| &state.task_definitions[name])
+            .filter(|arn| is_family_revision(arn, family))
+            .cloned()
+            .collect())
+    }
+
+    async fn list_task_definition_families(&self) -> Result<Vec<String>> {
+        let state = self.record("list_task_definition_families".to_string())?;
+        let mut families: Vec<String> = state.task_definitions.keys().cloned().collect();
+        families.sort();
+        Ok(families)
+    }
+
+    async fn describe_task_definition(&self, task_definition: &str) -> Result<TaskDefinitionInfo> {
+        let state = self.record(format!("describe_task_definition {task_definition}"))?;
+        // Like ECS, a bare family name resolves to its latest active revision
+        let latest = state
+            .task_definitions
+            .get(task_definition)
+            .and_then(|revisions| revisions.first());
+        let arn = latest.map(String::as_str).unwrap_or(task_definition);
+        state
+            .task_definition_details
+            .values()
+            .find(|definition| definition.arn == arn || definition.family_revision() == arn)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Task definition not found: {task_definition}"))
+    }
+
+    async fn describe_task_definition_document(
+        &self,
+        task_definition: &str,
+    ) -> Result<serde_json::Value> {
+        let definition = self.describe_task_definition(task_definition).await?;
+        let mut document = serde_json::to_value(&definition)?;
+        if let Some(map) = document.as_object_mut() {
+            map.insert("taskDefinitionArn".to_string(), definition.arn.into());
+            map.insert("revision".to_string(), definition.revision.into());
+            map.insert("status".to_string(), definition.status.into());
+            map.insert(
+                "compatibilities".to_string(),
+                definition.compatibilities.into(),
+            );
+        }
+        Ok(document)
+    }
+
+    async fn describe_service_document(
+        &self,
+        cluster: &str,
+        service: &str,
+    ) -> Result<serde_json::Value> {
+        let state = self.record(format!("describe_service_document {cluster}/{service}"))?;
+        let info = find_service(&state, cluster, service)?;
+        Ok(serde_json::json!({
+            "serviceArn": format!("arn:aws:ecs:us-east-1:123456789012:service/{cluster}/{service}"),
+            "serviceName": info.name,
+            "clusterArn": format!("arn:aws:ecs:us-east-1:123456789012:cluster/{cluster}"),
+            "status": info.status,
+            "taskDefinition": state.service_task_definitions.get(&key(cluster, service)),
+            "desiredCount": info.desired_count,
+            "runningCount": info.running_count,
+            "pendingCount": info.pending_count,
+            "launchType": info.launch_type,
+        }))
+    }
+
+    async fn register_task_definition(
+        &self,
+        source: &str,
+        definition: &TaskDefinitionInfo,
+    ) -> Result<TaskDefinitionInfo> {
+        let mut state = self.record(format!("register_task_definition {}", definition.family))?;
+        if !state.task_definition_details.contains_key(source) {
+            anyhow::bail!("Task definition not found: {source}");
+        }
+        let revision = state
+            .task_definition_details
+            .values()
+            .filter(|registered| registered.family == definition.family)
+            .map(|registered| registered.revision)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let arn = task_definition_arn(&definition.family, revision as u32);
+        let registered = TaskDefinitionInfo {
+            arn: arn.clone(),
+            revision,
+            status: "ACTIVE".to_string(),
+            registered_at: Some(1_000 * i64::from(revision)),
+            registered_by: Some("arn:aws:iam::123456789012:role/deployer".to_string()),
+            compatibilities: vec!["EC2".to_string(), "FARGATE".to_string()],
+            ..definition.clone()
+        };
+        state
+            .task_definitions
+            .entry(definition.family.clone())
+            .or_default()
+            .insert(0, arn.clone());
+        state
+            .task_definition_details
+            .insert(arn, registered.clone());
+        Ok(registered)
+    }
+
+    async fn list_task_definitions_in_use(&self) -> Result<HashSet<String>> {
+        let state = self.record("list_task_definitions_in_use".to_string())?;
+        Ok(state
+            .service_task_definitions
+            .values()
+            .cloned()
+            .chain(
+                state
+                    .deployments
+                    .values()
+                    .flatten()
+                    .map(|deployment| deployment.task_definition.clone()),
+            )
+            .collect())
+    }
+
+    async fn deregister_task_definition(&self, task_definition: &str) -> Result<()> {
+        let mut state = self.record(format!("deregister_task_definition {task_definition}"))?;
+        let definition = state
+            .task_definition_details
+            .get_mut(task_definition)
+            .filter(|definition| definition.status == "ACTIVE")
+            .ok_or_else(|| anyhow::anyhow!("Task definition not found: {task_definition}"))?;
+        definition.status = "INACTIVE".to_string();
+        let family = definition.family.clone();
+        if let Some(revisions) = state.task_definitions.get_mut(&family) {
+            revisions.retain(|arn| arn != task_definition);
+        }
+        Ok(())
+    }
+
+    async fn delete_task_definitions(
+        &self,
+        task_definitions: &[String],
+    ) -> Result<Vec<(String, String)>> {
+        let mut state = self.record(format!(
+            "delete_task_definitions {}",
+            task_definitions.join(",")
+        ))?;
+        let mut failures = Vec::new();
+        for arn in task_definitions {
+            let inactive = state
+                .task_definition_details
+                .get(arn)
+                .is_some_and(|definition| definition.status == "INACTIVE");
+            if inactive {
+                state.task_definition_details.remove(arn);
+            } else {
+                failures.push((
+                    arn.clone(),
+                    "The specified task definition is not INACTIVE".to_string(),
+                ));
+            }
+        }
+        Ok(failures)
+    }
+
+    async fn stop_task(&self, cluster: &str, task_arn: &str) -> Result<()> {
+        let mut state = self.record(format!("stop_task {cluster}/{task_arn}"))?;
+        let (service_key, task) = state
+            .tasks
+            .iter_mut()
+            .filter(|((task_cluster, _), _)| task_cluster == cluster)
+            .flat_map(|(service_key, tasks)| tasks.iter_mut().map(move |t| (service_key, t)))
+            .find(|(_, task)| task.task_arn == task_arn)
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {task_arn}"))?;
+        task.status = "STOPPED".to_string();
+        task.desired_status = "STOPPED".to_string();
+
+        // The task now also shows up among the stopped tasks of its service
+        let stopped = StoppedTaskInfo {
+            task_arn: task.task_arn.clone(),
+            task_id: task.task_id.clone(),
+            last_status: "STOPPED".to_string(),
+            stopped_reason: "Task stopped by user".to_string(),
+            stop_code: "UserInitiated".to_string(),
+            started_at: None,
+            stopping_at: None,
+            stopped_at: None,
+            containers: Vec::new(),
+        };
+        let service_key = service_key.clone();
+        state
+            .stopped_tasks
+            .entry(service_key)
+            .or_default()
+            .insert(0, stopped);
+        Ok(())
+    }
+
+    async fn get_task_logs(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        request: &LogPageRequest,
+    ) -> Result<LogPage> {
+        let label = match request {
+            LogPageRequest::Latest => "latest".to_string(),
+            LogPageRequest::At(start_time) => format!("at {start_time}"),
+            LogPageRequest::Older(_) => "older".to_string(),
+            LogPageRequest::Newer(_) => "newer".to_string(),
+        };
+        let state = self.record(format!("get_task_logs {cluster}/{task_arn} {label}"))?;
+        let page_size = state.log_page_size.unwrap_or(LOG_PAGE_SIZE as usize);
+        let mut logs = state.logs.get(task_arn).cloned().unwrap_or_default();
+        logs.sort_by_key(|log| log.timestamp);
+
+        // One stream per container; tokens are indexes into the stream's events
+        let mut containers: Vec<String> = logs.iter().map(|l| l.container_name.clone()).collect();
+        containers.sort();
+        containers.dedup();
+        let streams: Vec<LogStreamCursor> = match request {
+            LogPageRequest::Older(cursors) | LogPageRequest::Newer(cursors) => cursors.clone(),
+            _ => containers
+                .iter()
+                .map(|container| LogStreamCursor {
+                    container_name: container.clone(),
+                    log_group: "/ecs/fake".to_string(),
+                    log_stream: format!("ecs/{container}/{task_arn}"),
+                    end_time: None,
+                    backward_token: None,
+                    forward_token: None,
+                    exhausted: false,
+                })
+                .collect(),
+        };
+
+        let unavailable = match request {
+            LogPageRequest::Latest | LogPageRequest::At(_) => state
+                .unavailable_logs
+                .get(task_arn)
+                .cloned()
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        let mut page = LogPage {
+            entries: Vec::new(),
+            cursors: Vec::new(),
+            unavailable,
+        };
+        for mut cursor in streams {
+            let events: Vec<&LogEntry> = logs
+                .iter()
+                .filter(|l| l.container_name == cursor.container_name)
+                .collect();
+            let (start, end) = match request {
+                LogPageRequest::Latest => {
+                    let start = events.len().saturating_sub(page_size);
+                    cursor.backward_token = Some(start.to_string());
+                    cursor.forward_token = Some(events.len().to_string());
+                    (start, events.len())
+                }
+                LogPageRequest::At(start_time) => {
+                    let start = events.partition_point(|l| l.timestamp < *start_time);
+                    let end = (start + page_size).min(events.len());
+                    cursor.end_time = Some(*start_time);
+                    cursor.forward_token = Some(end.to_string());
+                    (start, end)
+                }
+                LogPageRequest::Newer(_) => {
+                    let start = cursor
+                        .forward_token
+                        .as_ref()
+                        .and_then(|token| token.parse().ok())
+                        .unwrap_or(0)
+                        .min(events.len());
+                    cursor.forward_token = Some(events.len().to_string());
+                    (start, events.len())
+                }
+                LogPageRequest::Older(_) if cursor.exhausted => (0, 0),
+                LogPageRequest::Older(_) => {
+                    let end = match &cursor.backward_token {
+                        Some(token) => token.parse().unwrap_or(0),
+                        None => events.partition_point(|l| {
+                            cursor
+                                .end_time
+                                .is_none_or(|end_time| l.timestamp < end_time)
+                        }),
+                    };
+                    let start = end.saturating_sub(page_size);
+                    cursor.exhausted = start == end;
+                    cursor.backward_token = Some(start.to_string());
+                    (start, end)
+                }
+            };
+            page.entries
+                .extend(events[start..end].iter().map(|&l| l.clone()));
+            page.cursors.push(cursor);
+        }
+        page.entries.sort_by_key(|log| log.timestamp);
+        Ok(page)
+    }
+
+    async fn get_service_logs(
+        &self,
+        cluster: &str,
+        service: &str,
+        start_time: i64,
+        end_time: Option<i64>,
+        limit: usize,
+    ) -> Result<ServiceLogs> {
+        let end = end_time.map(|end| end.to_string()).unwrap_or_default();
+        // The state is released before paging, which awaits
+        let (mut logs, page_size) = {
+            let state = self.record(format!(
+                "get_service_logs {cluster}/{service} {start_time}-{end}"
+            ))?;
+            find_service(&state, cluster, service)?;
+
+            let service_key = key(cluster, service);
+            let running = state.tasks.get(&service_key).into_iter().flatten();
+            let stopped = state.stopped_tasks.get(&service_key).into_iter().flatten();
+            let task_arns = running
+                .map(|task| &task.task_arn)
+                .chain(stopped.map(|task| &task.task_arn));
+
+            let mut logs = Vec::new();
+            for task_arn in task_arns {
+                let task_id = task_arn.split('/').next_back().unwrap_or(task_arn);
+                for (index, log) in state.logs.get(task_arn).into_iter().flatten().enumerate() {
+                    if log.timestamp >= start_time && end_time.is_none_or(|end| log.timestamp < end)
+                    {
+                        logs.push(LogEntry {
+                            event_id: Some(format!("{task_id}-{index}")),
+                            task_id: Some(task_id.to_string()),
+                            ..log.clone()
+                        });
+                    }
+                }
+            }
+            logs.sort_by_key(|log| log.timestamp);
+            (logs, state.filter_page_size)
+        };
+        let Some(page_size) = page_size else {
+            let truncated = logs.len() > limit;
+            logs.truncate(limit);
+            return Ok(ServiceLogs {
+                entries: logs,
+                truncated,
+            });
+        };
+
+        let pages: Vec<Vec<LogEntry>> = logs
+            .chunks(page_size.max(1))
+            .flat_map(|page| [page.to_vec(), Vec::new()])
+            .collect();
+        let (entries, truncated) = read_pages(limit, |token| {
+            let index = token
+                .and_then(|token| token.parse::<usize>().ok())
+                .unwrap_or(0);
+            let page = pages.get(index).cloned().unwrap_or_default();
+            let next_token = (index + 1 < pages.len()).then(|| (index + 1).to_string());
+            async move { Ok((page, next_token)) }
+        })
+        .await?;
+        Ok(ServiceLogs { entries, truncated })
+    }
+
+    async fn get_service_log_groups(&self, cluster: &str, service: &str) -> Result<Vec<String>> {
+        let state = self.record(format!("get_service_log_groups {cluster}/{service}"))?;
+        find_service(&state, cluster, service)?;
+        Ok(state
+            .service_task_definitions
+            .get(&key(cluster, service))
+            .map(|task_definition| vec![log_group(task_definition)])
+            .unwrap_or_default())
+    }
+
+    async fn get_task_log_groups(&self, cluster: &str, task_arn: &str) -> Result<Vec<String>> {
+        let state = self.record(format!("get_task_log_groups {cluster}/{task_arn}"))?;
+        // Running and stopped tasks log to the group of their service's task definition
+        let running = state.tasks.iter().flat_map(|(service_key, tasks)| {
+            tasks.iter().map(move |task| (service_key, &task.task_arn))
+        });
+        let stopped = state.stopped_tasks.iter().flat_map(|(service_key, tasks)| {
+            tasks.iter().map(move |task| (service_key, &task.task_arn))
+        });
+        let (service_key, _) = running
+            .chain(stopped)
+            .find(|((task_cluster, _), arn)| task_cluster == cluster && *arn == task_arn)
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {task_arn}"))?;
+        Ok(state
+            .service_task_definitions
+            .get(service_key)
+            .map(|task_definition| vec![log_group(task_definition)])
+            .unwrap_or_default())
+    }
+
+    async fn start_insights_query(
+        &self,
+        log_groups: &[String],
+        query: &str,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<String> {
+        let state = self.record(format!(
+            "start_insights_query {} {} {query}",
+            log_groups.join(","),
+            end_time - start_time
+        ))?;
+        Ok(format!("query-{}", state.calls.len()))
+    }
+
+    async fn get_insights_query_results(&self, query_id: &str) -> Result<InsightsQueryResults> {
+        let mut state = self.record(format!("get_insights_query_results {query_id}"))?;
+        if state.insights_running_polls > 0 {
+            state.insights_running_polls -= 1;
+            return Ok(InsightsQueryResults {
+                status: "Running".to_string(),
+                ..InsightsQueryResults::default()
+            });
+        }
+        let mut results =
+            InsightsQueryResults::from_rows("Complete".to_string(), state.insights_results.clone());
+        results.records_matched = results.rows.len() as f64;
+        results.records_scanned = state.logs.values().map(Vec::len).sum::<usize>() as f64;
+        Ok(results)
+    }
+
+    async fn stop_insights_query(&self, query_id: &str) -> Result<()> {
+        let _state = self.record(format!("stop_insights_query {query_id}"))?;
+        Ok(())
+    }
+
+    async fn get_service_alarms(
+        &self,
+        cluster_name: &str,
+        service_name: &str,
+    ) -> Result<Vec<CloudWatchAlarm>> {
+        let state = self.record(format!("get_service_alarms {cluster_name}/{service_name}"))?;
+        Ok(state.alarms.clone())
+    }
+
+    async fn get_service_metrics(
+        &self,
+        cluster_name: &str,
+        service_name: &str,
+        time_range: TimeRange,
+    ) -> Result<Metrics> {
+        let state = self.record(format!(
+            "get_service_metrics {cluster_name}/{service_name} {}",
+            time_range.label()
+        ))?;
+        find_service(&state, cluster_name, service_name)?;
+        Ok(Metrics {
+            cpu_datapoints: state.cpu_datapoints.clone(),
+            memory_datapoints: state.memory_datapoints.clone(),
+            alarms: state.alarms.clone(),
+            time_range,
+            cluster_name: cluster_name.to_string(),
+            service_name: service_name.to_string(),
+        })
+    }
+
+    async fn get_task_metrics(
+        &self,
+        cluster_name: &str,
+        task_arn: &str,
+        time_range: TimeRange,
+    ) -> Result<TaskMetrics> {
+        let state = self.record(format!(
+            "get_task_metrics {cluster_name}/{task_arn} {}",
+            time_range.label()
+        ))?;
+        // Container Insights keeps metrics of stopped tasks for the retention period
+        let stopped = state
+            .stopped_tasks
+            .iter()
+            .filter(|((cluster, _), _)| cluster == cluster_name)
+            .flat_map(|(_, tasks)| tasks.iter())
+            .any(|task| task.task_arn == task_arn);
+        if !stopped {
+            find_task(&state, cluster_name, task_arn)?;
+        }
+        let task_id = task_arn.split('/').next_back().unwrap_or(task_arn);
+        let mut metrics = TaskMetrics::empty(cluster_name, task_id, time_range);
+        if state.task_insights {
+            metrics.cpu_utilized = state.cpu_datapoints.clone();
+            metrics.cpu_reserved = Some(256.0);
+            metrics.memory_utilized = state.memory_datapoints.clone();
+            metrics.memory_reserved = Some(512.0);
+            metrics.network_rx = vec![fixture_datapoint(1000, 2048.0)];
+            metrics.network_tx = vec![fixture_datapoint(1000, 1024.0)];
+            metrics.containers = vec![ContainerMetrics {
+                name: "web".to_string(),
+                cpu_utilized: state.cpu_datapoints.clone(),
+                memory_utilized: state.memory_datapoints.clone(),
+            }];
+        }
+        Ok(metrics)
+    }
+
+    async fn check_task_exec_enabled(&self, cluster: &str, task_arn: &str) -> Result<bool> {
+        let state = self.record(format!("check_task_exec_enabled {cluster}/{task_arn}"))?;
+        find_task(&state, cluster, task_arn)?;
+        Ok(state.exec_enabled)
+    }
+
+    async fn execute_command(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        _container_name: Option<String>,
+        command: &str,
+    ) -> Result<Session> {
+        let state = self.record(format!("execute_command {cluster}/{task_arn} {command}"))?;
+        let task = find_task(&state, cluster, task_arn)?;
+        Ok(Session {
+            session_id: format!("session-{}", task.task_id),
+            stream_url: "wss://ssmmessages.us-east-1.amazonaws.com/v1/data-channel".to_string(),
+            token_value: "fake-token".to_string(),
+        })
+    }
+
+    async fn start_exec_session(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        _container_name: Option<String>,
+        _region: &str,
+    ) -> Result<()> {
+        let state = self.record(format!("start_exec_session {cluster}/{task_arn}"))?;
+        find_task(&state, cluster, task_arn)?;
+        Ok(())
+    }
+
+    async fn start_port_forwarding(
+        &self,
+        cluster: &str,
+        task_arn: &str,
+        local_port: u16,
+        remote_port: u16,
+        _region: &str,
+    ) -> Result<()> {
+        let state = self.record(format!(
+            "start_port_forwarding {cluster}/{task_arn} {local_port}:{remote_port}"
+        ))?;
+        find_task(&state, cluster, task_arn)?;
+        Ok(())
+    }
+}
+
+/// Builds the key used for per-service collections.
+fn key(cluster: &str, service: &str) -> (String, String) {
+    (cluster.to_string(), service.to_string())
+}
+
+/// Finds a service in the fake state.
+fn find_service<'a>(state: &'a FakeState, cluster: &str, service: &str) -> Result<&'a ServiceInfo> {
+    state
+        .services
+        .get(cluster)
+        .and_then(|services| services.iter().find(|s| s.name == service))
+        .ok_or_else(|| anyhow::anyhow!("Service not found: {service}"))
+}
+
+/// Finds a task of any service of a cluster in the fake state.
+fn find_task<'a>(state: &'a FakeState, cluster: &str, task_arn: &str) -> Result<&'a TaskInfo> {
+    state
+        .tasks
+        .iter()
+        .filter(|((task_cluster, _), _)| task_cluster == cluster)
+        .flat_map(|(_, tasks)| tasks.iter())
+        .find(|task| task.task_arn == task_arn)
+        .ok_or_else(|| anyhow::anyhow!("Task not found: {task_arn}"))
+}
+
+/// Returns the log group of the containers of a task definition: `/ecs/<family>`.
+fn log_group(task_definition: &str) -> String {
+    let family = task_definition
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.split(':').next())
+        .unwrap_or(task_definition);
+    format!("/ecs/{family}")
+}
+
+fn fixture_service(name: &str, count: i32, launch_type: &str) -> ServiceInfo {
+    ServiceInfo {
+        name: name.to_string(),
+        status: "ACTIVE".to_string(),
+        desired_count: count,
+        running_count: count,
+        pending_count: 0,
+        launch_type: launch_type.to_string(),
+    }
+}
+
+fn fixture_cluster(name: &str) -> ClusterInfo {
+    ClusterInfo {
+        name: name.to_string(),
+        status: "ACTIVE".to_string(),
+        active_services: 0,
+        running_tasks: 0,
+        pending_tasks: 0,
+        registered_container_instances: 0,
+        capacity_providers: Vec::new(),
+        default_strategy: Vec::new(),
+        container_insights: String::new(),
+        tags: Vec::new(),
+    }
+}
+
+fn fixture_container_instance(
+    id: &str,
+    ec2_instance_id: &str,
+    running: i32,
+) -> ContainerInstanceInfo {
+    ContainerInstanceInfo {
+        arn: container_instance_arn(id),
+        ec2_instance_id: ec2_instance_id.to_string(),
+        status: "ACTIVE".to_string(),
+        agent_connected: true,
+        agent_version: "1.82.0".to_string(),
+        registered_cpu: 2048,
+        remaining_cpu: 2048 - 256 * running,
+        registered_memory: 4096,
+        remaining_memory: 4096 - 512 * running,
+        running_tasks: running,
+        pending_tasks: 0,
+    }
+}
+
+fn fixture_task(task_id: &str) -> TaskInfo {
+    TaskInfo {
+        task_arn: task_arn(task_id),
+        task_id: task_id.to_string(),
+        status: "RUNNING".to_string(),
+        desired_status: "RUNNING".to_string(),
+        container_instance: "N/A".to_string(),
+        cpu: "256".to_string(),
+        memory: "512".to_string(),
+    }
+}
+
+fn fixture_deployment(number: usize, task_definition: &str, rollout_state: &str) -> DeploymentInfo {
+    DeploymentInfo {
+        id: format!("ecs-svc/{number}"),
+        status: "PRIMARY".to_string(),
+        rollout_state: rollout_state.to_string(),
+        rollout_state_reason: String::new(),
+        task_definition: task_definition.to_string(),
+        desired_count: 1,
+        running_count: if rollout_state == "COMPLETED" { 1 } else { 0 },
+        pending_count: 0,
+        failed_tasks: 0,
+        updated_at: 1000,
+    }
+}
+
+fn fixture_task_definition(family: &str, revision: u32) -> TaskDefinitionInfo {
+    TaskDefinitionInfo {
+        arn: task_definition_arn(family, revision),
+        family: family.to_string(),
+        revision: revision as i32,
+        status: "ACTIVE".to_string(),
+        registered_at: Some(1_000 * i64::from(revision)),
+        registered_by: Some("arn:aws:iam::123456789012:role/deployer".to_string()),
+        compatibilities: vec!["EC2".to_string(), "FARGATE".to_string()],
+        requires_compatibilities: vec!["FARGATE".to_string()],
+        task_role_arn: Some(format!("arn:aws:iam::123456789012:role/{family}-task")),
+        execution_role_arn: Some("arn:aws:iam::123456789012:role/ecsTaskExecutionRole".to_string()),
+        network_mode: Some("awsvpc".to_string()),
+        cpu: Some("256".to_string()),
+        memory: Some("512".to_string()),
+        runtime_platform: None,
+        ephemeral_storage_gib: None,
+        containers: vec![ContainerDefinitionInfo {
+            name: family.to_string(),
+            image: format!("123456789012.dkr.ecr.us-east-1.amazonaws.com/{family}:1.{revision}.0"),
+            essential: Some(true),
+            port_mappings: vec![PortMappingInfo {
+                container_port: Some(8080),
+                protocol: Some("tcp".to_string()),
+                ..Default::default()
+            }],
+            environment: vec![EnvironmentVariable {
+                name: "LOG_LEVEL".to_string(),
+                value: "info".to_string(),
+            }],
+            secrets: vec![SecretReference {
+                name: "DB_PASSWORD".to_string(),
+                value_from: format!("arn:aws:ssm:us-east-1:123456789012:parameter/{family}/db"),
+            }],
+            health_check: Some(HealthCheckInfo {
+                command: vec![
+                    "CMD-SHELL".to_string(),
+                    "curl -f http://localhost:8080/health || exit 1".to_string(),
+                ],
+                interval: Some(30),
+                timeout: Some(5),
+                retries: Some(3),
+                start_period: None,
+            }),
+            log_configuration: Some(LogConfigurationInfo {
+                log_driver: "awslogs".to_string(),
+                options: [
+                    ("awslogs-group".to_string(), format!("/ecs/{family}")),
+                    ("awslogs-stream-prefix".to_string(), "ecs".to_string()),
+                ]
+                .into_iter()
+                .collect(),
+                secret_options: Vec::new(),
+            }),
+            ..Default::default()
+        }],
+        volumes: Vec::new(),
+    }
+}
+
+fn fixture_datapoint(timestamp: i64, average: f64) -> MetricDatapoint {
+    MetricDatapoint {
+        timestamp,
+        average: Some(average),
+        maximum: Some(average + 10.0),
+        minimum: Some(average - 10.0),
+        sum: None,
+        sample_count: None,
+    }
+}
+
+/// Builds the task ARN used by the fixtures for a task ID.
+pub fn task_arn(task_id: &str) -> String {
+    format!("arn:aws:ecs:us-east-1:123456789012:task/{task_id}")
+}
+
+/// Builds the container instance ARN used by the fixtures for a container instance ID.
+pub fn container_instance_arn(id: &str) -> String {
+    format!("arn:aws:ecs:us-east-1:123456789012:container-instance/cluster-prod/{id}")
+}
+
+/// Builds the task definition ARN used by the fixtures for a family and revision.
+pub fn task_definition_arn(family: &str, revision: u32) -> String {
+    format!("arn:aws:ecs:us-east-1:123456789012:task-definition/{family}:{revision}")
+}
@@ -6,9 +6,12 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+use crate::multiline::DEFAULT_CONTINUATION_PATTERN;
+
 /// Main configuration structure for ECS Voyager.
 ///
 /// All configuration options are optional and will fall back to sensible defaults
@@ -66,6 +69,34 @@ pub struct BehaviorConfig {
     /// Enable read-only mode (prevents destructive actions)
     #[serde(default = "default_false")]
     pub read_only: bool,
+
+    /// Regular expressions matched (case-insensitively) against cluster and service names.
+    /// Destructive actions on matching resources require typing the resource name to confirm.
+    #[serde(default)]
+    pub production_patterns: Vec<String>,
+}
+
+impl BehaviorConfig {
+    /// Returns `true` if any of the given resource names matches a production pattern.
+    ///
+    /// Patterns that are not valid regular expressions are matched as plain substrings,
+    /// so a typo never silently disables the extra confirmation.
+    pub fn is_production(&self, names: &[&str]) -> bool {
+        self.production_patterns.iter().any(|pattern| {
+            match regex::RegexBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+            {
+                Ok(re) => names.iter().any(|name| re.is_match(name)),
+                Err(_) => {
+                    let pattern = pattern.to_lowercase();
+                    names
+                        .iter()
+                        .any(|name| name.to_lowercase().contains(&pattern))
+                }
+            }
+        })
+    }
 }
 
 /// UI configuration options.
@@ -112,6 +143,40 @@ pub struct LogsConfig {
     /// Default log export directory
     #[serde(default = "default_export_dir")]
     pub export_dir: String,
+
+    /// Maximum number of log entries kept in memory while paging through logs
+    #[serde(default = "default_max_buffer_lines")]
+    pub max_buffer_lines: usize,
+
+    /// Fields of JSON log lines shown as columns in the Logs view
+    #[serde(default)]
+    pub columns: Vec<String>,
+
+    /// Regex matching continuation lines (e.g. stack trace frames) merged into the
+    /// preceding entry; empty disables multi-line grouping
+    #[serde(default = "default_multiline_pattern")]
+    pub multiline_pattern: String,
+
+    /// Minutes of logs loaded before and after an entry when showing its context
+    #[serde(default = "default_context_minutes")]
+    pub context_minutes: u32,
+
+    /// Logs Insights queries that can be loaded in the Logs Insights view
+    #[serde(default)]
+    pub saved_queries: Vec<SavedQuery>,
+
+    /// Continuation line regexes of specific containers, overriding `multiline_pattern`
+    #[serde(default)]
+    pub multiline_patterns: HashMap<String, String>,
+}
+
+/// A CloudWatch Logs Insights query saved for reuse.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedQuery {
+    /// Name shown when the query is loaded
+    pub name: String,
+    /// Logs Insights query string
+    pub query: String,
 }
 
 /// Metrics configuration options.
@@ -179,6 +244,18 @@ fn default_export_dir() -> String {
     "~/Downloads".to_string()
 }
 
+fn default_multiline_pattern() -> String {
+    DEFAULT_CONTINUATION_PATTERN.to_string()
+}
+
+fn default_context_minutes() -> u32 {
+    5
+}
+
+fn default_max_buffer_lines() -> usize {
+    10_000
+}
+
 fn default_metrics_range() -> i32 {
     60
 }
@@ -196,6 +273,7 @@ impl Default for BehaviorConfig {
             refresh_interval: default_refresh_interval(),
             default_view: default_view(),
             read_only: default_false(),
+            production_patterns: Vec::new(),
         }
     }
 }
@@ -219,6 +297,12 @@ impl Default for LogsConfig {
             enable_filtering: default_true(),
             show_timestamps: default_true(),
             export_dir: default_export_dir(),
+            max_buffer_lines: default_max_buffer_lines(),
+            columns: Vec::new(),
+            multiline_pattern: default_multiline_pattern(),
+            context_minutes: default_context_minutes(),
+            saved_queries: Vec::new(),
+            multiline_patterns: HashMap::new(),
         }
     }
 }
@@ -332,6 +416,10 @@ default_view = "clusters"
 # Can be overridden with --read-only or -r CLI flag
 read_only = false
 
+# Regular expressions matched against cluster and service names. Restarting,
+# stopping, or updating a matching resource requires typing its name to confirm.
+# production_patterns = ["prod", "^live-"]
+
 [ui]
 # Color theme preset
 # Options: "dark", "light", "custom"
@@ -361,6 +449,34 @@ show_timestamps = true
 # Default directory for log exports
 export_dir = "~/Downloads"
 
+# Maximum number of log entries kept in memory; loading older pages beyond
+# this drops the newest entries, tailing drops the oldest
+max_buffer_lines = 10000
+
+# Fields of JSON log lines shown as columns in the Logs view ('c' edits them),
+# e.g. ["level", "logger", "trace_id", "http.status"]
+columns = []
+
+# Regex matching continuation lines, such as stack trace frames, that are merged
+# into the preceding entry ('o' expands a merged entry). Leave out to use the
+# built-in Java/Python stack trace pattern, or set to "" to disable grouping.
+# multiline_pattern = "^\\s"
+
+# Minutes of logs shown before and after an entry when showing its context
+# ('C' in the Logs view)
+context_minutes = 5
+
+# Saved Logs Insights queries, cycled with 'n' in the Logs Insights view ('S' saves
+# the current query here)
+# [[logs.saved_queries]]
+# name = "Errors"
+# query = "fields @timestamp, @message | filter @message like /ERROR/ | sort @timestamp desc"
+
+# Continuation line patterns of specific containers
+# [logs.multiline_patterns]
+# web = "^(\\s|Caused by:)"
+# nginx = ""
+
 [metrics]
 # Enable CloudWatch metrics display
 enabled = true
@@ -417,6 +533,77 @@ show_charts = true
 
         Ok(())
     }
+
+    /// Appends a saved Logs Insights query to the config file.
+    ///
+    /// Unlike [`Config::save`], only `logs.saved_queries` is changed: the rest of the
+    /// file, including comments, is written back as it was read, and settings overridden
+    /// on the command line are not persisted.
+    ///
+    /// # Errors
+    /// This function will return an error if:
+    /// - Directory creation fails
+    /// - The config file cannot be read or parsed
+    /// - File write operations fail
+    pub fn append_saved_query(saved: &SavedQuery) -> Result<()> {
+        let config_dir = Self::config_dir()?;
+        let config_path = Self::config_file_path()?;
+
+        // Create directory if it doesn't exist
+        if !config_dir.exists() {
+            fs::create_dir_all(&config_dir)
+                .with_context(|| format!("Failed to create config directory: {config_dir:?}"))?;
+        }
+
+        let contents = if config_path.exists() {
+            fs::read_to_string(&config_path)
+                .with_context(|| format!("Failed to read config file: {config_path:?}"))?
+        } else {
+            String::new()
+        };
+        let contents = with_saved_query(&contents, saved)
+            .with_context(|| format!("Failed to parse config file: {config_path:?}"))?;
+
+        fs::write(&config_path, contents)
+            .with_context(|| format!("Failed to write config file: {config_path:?}"))?;
+
+        Ok(())
+    }
+}
+
+/// Returns the TOML document `contents` with `saved` appended to `logs.saved_queries`.
+fn with_saved_query(contents: &str, saved: &SavedQuery) -> Result<String> {
+    let mut document: toml_edit::DocumentMut = contents.parse()?;
+
+    let logs = document
+        .entry("logs")
+        .or_insert_with(toml_edit::table)
+        .as_table_like_mut()
+        .context("'logs' is not a table")?;
+    let saved_queries = logs
+        .entry("saved_queries")
+        .or_insert(toml_edit::Item::ArrayOfTables(
+            toml_edit::ArrayOfTables::new(),
+        ));
+    match saved_queries {
+        toml_edit::Item::ArrayOfTables(tables) => {
+            let mut table = toml_edit::Table::new();
+            table["name"] = toml_edit::value(saved.name.as_str());
+            table["query"] = toml_edit::value(saved.query.as_str());
+            tables.push(table);
+        }
+        item => {
+            let array = item
+                .as_array_mut()
+                .context("'logs.saved_queries' is not an array")?;
+            let mut table = toml_edit::InlineTable::new();
+            table.insert("name", saved.name.as_str().into());
+            table.insert("query", saved.query.as_str().into());
+            array.push(table);
+        }
+    }
+
+    Ok(document.to_string())
 }
 
 #[cfg(test)]
@@ -431,6 +618,7 @@ mod tests {
         assert_eq!(config.behavior.refresh_interval, 30);
         assert_eq!(config.behavior.default_view, "clusters");
         assert!(!config.behavior.read_only);
+        assert!(config.behavior.production_patterns.is_empty());
         assert_eq!(config.ui.theme, "dark");
         assert!(config.metrics.enabled);
         assert_eq!(config.metrics.time_range_minutes, 60);
@@ -439,6 +627,23 @@ mod tests {
         assert!(config.aws.profile.is_none());
     }
 
+    #[test]
+    fn test_production_patterns_match_names() {
+        let toml_str = r#"
+[behavior]
+production_patterns = ["prod", "^live-", "[unclosed"]
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let behavior = &config.behavior;
+        assert!(behavior.is_production(&["cluster-prod", "web"]));
+        assert!(behavior.is_production(&["staging", "LIVE-api"]));
+        assert!(!behavior.is_production(&["staging", "deliver-live-api"]));
+        // Invalid regexes fall back to substring matching
+        assert!(behavior.is_production(&["dev", "svc[unclosed"]));
+        assert!(!BehaviorConfig::default().is_production(&["cluster-prod"]));
+    }
+
     #[test]
     fn test_config_deserialization() {
         let toml_str = r#"
@@ -478,4 +683,87 @@ region = "eu-west-1"
         assert!(config.behavior.auto_refresh);
         assert_eq!(config.behavior.refresh_interval, 30);
     }
+
+    #[test]
+    fn test_saved_queries_deserialization() {
+        let toml_str = r#"
+[logs]
+[[logs.saved_queries]]
+name = "Errors"
+query = "fields @message | filter @message like /ERROR/"
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.logs.saved_queries,
+            vec![SavedQuery {
+                name: "Errors".to_string(),
+                query: "fields @message | filter @message like /ERROR/".to_string(),
+            }]
+        );
+        assert_eq!(config.logs.max_buffer_lines, 10_000);
+        assert!(Config::default().logs.saved_queries.is_empty());
+    }
+
+    #[test]
+    fn test_with_saved_query_keeps_the_rest_of_the_file() {
+        let saved = SavedQuery {
+            name: "Query 2".to_string(),
+            query: "fields @message".to_string(),
+        };
+        let toml_str = r#"# My settings
+[behavior]
+read_only = false # flipped with --read-only
+
+[[logs.saved_queries]]
+name = "Errors"
+query = "fields @message | filter @message like /ERROR/"
+"#;
+
+        let contents = with_saved_query(toml_str, &saved).unwrap();
+        assert!(contents.starts_with(toml_str));
+        let config: Config = toml::from_str(&contents).unwrap();
+        assert_eq!(config.logs.saved_queries.len(), 2);
+        assert_eq!(config.logs.saved_queries[1], saved);
+        assert!(!config.behavior.read_only);
+
+        // A missing file or an inline array of queries
+        let config: Config = toml::from_str(&with_saved_query("", &saved).unwrap()).unwrap();
+        assert_eq!(config.logs.saved_queries, vec![saved.clone()]);
+        let contents = with_saved_query("[logs]\nsaved_queries = []\n", &saved).unwrap();
+        let config: Config = toml::from_str(&contents).unwrap();
+        assert_eq!(config.logs.saved_queries, vec![saved]);
+    }
+
+    #[test]
+    fn test_log_columns_deserialization() {
+        let toml_str = r#"
+[logs]
+columns = ["level", "trace_id"]
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.logs.columns, vec!["level", "trace_id"]);
+        assert!(Config::default().logs.columns.is_empty());
+    }
+
+    #[test]
+    fn test_multiline_pattern_deserialization() {
+        let toml_str = r#"
+[logs]
+multiline_pattern = ""
+
+[logs.multiline_patterns]
+web = "^\\s"
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.logs.multiline_pattern, "");
+        assert_eq!(config.logs.multiline_patterns["web"], "^\\s");
+
+        let config: Config = toml::from_str("[logs]\nmax_buffer_lines = 10").unwrap();
+        assert_eq!(config.logs.multiline_pattern, DEFAULT_CONTINUATION_PATTERN);
+        assert!(config.logs.multiline_patterns.is_empty());
+        assert_eq!(config.logs.context_minutes, 5);
+    }
 }
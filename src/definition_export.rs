@@ -0,0 +1,817 @@
+//! Export of task definitions and services to JSON, YAML, CloudFormation and Terraform.
+//!
+//! Exports start from the API-shaped documents of [`crate::ecs_json`] and leave out
+//! the fields ECS sets itself, so a task definition export can be passed back to
+//! `aws ecs register-task-definition --cli-input-json` and a service export to
+//! `aws ecs create-service`. The export dialog is described by a
+//! [`DefinitionExportDialog`]; files are written with [`crate::log_export::write_export`].
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+/// Fields of a task definition that ECS sets on registration.
+const TASK_DEFINITION_READ_ONLY_FIELDS: [&str; 8] = [
+    "taskDefinitionArn",
+    "revision",
+    "status",
+    "requiresAttributes",
+    "compatibilities",
+    "registeredAt",
+    "deregisteredAt",
+    "registeredBy",
+];
+
+/// Fields of a service that ECS reports but CreateService does not accept.
+const SERVICE_READ_ONLY_FIELDS: [&str; 10] = [
+    "serviceArn",
+    "status",
+    "runningCount",
+    "pendingCount",
+    "platformFamily",
+    "deployments",
+    "events",
+    "taskSets",
+    "createdAt",
+    "createdBy",
+];
+
+/// String maps whose keys are names chosen by the user rather than field names.
+const MAP_FIELDS: [&str; 4] = ["options", "dockerLabels", "driverOpts", "labels"];
+
+/// What is exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinitionSource {
+    /// A task definition revision, by ARN or `family:revision`
+    TaskDefinition(String),
+    /// A service of a cluster
+    Service { cluster: String, service: String },
+}
+
+impl DefinitionSource {
+    /// Returns the name the export file is named after, e.g. "web-3" or "web-service".
+    fn file_stem(&self) -> String {
+        match self {
+            DefinitionSource::TaskDefinition(task_definition) => task_definition
+                .rsplit('/')
+                .next()
+                .unwrap_or(task_definition)
+                .replace(':', "-"),
+            DefinitionSource::Service { service, .. } => service.clone(),
+        }
+    }
+
+    /// Returns a human-readable description, e.g. "task definition web:3".
+    pub fn label(&self) -> String {
+        match self {
+            DefinitionSource::TaskDefinition(task_definition) => format!(
+                "task definition {}",
+                task_definition
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or(task_definition)
+            ),
+            DefinitionSource::Service { service, .. } => format!("service {service}"),
+        }
+    }
+}
+
+/// File format of a task definition or service export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionFormat {
+    /// The RegisterTaskDefinition or CreateService input as JSON
+    Json,
+    /// The RegisterTaskDefinition or CreateService input as YAML
+    Yaml,
+    /// A CloudFormation template with an `AWS::ECS::TaskDefinition` resource
+    CloudFormation,
+    /// A Terraform `aws_ecs_task_definition` resource
+    Terraform,
+}
+
+impl DefinitionFormat {
+    /// Returns a human-readable label for the format.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DefinitionFormat::Json => "JSON",
+            DefinitionFormat::Yaml => "YAML",
+            DefinitionFormat::CloudFormation => "CloudFormation (AWS::ECS::TaskDefinition)",
+            DefinitionFormat::Terraform => "Terraform (aws_ecs_task_definition)",
+        }
+    }
+
+    /// Returns the file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DefinitionFormat::Json => "json",
+            DefinitionFormat::Yaml => "yaml",
+            DefinitionFormat::CloudFormation => "cfn.yaml",
+            DefinitionFormat::Terraform => "tf",
+        }
+    }
+
+    /// Returns the next format in the cycle; services are exported as JSON or YAML only.
+    pub fn next(&self, source: &DefinitionSource) -> DefinitionFormat {
+        match (self, source) {
+            (DefinitionFormat::Json, _) => DefinitionFormat::Yaml,
+            (DefinitionFormat::Yaml, DefinitionSource::TaskDefinition(_)) => {
+                DefinitionFormat::CloudFormation
+            }
+            (DefinitionFormat::CloudFormation, _) => DefinitionFormat::Terraform,
+            (DefinitionFormat::Yaml, DefinitionSource::Service { .. })
+            | (DefinitionFormat::Terraform, _) => DefinitionFormat::Json,
+        }
+    }
+}
+
+/// Fields of the definition export dialog, in the order Tab moves through them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionExportField {
+    Format,
+    Path,
+}
+
+/// Choices made in the task definition and service export dialog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefinitionExportDialog {
+    /// Task definition or service to export
+    pub source: DefinitionSource,
+    /// File format
+    pub format: DefinitionFormat,
+    /// Destination file; `~` is expanded to the home directory
+    pub path: String,
+    /// Field being edited
+    pub field: DefinitionExportField,
+}
+
+impl DefinitionExportDialog {
+    /// Creates a dialog exporting `source` as JSON to a file in `export_dir` named
+    /// after it, e.g. "task-definition-web-3.json".
+    pub fn new(export_dir: &str, source: DefinitionSource) -> Self {
+        let format = DefinitionFormat::Json;
+        let kind = match source {
+            DefinitionSource::TaskDefinition(_) => "task-definition",
+            DefinitionSource::Service { .. } => "service",
+        };
+        Self {
+            path: format!(
+                "{}/{kind}-{}.{}",
+                export_dir.trim_end_matches('/'),
+                source.file_stem(),
+                format.extension()
+            ),
+            source,
+            format,
+            field: DefinitionExportField::Format,
+        }
+    }
+
+    /// Moves to the other field.
+    pub fn next_field(&mut self) {
+        self.field = match self.field {
+            DefinitionExportField::Format => DefinitionExportField::Path,
+            DefinitionExportField::Path => DefinitionExportField::Format,
+        };
+    }
+
+    /// Cycles the format when it is being edited. A path ending in the old format's
+    /// extension follows the format.
+    pub fn change_field(&mut self) {
+        if self.field != DefinitionExportField::Format {
+            return;
+        }
+        let old_extension = format!(".{}", self.format.extension());
+        self.format = self.format.next(&self.source);
+        if let Some(stem) = self.path.strip_suffix(&old_extension) {
+            self.path = format!("{stem}.{}", self.format.extension());
+        }
+    }
+}
+
+/// Returns the RegisterTaskDefinition input of a task definition document.
+pub fn register_task_definition_input(document: &Value) -> Value {
+    without_fields(document, &TASK_DEFINITION_READ_ONLY_FIELDS)
+}
+
+/// Returns the CreateService input of a service document: read-only fields are left
+/// out and `clusterArn` and `roleArn` become `cluster` and `role`.
+pub fn create_service_input(document: &Value) -> Value {
+    let mut input = without_fields(document, &SERVICE_READ_ONLY_FIELDS);
+    if let Value::Object(map) = &mut input {
+        for (from, to) in [("clusterArn", "cluster"), ("roleArn", "role")] {
+            if let Some(value) = map.remove(from) {
+                map.insert(to.to_string(), value);
+            }
+        }
+    }
+    input
+}
+
+fn without_fields(document: &Value, fields: &[&str]) -> Value {
+    let mut input = document.clone();
+    if let Value::Object(map) = &mut input {
+        for field in fields {
+            map.remove(*field);
+        }
+    }
+    input
+}
+
+/// Formats the document of a task definition or service in an export format.
+///
+/// # Errors
+/// This function will return an error if a service is exported as a CloudFormation
+/// or Terraform task definition, or the document cannot be serialized.
+pub fn format_definition(
+    document: &Value,
+    source: &DefinitionSource,
+    format: DefinitionFormat,
+) -> Result<String> {
+    let input = match source {
+        DefinitionSource::TaskDefinition(_) => register_task_definition_input(document),
+        DefinitionSource::Service { .. } => create_service_input(document),
+    };
+    match (format, source) {
+        (DefinitionFormat::Json, _) => Ok(format!("{}\n", serde_json::to_string_pretty(&input)?)),
+        (DefinitionFormat::Yaml, _) => Ok(to_yaml(&input, 0)),
+        (DefinitionFormat::CloudFormation, DefinitionSource::TaskDefinition(_)) => {
+            Ok(cloudformation_template(&input))
+        }
+        (DefinitionFormat::Terraform, DefinitionSource::TaskDefinition(_)) => {
+            Ok(terraform_resource(&input))
+        }
+        (_, DefinitionSource::Service { .. }) => {
+            anyhow::bail!("Services are exported as JSON or YAML")
+        }
+    }
+}
+
+/// Writes a JSON value as a YAML block indented by `indent` spaces.
+///
+/// Strings that YAML would read as another type, or that contain special
+/// characters, are written as double-quoted (JSON) strings.
+pub fn to_yaml(value: &Value, indent: usize) -> String {
+    let mut out = String::new();
+    if is_collection(value) {
+        yaml_block(&mut out, value, indent);
+    } else {
+        out.push_str(&format!("{}{}\n", " ".repeat(indent), yaml_scalar(value)));
+    }
+    out
+}
+
+/// Whether a value is a non-empty object or array, written as a nested block.
+fn is_collection(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => !map.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        _ => false,
+    }
+}
+
+fn yaml_block(out: &mut String, value: &Value, indent: usize) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map {
+                out.push_str(&format!("{pad}{}:", yaml_string(key)));
+                yaml_child(out, item, indent);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                out.push_str(&format!("{pad}-"));
+                if matches!(item, Value::Object(_)) && is_collection(item) {
+                    // The first key of an object goes on the line of its dash
+                    let mut nested = String::new();
+                    yaml_block(&mut nested, item, indent + 2);
+                    out.push(' ');
+                    out.push_str(nested.trim_start());
+                } else {
+                    yaml_child(out, item, indent);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn yaml_child(out: &mut String, value: &Value, indent: usize) {
+    if is_collection(value) {
+        out.push('\n');
+        yaml_block(out, value, indent + 2);
+    } else {
+        out.push(' ');
+        out.push_str(&yaml_scalar(value));
+        out.push('\n');
+    }
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::String(text) => yaml_string(text),
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Writes a string plain when YAML reads it back as the same string, quoted otherwise.
+fn yaml_string(text: &str) -> String {
+    let reserved = [
+        "true", "false", "yes", "no", "on", "off", "y", "n", "null", "~",
+    ];
+    let plain = text
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '_')
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || " -_./:@=+".contains(c))
+        && !text.ends_with([' ', ':'])
+        && !text.contains(": ")
+        && !reserved.contains(&text.to_ascii_lowercase().as_str());
+    if plain {
+        text.to_string()
+    } else {
+        Value::from(text).to_string()
+    }
+}
+
+/// Returns a CloudFormation template declaring the task definition of a
+/// RegisterTaskDefinition input, with a logical ID named after its family.
+fn cloudformation_template(input: &Value) -> String {
+    let family = input
+        .get("family")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let logical_id: String = family
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    format!(
+        "Resources:\n  {logical_id}TaskDefinition:\n    Type: AWS::ECS::TaskDefinition\n    Properties:\n{}",
+        to_yaml(&cloudformation_value(input, ""), 6)
+    )
+}
+
+/// Renames the fields of an API document to CloudFormation property names.
+fn cloudformation_value(value: &Value, parent: &str) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| {
+                    let item = if MAP_FIELDS.contains(&key.as_str()) {
+                        item.clone()
+                    } else {
+                        cloudformation_value(item, key)
+                    };
+                    (cloudformation_key(parent, key), item)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| cloudformation_value(item, parent))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Returns the CloudFormation name of an API field: the field in PascalCase, except
+/// where CloudFormation spells it differently.
+fn cloudformation_key(parent: &str, key: &str) -> String {
+    match (parent, key) {
+        ("efsVolumeConfiguration", "fileSystemId") => "FilesystemId".to_string(),
+        ("proxyConfiguration", "properties") => "ProxyConfigurationProperties".to_string(),
+        (_, "efsVolumeConfiguration") => "EFSVolumeConfiguration".to_string(),
+        (_, "fsxWindowsFileServerVolumeConfiguration") => {
+            "FSxWindowsFileServerVolumeConfiguration".to_string()
+        }
+        (_, "iam") => "IAM".to_string(),
+        _ => {
+            let mut chars = key.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+    }
+}
+
+/// Returns a Terraform `aws_ecs_task_definition` resource for a RegisterTaskDefinition
+/// input. Container definitions are passed to `jsonencode`, nested settings become
+/// blocks and tags a map.
+fn terraform_resource(input: &Value) -> String {
+    let empty = Map::new();
+    let map = input.as_object().unwrap_or(&empty);
+    let family = map
+        .get("family")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let mut name: String = family
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name = format!("task_definition_{name}");
+    }
+
+    let mut body = Vec::new();
+    if let Some(family) = map.get("family") {
+        body.push(HclItem::Attribute(
+            "family".to_string(),
+            hcl_expression(family, 2),
+        ));
+    }
+    for (key, value) in map {
+        match key.as_str() {
+            "family" => {}
+            "containerDefinitions" => body.push(HclItem::Attribute(
+                "container_definitions".to_string(),
+                format!("jsonencode({})", hcl_expression(value, 2)),
+            )),
+            "tags" => {
+                let tags: Map<String, Value> = value
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|tag| {
+                        let key = tag.get("key")?.as_str()?;
+                        Some((
+                            key.to_string(),
+                            tag.get("value").cloned().unwrap_or_default(),
+                        ))
+                    })
+                    .collect();
+                body.push(HclItem::Attribute(
+                    "tags".to_string(),
+                    hcl_expression(&Value::Object(tags), 2),
+                ));
+            }
+            "proxyConfiguration" => {
+                let mut proxy = value.clone();
+                if let Some(proxy) = proxy.as_object_mut() {
+                    let properties: Map<String, Value> = proxy
+                        .remove("properties")
+                        .and_then(|properties| properties.as_array().cloned())
+                        .unwrap_or_default()
+                        .iter()
+                        .filter_map(|property| {
+                            let name = property.get("name")?.as_str()?;
+                            Some((
+                                name.to_string(),
+                                property.get("value").cloned().unwrap_or_default(),
+                            ))
+                        })
+                        .collect();
+                    proxy.insert("properties".to_string(), Value::Object(properties));
+                }
+                body.push(HclItem::Block(
+                    "proxy_configuration".to_string(),
+                    terraform_body(&proxy, 4),
+                ));
+            }
+            _ => body.extend(terraform_items(key, value, 2)),
+        }
+    }
+    sort_hcl_body(&mut body);
+
+    let mut out = format!("resource \"aws_ecs_task_definition\" \"{name}\" {{\n");
+    write_hcl_body(&mut out, &body, 2);
+    out.push_str("}\n");
+    out
+}
+
+/// An attribute (name and expression) or a nested block of a Terraform body.
+enum HclItem {
+    Attribute(String, String),
+    Block(String, Vec<HclItem>),
+}
+
+/// Converts an API field to Terraform attributes or blocks at `indent`.
+fn terraform_items(key: &str, value: &Value, indent: usize) -> Vec<HclItem> {
+    let name = terraform_name(key);
+    match value {
+        Value::Object(_) if MAP_FIELDS.contains(&key) || key == "properties" => {
+            vec![HclItem::Attribute(name, hcl_expression(value, indent))]
+        }
+        // A host volume is a `host_path` attribute of the volume block
+        Value::Object(map) if key == "host" => map
+            .get("sourcePath")
+            .map(|path| HclItem::Attribute("host_path".to_string(), hcl_expression(path, indent)))
+            .into_iter()
+            .collect(),
+        Value::Object(_) => vec![HclItem::Block(name, terraform_body(value, indent + 2))],
+        Value::Array(items) if items.iter().all(Value::is_object) && !items.is_empty() => {
+            let block = match key {
+                "volumes" => "volume".to_string(),
+                "inferenceAccelerators" => "inference_accelerator".to_string(),
+                _ => name,
+            };
+            items
+                .iter()
+                .map(|item| HclItem::Block(block.clone(), terraform_body(item, indent + 2)))
+                .collect()
+        }
+        _ => vec![HclItem::Attribute(name, hcl_expression(value, indent))],
+    }
+}
+
+fn terraform_body(value: &Value, indent: usize) -> Vec<HclItem> {
+    let mut body: Vec<HclItem> = value
+        .as_object()
+        .into_iter()
+        .flatten()
+        .flat_map(|(key, item)| terraform_items(key, item, indent))
+        .collect();
+    sort_hcl_body(&mut body);
+    body
+}
+
+/// Moves the attributes of a body before its blocks, keeping their order.
+fn sort_hcl_body(body: &mut [HclItem]) {
+    body.sort_by_key(|item| matches!(item, HclItem::Block(..)));
+}
+
+/// Returns the Terraform name of an API field: the field in snake_case, except
+/// where the provider spells it differently.
+fn terraform_name(key: &str) -> String {
+    match key {
+        "sizeInGiB" => "size_in_gib".to_string(),
+        "configuredAtLaunch" => "configure_at_launch".to_string(),
+        _ => {
+            let mut name = String::new();
+            for c in key.chars() {
+                if c.is_ascii_uppercase() {
+                    name.push('_');
+                    name.push(c.to_ascii_lowercase());
+                } else {
+                    name.push(c);
+                }
+            }
+            name
+        }
+    }
+}
+
+fn write_hcl_body(out: &mut String, items: &[HclItem], indent: usize) {
+    let pad = " ".repeat(indent);
+    for (index, item) in items.iter().enumerate() {
+        match item {
+            HclItem::Attribute(name, expression) => {
+                out.push_str(&format!("{pad}{name} = {expression}\n"));
+            }
+            HclItem::Block(name, body) => {
+                if index > 0 {
+                    out.push('\n');
+                }
+                out.push_str(&format!("{pad}{name} {{\n"));
+                write_hcl_body(out, body, indent + 2);
+                out.push_str(&format!("{pad}}}\n"));
+            }
+        }
+    }
+}
+
+/// Writes a JSON value as an HCL expression whose nested lines are indented
+/// relative to `indent`.
+fn hcl_expression(value: &Value, indent: usize) -> String {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::String(text) => hcl_string(text),
+        Value::Array(items) if items.iter().all(|item| !is_collection(item)) => {
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| hcl_expression(item, indent))
+                .collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Array(items) => {
+            let mut out = "[\n".to_string();
+            for item in items {
+                out.push_str(&format!("{pad}  {},\n", hcl_expression(item, indent + 2)));
+            }
+            out.push_str(&format!("{pad}]"));
+            out
+        }
+        Value::Object(map) if map.is_empty() => "{}".to_string(),
+        Value::Object(map) => {
+            let mut out = "{\n".to_string();
+            for (key, item) in map {
+                let is_identifier = key.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && key
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+                let key = if is_identifier {
+                    key.clone()
+                } else {
+                    hcl_string(key)
+                };
+                out.push_str(&format!(
+                    "{pad}  {key} = {}\n",
+                    hcl_expression(item, indent + 2)
+                ));
+            }
+            out.push_str(&format!("{pad}}}"));
+            out
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Quotes a string for HCL, escaping template sequences so `${` stays literal.
+fn hcl_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '$' | '%' if chars.peek() == Some(&'{') => {
+                out.push(c);
+                out.push(c);
+            }
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn task_definition_document() -> Value {
+        json!({
+            "taskDefinitionArn": "arn:aws:ecs:us-east-1:123456789012:task-definition/web-api:3",
+            "family": "web-api",
+            "revision": 3,
+            "status": "ACTIVE",
+            "registeredAt": "2024-01-01T00:00:00.000Z",
+            "registeredBy": "arn:aws:iam::123456789012:role/deployer",
+            "compatibilities": ["EC2", "FARGATE"],
+            "requiresCompatibilities": ["FARGATE"],
+            "networkMode": "awsvpc",
+            "cpu": "256",
+            "memory": "512",
+            "containerDefinitions": [{
+                "name": "web",
+                "image": "nginx:1.27",
+                "essential": true,
+                "command": ["sh", "-c", "echo ${HOME}"],
+                "logConfiguration": {
+                    "logDriver": "awslogs",
+                    "options": {"awslogs-group": "/ecs/web"}
+                }
+            }],
+            "volumes": [{
+                "name": "data",
+                "efsVolumeConfiguration": {
+                    "fileSystemId": "fs-1",
+                    "authorizationConfig": {"iam": "ENABLED"}
+                }
+            }, {
+                "name": "scratch",
+                "host": {"sourcePath": "/tmp"}
+            }],
+            "ephemeralStorage": {"sizeInGiB": 30},
+            "tags": [{"key": "team", "value": "web"}]
+        })
+    }
+
+    #[test]
+    fn test_exports_leave_out_read_only_fields() {
+        let input = register_task_definition_input(&task_definition_document());
+        for field in TASK_DEFINITION_READ_ONLY_FIELDS {
+            assert!(input.get(field).is_none(), "{field} was exported");
+        }
+        assert_eq!(input["family"], "web-api");
+        assert_eq!(input["requiresCompatibilities"], json!(["FARGATE"]));
+
+        let service = json!({
+            "serviceArn": "arn:aws:ecs:us-east-1:123456789012:service/prod/web-service",
+            "serviceName": "web-service",
+            "clusterArn": "arn:aws:ecs:us-east-1:123456789012:cluster/prod",
+            "status": "ACTIVE",
+            "desiredCount": 2,
+            "runningCount": 2,
+            "events": [{"message": "steady state"}]
+        });
+        assert_eq!(
+            create_service_input(&service),
+            json!({
+                "serviceName": "web-service",
+                "cluster": "arn:aws:ecs:us-east-1:123456789012:cluster/prod",
+                "desiredCount": 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_yaml_quotes_strings_that_would_change_type() {
+        let value = json!({
+            "cpu": "256",
+            "image": "nginx:1.27",
+            "enabled": "yes",
+            "essential": true,
+            "command": ["echo", "a: b"],
+            "mounts": [{"sourceVolume": "data", "readOnly": false}],
+            "empty": []
+        });
+        assert_eq!(
+            to_yaml(&value, 0),
+            "command:\n  - echo\n  - \"a: b\"\ncpu: \"256\"\nempty: []\nenabled: \"yes\"\nessential: true\nimage: nginx:1.27\nmounts:\n  - readOnly: false\n    sourceVolume: data\n"
+        );
+    }
+
+    #[test]
+    fn test_cloudformation_uses_property_names() -> Result<()> {
+        let source = DefinitionSource::TaskDefinition("web-api:3".to_string());
+        let template = format_definition(
+            &task_definition_document(),
+            &source,
+            DefinitionFormat::CloudFormation,
+        )?;
+
+        assert!(template.starts_with(
+            "Resources:\n  WebApiTaskDefinition:\n    Type: AWS::ECS::TaskDefinition\n    Properties:\n      ContainerDefinitions:\n        - Command:\n"
+        ));
+        assert!(template.contains("          LogConfiguration:\n            LogDriver: awslogs\n            Options:\n              awslogs-group: /ecs/web\n"));
+        assert!(template.contains("        - EFSVolumeConfiguration:\n            AuthorizationConfig:\n              IAM: ENABLED\n            FilesystemId: fs-1\n"));
+        assert!(template.contains("      Tags:\n        - Key: team\n          Value: web\n"));
+        assert!(!template.contains("Revision"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_terraform_resource_uses_blocks_and_jsonencode() -> Result<()> {
+        let source = DefinitionSource::TaskDefinition("web-api:3".to_string());
+        let resource = format_definition(
+            &task_definition_document(),
+            &source,
+            DefinitionFormat::Terraform,
+        )?;
+
+        assert!(resource.starts_with(
+            "resource \"aws_ecs_task_definition\" \"web_api\" {\n  family = \"web-api\"\n  container_definitions = jsonencode([\n    {\n      command = [\"sh\", \"-c\", \"echo $${HOME}\"]\n"
+        ));
+        assert!(resource.contains("  requires_compatibilities = [\"FARGATE\"]\n"));
+        assert!(resource.contains("  tags = {\n    team = \"web\"\n  }\n"));
+        assert!(resource.contains("\n  ephemeral_storage {\n    size_in_gib = 30\n  }\n"));
+        assert!(resource
+            .contains("\n  volume {\n    host_path = \"/tmp\"\n    name = \"scratch\"\n  }\n"));
+        assert!(resource.contains("  volume {\n    name = \"data\"\n\n    efs_volume_configuration {\n      file_system_id = \"fs-1\"\n\n      authorization_config {\n        iam = \"ENABLED\"\n      }\n    }\n  }\n"));
+        assert!(resource.ends_with("}\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dialog_cycles_formats_by_source() {
+        let mut dialog = DefinitionExportDialog::new(
+            "~/Downloads/",
+            DefinitionSource::TaskDefinition(
+                "arn:aws:ecs:us-east-1:123456789012:task-definition/web:3".to_string(),
+            ),
+        );
+        assert_eq!(dialog.path, "~/Downloads/task-definition-web-3.json");
+        for extension in ["yaml", "cfn.yaml", "tf", "json"] {
+            dialog.change_field();
+            assert!(dialog.path.ends_with(&format!("web-3.{extension}")));
+        }
+
+        let mut dialog = DefinitionExportDialog::new(
+            "/tmp",
+            DefinitionSource::Service {
+                cluster: "prod".to_string(),
+                service: "web-service".to_string(),
+            },
+        );
+        dialog.change_field();
+        dialog.change_field();
+        assert_eq!(dialog.format, DefinitionFormat::Json);
+        assert_eq!(dialog.path, "/tmp/service-web-service.json");
+        dialog.next_field();
+        dialog.change_field();
+        assert_eq!(dialog.format, DefinitionFormat::Json);
+    }
+}
@@ -0,0 +1,857 @@
+//! JSON documents of ECS resources in the shape of the ECS API.
+//!
+//! The SDK types don't implement `Serialize`, so [`task_definition_document`] and
+//! [`service_document`] write them out field by field, with the camelCase names the
+//! API and the AWS CLI use. Absent values and empty lists and maps are left out.
+
+use std::collections::HashMap;
+
+use aws_sdk_ecs::primitives::DateTime;
+use aws_sdk_ecs::types::{
+    Attribute, AwsVpcConfiguration, CapacityProviderStrategyItem, ContainerDefinition,
+    ContainerDependency, ContainerRestartPolicy, Deployment, DeploymentAlarms,
+    DeploymentCircuitBreaker, DeploymentConfiguration, DeploymentController, Device,
+    DockerVolumeConfiguration, EfsAuthorizationConfig, EfsVolumeConfiguration, EnvironmentFile,
+    EphemeralStorage, FSxWindowsFileServerAuthorizationConfig,
+    FSxWindowsFileServerVolumeConfiguration, FirelensConfiguration, HealthCheck, HostEntry,
+    HostVolumeProperties, InferenceAccelerator, KernelCapabilities, KeyValuePair, LinuxParameters,
+    LoadBalancer, LogConfiguration, MountPoint, NetworkConfiguration, PlacementConstraint,
+    PlacementStrategy, PortMapping, ProxyConfiguration, RepositoryCredentials, ResourceRequirement,
+    RuntimePlatform, Secret, Service, ServiceEvent, ServiceRegistry, SystemControl, Tag,
+    TaskDefinition, TaskDefinitionPlacementConstraint, Tmpfs, Ulimit, Volume, VolumeFrom,
+};
+use serde_json::{Map, Value};
+
+/// Returns the JSON document of a task definition, as DescribeTaskDefinition returns
+/// it, with the task definition's `tags` added.
+pub fn task_definition_document(task_definition: &TaskDefinition, tags: &[Tag]) -> Value {
+    let td = task_definition;
+    Document::default()
+        .text("taskDefinitionArn", td.task_definition_arn())
+        .text("family", td.family())
+        .number("revision", td.revision())
+        .name("status", td.status())
+        .items("containerDefinitions", td.container_definitions())
+        .text("taskRoleArn", td.task_role_arn())
+        .text("executionRoleArn", td.execution_role_arn())
+        .name("networkMode", td.network_mode())
+        .items("volumes", td.volumes())
+        .items("requiresAttributes", td.requires_attributes())
+        .items("placementConstraints", td.placement_constraints())
+        .names("compatibilities", td.compatibilities())
+        .item("runtimePlatform", td.runtime_platform())
+        .names("requiresCompatibilities", td.requires_compatibilities())
+        .text("cpu", td.cpu())
+        .text("memory", td.memory())
+        .items("inferenceAccelerators", td.inference_accelerators())
+        .name("pidMode", td.pid_mode())
+        .name("ipcMode", td.ipc_mode())
+        .item("proxyConfiguration", td.proxy_configuration())
+        .time("registeredAt", td.registered_at())
+        .time("deregisteredAt", td.deregistered_at())
+        .text("registeredBy", td.registered_by())
+        .item("ephemeralStorage", td.ephemeral_storage())
+        .flag("enableFaultInjection", td.enable_fault_injection())
+        .items("tags", tags)
+        .build()
+}
+
+/// Returns the JSON document of a service, as DescribeServices returns it.
+///
+/// Task sets, Service Connect and VPC Lattice settings are not included.
+pub fn service_document(service: &Service) -> Value {
+    let svc = service;
+    Document::default()
+        .text("serviceArn", svc.service_arn())
+        .text("serviceName", svc.service_name())
+        .text("clusterArn", svc.cluster_arn())
+        .items("loadBalancers", svc.load_balancers())
+        .items("serviceRegistries", svc.service_registries())
+        .text("status", svc.status())
+        .number("desiredCount", svc.desired_count())
+        .number("runningCount", svc.running_count())
+        .number("pendingCount", svc.pending_count())
+        .name("launchType", svc.launch_type())
+        .items("capacityProviderStrategy", svc.capacity_provider_strategy())
+        .text("platformVersion", svc.platform_version())
+        .text("platformFamily", svc.platform_family())
+        .text("taskDefinition", svc.task_definition())
+        .item("deploymentConfiguration", svc.deployment_configuration())
+        .items("deployments", svc.deployments())
+        .text("roleArn", svc.role_arn())
+        .items("events", svc.events())
+        .time("createdAt", svc.created_at())
+        .items("placementConstraints", svc.placement_constraints())
+        .items("placementStrategy", svc.placement_strategy())
+        .item("networkConfiguration", svc.network_configuration())
+        .number(
+            "healthCheckGracePeriodSeconds",
+            svc.health_check_grace_period_seconds(),
+        )
+        .name("schedulingStrategy", svc.scheduling_strategy())
+        .item("deploymentController", svc.deployment_controller())
+        .items("tags", svc.tags())
+        .text("createdBy", svc.created_by())
+        .flag("enableECSManagedTags", svc.enable_ecs_managed_tags())
+        .name("propagateTags", svc.propagate_tags())
+        .flag("enableExecuteCommand", svc.enable_execute_command())
+        .name(
+            "availabilityZoneRebalancing",
+            svc.availability_zone_rebalancing(),
+        )
+        .build()
+}
+
+/// An SDK type with a JSON document.
+trait ToDocument {
+    fn to_document(&self) -> Value;
+}
+
+/// Builder of a JSON object that leaves out absent values and empty lists and maps.
+#[derive(Default)]
+struct Document(Map<String, Value>);
+
+impl Document {
+    fn set(mut self, key: &str, value: Value) -> Self {
+        self.0.insert(key.to_string(), value);
+        self
+    }
+
+    fn text(self, key: &str, value: Option<&str>) -> Self {
+        match value {
+            Some(value) => self.set(key, Value::from(value)),
+            None => self,
+        }
+    }
+
+    fn number(self, key: &str, value: impl Into<Option<i32>>) -> Self {
+        match value.into() {
+            Some(value) => self.set(key, Value::from(value)),
+            None => self,
+        }
+    }
+
+    fn flag(self, key: &str, value: impl Into<Option<bool>>) -> Self {
+        match value.into() {
+            Some(value) => self.set(key, Value::from(value)),
+            None => self,
+        }
+    }
+
+    /// Adds an enum value by its API name.
+    fn name<E: AsRef<str>>(self, key: &str, value: Option<&E>) -> Self {
+        self.text(key, value.map(AsRef::as_ref))
+    }
+
+    fn texts(self, key: &str, values: &[String]) -> Self {
+        if values.is_empty() {
+            return self;
+        }
+        self.set(key, Value::from(values.to_vec()))
+    }
+
+    fn names<E: AsRef<str>>(self, key: &str, values: &[E]) -> Self {
+        if values.is_empty() {
+            return self;
+        }
+        let names: Vec<&str> = values.iter().map(AsRef::as_ref).collect();
+        self.set(key, Value::from(names))
+    }
+
+    /// Adds a string map with its keys sorted.
+    fn labels(self, key: &str, values: Option<&HashMap<String, String>>) -> Self {
+        match values.filter(|values| !values.is_empty()) {
+            Some(values) => {
+                let map = values
+                    .iter()
+                    .map(|(name, value)| (name.clone(), Value::from(value.as_str())))
+                    .collect();
+                self.set(key, Value::Object(map))
+            }
+            None => self,
+        }
+    }
+
+    fn item<T: ToDocument>(self, key: &str, value: Option<&T>) -> Self {
+        match value {
+            Some(value) => self.set(key, value.to_document()),
+            None => self,
+        }
+    }
+
+    fn items<T: ToDocument>(self, key: &str, values: &[T]) -> Self {
+        if values.is_empty() {
+            return self;
+        }
+        let items = values.iter().map(ToDocument::to_document).collect();
+        self.set(key, Value::Array(items))
+    }
+
+    /// Adds a time as an RFC 3339 UTC timestamp, like the AWS CLI prints it.
+    fn time(self, key: &str, value: Option<&DateTime>) -> Self {
+        let time = value
+            .and_then(|time| chrono::DateTime::from_timestamp(time.secs(), time.subsec_nanos()))
+            .map(|time| time.to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+        self.text(key, time.as_deref())
+    }
+
+    fn build(self) -> Value {
+        Value::Object(self.0)
+    }
+}
+
+impl ToDocument for ContainerDefinition {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("name", self.name())
+            .text("image", self.image())
+            .item("repositoryCredentials", self.repository_credentials())
+            .number("cpu", self.cpu())
+            .number("memory", self.memory())
+            .number("memoryReservation", self.memory_reservation())
+            .texts("links", self.links())
+            .items("portMappings", self.port_mappings())
+            .flag("essential", self.essential())
+            .item("restartPolicy", self.restart_policy())
+            .texts("entryPoint", self.entry_point())
+            .texts("command", self.command())
+            .items("environment", self.environment())
+            .items("environmentFiles", self.environment_files())
+            .items("mountPoints", self.mount_points())
+            .items("volumesFrom", self.volumes_from())
+            .item("linuxParameters", self.linux_parameters())
+            .items("secrets", self.secrets())
+            .items("dependsOn", self.depends_on())
+            .number("startTimeout", self.start_timeout())
+            .number("stopTimeout", self.stop_timeout())
+            .name("versionConsistency", self.version_consistency())
+            .text("hostname", self.hostname())
+            .text("user", self.user())
+            .text("workingDirectory", self.working_directory())
+            .flag("disableNetworking", self.disable_networking())
+            .flag("privileged", self.privileged())
+            .flag("readonlyRootFilesystem", self.readonly_root_filesystem())
+            .texts("dnsServers", self.dns_servers())
+            .texts("dnsSearchDomains", self.dns_search_domains())
+            .items("extraHosts", self.extra_hosts())
+            .texts("dockerSecurityOptions", self.docker_security_options())
+            .flag("interactive", self.interactive())
+            .flag("pseudoTerminal", self.pseudo_terminal())
+            .labels("dockerLabels", self.docker_labels())
+            .items("ulimits", self.ulimits())
+            .item("logConfiguration", self.log_configuration())
+            .item("healthCheck", self.health_check())
+            .items("systemControls", self.system_controls())
+            .items("resourceRequirements", self.resource_requirements())
+            .item("firelensConfiguration", self.firelens_configuration())
+            .texts("credentialSpecs", self.credential_specs())
+            .build()
+    }
+}
+
+impl ToDocument for RepositoryCredentials {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("credentialsParameter", Some(self.credentials_parameter()))
+            .build()
+    }
+}
+
+impl ToDocument for PortMapping {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .number("containerPort", self.container_port())
+            .number("hostPort", self.host_port())
+            .name("protocol", self.protocol())
+            .text("name", self.name())
+            .name("appProtocol", self.app_protocol())
+            .text("containerPortRange", self.container_port_range())
+            .build()
+    }
+}
+
+impl ToDocument for ContainerRestartPolicy {
+    fn to_document(&self) -> Value {
+        let document = Document::default().flag("enabled", self.enabled());
+        let document = if self.ignored_exit_codes().is_empty() {
+            document
+        } else {
+            document.set(
+                "ignoredExitCodes",
+                Value::from(self.ignored_exit_codes().to_vec()),
+            )
+        };
+        document
+            .number("restartAttemptPeriod", self.restart_attempt_period())
+            .build()
+    }
+}
+
+impl ToDocument for KeyValuePair {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("name", self.name())
+            .text("value", self.value())
+            .build()
+    }
+}
+
+impl ToDocument for EnvironmentFile {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("value", Some(self.value()))
+            .name("type", Some(self.r#type()))
+            .build()
+    }
+}
+
+impl ToDocument for MountPoint {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("sourceVolume", self.source_volume())
+            .text("containerPath", self.container_path())
+            .flag("readOnly", self.read_only())
+            .build()
+    }
+}
+
+impl ToDocument for VolumeFrom {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("sourceContainer", self.source_container())
+            .flag("readOnly", self.read_only())
+            .build()
+    }
+}
+
+impl ToDocument for LinuxParameters {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .item("capabilities", self.capabilities())
+            .items("devices", self.devices())
+            .flag("initProcessEnabled", self.init_process_enabled())
+            .number("sharedMemorySize", self.shared_memory_size())
+            .items("tmpfs", self.tmpfs())
+            .number("maxSwap", self.max_swap())
+            .number("swappiness", self.swappiness())
+            .build()
+    }
+}
+
+impl ToDocument for KernelCapabilities {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .texts("add", self.add())
+            .texts("drop", self.drop())
+            .build()
+    }
+}
+
+impl ToDocument for Device {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("hostPath", Some(self.host_path()))
+            .text("containerPath", self.container_path())
+            .names("permissions", self.permissions())
+            .build()
+    }
+}
+
+impl ToDocument for Tmpfs {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("containerPath", Some(self.container_path()))
+            .number("size", self.size())
+            .texts("mountOptions", self.mount_options())
+            .build()
+    }
+}
+
+impl ToDocument for Secret {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("name", Some(self.name()))
+            .text("valueFrom", Some(self.value_from()))
+            .build()
+    }
+}
+
+impl ToDocument for ContainerDependency {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("containerName", Some(self.container_name()))
+            .name("condition", Some(self.condition()))
+            .build()
+    }
+}
+
+impl ToDocument for HostEntry {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("hostname", Some(self.hostname()))
+            .text("ipAddress", Some(self.ip_address()))
+            .build()
+    }
+}
+
+impl ToDocument for Ulimit {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("name", Some(self.name()))
+            .number("softLimit", self.soft_limit())
+            .number("hardLimit", self.hard_limit())
+            .build()
+    }
+}
+
+impl ToDocument for LogConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("logDriver", Some(self.log_driver()))
+            .labels("options", self.options())
+            .items("secretOptions", self.secret_options())
+            .build()
+    }
+}
+
+impl ToDocument for HealthCheck {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .texts("command", self.command())
+            .number("interval", self.interval())
+            .number("timeout", self.timeout())
+            .number("retries", self.retries())
+            .number("startPeriod", self.start_period())
+            .build()
+    }
+}
+
+impl ToDocument for SystemControl {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("namespace", self.namespace())
+            .text("value", self.value())
+            .build()
+    }
+}
+
+impl ToDocument for ResourceRequirement {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("value", Some(self.value()))
+            .name("type", Some(self.r#type()))
+            .build()
+    }
+}
+
+impl ToDocument for FirelensConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("type", Some(self.r#type()))
+            .labels("options", self.options())
+            .build()
+    }
+}
+
+impl ToDocument for Volume {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("name", self.name())
+            .item("host", self.host())
+            .item(
+                "dockerVolumeConfiguration",
+                self.docker_volume_configuration(),
+            )
+            .item("efsVolumeConfiguration", self.efs_volume_configuration())
+            .item(
+                "fsxWindowsFileServerVolumeConfiguration",
+                self.fsx_windows_file_server_volume_configuration(),
+            )
+            .flag("configuredAtLaunch", self.configured_at_launch())
+            .build()
+    }
+}
+
+impl ToDocument for HostVolumeProperties {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("sourcePath", self.source_path())
+            .build()
+    }
+}
+
+impl ToDocument for DockerVolumeConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("scope", self.scope())
+            .flag("autoprovision", self.autoprovision())
+            .text("driver", self.driver())
+            .labels("driverOpts", self.driver_opts())
+            .labels("labels", self.labels())
+            .build()
+    }
+}
+
+impl ToDocument for EfsVolumeConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("fileSystemId", Some(self.file_system_id()))
+            .text("rootDirectory", self.root_directory())
+            .name("transitEncryption", self.transit_encryption())
+            .number("transitEncryptionPort", self.transit_encryption_port())
+            .item("authorizationConfig", self.authorization_config())
+            .build()
+    }
+}
+
+impl ToDocument for EfsAuthorizationConfig {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("accessPointId", self.access_point_id())
+            .name("iam", self.iam())
+            .build()
+    }
+}
+
+impl ToDocument for FSxWindowsFileServerVolumeConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("fileSystemId", Some(self.file_system_id()))
+            .text("rootDirectory", Some(self.root_directory()))
+            .item("authorizationConfig", self.authorization_config())
+            .build()
+    }
+}
+
+impl ToDocument for FSxWindowsFileServerAuthorizationConfig {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("credentialsParameter", Some(self.credentials_parameter()))
+            .text("domain", Some(self.domain()))
+            .build()
+    }
+}
+
+impl ToDocument for Attribute {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("name", Some(self.name()))
+            .text("value", self.value())
+            .name("targetType", self.target_type())
+            .text("targetId", self.target_id())
+            .build()
+    }
+}
+
+impl ToDocument for TaskDefinitionPlacementConstraint {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("type", self.r#type())
+            .text("expression", self.expression())
+            .build()
+    }
+}
+
+impl ToDocument for RuntimePlatform {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("cpuArchitecture", self.cpu_architecture())
+            .name("operatingSystemFamily", self.operating_system_family())
+            .build()
+    }
+}
+
+impl ToDocument for InferenceAccelerator {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("deviceName", Some(self.device_name()))
+            .text("deviceType", Some(self.device_type()))
+            .build()
+    }
+}
+
+impl ToDocument for ProxyConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("type", self.r#type())
+            .text("containerName", Some(self.container_name()))
+            .items("properties", self.properties())
+            .build()
+    }
+}
+
+impl ToDocument for EphemeralStorage {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .number("sizeInGiB", self.size_in_gib())
+            .build()
+    }
+}
+
+impl ToDocument for Tag {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("key", self.key())
+            .text("value", self.value())
+            .build()
+    }
+}
+
+impl ToDocument for LoadBalancer {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("targetGroupArn", self.target_group_arn())
+            .text("loadBalancerName", self.load_balancer_name())
+            .text("containerName", self.container_name())
+            .number("containerPort", self.container_port())
+            .build()
+    }
+}
+
+impl ToDocument for ServiceRegistry {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("registryArn", self.registry_arn())
+            .number("port", self.port())
+            .text("containerName", self.container_name())
+            .number("containerPort", self.container_port())
+            .build()
+    }
+}
+
+impl ToDocument for CapacityProviderStrategyItem {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("capacityProvider", Some(self.capacity_provider()))
+            .number("weight", self.weight())
+            .number("base", self.base())
+            .build()
+    }
+}
+
+impl ToDocument for DeploymentConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .item(
+                "deploymentCircuitBreaker",
+                self.deployment_circuit_breaker(),
+            )
+            .number("maximumPercent", self.maximum_percent())
+            .number("minimumHealthyPercent", self.minimum_healthy_percent())
+            .item("alarms", self.alarms())
+            .name("strategy", self.strategy())
+            .number("bakeTimeInMinutes", self.bake_time_in_minutes())
+            .build()
+    }
+}
+
+impl ToDocument for DeploymentCircuitBreaker {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .flag("enable", self.enable())
+            .flag("rollback", self.rollback())
+            .build()
+    }
+}
+
+impl ToDocument for DeploymentAlarms {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .texts("alarmNames", self.alarm_names())
+            .flag("rollback", self.rollback())
+            .flag("enable", self.enable())
+            .build()
+    }
+}
+
+impl ToDocument for Deployment {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("id", self.id())
+            .text("status", self.status())
+            .text("taskDefinition", self.task_definition())
+            .number("desiredCount", self.desired_count())
+            .number("pendingCount", self.pending_count())
+            .number("runningCount", self.running_count())
+            .number("failedTasks", self.failed_tasks())
+            .time("createdAt", self.created_at())
+            .time("updatedAt", self.updated_at())
+            .items(
+                "capacityProviderStrategy",
+                self.capacity_provider_strategy(),
+            )
+            .name("launchType", self.launch_type())
+            .text("platformVersion", self.platform_version())
+            .text("platformFamily", self.platform_family())
+            .item("networkConfiguration", self.network_configuration())
+            .name("rolloutState", self.rollout_state())
+            .text("rolloutStateReason", self.rollout_state_reason())
+            .build()
+    }
+}
+
+impl ToDocument for ServiceEvent {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .text("id", self.id())
+            .time("createdAt", self.created_at())
+            .text("message", self.message())
+            .build()
+    }
+}
+
+impl ToDocument for PlacementConstraint {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("type", self.r#type())
+            .text("expression", self.expression())
+            .build()
+    }
+}
+
+impl ToDocument for PlacementStrategy {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("type", self.r#type())
+            .text("field", self.field())
+            .build()
+    }
+}
+
+impl ToDocument for NetworkConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .item("awsvpcConfiguration", self.awsvpc_configuration())
+            .build()
+    }
+}
+
+impl ToDocument for AwsVpcConfiguration {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .texts("subnets", self.subnets())
+            .texts("securityGroups", self.security_groups())
+            .name("assignPublicIp", self.assign_public_ip())
+            .build()
+    }
+}
+
+impl ToDocument for DeploymentController {
+    fn to_document(&self) -> Value {
+        Document::default()
+            .name("type", Some(self.r#type()))
+            .build()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use aws_sdk_ecs::types::{
+        Compatibility, DeploymentControllerType, LogDriver, NetworkMode, TaskDefinitionStatus,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_task_definition_document_uses_api_names() {
+        let task_definition = TaskDefinition::builder()
+            .task_definition_arn("arn:aws:ecs:us-east-1:123456789012:task-definition/web:3")
+            .family("web")
+            .revision(3)
+            .status(TaskDefinitionStatus::Active)
+            .network_mode(NetworkMode::Awsvpc)
+            .requires_compatibilities(Compatibility::Fargate)
+            .cpu("256")
+            .memory("512")
+            .registered_at(DateTime::from_secs(1_700_000_000))
+            .ephemeral_storage(EphemeralStorage::builder().size_in_gib(30).build())
+            .container_definitions(
+                ContainerDefinition::builder()
+                    .name("web")
+                    .image("nginx:1.27")
+                    .essential(true)
+                    .linux_parameters(
+                        LinuxParameters::builder()
+                            .init_process_enabled(true)
+                            .build(),
+                    )
+                    .log_configuration(
+                        LogConfiguration::builder()
+                            .log_driver(LogDriver::Awslogs)
+                            .options("awslogs-group", "/ecs/web")
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .build();
+        let tags = [Tag::builder().key("team").value("web").build()];
+
+        let document = task_definition_document(&task_definition, &tags);
+
+        assert_eq!(document["revision"], 3);
+        assert_eq!(document["status"], "ACTIVE");
+        assert_eq!(document["networkMode"], "awsvpc");
+        assert_eq!(document["requiresCompatibilities"], json!(["FARGATE"]));
+        assert_eq!(document["registeredAt"], "2023-11-14T22:13:20.000Z");
+        assert_eq!(document["ephemeralStorage"], json!({"sizeInGiB": 30}));
+        assert_eq!(document["tags"], json!([{"key": "team", "value": "web"}]));
+        assert_eq!(
+            document["containerDefinitions"],
+            json!([{
+                "name": "web",
+                "image": "nginx:1.27",
+                "cpu": 0,
+                "essential": true,
+                "linuxParameters": {"initProcessEnabled": true},
+                "logConfiguration": {
+                    "logDriver": "awslogs",
+                    "options": {"awslogs-group": "/ecs/web"}
+                }
+            }])
+        );
+        assert!(document.get("volumes").is_none());
+    }
+
+    #[test]
+    fn test_service_document_includes_network_and_deployment_settings() {
+        let service = Service::builder()
+            .service_name("web-service")
+            .cluster_arn("arn:aws:ecs:us-east-1:123456789012:cluster/prod")
+            .desired_count(2)
+            .running_count(1)
+            .task_definition("arn:aws:ecs:us-east-1:123456789012:task-definition/web:3")
+            .network_configuration(
+                NetworkConfiguration::builder()
+                    .awsvpc_configuration(
+                        AwsVpcConfiguration::builder()
+                            .subnets("subnet-1")
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .deployment_controller(
+                DeploymentController::builder()
+                    .r#type(DeploymentControllerType::Ecs)
+                    .build()
+                    .unwrap(),
+            )
+            .enable_execute_command(true)
+            .build();
+
+        let document = service_document(&service);
+
+        assert_eq!(document["serviceName"], "web-service");
+        assert_eq!(document["desiredCount"], 2);
+        assert_eq!(document["runningCount"], 1);
+        assert_eq!(
+            document["networkConfiguration"],
+            json!({"awsvpcConfiguration": {"subnets": ["subnet-1"]}})
+        );
+        assert_eq!(document["deploymentController"], json!({"type": "ECS"}));
+        assert_eq!(document["enableExecuteCommand"], true);
+        assert!(document.get("loadBalancers").is_none());
+    }
+}
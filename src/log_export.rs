@@ -0,0 +1,442 @@
+//! Log export to plain text, JSON Lines and CSV files.
+//!
+//! The export dialog of the Logs view is described by a [`LogExportDialog`]; the
+//! entries it selects are written by [`format_logs`] and [`write_export`].
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::app::LogEntry;
+use crate::aws::TimeRange;
+
+/// File format of a log export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One formatted line per entry, below a header describing the export
+    Text,
+    /// One JSON object per line with the timestamp, container, task, level and message
+    JsonLines,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+impl ExportFormat {
+    /// Returns a human-readable label for the format.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Text => "Plain text",
+            ExportFormat::JsonLines => "JSON Lines",
+            ExportFormat::Csv => "CSV",
+        }
+    }
+
+    /// Returns the file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Text => "txt",
+            ExportFormat::JsonLines => "jsonl",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    /// Returns the next format in the cycle.
+    pub fn next(&self) -> ExportFormat {
+        match self {
+            ExportFormat::Text => ExportFormat::JsonLines,
+            ExportFormat::JsonLines => ExportFormat::Csv,
+            ExportFormat::Csv => ExportFormat::Text,
+        }
+    }
+}
+
+/// Time range of the entries a log export contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportRange {
+    /// Every entry
+    All,
+    /// Entries of a time range ending now
+    Last(TimeRange),
+}
+
+impl ExportRange {
+    /// Returns a human-readable label for the range.
+    pub fn label(&self) -> String {
+        match self {
+            ExportRange::All => "All".to_string(),
+            ExportRange::Last(range) => format!("Last {}", range.label()),
+        }
+    }
+
+    /// Returns the next range in the cycle.
+    pub fn next(&self) -> ExportRange {
+        match self {
+            ExportRange::All => ExportRange::Last(TimeRange::OneHour),
+            ExportRange::Last(TimeRange::SevenDays) => ExportRange::All,
+            ExportRange::Last(range) => ExportRange::Last(range.next()),
+        }
+    }
+
+    /// Returns the start of the range (Unix milliseconds), `None` for all entries.
+    pub fn start_time(&self, now: i64) -> Option<i64> {
+        match self {
+            ExportRange::All => None,
+            ExportRange::Last(range) => Some(now - i64::from(range.minutes()) * 60_000),
+        }
+    }
+}
+
+/// Fields of the log export dialog, in the order Tab moves through them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportField {
+    Format,
+    ApplyFilters,
+    Range,
+    FullHistory,
+    Path,
+}
+
+impl ExportField {
+    const ALL: [ExportField; 5] = [
+        ExportField::Format,
+        ExportField::ApplyFilters,
+        ExportField::Range,
+        ExportField::FullHistory,
+        ExportField::Path,
+    ];
+}
+
+/// Choices made in the log export dialog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogExportDialog {
+    /// File format
+    pub format: ExportFormat,
+    /// Whether only entries passing the search, level and task filters are exported
+    pub apply_filters: bool,
+    /// Time range of the exported entries
+    pub range: ExportRange,
+    /// Whether every older page is fetched, instead of exporting the loaded entries
+    pub full_history: bool,
+    /// Destination file; `~` is expanded to the home directory
+    pub path: String,
+    /// Field being edited
+    pub field: ExportField,
+}
+
+impl LogExportDialog {
+    /// Creates a dialog exporting the filtered, loaded entries as plain text to
+    /// `export_dir`, in a file named after `source` and the current time.
+    pub fn new(export_dir: &str, source: &str) -> Self {
+        let format = ExportFormat::Text;
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        Self {
+            format,
+            apply_filters: true,
+            range: ExportRange::All,
+            full_history: false,
+            path: format!(
+                "{}/ecs-logs-{source}-{timestamp}.{}",
+                export_dir.trim_end_matches('/'),
+                format.extension()
+            ),
+            field: ExportField::Format,
+        }
+    }
+
+    /// Moves to the next field, wrapping around.
+    pub fn next_field(&mut self) {
+        let index = ExportField::ALL.iter().position(|f| *f == self.field);
+        self.field = ExportField::ALL[index.map_or(0, |i| (i + 1) % ExportField::ALL.len())];
+    }
+
+    /// Moves to the previous field, wrapping around.
+    pub fn previous_field(&mut self) {
+        let index = ExportField::ALL.iter().position(|f| *f == self.field);
+        let len = ExportField::ALL.len();
+        self.field = ExportField::ALL[index.map_or(0, |i| (i + len - 1) % len)];
+    }
+
+    /// Changes the value of the current field: cycles the format or range, or
+    /// toggles an option. A path ending in the old format's extension follows the format.
+    pub fn change_field(&mut self) {
+        match self.field {
+            ExportField::Format => {
+                let old_extension = format!(".{}", self.format.extension());
+                self.format = self.format.next();
+                if let Some(stem) = self.path.strip_suffix(&old_extension) {
+                    self.path = format!("{stem}.{}", self.format.extension());
+                }
+            }
+            ExportField::ApplyFilters => self.apply_filters = !self.apply_filters,
+            ExportField::Range => self.range = self.range.next(),
+            ExportField::FullHistory => self.full_history = !self.full_history,
+            ExportField::Path => {}
+        }
+    }
+}
+
+/// Source and filters of a log export, written above plain text exports.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportHeader {
+    /// "Task" or "Service"
+    pub source_label: String,
+    /// Task ID or service name
+    pub source: String,
+    /// Filters applied to the entries, e.g. "Level filter: Error"
+    pub filters: Vec<String>,
+}
+
+/// Formats log entries in an export format.
+///
+/// # Arguments
+/// * `logs` - Entries to export, sorted by timestamp
+/// * `format` - File format
+/// * `header` - Source and filters, written above plain text exports
+/// * `show_timestamps` - Whether plain text lines start with their timestamp
+pub fn format_logs(
+    logs: &[LogEntry],
+    format: ExportFormat,
+    header: &ExportHeader,
+    show_timestamps: bool,
+) -> String {
+    let mut content = String::new();
+    match format {
+        ExportFormat::Text => {
+            content.push_str("ECS Voyager Log Export\n");
+            content.push_str(&format!(
+                "Exported: {}\n",
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+            ));
+            content.push_str(&format!("{}: {}\n", header.source_label, header.source));
+            content.push_str(&format!("Total logs: {}\n", logs.len()));
+            for filter in &header.filters {
+                content.push_str(&format!("{filter}\n"));
+            }
+            content.push_str(&format!("{}\n\n", "=".repeat(80)));
+
+            for log in logs {
+                let timestamp_str = if show_timestamps {
+                    format!("[{}] ", local_time(log.timestamp))
+                } else {
+                    String::new()
+                };
+                let task_str = log
+                    .task_id
+                    .as_ref()
+                    .map(|task_id| format!("[{task_id}] "))
+                    .unwrap_or_default();
+                content.push_str(&format!(
+                    "{}{}[{:?}] [{}] {}\n",
+                    timestamp_str,
+                    task_str,
+                    log.level,
+                    log.container_name,
+                    log.full_message()
+                ));
+            }
+        }
+        ExportFormat::JsonLines => {
+            for log in logs {
+                let line = json!({
+                    "timestamp": log.timestamp,
+                    "time": utc_time(log.timestamp),
+                    "container": log.container_name,
+                    "task": log.task_id,
+                    "level": format!("{:?}", log.level).to_uppercase(),
+                    "message": log.full_message(),
+                });
+                content.push_str(&line.to_string());
+                content.push('\n');
+            }
+        }
+        ExportFormat::Csv => {
+            content.push_str("timestamp,time,container,task,level,message\n");
+            for log in logs {
+                let row = [
+                    log.timestamp.to_string(),
+                    utc_time(log.timestamp),
+                    log.container_name.clone(),
+                    log.task_id.clone().unwrap_or_default(),
+                    format!("{:?}", log.level).to_uppercase(),
+                    log.full_message(),
+                ];
+                let row: Vec<String> = row.iter().map(|value| csv_field(value)).collect();
+                content.push_str(&row.join(","));
+                content.push('\n');
+            }
+        }
+    }
+    content
+}
+
+/// Formats a Unix millisecond timestamp as local time for plain text exports.
+fn local_time(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(timestamp)
+        .map(|dt| {
+            dt.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S%.3f")
+                .to_string()
+        })
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Formats a Unix millisecond timestamp as an RFC 3339 UTC time.
+fn utc_time(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(timestamp)
+        .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+        .unwrap_or_default()
+}
+
+/// Quotes a CSV field when it contains a comma, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes an export to `path`, expanding `~` and creating missing directories.
+///
+/// # Returns
+/// The path of the written file
+///
+/// # Errors
+/// This function will return an error if the path is empty, the home directory
+/// cannot be determined, or the directory or file cannot be written.
+pub fn write_export(path: &str, content: &str) -> Result<String> {
+    let path = path.trim();
+    if path.is_empty() {
+        anyhow::bail!("No export path given");
+    }
+    let path = match path.strip_prefix('~') {
+        Some(rest) => {
+            let home = dirs::home_dir()
+                .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
+            PathBuf::from(format!("{}{rest}", home.to_string_lossy()))
+        }
+        None => PathBuf::from(path),
+    };
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, content)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<LogEntry> {
+        vec![
+            LogEntry::new(0, "INFO: started".to_string(), "web".to_string()),
+            LogEntry {
+                task_id: Some("abc".to_string()),
+                ..LogEntry::new(
+                    1500,
+                    "ERROR: failed, \"db\" down".to_string(),
+                    "api".to_string(),
+                )
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json_lines_preserve_container_level_and_timestamp() {
+        let content = format_logs(
+            &entries(),
+            ExportFormat::JsonLines,
+            &ExportHeader::default(),
+            true,
+        );
+        let lines: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["timestamp"], 1500);
+        assert_eq!(lines[1]["time"], "1970-01-01T00:00:01.500Z");
+        assert_eq!(lines[1]["container"], "api");
+        assert_eq!(lines[1]["task"], "abc");
+        assert_eq!(lines[1]["level"], "ERROR");
+        assert_eq!(lines[1]["message"], "ERROR: failed, \"db\" down");
+        assert!(lines[0]["task"].is_null());
+    }
+
+    #[test]
+    fn test_csv_quotes_fields_with_separators() {
+        let content = format_logs(
+            &entries(),
+            ExportFormat::Csv,
+            &ExportHeader::default(),
+            true,
+        );
+        let lines: Vec<&str> = content.lines().collect();
+
+        assert_eq!(lines[0], "timestamp,time,container,task,level,message");
+        assert_eq!(
+            lines[1],
+            "0,1970-01-01T00:00:00.000Z,web,,INFO,INFO: started"
+        );
+        assert_eq!(
+            lines[2],
+            "1500,1970-01-01T00:00:01.500Z,api,abc,ERROR,\"ERROR: failed, \"\"db\"\" down\""
+        );
+    }
+
+    #[test]
+    fn test_text_export_lists_source_and_filters() {
+        let header = ExportHeader {
+            source_label: "Task".to_string(),
+            source: "abc".to_string(),
+            filters: vec!["Level filter: Error".to_string()],
+        };
+        let content = format_logs(&entries(), ExportFormat::Text, &header, false);
+
+        assert!(content.contains("Task: abc\nTotal logs: 2\nLevel filter: Error\n"));
+        assert!(content.ends_with("[abc] [Error] [api] ERROR: failed, \"db\" down\n"));
+    }
+
+    #[test]
+    fn test_dialog_format_changes_path_extension() {
+        let mut dialog = LogExportDialog::new("~/Downloads/", "abc");
+        assert!(dialog.path.starts_with("~/Downloads/ecs-logs-abc-"));
+        assert!(dialog.path.ends_with(".txt"));
+
+        dialog.change_field();
+        assert_eq!(dialog.format, ExportFormat::JsonLines);
+        assert!(dialog.path.ends_with(".jsonl"));
+
+        dialog.path = "/tmp/out.log".to_string();
+        dialog.change_field();
+        assert_eq!(dialog.path, "/tmp/out.log");
+
+        dialog.previous_field();
+        assert_eq!(dialog.field, ExportField::Path);
+        dialog.next_field();
+        dialog.next_field();
+        dialog.change_field();
+        assert!(!dialog.apply_filters);
+    }
+
+    #[test]
+    fn test_export_range_cycles_and_starts_before_now() {
+        let mut range = ExportRange::All;
+        assert_eq!(range.start_time(10_000_000), None);
+        range = range.next();
+        assert_eq!(range, ExportRange::Last(TimeRange::OneHour));
+        assert_eq!(range.start_time(10_000_000), Some(10_000_000 - 3_600_000));
+        for _ in 0..4 {
+            range = range.next();
+        }
+        assert_eq!(range, ExportRange::All);
+    }
+}
@@ -6,18 +6,33 @@
 
 mod app;
 mod aws;
+#[cfg(test)]
+mod aws_fake;
 mod charts;
 mod config;
+mod definition_export;
+mod ecs_json;
+mod log_export;
+mod multiline;
+mod rollout;
+mod structured_log;
+mod task_definition;
+mod task_definition_cleanup;
+mod task_definition_editor;
 mod ui;
 
 use anyhow::Result;
 use app::{App, AppState, ModalState};
 use config::Config;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use definition_export::DefinitionExportField;
+use log_export::ExportField;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 
@@ -120,8 +135,9 @@ async fn main() -> Result<()> {
 /// This function will return an error if:
 /// - Terminal drawing fails
 /// - Event polling or reading fails
-/// - AWS API calls during refresh/select/describe operations fail
-/// - CloudWatch logs retrieval fails
+///
+/// AWS requests run on background tasks; their failures are reported through the
+/// status bar and never end the event loop.
 ///
 /// # Event Handling
 /// - In search mode: Handles character input, backspace, enter, and escape
@@ -132,6 +148,11 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
     app: &mut App,
 ) -> Result<()> {
     loop {
+        // Apply results of background AWS requests before drawing
+        app.process_responses();
+        app.poll_rollout();
+        app.poll_insights_query();
+
         terminal.draw(|f| ui::draw(f, app))?;
 
         if event::poll(std::time::Duration::from_millis(100))? {
@@ -215,22 +236,23 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                         else if app.modal_state == ModalState::ServiceEditor {
                             match key.code {
                                 // Tab switches between fields
-                                KeyCode::Tab => {
-                                    app.service_editor_editing_field =
-                                        (app.service_editor_editing_field + 1) % 2;
-                                }
-                                // Numeric input for desired count field
-                                KeyCode::Char(c)
-                                    if c.is_ascii_digit()
-                                        && app.service_editor_editing_field == 0 =>
-                                {
-                                    app.service_editor_desired_count_input.push(c);
+                                KeyCode::Tab => app.service_editor_next_field(),
+                                // Numeric input for desired count and capacity fields
+                                KeyCode::Char(c) if c.is_ascii_digit() => {
+                                    if let Some(input) = app.service_editor_input_mut() {
+                                        input.push(c);
+                                    }
                                 }
-                                // Backspace for desired count field
-                                KeyCode::Backspace if app.service_editor_editing_field == 0 => {
-                                    app.service_editor_desired_count_input.pop();
+                                // Backspace for desired count and capacity fields
+                                KeyCode::Backspace => {
+                                    if let Some(input) = app.service_editor_input_mut() {
+                                        input.pop();
+                                    }
                                 }
+                                // Space toggles suspending auto scaling
+                                KeyCode::Char(' ') => app.toggle_service_editor_scaling_suspended(),
                                 // Up/Down for task definition list when that field is active
+                                #[allow(clippy::collapsible_match)]
                                 KeyCode::Up | KeyCode::Char('k')
                                     if app.service_editor_editing_field == 1 =>
                                 {
@@ -238,6 +260,7 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                                         app.service_editor_selected_revision -= 1;
                                     }
                                 }
+                                #[allow(clippy::collapsible_match)]
                                 KeyCode::Down | KeyCode::Char('j')
                                     if app.service_editor_editing_field == 1 =>
                                 {
@@ -247,7 +270,200 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                                         app.service_editor_selected_revision += 1;
                                     }
                                 }
-                                KeyCode::Enter => app.modal_select().await?,
+                                // D compares the selected revision with the running one
+                                KeyCode::Char('D') => app.show_service_editor_diff(),
+                                // N registers an edited copy of the selected revision
+                                KeyCode::Char('N') => app.register_from_service_editor(),
+                                KeyCode::Enter => app.modal_select()?,
+                                KeyCode::Esc => app.close_modal(),
+                                _ => {}
+                            }
+                        }
+                        // Task definition diff: u shows or hides unchanged fields
+                        else if app.modal_state == ModalState::TaskDefinitionDiff {
+                            match key.code {
+                                KeyCode::Up | KeyCode::Char('k') => app.modal_previous(),
+                                KeyCode::Down | KeyCode::Char('j') => app.modal_next(),
+                                KeyCode::Char('u') => app.toggle_diff_unchanged(),
+                                KeyCode::Enter | KeyCode::Esc => app.close_modal(),
+                                _ => {}
+                            }
+                        }
+                        // Register dialog: fields are typed, Ctrl+E edits the JSON in $EDITOR
+                        else if app.modal_state == ModalState::TaskDefinitionEditor {
+                            let editing_deploy = app
+                                .task_definition_editor
+                                .as_ref()
+                                .is_some_and(|editor| editor.editing_deploy());
+                            match key.code {
+                                KeyCode::Char('e')
+                                    if key.modifiers.contains(KeyModifiers::CONTROL) =>
+                                {
+                                    if let Some((json, family)) = app.task_definition_editor_json()
+                                    {
+                                        // Suspend TUI to hand the terminal to the editor
+                                        disable_raw_mode()?;
+                                        execute!(
+                                            terminal.backend_mut(),
+                                            LeaveAlternateScreen,
+                                            DisableMouseCapture
+                                        )?;
+                                        terminal.show_cursor()?;
+
+                                        let edited =
+                                            task_definition_editor::edit_in_external_editor(
+                                                &json, &family,
+                                            );
+
+                                        // Resume TUI
+                                        terminal.hide_cursor()?;
+                                        execute!(
+                                            terminal.backend_mut(),
+                                            EnterAlternateScreen,
+                                            EnableMouseCapture
+                                        )?;
+                                        enable_raw_mode()?;
+                                        terminal.clear()?;
+
+                                        app.apply_task_definition_json(edited);
+                                    }
+                                }
+                                KeyCode::Tab | KeyCode::Down => {
+                                    if let Some(editor) = &mut app.task_definition_editor {
+                                        editor.next_field();
+                                    }
+                                }
+                                KeyCode::BackTab | KeyCode::Up => {
+                                    if let Some(editor) = &mut app.task_definition_editor {
+                                        editor.previous_field();
+                                    }
+                                }
+                                KeyCode::Char(' ') if editing_deploy => {
+                                    if let Some(editor) = &mut app.task_definition_editor {
+                                        editor.toggle_deploy();
+                                    }
+                                }
+                                KeyCode::Char(c) => {
+                                    if let Some(editor) = &mut app.task_definition_editor {
+                                        editor.insert_char(c);
+                                    }
+                                }
+                                KeyCode::Backspace => {
+                                    if let Some(editor) = &mut app.task_definition_editor {
+                                        editor.delete_char();
+                                    }
+                                }
+                                KeyCode::Enter => app.modal_select()?,
+                                KeyCode::Esc => app.close_modal(),
+                                _ => {}
+                            }
+                        }
+                        // Definition export dialog: Tab switches fields, the path is typed
+                        else if app.modal_state == ModalState::DefinitionExport {
+                            let editing_path = app
+                                .definition_export
+                                .as_ref()
+                                .is_some_and(|dialog| dialog.field == DefinitionExportField::Path);
+                            match key.code {
+                                KeyCode::Tab | KeyCode::BackTab | KeyCode::Down | KeyCode::Up => {
+                                    if let Some(dialog) = &mut app.definition_export {
+                                        dialog.next_field();
+                                    }
+                                }
+                                KeyCode::Char(c) if editing_path => {
+                                    app.update_definition_export_path(c);
+                                }
+                                KeyCode::Backspace if editing_path => {
+                                    app.delete_definition_export_path_char();
+                                }
+                                KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => {
+                                    if let Some(dialog) = &mut app.definition_export {
+                                        dialog.change_field();
+                                    }
+                                }
+                                KeyCode::Enter => app.modal_select()?,
+                                KeyCode::Esc => app.close_modal(),
+                                _ => {}
+                            }
+                        }
+                        // Cleanup dialog: Tab moves between rules, numbers are typed,
+                        // Space toggles options
+                        else if app.modal_state == ModalState::TaskDefinitionCleanup {
+                            match key.code {
+                                KeyCode::Tab | KeyCode::Down => {
+                                    if let Some(cleanup) = &mut app.task_definition_cleanup {
+                                        cleanup.next_field();
+                                    }
+                                }
+                                KeyCode::BackTab | KeyCode::Up => {
+                                    if let Some(cleanup) = &mut app.task_definition_cleanup {
+                                        cleanup.previous_field();
+                                    }
+                                }
+                                KeyCode::Char(' ') => {
+                                    if let Some(cleanup) = &mut app.task_definition_cleanup {
+                                        cleanup.toggle();
+                                    }
+                                }
+                                KeyCode::Char(c) => {
+                                    if let Some(cleanup) = &mut app.task_definition_cleanup {
+                                        cleanup.insert_char(c);
+                                    }
+                                }
+                                KeyCode::Backspace => {
+                                    if let Some(cleanup) = &mut app.task_definition_cleanup {
+                                        cleanup.delete_char();
+                                    }
+                                }
+                                KeyCode::Enter => app.modal_select()?,
+                                KeyCode::Esc => app.close_modal(),
+                                _ => {}
+                            }
+                        }
+                        // Log export dialog: Tab moves between options, the path is typed
+                        else if app.modal_state == ModalState::LogExport {
+                            let editing_path = app
+                                .log_export
+                                .as_ref()
+                                .is_some_and(|dialog| dialog.field == ExportField::Path);
+                            match key.code {
+                                KeyCode::Tab | KeyCode::Down => {
+                                    if let Some(dialog) = &mut app.log_export {
+                                        dialog.next_field();
+                                    }
+                                }
+                                KeyCode::BackTab | KeyCode::Up => {
+                                    if let Some(dialog) = &mut app.log_export {
+                                        dialog.previous_field();
+                                    }
+                                }
+                                KeyCode::Char(c) if editing_path => app.update_log_export_path(c),
+                                KeyCode::Backspace if editing_path => {
+                                    app.delete_log_export_path_char();
+                                }
+                                KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => {
+                                    if let Some(dialog) = &mut app.log_export {
+                                        dialog.change_field();
+                                    }
+                                }
+                                KeyCode::Enter => app.modal_select()?,
+                                KeyCode::Esc => app.close_modal(),
+                                _ => {}
+                            }
+                        }
+                        // Confirmation dialog: y/n, or typing the name for production resources
+                        else if app.modal_state == ModalState::Confirm {
+                            match key.code {
+                                KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
+                                    app.toggle_confirm_selection();
+                                }
+                                KeyCode::Backspace => app.delete_confirm_char(),
+                                KeyCode::Char(c) if app.confirmation_requires_name() => {
+                                    app.update_confirm_input(c);
+                                }
+                                KeyCode::Char('y') => app.confirm_action(),
+                                KeyCode::Char('n') => app.close_modal(),
+                                KeyCode::Enter => app.modal_select()?,
                                 KeyCode::Esc => app.close_modal(),
                                 _ => {}
                             }
@@ -256,7 +472,7 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                             match key.code {
                                 KeyCode::Up | KeyCode::Char('k') => app.modal_previous(),
                                 KeyCode::Down | KeyCode::Char('j') => app.modal_next(),
-                                KeyCode::Enter => app.modal_select().await?,
+                                KeyCode::Enter => app.modal_select()?,
                                 KeyCode::Esc => app.close_modal(),
                                 _ => {}
                             }
@@ -282,6 +498,46 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                             _ => {}
                         }
                     }
+                    // Handle find input in the logs view
+                    else if app.log_find_mode {
+                        match key.code {
+                            KeyCode::Char(c) => app.update_log_find(c),
+                            KeyCode::Backspace => app.delete_log_find_char(),
+                            KeyCode::Enter => app.submit_log_find(),
+                            KeyCode::Esc => app.cancel_log_find(),
+                            _ => {}
+                        }
+                    }
+                    // Handle jump-to-time input in the logs view
+                    else if app.log_jump_mode {
+                        match key.code {
+                            KeyCode::Char(c) => app.update_log_jump(c),
+                            KeyCode::Backspace => app.delete_log_jump_char(),
+                            KeyCode::Enter => app.submit_log_jump(),
+                            KeyCode::Esc => app.cancel_log_jump(),
+                            _ => {}
+                        }
+                    }
+                    // Handle log column editing in the logs view
+                    else if app.log_columns_mode {
+                        match key.code {
+                            KeyCode::Char(c) => app.update_log_columns(c),
+                            KeyCode::Backspace => app.delete_log_columns_char(),
+                            KeyCode::Enter => app.submit_log_columns(),
+                            KeyCode::Esc => app.cancel_log_columns(),
+                            _ => {}
+                        }
+                    }
+                    // Handle Logs Insights query editing
+                    else if app.insights_editing && app.state == AppState::LogInsights {
+                        match key.code {
+                            KeyCode::Char(c) => app.update_insights_query(c),
+                            KeyCode::Backspace => app.delete_insights_query_char(),
+                            KeyCode::Enter => app.run_insights_query(),
+                            KeyCode::Esc => app.exit_insights_edit_mode(),
+                            _ => {}
+                        }
+                    }
                     // Handle normal mode input
                     else {
                         match key.code {
@@ -294,21 +550,92 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                                     AppState::Clusters
                                     | AppState::Services
                                     | AppState::Tasks
-                                    | AppState::TaskDefinitions => {
+                                    | AppState::TaskDefinitions
+                                    | AppState::TaskDefinitionRevisions
+                                    | AppState::ServiceEvents
+                                    | AppState::ContainerInstances => {
                                         app.enter_search_mode();
                                     }
                                     AppState::Logs => {
                                         app.enter_log_search_mode();
                                     }
+                                    AppState::LogInsights => {
+                                        app.enter_insights_edit_mode();
+                                    }
                                     _ => {}
                                 }
                             }
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('f') => {
                                 // Filter logs by level in logs view
                                 if app.state == AppState::Logs {
                                     app.cycle_log_level_filter();
                                 }
                             }
+                            KeyCode::Char('g') if app.state == AppState::Logs => {
+                                // Jump to a point in time in logs view
+                                app.enter_log_jump_mode();
+                            }
+                            KeyCode::Char('c') if app.state == AppState::Logs => {
+                                // Choose the JSON fields shown as columns in logs view
+                                app.enter_log_columns_mode();
+                            }
+                            KeyCode::Char('o') if app.state == AppState::Logs => {
+                                // Expand or collapse the selected multi-line log entry
+                                app.toggle_log_expanded();
+                            }
+                            KeyCode::Char('O') if app.state == AppState::Logs => {
+                                // Expand or collapse every multi-line log entry
+                                app.toggle_all_logs_expanded();
+                            }
+                            KeyCode::Char('s') if app.state == AppState::Logs => {
+                                // Find and highlight text in logs view without filtering
+                                app.enter_log_find_mode();
+                            }
+                            KeyCode::Char('n') if app.state == AppState::Logs => {
+                                // Select the next entry matching the find query
+                                app.next_log_match();
+                            }
+                            KeyCode::Char('N') if app.state == AppState::Logs => {
+                                // Select the previous entry matching the find query
+                                app.previous_log_match();
+                            }
+                            KeyCode::Char('b') if app.state == AppState::Logs => {
+                                // Bookmark the selected log entry
+                                app.toggle_log_bookmark();
+                            }
+                            KeyCode::Char('B') if app.state == AppState::Logs => {
+                                // List bookmarked log entries
+                                app.show_log_bookmarks();
+                            }
+                            KeyCode::Char(']') if app.state == AppState::Logs => {
+                                // Select the next bookmarked log entry
+                                app.next_log_bookmark();
+                            }
+                            KeyCode::Char('[') if app.state == AppState::Logs => {
+                                // Select the previous bookmarked log entry
+                                app.previous_log_bookmark();
+                            }
+                            KeyCode::Char('C') if app.state == AppState::Logs => {
+                                // Logs of the task's containers around the selected entry
+                                app.show_log_context(false);
+                            }
+                            KeyCode::Char('A') if app.state == AppState::Logs => {
+                                // Logs of all tasks of the service around the selected entry
+                                app.show_log_context(true);
+                            }
+                            KeyCode::Char('I') if app.state == AppState::Logs => {
+                                // Query the shown logs with CloudWatch Logs Insights
+                                app.view_log_insights();
+                            }
+                            KeyCode::Char('n') if app.state == AppState::LogInsights => {
+                                // Load the next saved Logs Insights query
+                                app.load_next_saved_query();
+                            }
+                            KeyCode::Char('S') if app.state == AppState::LogInsights => {
+                                // Save the Logs Insights query to the config file
+                                app.save_insights_query();
+                            }
                             KeyCode::Char('F') => {
                                 // Cycle filters based on current view
                                 match app.state {
@@ -321,35 +648,74 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                                     _ => {}
                                 }
                             }
+                            KeyCode::Char(' ')
+                                if app.state == AppState::TaskDefinitionRevisions =>
+                            {
+                                // Mark the revision to compare others against
+                                app.mark_task_definition_diff_base();
+                            }
+                            KeyCode::Char('D')
+                                if app.state == AppState::TaskDefinitionRevisions
+                                    || app.state == AppState::TaskDefinitionDetail =>
+                            {
+                                // Compare with the marked or the previous revision
+                                app.show_task_definition_diff();
+                            }
+                            KeyCode::Char('N')
+                                if app.state == AppState::TaskDefinitionRevisions
+                                    || app.state == AppState::TaskDefinitionDetail =>
+                            {
+                                // Register a new revision from an edited copy
+                                app.show_task_definition_editor();
+                            }
+                            KeyCode::Char('X')
+                                if app.state == AppState::TaskDefinitions
+                                    || app.state == AppState::TaskDefinitionRevisions =>
+                            {
+                                // Clean up old revisions of the family by rule
+                                app.show_task_definition_cleanup();
+                            }
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('L') => {
                                 // Cycle launch type filter in services view
                                 if app.state == AppState::Services {
                                     app.cycle_launch_type_filter();
                                 }
                             }
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('C') => {
                                 // Clear all filters (except in logs view where it might be confusing)
                                 if app.state == AppState::Clusters
                                     || app.state == AppState::Services
                                     || app.state == AppState::Tasks
                                     || app.state == AppState::TaskDefinitions
+                                    || app.state == AppState::TaskDefinitionRevisions
                                 {
                                     app.clear_all_filters();
                                 }
                             }
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('M') => {
-                                // Toggle regex mode
+                                // Toggle regex mode (of find in logs view)
                                 if app.state == AppState::Clusters
                                     || app.state == AppState::Services
                                     || app.state == AppState::Tasks
                                     || app.state == AppState::TaskDefinitions
+                                    || app.state == AppState::TaskDefinitionRevisions
+                                    || app.state == AppState::ServiceEvents
+                                    || app.state == AppState::ContainerInstances
+                                    || app.state == AppState::Logs
                                 {
                                     app.toggle_regex_mode();
                                 }
                             }
                             KeyCode::Char('e') => {
+                                if app.state == AppState::Tasks && app.show_stopped_tasks {
+                                    app.status_message =
+                                        "ECS Exec is not available for stopped tasks".to_string();
+                                }
                                 // ECS Exec in tasks view
-                                if app.state == AppState::Tasks {
+                                else if app.state == AppState::Tasks {
                                     // Suspend TUI to hand terminal to session-manager-plugin
                                     disable_raw_mode()?;
                                     execute!(
@@ -390,17 +756,21 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                                 }
                                 // Export logs in logs view
                                 else if app.state == AppState::Logs {
-                                    match app.export_logs() {
-                                        Ok(path) => {
-                                            app.status_message =
-                                                format!("Logs exported to: {path}");
-                                        }
-                                        Err(e) => {
-                                            app.status_message = format!("Export failed: {e}");
-                                        }
-                                    }
+                                    app.show_log_export_dialog();
+                                }
+                                // Export the selected service or task definition revision
+                                else if app.state == AppState::Services
+                                    || app.state == AppState::TaskDefinitionRevisions
+                                    || app.state == AppState::TaskDefinitionDetail
+                                {
+                                    app.show_definition_export_dialog();
                                 }
                             }
+                            KeyCode::Char('S') if app.state == AppState::Tasks => {
+                                // Switch between running and stopped tasks
+                                app.toggle_stopped_tasks();
+                            }
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('p') => {
                                 // Port forwarding setup in tasks view
                                 if app.state == AppState::Tasks {
@@ -415,12 +785,12 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                                 app.set_view(AppState::TaskDefinitions);
                                 // Load task definition families if not already loaded
                                 if app.task_definition_families.is_empty() {
-                                    app.refresh().await?;
+                                    app.refresh();
                                 }
                             }
                             KeyCode::Up | KeyCode::Char('k') => app.previous(),
                             KeyCode::Down | KeyCode::Char('j') => app.next(),
-                            KeyCode::Enter => app.select().await?,
+                            KeyCode::Enter => app.select(),
                             KeyCode::Esc | KeyCode::Char('h') => {
                                 if !app.search_query.is_empty() {
                                     app.clear_search();
@@ -428,30 +798,43 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
                                     app.back();
                                 }
                             }
-                            KeyCode::Char('r') => app.refresh().await?,
-                            KeyCode::Char('d') => app.describe().await?,
-                            KeyCode::Char('l') => app.view_logs().await?,
-                            KeyCode::Char('m') => app.view_metrics().await?,
+                            KeyCode::Char('r') => app.refresh(),
+                            KeyCode::Char('d') => app.describe(),
+                            KeyCode::Char('l') => app.view_logs(),
+                            KeyCode::Char('m') => app.view_metrics(),
+                            KeyCode::Char('v') => app.view_service_events(),
+                            KeyCode::Char('i') => app.view_container_instances(),
                             KeyCode::Char('t') => app.toggle_auto_tail(),
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('J') => {
                                 // Toggle JSON view in Details
                                 if app.state == AppState::Details {
                                     app.toggle_json_view();
                                 }
                             }
+                            KeyCode::Char('T') if app.state == AppState::Logs => {
+                                // Cycle task filter in service-wide logs
+                                app.cycle_log_task_filter();
+                            }
+                            KeyCode::Char('T') if app.state == AppState::LogInsights => {
+                                // Cycle the time range Logs Insights queries run over
+                                app.cycle_insights_time_range();
+                            }
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('T') => {
                                 // Cycle time range in Metrics view
                                 if app.state == AppState::Metrics {
-                                    app.cycle_metrics_time_range().await?;
+                                    app.cycle_metrics_time_range();
                                 }
                             }
+                            #[allow(clippy::collapsible_match)]
                             KeyCode::Char('s') | KeyCode::Char('S') => {
                                 // Open service editor in Services view
                                 if app.state == AppState::Services {
-                                    app.show_service_editor().await?;
+                                    app.show_service_editor();
                                 }
                             }
-                            KeyCode::Char('x') => app.execute_action().await?,
+                            KeyCode::Char('x') => app.execute_action(),
                             _ => {}
                         }
                     }
@@ -461,7 +844,7 @@ async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
 
         // Auto-refresh data periodically
         if app.should_refresh() {
-            app.refresh().await?;
+            app.refresh();
         }
     }
 }
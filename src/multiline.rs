@@ -0,0 +1,208 @@
+//! Grouping of multi-line log entries.
+//!
+//! Stack traces and other multi-line messages reach CloudWatch Logs as one event per
+//! line unless the log driver is configured to join them. [`group_multiline`] merges
+//! continuation lines into the entry they belong to, so they share its level and are
+//! searched, filtered and exported together.
+
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::app::LogEntry;
+use crate::config::LogsConfig;
+
+/// Continuation lines of Java and Python stack traces: indented lines, "Caused by:"
+/// and "... N more" lines, Python traceback headers, and package-qualified exception
+/// lines such as "java.lang.IllegalStateException: ...".
+pub const DEFAULT_CONTINUATION_PATTERN: &str = r"^(\s|Caused by:|Suppressed:|\.\.\. \d+ (more|common frames omitted)|Traceback \(most recent call last\)|During handling of the above exception|The above exception was the direct cause|([\w$]+\.)+[\w$]*(Error|Exception|Throwable)(:|$))";
+
+/// Exception lines without a package, such as "ValueError: ..." ending a Python
+/// traceback. With the default pattern they only continue an entry that is already a
+/// stack trace, since "Error: connection refused" is just as likely a new entry.
+const TRACE_EXCEPTION_PATTERN: &str = r"^[\w$]*(Error|Exception|Throwable)(:|$)";
+
+/// Longest gap (milliseconds) between the lines of one multi-line entry; the lines of
+/// a stack trace are written at once, so later matching lines start new entries.
+const MAX_CONTINUATION_GAP_MS: i64 = 1000;
+
+/// Compiles a continuation pattern; empty patterns disable grouping and invalid ones
+/// fall back to `DEFAULT_CONTINUATION_PATTERN`.
+fn continuation_regex(pattern: &str) -> Option<Regex> {
+    if pattern.is_empty() {
+        return None;
+    }
+    Regex::new(pattern)
+        .or_else(|_| Regex::new(DEFAULT_CONTINUATION_PATTERN))
+        .ok()
+}
+
+/// Merges continuation lines into the preceding entry of the same container and task.
+///
+/// A line continues an entry when it is plain text matching the container's pattern
+/// in `logs.multiline_patterns` (or `logs.multiline_pattern`) and was logged at most
+/// `MAX_CONTINUATION_GAP_MS` after the entry's last line. Entries must be sorted by
+/// timestamp.
+pub fn group_multiline(logs: Vec<LogEntry>, config: &LogsConfig) -> Vec<LogEntry> {
+    let default_regex = continuation_regex(&config.multiline_pattern);
+    let exception_regex = Regex::new(TRACE_EXCEPTION_PATTERN).ok();
+    let mut container_regexes: HashMap<String, Option<Regex>> = HashMap::new();
+    // Index of the last entry of each container and task, and the time of its last line
+    let mut open_entries: HashMap<(String, Option<String>), (usize, i64)> = HashMap::new();
+    let mut grouped: Vec<LogEntry> = Vec::with_capacity(logs.len());
+
+    for log in logs {
+        let regex = match config.multiline_patterns.get(&log.container_name) {
+            Some(pattern) => container_regexes
+                .entry(log.container_name.clone())
+                .or_insert_with(|| continuation_regex(pattern))
+                .as_ref(),
+            None => default_regex.as_ref(),
+        };
+        let key = (log.container_name.clone(), log.task_id.clone());
+        let continues =
+            regex.is_some_and(|regex| regex.is_match(&log.message)) && log.structured.is_none();
+        let ends_trace = regex.is_some_and(|regex| regex.as_str() == DEFAULT_CONTINUATION_PATTERN)
+            && exception_regex
+                .as_ref()
+                .is_some_and(|regex| regex.is_match(&log.message))
+            && log.structured.is_none();
+
+        match open_entries.get_mut(&key) {
+            Some((index, last_timestamp))
+                if (continues || (ends_trace && is_trace(&grouped[*index])))
+                    && log.timestamp - *last_timestamp <= MAX_CONTINUATION_GAP_MS =>
+            {
+                *last_timestamp = log.timestamp;
+                grouped[*index].continuation.push(log.message);
+            }
+            _ => {
+                open_entries.insert(key, (grouped.len(), log.timestamp));
+                grouped.push(log);
+            }
+        }
+    }
+    grouped
+}
+
+/// Returns whether an entry is a stack trace: it has continuation lines already, or
+/// starts a Python traceback.
+fn is_trace(entry: &LogEntry) -> bool {
+    !entry.continuation.is_empty()
+        || entry
+            .message
+            .starts_with("Traceback (most recent call last)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::LogLevel;
+
+    fn entry(timestamp: i64, message: &str, container: &str) -> LogEntry {
+        LogEntry::new(timestamp, message.to_string(), container.to_string())
+    }
+
+    #[test]
+    fn test_java_stack_trace_is_merged_into_its_entry() {
+        let logs = vec![
+            entry(1000, "ERROR Request failed", "web"),
+            entry(1000, "java.lang.IllegalStateException: closed", "web"),
+            entry(1001, "INFO sidecar ready", "envoy"),
+            entry(1001, "\tat com.example.Pool.get(Pool.java:42)", "web"),
+            entry(1002, "Caused by: java.io.IOException: reset", "web"),
+            entry(1002, "\t... 12 more", "web"),
+            entry(1500, "INFO next request", "web"),
+        ];
+
+        let grouped = group_multiline(logs, &LogsConfig::default());
+        let messages: Vec<&str> = grouped.iter().map(|log| log.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "ERROR Request failed",
+                "INFO sidecar ready",
+                "INFO next request"
+            ]
+        );
+        assert_eq!(grouped[0].level, LogLevel::Error);
+        assert_eq!(grouped[0].continuation.len(), 4);
+        assert_eq!(grouped[0].continuation[3], "\t... 12 more");
+    }
+
+    #[test]
+    fn test_python_traceback_is_merged_into_its_entry() {
+        let logs = vec![
+            entry(1000, "ERROR:root:job failed", "worker"),
+            entry(1000, "Traceback (most recent call last):", "worker"),
+            entry(1000, "  File \"job.py\", line 3, in run", "worker"),
+            entry(1000, "ValueError: bad input", "worker"),
+        ];
+
+        let grouped = group_multiline(logs, &LogsConfig::default());
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(grouped[0].continuation.len(), 3);
+        assert_eq!(
+            grouped[0].full_message(),
+            "ERROR:root:job failed\nTraceback (most recent call last):\n  File \"job.py\", line 3, in run\nValueError: bad input"
+        );
+    }
+
+    #[test]
+    fn test_standalone_error_line_stays_its_own_entry() {
+        let logs = vec![
+            entry(1000, "INFO connecting to db", "web"),
+            entry(1000, "Error: connection refused", "web"),
+            entry(1001, "ERROR request failed", "web"),
+            entry(1001, "java.io.IOException: reset", "web"),
+        ];
+
+        let grouped = group_multiline(logs, &LogsConfig::default());
+        let messages: Vec<&str> = grouped.iter().map(|log| log.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "INFO connecting to db",
+                "Error: connection refused",
+                "ERROR request failed"
+            ]
+        );
+        assert_eq!(grouped[1].level, LogLevel::Error);
+        assert_eq!(grouped[2].continuation, vec!["java.io.IOException: reset"]);
+    }
+
+    #[test]
+    fn test_grouping_respects_gap_and_container_patterns() {
+        let mut config = LogsConfig::default();
+        config
+            .multiline_patterns
+            .insert("api".to_string(), String::new());
+        config
+            .multiline_patterns
+            .insert("web".to_string(), "^\\+".to_string());
+        let logs = vec![
+            entry(1000, "ERROR failed", "api"),
+            entry(1000, "  indented", "api"),
+            entry(1000, "start", "web"),
+            entry(1000, "  indented", "web"),
+            entry(1000, "+ continued", "web"),
+            entry(5000, "+ too late", "web"),
+        ];
+
+        let grouped = group_multiline(logs, &config);
+        let sizes: Vec<(&str, usize)> = grouped
+            .iter()
+            .map(|log| (log.message.as_str(), log.continuation.len()))
+            .collect();
+        assert_eq!(
+            sizes,
+            vec![
+                ("ERROR failed", 0),
+                ("  indented", 0),
+                ("start", 0),
+                ("  indented", 1),
+                ("+ too late", 0),
+            ]
+        );
+    }
+}
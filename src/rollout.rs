@@ -0,0 +1,400 @@
+//! Deployment rollout monitoring.
+//!
+//! After a service is restarted or pointed at a new task definition, a
+//! [`RolloutMonitor`] follows the new PRIMARY deployment through successive
+//! DescribeServices snapshots and reports when the rollout completes, fails,
+//! is rolled back by the deployment circuit breaker, or stalls.
+
+use std::time::{Duration, Instant};
+
+use crate::app::{DeploymentInfo, ServiceActivity};
+
+/// How often a monitored service is polled.
+pub const ROLLOUT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a rollout may go without progress before it is reported as stalled.
+pub const ROLLOUT_STALL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Noteworthy change detected while monitoring a rollout.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RolloutOutcome {
+    /// The deployment reached its desired count and ECS marked it COMPLETED
+    Completed,
+    /// The deployment failed and no rollback was started
+    Failed(String),
+    /// The deployment failed and ECS is rolling back to the previous deployment
+    RolledBack(String),
+    /// No task started or failed for `ROLLOUT_STALL_TIMEOUT`; monitoring continues
+    Stalled,
+}
+
+impl RolloutOutcome {
+    /// Returns `true` if monitoring ends with this outcome.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, RolloutOutcome::Stalled)
+    }
+}
+
+/// Follows one deployment of a service until its rollout finishes.
+#[derive(Debug, Clone)]
+pub struct RolloutMonitor {
+    /// Cluster of the monitored service
+    pub cluster: String,
+    /// Name of the monitored service
+    pub service: String,
+    /// ID of the followed deployment, picked from the first snapshot
+    pub deployment_id: Option<String>,
+    /// Latest deployments of the service, PRIMARY first
+    pub deployments: Vec<DeploymentInfo>,
+    /// Whether the service has the deployment circuit breaker enabled
+    pub circuit_breaker_enabled: bool,
+    /// Whether the circuit breaker rolls back failed deployments
+    pub circuit_breaker_rollback: bool,
+    /// When monitoring started
+    pub started_at: Instant,
+    /// When the followed deployment last started or failed a task
+    pub last_progress_at: Instant,
+    /// When the service was last polled
+    pub last_poll: Option<Instant>,
+    /// Whether a poll is currently in flight
+    pub poll_in_flight: bool,
+    /// Whether a stall was already reported
+    pub stall_reported: bool,
+    /// Running count of the followed deployment in the first snapshot
+    initial_running: Option<i32>,
+}
+
+impl RolloutMonitor {
+    /// Starts monitoring the rollout of a service.
+    pub fn new(cluster: String, service: String, now: Instant) -> Self {
+        Self {
+            cluster,
+            service,
+            deployment_id: None,
+            deployments: Vec::new(),
+            circuit_breaker_enabled: false,
+            circuit_breaker_rollback: false,
+            started_at: now,
+            last_progress_at: now,
+            last_poll: None,
+            poll_in_flight: false,
+            stall_reported: false,
+            initial_running: None,
+        }
+    }
+
+    /// Returns `true` if the service should be polled again.
+    pub fn is_due(&self, now: Instant) -> bool {
+        !self.poll_in_flight
+            && self
+                .last_poll
+                .is_none_or(|last| now.duration_since(last) >= ROLLOUT_POLL_INTERVAL)
+    }
+
+    /// Returns the followed deployment from the latest snapshot.
+    pub fn deployment(&self) -> Option<&DeploymentInfo> {
+        let id = self.deployment_id.as_ref()?;
+        self.deployments.iter().find(|d| &d.id == id)
+    }
+
+    /// Applies a new snapshot of the service.
+    ///
+    /// The first snapshot picks the PRIMARY deployment as the one to follow.
+    ///
+    /// # Arguments
+    /// * `activity` - Latest deployments and settings of the service
+    /// * `now` - Time of the snapshot
+    ///
+    /// # Returns
+    /// The outcome detected in this snapshot, if any
+    pub fn update(&mut self, activity: &ServiceActivity, now: Instant) -> Option<RolloutOutcome> {
+        let previous = self.deployment().cloned();
+
+        self.deployments = activity.deployments.clone();
+        self.circuit_breaker_enabled = activity.circuit_breaker_enabled;
+        self.circuit_breaker_rollback = activity.circuit_breaker_rollback;
+
+        if self.deployment_id.is_none() {
+            let primary = self.deployments.iter().find(|d| d.status == "PRIMARY")?;
+            self.deployment_id = Some(primary.id.clone());
+            self.initial_running = Some(primary.running_count);
+        }
+
+        let Some(current) = self.deployment().cloned() else {
+            return Some(RolloutOutcome::Failed(
+                "deployment is no longer listed by the service".to_string(),
+            ));
+        };
+
+        match current.rollout_state.as_str() {
+            "COMPLETED" => return Some(RolloutOutcome::Completed),
+            "FAILED" => {
+                let reason = if current.rollout_state_reason.is_empty() {
+                    "rollout failed".to_string()
+                } else {
+                    current.rollout_state_reason.clone()
+                };
+                return Some(if self.circuit_breaker_rollback {
+                    RolloutOutcome::RolledBack(reason)
+                } else {
+                    RolloutOutcome::Failed(reason)
+                });
+            }
+            _ => {}
+        }
+
+        // Another deployment took over before this one finished
+        if current.status != "PRIMARY" {
+            let reason = format!("deployment {} was superseded", current.id);
+            return Some(if self.circuit_breaker_rollback {
+                RolloutOutcome::RolledBack(reason)
+            } else {
+                RolloutOutcome::Failed(reason)
+            });
+        }
+
+        let progressed = previous.is_none_or(|prev| {
+            prev.running_count != current.running_count || prev.failed_tasks != current.failed_tasks
+        });
+        if progressed {
+            self.last_progress_at = now;
+            self.stall_reported = false;
+        } else if !self.stall_reported
+            && now.duration_since(self.last_progress_at) >= ROLLOUT_STALL_TIMEOUT
+        {
+            self.stall_reported = true;
+            return Some(RolloutOutcome::Stalled);
+        }
+
+        None
+    }
+
+    /// Returns the share of desired tasks of the followed deployment that are running.
+    pub fn progress(&self) -> f32 {
+        match self.deployment() {
+            Some(d) if d.desired_count > 0 => d.running_count as f32 / d.desired_count as f32,
+            Some(_) => 1.0,
+            None => 0.0,
+        }
+    }
+
+    /// Estimates the remaining rollout time from the rate tasks have started so far.
+    ///
+    /// # Returns
+    /// `None` until at least one task of the followed deployment has started
+    pub fn eta(&self, now: Instant) -> Option<Duration> {
+        let deployment = self.deployment()?;
+        let started = deployment.running_count - self.initial_running?;
+        if started <= 0 {
+            return None;
+        }
+        let remaining = (deployment.desired_count - deployment.running_count).max(0);
+        let elapsed = now.duration_since(self.started_at);
+        Some(elapsed.mul_f64(remaining as f64 / started as f64))
+    }
+
+    /// Returns `true` if ECS reported a circuit breaker trigger on the followed deployment.
+    pub fn circuit_breaker_triggered(&self) -> bool {
+        self.deployment().is_some_and(|d| {
+            d.rollout_state_reason
+                .to_lowercase()
+                .contains("circuit breaker")
+        })
+    }
+}
+
+/// Formats a duration as a compact human-readable string (e.g., "2m 05s").
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deployment(id: &str, status: &str, rollout_state: &str, running: i32) -> DeploymentInfo {
+        DeploymentInfo {
+            id: id.to_string(),
+            status: status.to_string(),
+            rollout_state: rollout_state.to_string(),
+            rollout_state_reason: String::new(),
+            task_definition: "web:2".to_string(),
+            desired_count: 4,
+            running_count: running,
+            pending_count: 0,
+            failed_tasks: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn activity(deployments: Vec<DeploymentInfo>) -> ServiceActivity {
+        ServiceActivity {
+            deployments,
+            ..ServiceActivity::default()
+        }
+    }
+
+    fn monitor(now: Instant) -> RolloutMonitor {
+        RolloutMonitor::new("cluster".to_string(), "web".to_string(), now)
+    }
+
+    #[test]
+    fn test_first_snapshot_follows_primary() {
+        let now = Instant::now();
+        let mut monitor = monitor(now);
+        let outcome = monitor.update(
+            &activity(vec![
+                deployment("new", "PRIMARY", "IN_PROGRESS", 0),
+                deployment("old", "ACTIVE", "COMPLETED", 4),
+            ]),
+            now,
+        );
+        assert_eq!(outcome, None);
+        assert_eq!(monitor.deployment_id.as_deref(), Some("new"));
+    }
+
+    #[test]
+    fn test_completed_rollout() {
+        let now = Instant::now();
+        let mut monitor = monitor(now);
+        monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 0)]),
+            now,
+        );
+        let outcome = monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "COMPLETED", 4)]),
+            now,
+        );
+        assert_eq!(outcome, Some(RolloutOutcome::Completed));
+        assert!(outcome.is_some_and(|o| o.is_final()));
+    }
+
+    #[test]
+    fn test_failed_rollout_with_circuit_breaker_rollback() {
+        let now = Instant::now();
+        let mut monitor = monitor(now);
+        monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 0)]),
+            now,
+        );
+
+        let mut failed = deployment("new", "PRIMARY", "FAILED", 0);
+        failed.rollout_state_reason =
+            "ECS deployment circuit breaker: tasks failed to start.".to_string();
+        let outcome = monitor.update(
+            &ServiceActivity {
+                deployments: vec![failed],
+                circuit_breaker_enabled: true,
+                circuit_breaker_rollback: true,
+                ..ServiceActivity::default()
+            },
+            now,
+        );
+        assert!(matches!(outcome, Some(RolloutOutcome::RolledBack(_))));
+        assert!(monitor.circuit_breaker_triggered());
+    }
+
+    #[test]
+    fn test_failed_rollout_without_rollback() {
+        let now = Instant::now();
+        let mut monitor = monitor(now);
+        monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 0)]),
+            now,
+        );
+        let outcome = monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "FAILED", 0)]),
+            now,
+        );
+        assert_eq!(
+            outcome,
+            Some(RolloutOutcome::Failed("rollout failed".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_superseded_deployment_fails() {
+        let now = Instant::now();
+        let mut monitor = monitor(now);
+        monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 0)]),
+            now,
+        );
+        let outcome = monitor.update(
+            &activity(vec![
+                deployment("newer", "PRIMARY", "IN_PROGRESS", 0),
+                deployment("new", "ACTIVE", "IN_PROGRESS", 1),
+            ]),
+            now,
+        );
+        assert!(matches!(outcome, Some(RolloutOutcome::Failed(_))));
+    }
+
+    #[test]
+    fn test_stalled_rollout_is_reported_once() {
+        let start = Instant::now();
+        let mut monitor = monitor(start);
+        let snapshot = activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 1)]);
+        monitor.update(&snapshot, start);
+
+        let later = start + ROLLOUT_STALL_TIMEOUT + Duration::from_secs(1);
+        assert_eq!(
+            monitor.update(&snapshot, later),
+            Some(RolloutOutcome::Stalled)
+        );
+        assert_eq!(monitor.update(&snapshot, later), None);
+
+        // Progress clears the stall
+        let progressed = activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 2)]);
+        assert_eq!(monitor.update(&progressed, later), None);
+        assert!(!monitor.stall_reported);
+    }
+
+    #[test]
+    fn test_progress_and_eta() {
+        let start = Instant::now();
+        let mut monitor = monitor(start);
+        monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 0)]),
+            start,
+        );
+        assert_eq!(monitor.eta(start), None);
+
+        let later = start + Duration::from_secs(60);
+        monitor.update(
+            &activity(vec![deployment("new", "PRIMARY", "IN_PROGRESS", 1)]),
+            later,
+        );
+        assert!((monitor.progress() - 0.25).abs() < f32::EPSILON);
+        // One task per minute, three to go
+        assert_eq!(monitor.eta(later), Some(Duration::from_secs(180)));
+    }
+
+    #[test]
+    fn test_is_due() {
+        let start = Instant::now();
+        let mut monitor = monitor(start);
+        assert!(monitor.is_due(start));
+
+        monitor.last_poll = Some(start);
+        assert!(!monitor.is_due(start + Duration::from_secs(1)));
+        assert!(monitor.is_due(start + ROLLOUT_POLL_INTERVAL));
+
+        monitor.poll_in_flight = true;
+        assert!(!monitor.is_due(start + ROLLOUT_POLL_INTERVAL));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(42)), "42s");
+        assert_eq!(format_duration(Duration::from_secs(125)), "2m 05s");
+        assert_eq!(format_duration(Duration::from_secs(3720)), "1h 02m");
+    }
+}
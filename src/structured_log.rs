@@ -0,0 +1,347 @@
+//! Structured (JSON) log parsing.
+//!
+//! Log lines that are JSON objects are parsed into a [`StructuredLog`] holding the
+//! well-known fields (level, timestamp, logger, trace ID and message) and every field
+//! flattened into dotted names. The Logs view shows chosen fields as columns and
+//! filters entries with `field=value` expressions parsed by [`LogQuery`].
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::app::{LogEntry, LogLevel};
+
+/// Field names holding the log level, in order of preference.
+const LEVEL_KEYS: &[&str] = &[
+    "level",
+    "severity",
+    "lvl",
+    "log.level",
+    "levelname",
+    "loglevel",
+];
+
+/// Field names holding the time the application logged the entry.
+const TIMESTAMP_KEYS: &[&str] = &["timestamp", "time", "ts", "@timestamp", "datetime"];
+
+/// Field names holding the logger name.
+const LOGGER_KEYS: &[&str] = &[
+    "logger",
+    "logger_name",
+    "loggerName",
+    "log.logger",
+    "name",
+    "component",
+];
+
+/// Field names holding the trace ID.
+const TRACE_ID_KEYS: &[&str] = &[
+    "trace_id",
+    "traceId",
+    "traceID",
+    "trace.id",
+    "dd.trace_id",
+    "xray_trace_id",
+    "AWS-XRAY-TRACE-ID",
+];
+
+/// Field names holding the log message.
+const MESSAGE_KEYS: &[&str] = &["message", "msg", "@message", "log", "event"];
+
+/// A log line that is a JSON object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredLog {
+    /// Level named by the entry, `None` if it has no recognizable level field
+    pub level: Option<LogLevel>,
+    /// Time the application logged the entry (Unix milliseconds)
+    pub timestamp: Option<i64>,
+    /// Name of the logger that wrote the entry
+    pub logger: Option<String>,
+    /// Trace ID linking the entry to a request
+    pub trace_id: Option<String>,
+    /// Log message, without the other fields
+    pub message: Option<String>,
+    /// Every field of the entry, nested objects flattened into dotted names
+    pub fields: BTreeMap<String, String>,
+}
+
+impl StructuredLog {
+    /// Parses a log line, returning `None` unless it is a JSON object.
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            return None;
+        }
+        let Ok(Value::Object(object)) = serde_json::from_str::<Value>(line) else {
+            return None;
+        };
+
+        let mut fields = BTreeMap::new();
+        for (key, value) in &object {
+            flatten_field(key, value, &mut fields);
+        }
+        let first = |keys: &[&str]| keys.iter().find_map(|key| fields.get(*key).cloned());
+
+        Some(Self {
+            level: first(LEVEL_KEYS).and_then(|level| LogLevel::from_name(&level)),
+            timestamp: first(TIMESTAMP_KEYS).and_then(|time| parse_timestamp(&time)),
+            logger: first(LOGGER_KEYS),
+            trace_id: first(TRACE_ID_KEYS),
+            message: first(MESSAGE_KEYS),
+            fields,
+        })
+    }
+
+    /// Returns the value of a field.
+    ///
+    /// Besides the entry's own field names, `level`, `timestamp`, `logger`, `trace_id`
+    /// and `message` find the well-known fields under any of their common names.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        if let Some(value) = self.fields.get(name) {
+            return Some(value);
+        }
+        let keys = match name {
+            "level" => LEVEL_KEYS,
+            "timestamp" => TIMESTAMP_KEYS,
+            "logger" => LOGGER_KEYS,
+            "trace_id" => TRACE_ID_KEYS,
+            "message" => MESSAGE_KEYS,
+            _ => return None,
+        };
+        keys.iter()
+            .find_map(|key| self.fields.get(*key))
+            .map(String::as_str)
+    }
+}
+
+/// Adds a JSON field to `fields`, flattening nested objects into dotted names.
+fn flatten_field(name: &str, value: &Value, fields: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                flatten_field(&format!("{name}.{key}"), value, fields);
+            }
+        }
+        Value::String(text) => {
+            fields.insert(name.to_string(), text.clone());
+        }
+        other => {
+            fields.insert(name.to_string(), other.to_string());
+        }
+    }
+}
+
+/// Parses an RFC 3339 time or a Unix time in seconds or milliseconds into Unix milliseconds.
+fn parse_timestamp(time: &str) -> Option<i64> {
+    if let Ok(number) = time.parse::<f64>() {
+        // Unix times in seconds stay below 10^11 until the year 5138
+        return Some(if number.abs() < 1e11 {
+            (number * 1000.0) as i64
+        } else {
+            number as i64
+        });
+    }
+    chrono::DateTime::parse_from_rfc3339(time)
+        .ok()
+        .map(|time| time.timestamp_millis())
+}
+
+/// Pretty-prints a log line that is JSON, `None` for plain text.
+pub fn pretty_json(line: &str) -> Option<String> {
+    let value = serde_json::from_str::<Value>(line.trim()).ok()?;
+    if !value.is_object() && !value.is_array() {
+        return None;
+    }
+    serde_json::to_string_pretty(&value).ok()
+}
+
+/// A `field=value` (or `field!=value`) condition in a log search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldFilter {
+    /// Field name, see [`LogEntry::field`]
+    pub name: String,
+    /// Expected value, compared case-insensitively
+    pub value: String,
+    /// Whether entries must not have the value
+    pub negated: bool,
+}
+
+impl FieldFilter {
+    /// Parses a `field=value` or `field!=value` search term.
+    fn parse(term: &str) -> Option<Self> {
+        let (name, value) = term.split_once('=')?;
+        let (name, negated) = match name.strip_suffix('!') {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        let is_name_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '.' | '@' | '-');
+        if name.is_empty() || value.is_empty() || !name.chars().all(is_name_char) {
+            return None;
+        }
+        Some(Self {
+            name: name.to_string(),
+            value: value.to_string(),
+            negated,
+        })
+    }
+
+    /// Returns whether an entry passes the filter.
+    ///
+    /// Plain text entries without the field match when their message contains the
+    /// `field=value` pair, as written by logfmt loggers.
+    fn matches(&self, entry: &LogEntry) -> bool {
+        let found = match entry.field(&self.name) {
+            Some(value) => value.eq_ignore_ascii_case(&self.value),
+            None => {
+                entry.structured.is_none()
+                    && entry
+                        .message
+                        .to_lowercase()
+                        .contains(&format!("{}={}", self.name, self.value).to_lowercase())
+            }
+        };
+        found != self.negated
+    }
+}
+
+/// A log search: `field=value` filters and free text, e.g. `level=error status!=200 timeout`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LogQuery {
+    /// Field conditions every matching entry satisfies
+    pub filters: Vec<FieldFilter>,
+    /// Lowercase text the message or container name contains
+    pub text: String,
+}
+
+impl LogQuery {
+    /// Splits a search into field filters and free text.
+    pub fn parse(query: &str) -> Self {
+        let mut filters = Vec::new();
+        let mut words = Vec::new();
+        for term in query.split_whitespace() {
+            match FieldFilter::parse(term) {
+                Some(filter) => filters.push(filter),
+                None => words.push(term),
+            }
+        }
+        let text = if filters.is_empty() {
+            query.to_lowercase()
+        } else {
+            words.join(" ").to_lowercase()
+        };
+        Self { filters, text }
+    }
+
+    /// Returns whether an entry matches every filter and contains the text.
+    ///
+    /// The text is also searched for in the entry's continuation lines.
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        self.filters.iter().all(|filter| filter.matches(entry))
+            && (self.text.is_empty()
+                || entry.message.to_lowercase().contains(&self.text)
+                || entry
+                    .continuation
+                    .iter()
+                    .any(|line| line.to_lowercase().contains(&self.text))
+                || entry.container_name.to_lowercase().contains(&self.text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_extracts_well_known_fields() {
+        let log = StructuredLog::parse(
+            r#"{"level":"warn","time":"2026-10-14T09:00:00Z","logger":"api.http","trace_id":"abc","msg":"slow request","http":{"status":200,"path":"/health"},"retry":true}"#,
+        );
+        let Some(log) = log else {
+            unreachable!("JSON object should parse");
+        };
+
+        assert_eq!(log.level, Some(LogLevel::Warn));
+        assert_eq!(log.timestamp, Some(1_791_968_400_000));
+        assert_eq!(log.logger.as_deref(), Some("api.http"));
+        assert_eq!(log.trace_id.as_deref(), Some("abc"));
+        assert_eq!(log.message.as_deref(), Some("slow request"));
+        assert_eq!(log.get("http.status"), Some("200"));
+        assert_eq!(log.get("retry"), Some("true"));
+        assert_eq!(log.get("message"), Some("slow request"));
+        assert_eq!(log.get("missing"), None);
+    }
+
+    #[test]
+    fn test_parse_numeric_levels_and_epoch_timestamps() {
+        let Some(log) =
+            StructuredLog::parse(r#"{"level":50,"time":1791968400000,"name":"worker"}"#)
+        else {
+            unreachable!("JSON object should parse");
+        };
+        assert_eq!(log.level, Some(LogLevel::Error));
+        assert_eq!(log.timestamp, Some(1_791_968_400_000));
+        assert_eq!(log.logger.as_deref(), Some("worker"));
+
+        assert_eq!(parse_timestamp("1791968400.5"), Some(1_791_968_400_500));
+    }
+
+    #[test]
+    fn test_parse_ignores_plain_text_and_non_objects() {
+        assert!(StructuredLog::parse("INFO started").is_none());
+        assert!(StructuredLog::parse("{not json").is_none());
+        assert!(StructuredLog::parse("[1, 2]").is_none());
+        assert!(pretty_json("plain text").is_none());
+        assert_eq!(
+            pretty_json(r#"{"a":1}"#).as_deref(),
+            Some("{\n  \"a\": 1\n}")
+        );
+    }
+
+    #[test]
+    fn test_query_splits_filters_from_text() {
+        let query = LogQuery::parse("level=error Timeout status!=200 a=");
+        assert_eq!(
+            query.filters,
+            vec![
+                FieldFilter {
+                    name: "level".to_string(),
+                    value: "error".to_string(),
+                    negated: false,
+                },
+                FieldFilter {
+                    name: "status".to_string(),
+                    value: "200".to_string(),
+                    negated: true,
+                },
+            ]
+        );
+        assert_eq!(query.text, "timeout a=");
+
+        assert_eq!(
+            LogQuery::parse("Connection  refused").text,
+            "connection  refused"
+        );
+    }
+
+    #[test]
+    fn test_query_matches_structured_and_plain_entries() {
+        let entry = |message: &str| LogEntry::new(0, message.to_string(), "web".to_string());
+        let json = entry(r#"{"level":"error","msg":"db timeout","user":{"id":"42"}}"#);
+        let logfmt = entry("level=info user.id=42 msg=ok");
+        let plain = entry("ERROR: db timeout");
+
+        let query = LogQuery::parse("user.id=42");
+        assert!(query.matches(&json));
+        assert!(query.matches(&logfmt));
+        assert!(!query.matches(&plain));
+
+        let query = LogQuery::parse("level=ERROR timeout");
+        assert!(query.matches(&json));
+        assert!(!query.matches(&logfmt));
+        assert!(query.matches(&plain));
+
+        let query = LogQuery::parse("level!=error");
+        assert!(!query.matches(&json));
+        assert!(query.matches(&logfmt));
+    }
+}
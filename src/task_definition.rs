@@ -0,0 +1,1104 @@
+//! Task definition model.
+//!
+//! [`TaskDefinitionInfo`] holds a task definition revision as DescribeTaskDefinition
+//! reports it: the revision's metadata (ARN, revision, status, registration) and its
+//! registrable settings, including every container definition and volume. The task
+//! definition browser lists revisions and shows them in a structured detail view.
+//!
+//! The registrable settings serialize to the JSON accepted by RegisterTaskDefinition
+//! (`aws ecs register-task-definition --cli-input-json`); the metadata is left out.
+//! [`TaskDefinitionInfo::validate`] checks a definition before it is registered.
+//!
+//! [`TaskDefinitionDiff`] compares two revisions field by field, e.g. the revision a
+//! service runs against the one about to be deployed.
+
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// A task definition revision.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct TaskDefinitionInfo {
+    /// Full ARN of the task definition
+    #[serde(skip)]
+    pub arn: String,
+    /// Task definition family name
+    pub family: String,
+    /// Revision number
+    #[serde(skip)]
+    pub revision: i32,
+    /// Status (ACTIVE, INACTIVE or DELETE_IN_PROGRESS)
+    #[serde(skip)]
+    pub status: String,
+    /// When the revision was registered (Unix seconds)
+    #[serde(skip)]
+    pub registered_at: Option<i64>,
+    /// Principal that registered the revision
+    #[serde(skip)]
+    pub registered_by: Option<String>,
+    /// Launch types the revision is valid for, as validated by ECS
+    #[serde(skip)]
+    pub compatibilities: Vec<String>,
+    /// Launch types the revision was registered for
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub requires_compatibilities: Vec<String>,
+    /// IAM role the containers assume
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_role_arn: Option<String>,
+    /// IAM role ECS uses to pull images and read secrets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_role_arn: Option<String>,
+    /// Docker networking mode (awsvpc, bridge, host or none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_mode: Option<String>,
+    /// Task CPU units, e.g. "256" or "1 vCPU"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<String>,
+    /// Task memory in MiB, e.g. "512" or "1 GB"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+    /// CPU architecture and operating system family
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_platform: Option<RuntimePlatformInfo>,
+    /// Fargate ephemeral storage in GiB
+    #[serde(
+        rename = "ephemeralStorage",
+        with = "ephemeral_storage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ephemeral_storage_gib: Option<i32>,
+    /// Container definitions
+    #[serde(rename = "containerDefinitions")]
+    pub containers: Vec<ContainerDefinitionInfo>,
+    /// Data volumes the containers can mount
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub volumes: Vec<VolumeInfo>,
+}
+
+impl TaskDefinitionInfo {
+    /// Returns the `family:revision` name of the revision.
+    pub fn family_revision(&self) -> String {
+        format!("{}:{}", self.family, self.revision)
+    }
+
+    /// Returns the registrable settings as pretty-printed RegisterTaskDefinition JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize task definition")
+    }
+
+    /// Parses RegisterTaskDefinition JSON into a definition without revision metadata.
+    ///
+    /// # Errors
+    /// Returns an error naming the line and column of malformed JSON, and of fields
+    /// that are unknown or have the wrong type.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("Invalid task definition JSON")
+    }
+
+    /// Returns whether the definition is registered for Fargate.
+    pub fn requires_fargate(&self) -> bool {
+        self.requires_compatibilities
+            .iter()
+            .any(|compatibility| compatibility == "FARGATE")
+    }
+
+    /// Checks the definition for mistakes RegisterTaskDefinition would reject, or that
+    /// would keep its tasks from starting.
+    ///
+    /// # Returns
+    /// One message per problem; empty if the definition is valid
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let valid_name = |name: &str| {
+            !name.is_empty()
+                && name.len() <= 255
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        };
+
+        if !valid_name(&self.family) {
+            problems.push(format!(
+                "Family '{}' must be 1-255 letters, numbers, hyphens or underscores",
+                self.family
+            ));
+        }
+
+        let cpu = self.cpu.as_deref().map(|cpu| (cpu, cpu_units(cpu)));
+        let memory = self
+            .memory
+            .as_deref()
+            .map(|memory| (memory, memory_mib(memory)));
+        if let Some((cpu, None)) = cpu {
+            problems.push(format!("Task CPU '{cpu}' is not a number of CPU units"));
+        }
+        if let Some((memory, None)) = memory {
+            problems.push(format!("Task memory '{memory}' is not a number of MiB"));
+        }
+        let task_memory = memory.and_then(|(_, mib)| mib);
+
+        if self.requires_fargate() {
+            if self.network_mode.as_deref() != Some("awsvpc") {
+                problems.push("Fargate requires the awsvpc network mode".to_string());
+            }
+            match (cpu, memory) {
+                (Some((_, Some(cpu))), Some((_, Some(memory))))
+                    if !valid_fargate_size(cpu, memory) =>
+                {
+                    problems.push(format!(
+                        "Fargate does not support {cpu} CPU units with {memory} MiB of memory"
+                    ));
+                }
+                (None, _) | (_, None) => {
+                    problems.push("Fargate requires task CPU and memory".to_string());
+                }
+                _ => {}
+            }
+        }
+
+        if self.containers.is_empty() {
+            problems.push("At least one container definition is required".to_string());
+        }
+        if !self.containers.is_empty()
+            && !self
+                .containers
+                .iter()
+                .any(|container| container.essential.unwrap_or(true))
+        {
+            problems.push("At least one container must be essential".to_string());
+        }
+
+        let container_names: HashSet<&str> = self
+            .containers
+            .iter()
+            .map(|container| container.name.as_str())
+            .collect();
+        let volume_names: HashSet<&str> = self
+            .volumes
+            .iter()
+            .map(|volume| volume.name.as_str())
+            .collect();
+        let mut seen_containers = HashSet::new();
+        for container in &self.containers {
+            let name = &container.name;
+            if !valid_name(name) {
+                problems.push(format!(
+                    "Container name '{name}' must be 1-255 letters, numbers, hyphens or underscores"
+                ));
+            }
+            if !seen_containers.insert(name.as_str()) {
+                problems.push(format!("Container name '{name}' is used more than once"));
+            }
+            if container.image.trim().is_empty() {
+                problems.push(format!("{name}: image is required"));
+            }
+
+            if let (Some(limit), Some(reservation)) =
+                (container.memory, container.memory_reservation)
+            {
+                if reservation > limit {
+                    problems.push(format!(
+                        "{name}: memory reservation {reservation} MiB exceeds the memory limit {limit} MiB"
+                    ));
+                }
+            }
+            match task_memory {
+                Some(task_memory) => {
+                    let container_memory = container.memory.or(container.memory_reservation);
+                    if let Some(container_memory) = container_memory {
+                        if u32::try_from(container_memory).map_or(true, |m| m > task_memory) {
+                            problems.push(format!(
+                                "{name}: memory {container_memory} MiB exceeds the task memory {task_memory} MiB"
+                            ));
+                        }
+                    }
+                }
+                None if memory.is_none()
+                    && container.memory.is_none()
+                    && container.memory_reservation.is_none() =>
+                {
+                    problems.push(format!(
+                        "{name}: memory or memory reservation is required without task memory"
+                    ));
+                }
+                None => {}
+            }
+
+            let mut variables = HashSet::new();
+            for variable in &container.environment {
+                if variable.name.trim().is_empty() {
+                    problems.push(format!("{name}: environment variable without a name"));
+                } else if !variables.insert(variable.name.as_str()) {
+                    problems.push(format!(
+                        "{name}: environment variable {} is set more than once",
+                        variable.name
+                    ));
+                }
+            }
+            for secret in &container.secrets {
+                if secret.name.trim().is_empty() {
+                    problems.push(format!("{name}: secret without a name"));
+                } else if !variables.insert(secret.name.as_str()) {
+                    problems.push(format!(
+                        "{name}: {} is set more than once as a variable or secret",
+                        secret.name
+                    ));
+                }
+                if secret.value_from.trim().is_empty() {
+                    problems.push(format!("{name}: secret {} has no valueFrom", secret.name));
+                }
+            }
+
+            if self.network_mode.as_deref() == Some("awsvpc") {
+                for port in &container.port_mappings {
+                    if let (Some(container_port), Some(host_port)) =
+                        (port.container_port, port.host_port)
+                    {
+                        if container_port != host_port {
+                            problems.push(format!(
+                                "{name}: host port {host_port} must equal container port {container_port} in awsvpc mode"
+                            ));
+                        }
+                    }
+                }
+            }
+            for mount in &container.mount_points {
+                if !volume_names.contains(mount.source_volume.as_str()) {
+                    problems.push(format!(
+                        "{name}: mount point uses unknown volume '{}'",
+                        mount.source_volume
+                    ));
+                }
+            }
+            for dependency in &container.depends_on {
+                if dependency.container_name == *name
+                    || !container_names.contains(dependency.container_name.as_str())
+                {
+                    problems.push(format!(
+                        "{name}: depends on unknown container '{}'",
+                        dependency.container_name
+                    ));
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// Parses task CPU, e.g. "256" or "0.25 vCPU", into CPU units.
+fn cpu_units(cpu: &str) -> Option<u32> {
+    scaled_units(cpu, "vcpu")
+}
+
+/// Parses task memory, e.g. "512" or "0.5 GB", into MiB.
+fn memory_mib(memory: &str) -> Option<u32> {
+    scaled_units(memory, "gb")
+}
+
+/// Parses a plain number, or a number of `unit`s worth 1024 each.
+fn scaled_units(value: &str, unit: &str) -> Option<u32> {
+    let value = value.trim().to_lowercase();
+    match value.strip_suffix(unit) {
+        Some(number) => {
+            let number: f64 = number.trim().parse().ok()?;
+            (number > 0.0).then(|| (number * 1024.0).round() as u32)
+        }
+        None => value.parse().ok(),
+    }
+}
+
+/// Returns whether Fargate supports a task size (CPU units and MiB of memory).
+fn valid_fargate_size(cpu: u32, memory: u32) -> bool {
+    let range = |min: u32, max: u32, step: u32| {
+        (min..=max).contains(&memory) && (memory - min).is_multiple_of(step)
+    };
+    match cpu {
+        256 => matches!(memory, 512 | 1024 | 2048),
+        512 => range(1024, 4096, 1024),
+        1024 => range(2048, 8192, 1024),
+        2048 => range(4096, 16384, 1024),
+        4096 => range(8192, 30720, 1024),
+        8192 => range(16384, 61440, 4096),
+        16384 => range(32768, 122880, 8192),
+        _ => false,
+    }
+}
+
+/// (De)serializes the ephemeral storage size as `{"sizeInGiB": 21}`.
+mod ephemeral_storage {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct EphemeralStorage {
+        #[serde(rename = "sizeInGiB")]
+        size_in_gib: i32,
+    }
+
+    pub fn serialize<S: Serializer>(size: &Option<i32>, serializer: S) -> Result<S::Ok, S::Error> {
+        size.map(|size_in_gib| EphemeralStorage { size_in_gib })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<i32>, D::Error> {
+        Ok(Option::<EphemeralStorage>::deserialize(deserializer)?
+            .map(|storage| storage.size_in_gib))
+    }
+}
+
+/// CPU architecture and operating system a task definition runs on.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct RuntimePlatformInfo {
+    /// X86_64 or ARM64
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_architecture: Option<String>,
+    /// e.g. LINUX or WINDOWS_SERVER_2022_CORE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operating_system_family: Option<String>,
+}
+
+/// A container definition of a task definition.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ContainerDefinitionInfo {
+    /// Container name
+    pub name: String,
+    /// Image, e.g. "123456789012.dkr.ecr.us-east-1.amazonaws.com/web:1.4.2"
+    pub image: String,
+    /// CPU units reserved for the container
+    pub cpu: i32,
+    /// Hard memory limit in MiB
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i32>,
+    /// Soft memory limit in MiB
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i32>,
+    /// Whether the task stops when the container stops
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub essential: Option<bool>,
+    /// Command passed to the container
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<String>,
+    /// Entry point of the container
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entry_point: Vec<String>,
+    /// Working directory of the command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_directory: Option<String>,
+    /// User the command runs as
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Ports the container exposes
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub port_mappings: Vec<PortMappingInfo>,
+    /// Environment variables set in plain text
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub environment: Vec<EnvironmentVariable>,
+    /// Environment variables read from Secrets Manager or SSM Parameter Store
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secrets: Vec<SecretReference>,
+    /// Container health check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check: Option<HealthCheckInfo>,
+    /// Volumes mounted into the container
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mount_points: Vec<MountPointInfo>,
+    /// Log driver and its options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_configuration: Option<LogConfigurationInfo>,
+    /// Containers that must reach a condition before this one starts
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<ContainerDependencyInfo>,
+    /// Whether the root filesystem is mounted read-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readonly_root_filesystem: Option<bool>,
+    /// Docker labels
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub docker_labels: BTreeMap<String, String>,
+    /// Seconds to wait after SIGTERM before the container is killed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<i32>,
+    /// Seconds to wait for dependencies before giving up starting the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timeout: Option<i32>,
+}
+
+/// A port mapping of a container.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PortMappingInfo {
+    /// Port the container listens on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_port: Option<i32>,
+    /// Port on the host, for bridge and host networking
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<i32>,
+    /// tcp or udp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// Name used by Service Connect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Application protocol used by Service Connect (http, http2 or grpc)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_protocol: Option<String>,
+}
+
+/// A plain text environment variable.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnvironmentVariable {
+    /// Variable name
+    pub name: String,
+    /// Variable value
+    pub value: String,
+}
+
+/// An environment variable (or log option) read from a secret.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SecretReference {
+    /// Variable name
+    pub name: String,
+    /// ARN or name of the Secrets Manager secret or SSM parameter
+    pub value_from: String,
+}
+
+/// A container health check.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct HealthCheckInfo {
+    /// Command, e.g. ["CMD-SHELL", "curl -f http://localhost/ || exit 1"]
+    pub command: Vec<String>,
+    /// Seconds between checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i32>,
+    /// Seconds before a check fails
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i32>,
+    /// Failed checks before the container is unhealthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i32>,
+    /// Seconds after start during which failed checks are not counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_period: Option<i32>,
+}
+
+/// A volume mounted into a container.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MountPointInfo {
+    /// Name of the task definition volume
+    pub source_volume: String,
+    /// Path in the container
+    pub container_path: String,
+    /// Whether the volume is mounted read-only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+/// The log driver of a container.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct LogConfigurationInfo {
+    /// Log driver, e.g. awslogs or awsfirelens
+    pub log_driver: String,
+    /// Options of the log driver
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub options: BTreeMap<String, String>,
+    /// Options of the log driver read from secrets
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secret_options: Vec<SecretReference>,
+}
+
+/// A container that must reach a condition before another one starts.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ContainerDependencyInfo {
+    /// Name of the container depended on
+    pub container_name: String,
+    /// START, COMPLETE, SUCCESS or HEALTHY
+    pub condition: String,
+}
+
+/// A data volume of a task definition.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(from = "VolumeJson", into = "VolumeJson")]
+pub struct VolumeInfo {
+    /// Volume name referenced by mount points
+    pub name: String,
+    /// Path on the host, for bind mounts on EC2
+    pub host_source_path: Option<String>,
+    /// Amazon EFS file system mounted as the volume
+    pub efs: Option<EfsVolumeInfo>,
+}
+
+/// An Amazon EFS file system mounted as a volume.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EfsVolumeInfo {
+    /// EFS file system ID
+    pub file_system_id: String,
+    /// Directory of the file system mounted as the root of the volume
+    pub root_directory: Option<String>,
+    /// Whether data is encrypted in transit (ENABLED or DISABLED)
+    pub transit_encryption: Option<String>,
+    /// EFS access point ID
+    pub access_point_id: Option<String>,
+    /// Whether the task role is used to authorize access (ENABLED or DISABLED)
+    pub iam: Option<String>,
+}
+
+/// A volume in the nested shape of the ECS API.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct VolumeJson {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    host: Option<HostVolumeJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    efs_volume_configuration: Option<EfsVolumeJson>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct HostVolumeJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct EfsVolumeJson {
+    file_system_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    root_directory: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transit_encryption: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    authorization_config: Option<EfsAuthorizationJson>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct EfsAuthorizationJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    access_point_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iam: Option<String>,
+}
+
+impl From<VolumeJson> for VolumeInfo {
+    fn from(volume: VolumeJson) -> Self {
+        Self {
+            name: volume.name,
+            host_source_path: volume.host.and_then(|host| host.source_path),
+            efs: volume.efs_volume_configuration.map(|efs| {
+                let authorization = efs.authorization_config;
+                EfsVolumeInfo {
+                    file_system_id: efs.file_system_id,
+                    root_directory: efs.root_directory,
+                    transit_encryption: efs.transit_encryption,
+                    access_point_id: authorization
+                        .as_ref()
+                        .and_then(|auth| auth.access_point_id.clone()),
+                    iam: authorization.and_then(|auth| auth.iam),
+                }
+            }),
+        }
+    }
+}
+
+impl From<VolumeInfo> for VolumeJson {
+    fn from(volume: VolumeInfo) -> Self {
+        Self {
+            name: volume.name,
+            host: volume.host_source_path.map(|path| HostVolumeJson {
+                source_path: Some(path),
+            }),
+            efs_volume_configuration: volume.efs.map(|efs| EfsVolumeJson {
+                file_system_id: efs.file_system_id,
+                root_directory: efs.root_directory,
+                transit_encryption: efs.transit_encryption,
+                authorization_config: (efs.access_point_id.is_some() || efs.iam.is_some())
+                    .then_some(EfsAuthorizationJson {
+                        access_point_id: efs.access_point_id,
+                        iam: efs.iam,
+                    }),
+            }),
+        }
+    }
+}
+
+/// How a field differs between two task definition revisions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldChange {
+    /// Only the newer revision has the field
+    Added,
+    /// Only the older revision has the field
+    Removed,
+    /// Both revisions have the field with different values
+    Changed,
+    /// Both revisions have the field with the same value
+    Unchanged,
+}
+
+/// A field of two task definition revisions side by side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    /// Field name, e.g. "web › image" or "web › env LOG_LEVEL"
+    pub field: String,
+    /// Value in the older revision
+    pub old: Option<String>,
+    /// Value in the newer revision
+    pub new: Option<String>,
+}
+
+impl FieldDiff {
+    /// Returns how the field differs between the revisions.
+    pub fn change(&self) -> FieldChange {
+        match (&self.old, &self.new) {
+            (None, Some(_)) => FieldChange::Added,
+            (Some(_), None) => FieldChange::Removed,
+            (old, new) if old == new => FieldChange::Unchanged,
+            _ => FieldChange::Changed,
+        }
+    }
+}
+
+/// Two task definition revisions compared field by field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskDefinitionDiff {
+    /// Revision compared against, usually the older one
+    pub old: TaskDefinitionInfo,
+    /// Revision compared, usually the newer one
+    pub new: TaskDefinitionInfo,
+    /// Fields of both revisions, in the order of the structured view
+    pub fields: Vec<FieldDiff>,
+    /// Whether fields with the same value in both revisions are shown
+    pub show_unchanged: bool,
+    /// Whether the diff was opened from the service editor, which closing it returns to
+    pub from_service_editor: bool,
+}
+
+impl TaskDefinitionDiff {
+    /// Compares two revisions, hiding unchanged fields.
+    pub fn new(old: TaskDefinitionInfo, new: TaskDefinitionInfo) -> Self {
+        let fields = diff_fields(&task_definition_fields(&old), &task_definition_fields(&new));
+        Self {
+            old,
+            new,
+            fields,
+            show_unchanged: false,
+            from_service_editor: false,
+        }
+    }
+
+    /// Returns the fields shown: every field, or only those that differ.
+    pub fn visible_fields(&self) -> Vec<&FieldDiff> {
+        self.fields
+            .iter()
+            .filter(|field| self.show_unchanged || field.change() != FieldChange::Unchanged)
+            .collect()
+    }
+
+    /// Returns the number of added, removed and changed fields.
+    pub fn summary(&self) -> (usize, usize, usize) {
+        let count = |change: FieldChange| {
+            self.fields
+                .iter()
+                .filter(|field| field.change() == change)
+                .count()
+        };
+        (
+            count(FieldChange::Added),
+            count(FieldChange::Removed),
+            count(FieldChange::Changed),
+        )
+    }
+}
+
+/// Flattens a revision into named fields in the order of the structured view: task
+/// settings and roles, then each container's settings, then the volumes.
+pub fn task_definition_fields(revision: &TaskDefinitionInfo) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    let mut push = |name: String, value: Option<String>| {
+        if let Some(value) = value {
+            fields.push((name, value));
+        }
+    };
+    let list = |values: &[String]| (!values.is_empty()).then(|| values.join(", "));
+
+    push("CPU".to_string(), revision.cpu.clone());
+    push("Memory".to_string(), revision.memory.clone());
+    push("Network mode".to_string(), revision.network_mode.clone());
+    push(
+        "Requires compatibilities".to_string(),
+        list(&revision.requires_compatibilities),
+    );
+    if let Some(platform) = &revision.runtime_platform {
+        push(
+            "Operating system".to_string(),
+            platform.operating_system_family.clone(),
+        );
+        push(
+            "CPU architecture".to_string(),
+            platform.cpu_architecture.clone(),
+        );
+    }
+    push(
+        "Ephemeral storage".to_string(),
+        revision
+            .ephemeral_storage_gib
+            .map(|size| format!("{size} GiB")),
+    );
+    push("Task role".to_string(), revision.task_role_arn.clone());
+    push(
+        "Execution role".to_string(),
+        revision.execution_role_arn.clone(),
+    );
+
+    for container in &revision.containers {
+        let name = |field: &str| format!("{} › {field}", container.name);
+        push(name("image"), Some(container.image.clone()));
+        push(name("cpu"), Some(container.cpu.to_string()));
+        push(name("memory"), container.memory.map(|m| format!("{m} MiB")));
+        push(
+            name("memory reservation"),
+            container.memory_reservation.map(|m| format!("{m} MiB")),
+        );
+        push(
+            name("essential"),
+            Some(container.essential.unwrap_or(true).to_string()),
+        );
+        push(name("entry point"), list(&container.entry_point));
+        push(name("command"), list(&container.command));
+        push(
+            name("working directory"),
+            container.working_directory.clone(),
+        );
+        push(name("user"), container.user.clone());
+        for port in &container.port_mappings {
+            let container_port = port
+                .container_port
+                .map(|port| port.to_string())
+                .unwrap_or_else(|| "?".to_string());
+            let protocol = port.protocol.as_deref().unwrap_or("tcp");
+            let mut value = format!("{container_port}/{protocol}");
+            if let Some(host_port) = port.host_port {
+                value.push_str(&format!(" host {host_port}"));
+            }
+            if let Some(app_protocol) = &port.app_protocol {
+                value.push_str(&format!(" {app_protocol}"));
+            }
+            if let Some(port_name) = &port.name {
+                value.push_str(&format!(" ({port_name})"));
+            }
+            push(
+                name(&format!("port {container_port}/{protocol}")),
+                Some(value),
+            );
+        }
+        for variable in &container.environment {
+            push(
+                name(&format!("env {}", variable.name)),
+                Some(variable.value.clone()),
+            );
+        }
+        for secret in &container.secrets {
+            push(
+                name(&format!("secret {}", secret.name)),
+                Some(secret.value_from.clone()),
+            );
+        }
+        if let Some(check) = &container.health_check {
+            push(name("health check"), Some(check.command.join(" ")));
+            let timing: Vec<String> = [
+                check.interval.map(|s| format!("interval {s}s")),
+                check.timeout.map(|s| format!("timeout {s}s")),
+                check.retries.map(|n| format!("retries {n}")),
+                check.start_period.map(|s| format!("start period {s}s")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            push(name("health check timing"), list(&timing));
+        }
+        for mount in &container.mount_points {
+            let access = if mount.read_only.unwrap_or(false) {
+                "read-only"
+            } else {
+                "read-write"
+            };
+            push(
+                name(&format!("mount {}", mount.container_path)),
+                Some(format!("{} ({access})", mount.source_volume)),
+            );
+        }
+        if let Some(log_config) = &container.log_configuration {
+            push(name("log driver"), Some(log_config.log_driver.clone()));
+            for (key, value) in &log_config.options {
+                push(name(&format!("log option {key}")), Some(value.clone()));
+            }
+            for secret in &log_config.secret_options {
+                push(
+                    name(&format!("log secret {}", secret.name)),
+                    Some(secret.value_from.clone()),
+                );
+            }
+        }
+        for dependency in &container.depends_on {
+            push(
+                name(&format!("depends on {}", dependency.container_name)),
+                Some(dependency.condition.clone()),
+            );
+        }
+        push(
+            name("read-only root filesystem"),
+            container.readonly_root_filesystem.map(|b| b.to_string()),
+        );
+        for (key, value) in &container.docker_labels {
+            push(name(&format!("label {key}")), Some(value.clone()));
+        }
+        push(
+            name("stop timeout"),
+            container.stop_timeout.map(|s| format!("{s}s")),
+        );
+        push(
+            name("start timeout"),
+            container.start_timeout.map(|s| format!("{s}s")),
+        );
+    }
+
+    for volume in &revision.volumes {
+        let source = match (&volume.efs, &volume.host_source_path) {
+            (Some(efs), _) => format!(
+                "EFS {}{}",
+                efs.file_system_id,
+                efs.root_directory.as_deref().unwrap_or("")
+            ),
+            (None, Some(path)) => format!("host path {path}"),
+            (None, None) => "task storage".to_string(),
+        };
+        push(format!("volume {}", volume.name), Some(source));
+    }
+    fields
+}
+
+/// Lines up the fields of two revisions by name.
+///
+/// Fields keep the older revision's order; fields only the newer revision has are
+/// placed after the field preceding them in the newer revision, so a new environment
+/// variable shows up next to the container's other variables.
+fn diff_fields(old: &[(String, String)], new: &[(String, String)]) -> Vec<FieldDiff> {
+    let mut diffs: Vec<FieldDiff> = old
+        .iter()
+        .map(|(field, value)| FieldDiff {
+            field: field.clone(),
+            old: Some(value.clone()),
+            new: None,
+        })
+        .collect();
+
+    let mut insert_at = 0;
+    for (field, value) in new {
+        match diffs.iter().position(|diff| &diff.field == field) {
+            Some(index) => {
+                diffs[index].new = Some(value.clone());
+                insert_at = index + 1;
+            }
+            None => {
+                diffs.insert(
+                    insert_at,
+                    FieldDiff {
+                        field: field.clone(),
+                        old: None,
+                        new: Some(value.clone()),
+                    },
+                );
+                insert_at += 1;
+            }
+        }
+    }
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(revision: i32, image_tag: &str) -> TaskDefinitionInfo {
+        TaskDefinitionInfo {
+            family: "web".to_string(),
+            revision,
+            cpu: Some("256".to_string()),
+            memory: Some("512".to_string()),
+            containers: vec![ContainerDefinitionInfo {
+                name: "web".to_string(),
+                image: format!("nginx:{image_tag}"),
+                environment: vec![EnvironmentVariable {
+                    name: "MODE".to_string(),
+                    value: "prod".to_string(),
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn changes(diff: &TaskDefinitionDiff) -> Vec<(&str, FieldChange)> {
+        diff.visible_fields()
+            .into_iter()
+            .map(|field| (field.field.as_str(), field.change()))
+            .collect()
+    }
+
+    #[test]
+    fn test_json_uses_register_task_definition_shape() -> Result<()> {
+        let mut definition = revision(3, "1.26");
+        definition.arn = "arn:aws:ecs:us-east-1:123456789012:task-definition/web:3".to_string();
+        definition.ephemeral_storage_gib = Some(30);
+        definition.volumes.push(VolumeInfo {
+            name: "data".to_string(),
+            efs: Some(EfsVolumeInfo {
+                file_system_id: "fs-1234".to_string(),
+                access_point_id: Some("fsap-1".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let json: serde_json::Value = serde_json::from_str(&definition.to_json()?)?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "family": "web",
+                "cpu": "256",
+                "memory": "512",
+                "ephemeralStorage": {"sizeInGiB": 30},
+                "containerDefinitions": [{
+                    "name": "web",
+                    "image": "nginx:1.26",
+                    "cpu": 0,
+                    "environment": [{"name": "MODE", "value": "prod"}],
+                }],
+                "volumes": [{
+                    "name": "data",
+                    "efsVolumeConfiguration": {
+                        "fileSystemId": "fs-1234",
+                        "authorizationConfig": {"accessPointId": "fsap-1"},
+                    },
+                }],
+            })
+        );
+
+        let parsed = TaskDefinitionInfo::from_json(&json.to_string())?;
+        assert_eq!(parsed.arn, "");
+        assert_eq!(parsed.volumes, definition.volumes);
+        assert_eq!(parsed.containers, definition.containers);
+        assert!(TaskDefinitionInfo::from_json(r#"{"family": "web", "revison": 3}"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_reports_registration_problems() {
+        let mut definition = revision(1, "1.26");
+        assert_eq!(definition.validate(), Vec::<String>::new());
+
+        definition.requires_compatibilities = vec!["FARGATE".to_string()];
+        definition.network_mode = Some("awsvpc".to_string());
+        definition.cpu = Some(".5 vCPU".to_string());
+        definition.memory = Some("1 GB".to_string());
+        assert_eq!(definition.validate(), Vec::<String>::new());
+
+        definition.memory = Some("3000".to_string());
+        definition.containers[0].memory = Some(4096);
+        definition.containers[0].essential = Some(false);
+        definition.containers[0]
+            .port_mappings
+            .push(PortMappingInfo {
+                container_port: Some(8080),
+                host_port: Some(80),
+                ..Default::default()
+            });
+        definition.containers[0].mount_points.push(MountPointInfo {
+            source_volume: "data".to_string(),
+            container_path: "/data".to_string(),
+            read_only: None,
+        });
+        definition.containers.push(ContainerDefinitionInfo {
+            name: "web".to_string(),
+            essential: Some(false),
+            ..Default::default()
+        });
+        assert_eq!(
+            definition.validate(),
+            vec![
+                "Fargate does not support 512 CPU units with 3000 MiB of memory",
+                "At least one container must be essential",
+                "web: memory 4096 MiB exceeds the task memory 3000 MiB",
+                "web: host port 80 must equal container port 8080 in awsvpc mode",
+                "web: mount point uses unknown volume 'data'",
+                "Container name 'web' is used more than once",
+                "web: image is required",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed_fields() {
+        let old = revision(1, "1.26");
+        let mut new = revision(2, "1.27");
+        new.memory = Some("1024".to_string());
+        new.containers[0].environment.push(EnvironmentVariable {
+            name: "DEBUG".to_string(),
+            value: "false".to_string(),
+        });
+        new.containers[0].secrets.push(SecretReference {
+            name: "TOKEN".to_string(),
+            value_from: "arn:aws:ssm:us-east-1:123456789012:parameter/token".to_string(),
+        });
+        new.cpu = None;
+
+        let diff = TaskDefinitionDiff::new(old, new);
+        assert_eq!(
+            changes(&diff),
+            vec![
+                ("CPU", FieldChange::Removed),
+                ("Memory", FieldChange::Changed),
+                ("web › image", FieldChange::Changed),
+                ("web › env DEBUG", FieldChange::Added),
+                ("web › secret TOKEN", FieldChange::Added),
+            ]
+        );
+        assert_eq!(diff.summary(), (2, 1, 2));
+    }
+
+    #[test]
+    fn test_diff_keeps_unchanged_fields_in_order_when_shown() {
+        let mut diff = TaskDefinitionDiff::new(revision(1, "1.26"), revision(2, "1.26"));
+        assert!(diff.visible_fields().is_empty());
+
+        diff.show_unchanged = true;
+        let fields: Vec<&str> = diff
+            .visible_fields()
+            .into_iter()
+            .map(|field| field.field.as_str())
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "CPU",
+                "Memory",
+                "web › image",
+                "web › cpu",
+                "web › essential",
+                "web › env MODE"
+            ]
+        );
+    }
+}
@@ -0,0 +1,284 @@
+//! Rule-based cleanup of old task definition revisions.
+//!
+//! The cleanup dialog of the task definition browser is described by a
+//! [`TaskDefinitionCleanup`]: the rules selecting the revisions of a family, the
+//! [`CleanupPlan`] previewing the revisions they select, and the [`CleanupProgress`]
+//! of deregistering them in batches.
+
+use std::collections::HashSet;
+
+/// Revisions deregistered, and optionally deleted, per background action.
+/// DeleteTaskDefinitions accepts at most 10 revisions per call.
+pub const CLEANUP_BATCH_SIZE: usize = 10;
+
+/// Fields of the cleanup dialog, in the order Tab moves through them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupField {
+    OlderThanDays,
+    KeepLatest,
+    SkipInUse,
+    Delete,
+}
+
+impl CleanupField {
+    const ALL: [CleanupField; 4] = [
+        CleanupField::OlderThanDays,
+        CleanupField::KeepLatest,
+        CleanupField::SkipInUse,
+        CleanupField::Delete,
+    ];
+}
+
+/// Revisions of a family selected by the cleanup rules.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CleanupPlan {
+    /// Number of active revisions of the family
+    pub active: usize,
+    /// ARNs of the revisions to deregister, oldest first
+    pub revisions: Vec<String>,
+    /// ARNs of old revisions kept because a service or deployment uses them
+    pub in_use: Vec<String>,
+}
+
+/// Progress of a cleanup running in the background.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CleanupProgress {
+    /// Number of revisions to clean up
+    pub total: usize,
+    /// Number of revisions whose batch has run
+    pub processed: usize,
+    /// Number of revisions deregistered
+    pub deregistered: usize,
+    /// Number of deregistered revisions deleted
+    pub deleted: usize,
+    /// Revisions that could not be deregistered or deleted, with the reason
+    pub failures: Vec<(String, String)>,
+}
+
+impl CleanupProgress {
+    /// Creates the progress of a cleanup of `total` revisions.
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the fraction of revisions processed, between 0.0 and 1.0.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.processed as f32 / self.total as f32
+        }
+    }
+
+    /// Returns whether every revision has been processed.
+    pub fn is_finished(&self) -> bool {
+        self.processed >= self.total
+    }
+
+    /// Adds the outcome of a batch of `size` revisions.
+    pub fn record(&mut self, size: usize, batch: CleanupBatch) {
+        self.processed += size;
+        self.deregistered += batch.deregistered;
+        self.deleted += batch.deleted;
+        self.failures.extend(batch.failures);
+    }
+}
+
+/// Outcome of cleaning up one batch of revisions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CleanupBatch {
+    /// Number of revisions deregistered
+    pub deregistered: usize,
+    /// Number of revisions deleted
+    pub deleted: usize,
+    /// Revisions that could not be deregistered or deleted, with the reason
+    pub failures: Vec<(String, String)>,
+}
+
+/// Rules, preview and progress of the cleanup dialog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskDefinitionCleanup {
+    /// Task definition family being cleaned up
+    pub family: String,
+    /// Only revisions registered more than this many days ago are selected (digits)
+    pub older_than_days: String,
+    /// Number of newest revisions that are always kept (digits)
+    pub keep_latest: String,
+    /// Whether revisions used by a service of any cluster in the region are kept
+    pub skip_in_use: bool,
+    /// Whether deregistered revisions are also deleted permanently
+    pub delete: bool,
+    /// Field being edited
+    pub field: CleanupField,
+    /// Revisions selected by the rules, once previewed
+    pub plan: Option<CleanupPlan>,
+    /// Progress of the running cleanup
+    pub progress: Option<CleanupProgress>,
+}
+
+impl TaskDefinitionCleanup {
+    /// Creates a dialog selecting the revisions of `family` older than 90 days,
+    /// keeping the latest 5 and every revision in use, without deleting them.
+    pub fn new(family: String) -> Self {
+        Self {
+            family,
+            older_than_days: "90".to_string(),
+            keep_latest: "5".to_string(),
+            skip_in_use: true,
+            delete: false,
+            field: CleanupField::OlderThanDays,
+            plan: None,
+            progress: None,
+        }
+    }
+
+    /// Returns the age rule in days, `None` if the field is empty.
+    pub fn older_than_days(&self) -> Option<u32> {
+        self.older_than_days.parse().ok()
+    }
+
+    /// Returns the number of revisions to keep, `None` if the field is empty.
+    pub fn keep_latest(&self) -> Option<usize> {
+        self.keep_latest.parse().ok()
+    }
+
+    /// Moves to the next field, wrapping around.
+    pub fn next_field(&mut self) {
+        let index = CleanupField::ALL.iter().position(|f| *f == self.field);
+        self.field = CleanupField::ALL[index.map_or(0, |i| (i + 1) % CleanupField::ALL.len())];
+    }
+
+    /// Moves to the previous field, wrapping around.
+    pub fn previous_field(&mut self) {
+        let index = CleanupField::ALL.iter().position(|f| *f == self.field);
+        let len = CleanupField::ALL.len();
+        self.field = CleanupField::ALL[index.map_or(0, |i| (i + len - 1) % len)];
+    }
+
+    /// Types a digit into the current number field. Changing a rule discards the preview.
+    pub fn insert_char(&mut self, c: char) {
+        if !c.is_ascii_digit() {
+            return;
+        }
+        let value = match self.field {
+            CleanupField::OlderThanDays => &mut self.older_than_days,
+            CleanupField::KeepLatest => &mut self.keep_latest,
+            CleanupField::SkipInUse | CleanupField::Delete => return,
+        };
+        // Keeps the numbers within u32 range
+        if value.len() < 6 {
+            value.push(c);
+            self.plan = None;
+        }
+    }
+
+    /// Deletes the last digit of the current number field.
+    pub fn delete_char(&mut self) {
+        let value = match self.field {
+            CleanupField::OlderThanDays => &mut self.older_than_days,
+            CleanupField::KeepLatest => &mut self.keep_latest,
+            CleanupField::SkipInUse | CleanupField::Delete => return,
+        };
+        if value.pop().is_some() {
+            self.plan = None;
+        }
+    }
+
+    /// Toggles the current option. The delete option does not change the selection,
+    /// so it keeps the preview.
+    pub fn toggle(&mut self) {
+        match self.field {
+            CleanupField::SkipInUse => {
+                self.skip_in_use = !self.skip_in_use;
+                self.plan = None;
+            }
+            CleanupField::Delete => self.delete = !self.delete,
+            CleanupField::OlderThanDays | CleanupField::KeepLatest => {}
+        }
+    }
+}
+
+/// Selects the revisions of a family to deregister.
+///
+/// # Arguments
+/// * `revisions` - ARNs of the family's active revisions, newest first
+/// * `keep_latest` - Number of newest revisions that are always kept
+/// * `first_old` - Index in `revisions` of the newest revision older than the age rule
+/// * `in_use` - ARNs of the task definitions used by services; empty to ignore usage
+pub fn plan_cleanup(
+    revisions: &[String],
+    keep_latest: usize,
+    first_old: usize,
+    in_use: &HashSet<String>,
+) -> CleanupPlan {
+    let mut plan = CleanupPlan {
+        active: revisions.len(),
+        ..CleanupPlan::default()
+    };
+    for arn in revisions.iter().skip(keep_latest.max(first_old)).rev() {
+        if in_use.contains(arn) {
+            plan.in_use.push(arn.clone());
+        } else {
+            plan.revisions.push(arn.clone());
+        }
+    }
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revisions() -> Vec<String> {
+        (1..=6)
+            .rev()
+            .map(|revision| format!("arn:aws:ecs:us-east-1:123:task-definition/web:{revision}"))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_keeps_latest_recent_and_in_use_revisions() {
+        let revisions = revisions();
+        let in_use = HashSet::from([revisions[4].clone()]);
+
+        // Revisions 1 to 4 are old enough; the latest 3 are always kept
+        let plan = plan_cleanup(&revisions, 3, 2, &in_use);
+
+        assert_eq!(plan.active, 6);
+        assert_eq!(
+            plan.revisions,
+            vec![revisions[5].clone(), revisions[3].clone()]
+        );
+        assert_eq!(plan.in_use, vec![revisions[4].clone()]);
+
+        // Nothing is old enough
+        let plan = plan_cleanup(&revisions, 0, revisions.len(), &HashSet::new());
+        assert!(plan.revisions.is_empty());
+    }
+
+    #[test]
+    fn test_editing_rules_discards_preview() {
+        let mut cleanup = TaskDefinitionCleanup::new("web".to_string());
+        cleanup.plan = Some(CleanupPlan::default());
+
+        cleanup.field = CleanupField::Delete;
+        cleanup.toggle();
+        assert!(cleanup.delete);
+        assert!(cleanup.plan.is_some());
+
+        cleanup.next_field();
+        assert_eq!(cleanup.field, CleanupField::OlderThanDays);
+        cleanup.insert_char('x');
+        assert!(cleanup.plan.is_some());
+        cleanup.delete_char();
+        cleanup.insert_char('7');
+        assert_eq!(cleanup.older_than_days(), Some(97));
+        assert!(cleanup.plan.is_none());
+
+        cleanup.keep_latest.clear();
+        assert_eq!(cleanup.keep_latest(), None);
+    }
+}
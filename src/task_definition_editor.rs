@@ -0,0 +1,442 @@
+//! Form for registering a new task definition revision.
+//!
+//! [`TaskDefinitionEditor`] describes the register dialog: a copy of an existing
+//! revision with text fields for the settings changed most often (task CPU and
+//! memory, and each container's image, command and environment) and the option to
+//! deploy the new revision to a service. Every other registrable setting is changed
+//! by editing the whole definition as JSON in `$EDITOR` with [`edit_in_external_editor`].
+
+use std::fs;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+use crate::task_definition::{EnvironmentVariable, TaskDefinitionInfo};
+
+/// Setting a field of the register dialog changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorTarget {
+    /// Task CPU units
+    TaskCpu,
+    /// Task memory in MiB
+    TaskMemory,
+    /// Image of the container at the index
+    Image(usize),
+    /// Command of the container at the index
+    Command(usize),
+    /// A `KEY=VALUE` environment variable of the container at the index
+    Environment(usize),
+}
+
+/// A text field of the register dialog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditorField {
+    /// Label, e.g. "web › image"
+    pub label: String,
+    /// Text typed so far
+    pub value: String,
+    /// Setting the text is applied to
+    pub target: EditorTarget,
+}
+
+/// State of the dialog registering a new revision from a copy of an existing one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskDefinitionEditor {
+    /// Revision the new one is copied from
+    pub source: TaskDefinitionInfo,
+    /// Definition the fields are applied to; replaced when it is edited as JSON
+    pub base: TaskDefinitionInfo,
+    /// Text fields, in the order Tab moves through them
+    pub fields: Vec<EditorField>,
+    /// Index of the field being edited; `fields.len()` is the deploy option
+    pub field: usize,
+    /// Cluster and service the new revision can be deployed to
+    pub deploy_target: Option<(String, String)>,
+    /// Whether the new revision is deployed to `deploy_target` once registered
+    pub deploy: bool,
+    /// JSON from `$EDITOR` that failed to parse, reopened by the next edit
+    pub draft_json: Option<String>,
+    /// Problems found when the form was last submitted
+    pub problems: Vec<String>,
+}
+
+impl TaskDefinitionEditor {
+    /// Creates a dialog copying `source`.
+    ///
+    /// # Arguments
+    /// * `source` - Revision to copy
+    /// * `deploy_target` - Cluster and service the new revision can be deployed to
+    /// * `deploy` - Whether deploying is initially selected
+    pub fn new(
+        source: TaskDefinitionInfo,
+        deploy_target: Option<(String, String)>,
+        deploy: bool,
+    ) -> Self {
+        let fields = editor_fields(&source);
+        Self {
+            base: source.clone(),
+            source,
+            fields,
+            field: 0,
+            deploy: deploy && deploy_target.is_some(),
+            deploy_target,
+            draft_json: None,
+            problems: Vec::new(),
+        }
+    }
+
+    /// Returns the number of fields Tab moves through, including the deploy option.
+    fn field_count(&self) -> usize {
+        self.fields.len() + usize::from(self.deploy_target.is_some())
+    }
+
+    /// Returns whether the deploy option is selected rather than a text field.
+    pub fn editing_deploy(&self) -> bool {
+        self.field >= self.fields.len()
+    }
+
+    /// Moves to the next field, wrapping around.
+    pub fn next_field(&mut self) {
+        self.field = (self.field + 1) % self.field_count().max(1);
+    }
+
+    /// Moves to the previous field, wrapping around.
+    pub fn previous_field(&mut self) {
+        let count = self.field_count().max(1);
+        self.field = (self.field + count - 1) % count;
+    }
+
+    /// Appends a character to the current text field.
+    ///
+    /// Typing into a container's last, empty environment field adds another empty
+    /// one below it, so any number of variables can be added.
+    pub fn insert_char(&mut self, c: char) {
+        let Some(field) = self.fields.get_mut(self.field) else {
+            return;
+        };
+        let was_empty = field.value.is_empty();
+        field.value.push(c);
+        if let EditorTarget::Environment(container) = field.target {
+            let is_last = self
+                .fields
+                .get(self.field + 1)
+                .is_none_or(|next| next.target != EditorTarget::Environment(container));
+            if was_empty && is_last {
+                self.fields.insert(
+                    self.field + 1,
+                    EditorField {
+                        label: self.fields[self.field].label.clone(),
+                        value: String::new(),
+                        target: EditorTarget::Environment(container),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Removes the last character of the current text field.
+    pub fn delete_char(&mut self) {
+        if let Some(field) = self.fields.get_mut(self.field) {
+            field.value.pop();
+        }
+    }
+
+    /// Toggles deploying the new revision, if a service to deploy to is known.
+    pub fn toggle_deploy(&mut self) {
+        if self.deploy_target.is_some() {
+            self.deploy = !self.deploy;
+        }
+    }
+
+    /// Returns the service the new revision is deployed to, if deploying is selected.
+    pub fn deploy_to(&self) -> Option<(String, String)> {
+        self.deploy.then(|| self.deploy_target.clone()).flatten()
+    }
+
+    /// Applies the fields to the base definition.
+    ///
+    /// Emptied environment fields remove their variable; empty CPU and memory fields
+    /// remove the task-level size.
+    ///
+    /// # Errors
+    /// Returns an error for an environment field that is not `KEY=VALUE` or a command
+    /// that starts with `[` but is not a JSON array of strings.
+    pub fn definition(&self) -> Result<TaskDefinitionInfo> {
+        let mut definition = self.base.clone();
+        let optional = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+        for container in &mut definition.containers {
+            container.environment.clear();
+        }
+
+        for field in &self.fields {
+            match field.target {
+                EditorTarget::TaskCpu => definition.cpu = optional(&field.value),
+                EditorTarget::TaskMemory => definition.memory = optional(&field.value),
+                EditorTarget::Image(index) => {
+                    if let Some(container) = definition.containers.get_mut(index) {
+                        container.image = field.value.trim().to_string();
+                    }
+                }
+                EditorTarget::Command(index) => {
+                    if let Some(container) = definition.containers.get_mut(index) {
+                        container.command = parse_command(&field.value)
+                            .with_context(|| format!("{}: invalid command", field.label))?;
+                    }
+                }
+                EditorTarget::Environment(index) => {
+                    if field.value.trim().is_empty() {
+                        continue;
+                    }
+                    let Some((name, value)) = field.value.split_once('=') else {
+                        anyhow::bail!("{}: '{}' is not KEY=VALUE", field.label, field.value);
+                    };
+                    if let Some(container) = definition.containers.get_mut(index) {
+                        container.environment.push(EnvironmentVariable {
+                            name: name.trim().to_string(),
+                            value: value.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(definition)
+    }
+
+    /// Replaces the definition with one edited as JSON and rebuilds the fields from it.
+    pub fn replace_definition(&mut self, definition: TaskDefinitionInfo) {
+        self.fields = editor_fields(&definition);
+        self.base = definition;
+        self.field = self.field.min(self.field_count().saturating_sub(1));
+        self.draft_json = None;
+        self.problems.clear();
+    }
+}
+
+/// Builds the text fields of a definition: task CPU and memory, then each
+/// container's image, command and environment variables, followed by an empty
+/// field for a new variable.
+fn editor_fields(definition: &TaskDefinitionInfo) -> Vec<EditorField> {
+    let mut fields = vec![
+        EditorField {
+            label: "Task CPU".to_string(),
+            value: definition.cpu.clone().unwrap_or_default(),
+            target: EditorTarget::TaskCpu,
+        },
+        EditorField {
+            label: "Task memory".to_string(),
+            value: definition.memory.clone().unwrap_or_default(),
+            target: EditorTarget::TaskMemory,
+        },
+    ];
+    for (index, container) in definition.containers.iter().enumerate() {
+        let label = |field: &str| format!("{} › {field}", container.name);
+        fields.push(EditorField {
+            label: label("image"),
+            value: container.image.clone(),
+            target: EditorTarget::Image(index),
+        });
+        fields.push(EditorField {
+            label: label("command"),
+            value: format_command(&container.command),
+            target: EditorTarget::Command(index),
+        });
+        let variables = container
+            .environment
+            .iter()
+            .map(|variable| format!("{}={}", variable.name, variable.value))
+            .chain(std::iter::once(String::new()));
+        for value in variables {
+            fields.push(EditorField {
+                label: label("env"),
+                value,
+                target: EditorTarget::Environment(index),
+            });
+        }
+    }
+    fields
+}
+
+/// Formats a command as space-separated words, or as a JSON array when an argument
+/// contains whitespace or quotes and the words would not split back into it.
+pub fn format_command(command: &[String]) -> String {
+    let plain = command.iter().all(|argument| {
+        !argument.is_empty() && !argument.contains(char::is_whitespace) && !argument.contains('"')
+    }) && !command.first().is_some_and(|first| first.starts_with('['));
+    if plain {
+        command.join(" ")
+    } else {
+        serde_json::to_string(command).unwrap_or_default()
+    }
+}
+
+/// Parses a command typed as space-separated words or as a JSON array of strings.
+pub fn parse_command(command: &str) -> Result<Vec<String>> {
+    let command = command.trim();
+    if command.starts_with('[') {
+        serde_json::from_str(command).context("Expected a JSON array of strings")
+    } else {
+        Ok(command.split_whitespace().map(str::to_string).collect())
+    }
+}
+
+/// Opens text in the user's editor and returns it once the editor exits.
+///
+/// The editor is `$VISUAL` or `$EDITOR` (falling back to `vi`), which may include
+/// arguments such as `code --wait`. The text is written to a temporary file named
+/// after `name` and removed afterwards. The terminal must be handed to the editor
+/// (raw mode and the alternate screen left) before calling this.
+///
+/// # Errors
+/// Returns an error if the file can't be written or read, or the editor can't be
+/// started or exits unsuccessfully.
+pub fn edit_in_external_editor(text: &str, name: &str) -> Result<String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+
+    let path = std::env::temp_dir().join(format!("ecs-voyager-{name}-{}.json", std::process::id()));
+    fs::write(&path, text).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let status = Command::new(program)
+        .args(words)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{editor}'"));
+    let edited =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()));
+    let _ = fs::remove_file(&path);
+
+    let status = status?;
+    if !status.success() {
+        anyhow::bail!("Editor '{editor}' exited with {status}");
+    }
+    edited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task_definition::ContainerDefinitionInfo;
+
+    fn source() -> TaskDefinitionInfo {
+        TaskDefinitionInfo {
+            family: "web".to_string(),
+            revision: 3,
+            cpu: Some("256".to_string()),
+            memory: Some("512".to_string()),
+            containers: vec![ContainerDefinitionInfo {
+                name: "web".to_string(),
+                image: "nginx:1.26".to_string(),
+                command: vec![
+                    "nginx".to_string(),
+                    "-g".to_string(),
+                    "daemon off;".to_string(),
+                ],
+                environment: vec![EnvironmentVariable {
+                    name: "MODE".to_string(),
+                    value: "prod".to_string(),
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn set(editor: &mut TaskDefinitionEditor, label: &str, occurrence: usize, value: &str) {
+        let index = editor
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.label == label)
+            .nth(occurrence)
+            .map(|(index, _)| index);
+        let Some(index) = index else {
+            unreachable!("field {label} should exist");
+        };
+        editor.field = index;
+        while !editor.fields[index].value.is_empty() {
+            editor.delete_char();
+        }
+        for c in value.chars() {
+            editor.insert_char(c);
+        }
+    }
+
+    #[test]
+    fn test_fields_apply_image_memory_command_and_environment() -> Result<()> {
+        let mut editor = TaskDefinitionEditor::new(source(), None, true);
+        assert_eq!(
+            editor.fields[3].value, r#"["nginx","-g","daemon off;"]"#,
+            "arguments with spaces are shown as a JSON array"
+        );
+        assert!(!editor.deploy, "deploying needs a service");
+
+        set(&mut editor, "Task memory", 0, "1024");
+        set(&mut editor, "web › image", 0, "nginx:1.27");
+        set(&mut editor, "web › command", 0, "nginx -T");
+        set(&mut editor, "web › env", 0, "");
+        set(&mut editor, "web › env", 1, "DEBUG=a=b");
+        set(&mut editor, "web › env", 2, "TRACE=1");
+
+        let definition = editor.definition()?;
+        assert_eq!(definition.memory.as_deref(), Some("1024"));
+        let container = &definition.containers[0];
+        assert_eq!(container.image, "nginx:1.27");
+        assert_eq!(container.command, vec!["nginx", "-T"]);
+        let environment: Vec<(&str, &str)> = container
+            .environment
+            .iter()
+            .map(|variable| (variable.name.as_str(), variable.value.as_str()))
+            .collect();
+        assert_eq!(environment, vec![("DEBUG", "a=b"), ("TRACE", "1")]);
+
+        set(&mut editor, "web › env", 0, "BROKEN");
+        assert!(editor.definition().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deploy_option_and_json_replacement() -> Result<()> {
+        let target = Some(("cluster-prod".to_string(), "web-service".to_string()));
+        let mut editor = TaskDefinitionEditor::new(source(), target.clone(), true);
+        assert_eq!(editor.deploy_to(), target);
+
+        editor.previous_field();
+        assert!(editor.editing_deploy());
+        editor.toggle_deploy();
+        assert_eq!(editor.deploy_to(), None);
+
+        let mut edited = editor.definition()?;
+        edited.containers[0].stop_timeout = Some(30);
+        edited.containers[0].image = "nginx:1.28".to_string();
+        let parsed = TaskDefinitionInfo::from_json(&edited.to_json()?)?;
+        editor.replace_definition(parsed);
+
+        let definition = editor.definition()?;
+        assert_eq!(definition.containers[0].image, "nginx:1.28");
+        assert_eq!(definition.containers[0].stop_timeout, Some(30));
+        assert_eq!(editor.source.containers[0].image, "nginx:1.26");
+        Ok(())
+    }
+
+    #[test]
+    fn test_command_round_trips_through_text() -> Result<()> {
+        let plain = vec!["npm".to_string(), "start".to_string()];
+        assert_eq!(format_command(&plain), "npm start");
+        assert_eq!(parse_command("npm  start")?, plain);
+
+        let shell = vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            "echo \"hi\"".to_string(),
+        ];
+        assert_eq!(parse_command(&format_command(&shell))?, shell);
+        assert!(parse_command("[\"unterminated").is_err());
+        Ok(())
+    }
+}
@@ -9,13 +9,23 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Row, Table, Wrap},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState, Wrap,
+    },
     Frame,
 };
-use std::time::SystemTime;
+use regex::Regex;
+use std::time::{Instant, SystemTime};
 
-use crate::app::{App, AppState, ModalState};
+use crate::app::{App, AppState, LogEntry, ModalState};
 use crate::charts::{render_chart, ChartConfig, ChartDatapoint};
+use crate::definition_export::{DefinitionExportField, DefinitionSource};
+use crate::log_export::ExportField;
+use crate::rollout::{format_duration, RolloutMonitor};
+use crate::task_definition::{FieldChange, TaskDefinitionInfo};
+use crate::task_definition_cleanup::CleanupField;
+use crate::ui::utils::format_bytes;
+use crate::ui::widgets::{render_confirmation_dialog, render_progress_bar, render_toast};
 
 /// Main rendering function that draws the entire UI.
 ///
@@ -67,9 +77,15 @@ pub fn draw(f: &mut Frame, app: &App) {
             AppState::Tasks => draw_tasks(f, content_area, app),
             AppState::Details => draw_details(f, content_area, app),
             AppState::Logs => draw_logs(f, content_area, app),
+            AppState::LogInsights => draw_log_insights(f, content_area, app),
             AppState::Metrics => draw_metrics(f, content_area, app),
+            AppState::ServiceEvents => draw_service_events(f, content_area, app),
             AppState::TaskDefinitions => draw_task_definitions(f, content_area, app),
-            AppState::TaskDefinitionDetail => draw_details(f, content_area, app),
+            AppState::TaskDefinitionRevisions => {
+                draw_task_definition_revisions(f, content_area, app)
+            }
+            AppState::ContainerInstances => draw_container_instances(f, content_area, app),
+            AppState::TaskDefinitionDetail => draw_task_definition_detail(f, content_area, app),
         }
     }
 
@@ -87,9 +103,22 @@ pub fn draw(f: &mut Frame, app: &App) {
         ModalState::RegionSelector => draw_region_selector(f, app),
         ModalState::ServiceEditor => draw_service_editor(f, app),
         ModalState::PortForwardingSetup => draw_port_forwarding_setup(f, app),
+        ModalState::Confirm => draw_confirmation(f, app),
+        ModalState::LogEntry => draw_log_entry_detail(f, app),
+        ModalState::LogExport => draw_log_export(f, app),
+        ModalState::LogBookmarks => draw_log_bookmarks(f, app),
+        ModalState::TaskDefinitionDiff => draw_task_definition_diff(f, app),
+        ModalState::TaskDefinitionEditor => draw_task_definition_editor(f, app),
+        ModalState::DefinitionExport => draw_definition_export(f, app),
+        ModalState::TaskDefinitionCleanup => draw_task_definition_cleanup(f, app),
         ModalState::None => {}
     }
 
+    // Draw toast notification above the footer
+    if let Some(toast) = app.active_toast() {
+        render_toast(f, &toast.message, toast.toast_type.clone(), &app.theme);
+    }
+
     // Draw loading indicator overlay if loading (rendered last so it's on top)
     if app.loading {
         draw_loading_overlay(f, app);
@@ -120,11 +149,27 @@ fn draw_header(f: &mut Frame, area: Rect, app: &App) {
             "ECS Voyager - Services"
         }
         AppState::Tasks => {
+            let kind = if app.show_stopped_tasks {
+                "Stopped Tasks"
+            } else {
+                "Tasks"
+            };
+            if let (Some(cluster), Some(instance)) =
+                (&app.selected_cluster, &app.selected_container_instance)
+            {
+                let instance = instance.split('/').next_back().unwrap_or(instance);
+                return draw_custom_header(
+                    f,
+                    area,
+                    &format!("ECS Voyager - Tasks ({cluster}/instance {instance})"),
+                    app,
+                );
+            }
             if let (Some(cluster), Some(service)) = (&app.selected_cluster, &app.selected_service) {
                 return draw_custom_header(
                     f,
                     area,
-                    &format!("ECS Voyager - Tasks ({cluster}/{service})"),
+                    &format!("ECS Voyager - {kind} ({cluster}/{service})"),
                     app,
                 );
             }
@@ -132,6 +177,14 @@ fn draw_header(f: &mut Frame, area: Rect, app: &App) {
         }
         AppState::Details => "ECS Voyager - Details",
         AppState::Logs => {
+            if let (true, Some(service)) = (app.show_service_logs, &app.selected_service) {
+                return draw_custom_header(
+                    f,
+                    area,
+                    &format!("ECS Voyager - Logs (Service: {service})"),
+                    app,
+                );
+            }
             if let Some(task) = &app.selected_task {
                 return draw_custom_header(
                     f,
@@ -142,7 +195,32 @@ fn draw_header(f: &mut Frame, area: Rect, app: &App) {
             }
             "ECS Voyager - Logs"
         }
+        AppState::LogInsights => {
+            let scope = match (
+                app.show_service_logs,
+                &app.selected_service,
+                &app.selected_task,
+            ) {
+                (true, Some(service), _) => format!("Service: {service}"),
+                (false, _, Some(task)) => format!("Task: {}", task.task_id),
+                _ => return draw_custom_header(f, area, "ECS Voyager - Logs Insights", app),
+            };
+            return draw_custom_header(
+                f,
+                area,
+                &format!("ECS Voyager - Logs Insights ({scope})"),
+                app,
+            );
+        }
         AppState::Metrics => {
+            if let (true, Some(task)) = (app.show_task_metrics, &app.selected_task) {
+                return draw_custom_header(
+                    f,
+                    area,
+                    &format!("ECS Voyager - Metrics (Task: {})", task.task_id),
+                    app,
+                );
+            }
             if let Some(service) = &app.selected_service {
                 return draw_custom_header(
                     f,
@@ -153,8 +231,54 @@ fn draw_header(f: &mut Frame, area: Rect, app: &App) {
             }
             "ECS Voyager - Metrics"
         }
+        AppState::ServiceEvents => {
+            if let Some(service) = &app.selected_service {
+                return draw_custom_header(
+                    f,
+                    area,
+                    &format!("ECS Voyager - Events (Service: {service})"),
+                    app,
+                );
+            }
+            "ECS Voyager - Events"
+        }
         AppState::TaskDefinitions => "ECS Voyager - Task Definitions",
-        AppState::TaskDefinitionDetail => "ECS Voyager - Task Definition Details",
+        AppState::TaskDefinitionRevisions => {
+            if let Some(family) = &app.selected_task_definition_family {
+                return draw_custom_header(
+                    f,
+                    area,
+                    &format!("ECS Voyager - Task Definition Revisions ({family})"),
+                    app,
+                );
+            }
+            "ECS Voyager - Task Definition Revisions"
+        }
+        AppState::ContainerInstances => {
+            if let Some(cluster) = &app.selected_cluster {
+                return draw_custom_header(
+                    f,
+                    area,
+                    &format!("ECS Voyager - Container Instances ({cluster})"),
+                    app,
+                );
+            }
+            "ECS Voyager - Container Instances"
+        }
+        AppState::TaskDefinitionDetail => {
+            if let Some(revision) = &app.selected_task_definition {
+                return draw_custom_header(
+                    f,
+                    area,
+                    &format!(
+                        "ECS Voyager - Task Definition ({})",
+                        revision.family_revision()
+                    ),
+                    app,
+                );
+            }
+            "ECS Voyager - Task Definition Details"
+        }
     };
 
     draw_custom_header(f, area, title, app);
@@ -227,6 +351,18 @@ fn draw_info_header(f: &mut Frame, area: Rect, app: &App) {
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ),
+                Span::styled("  |  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("Running Tasks: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    app.cluster_details
+                        .iter()
+                        .map(|info| info.running_tasks)
+                        .sum::<i32>()
+                        .to_string(),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
             ])]
         }
         AppState::Services => {
@@ -381,15 +517,54 @@ fn draw_info_header(f: &mut Frame, area: Rect, app: &App) {
             ]
         }
         AppState::Logs => {
-            // Show task context and log info
-            if let Some(task) = &app.selected_task {
-                let filtered_logs = app.get_filtered_logs();
-                let log_count_display = if filtered_logs.len() != app.logs.len() {
-                    format!("{}/{}", filtered_logs.len(), app.logs.len())
+            // Show task or service context and log info
+            let filtered_logs = app.get_filtered_logs();
+            let log_count_display = if filtered_logs.len() != app.logs.len() {
+                format!("{}/{}", filtered_logs.len(), app.logs.len())
+            } else {
+                app.logs.len().to_string()
+            };
+            let log_info = Line::from(vec![
+                Span::styled("Log Entries: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    log_count_display,
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                if app.auto_tail {
+                    Span::styled("  |  Auto-Tail: ON", Style::default().fg(Color::Green))
                 } else {
-                    app.logs.len().to_string()
-                };
+                    Span::styled("  |  Auto-Tail: OFF", Style::default().fg(Color::DarkGray))
+                },
+            ]);
 
+            if let (true, Some(service)) = (app.show_service_logs, &app.selected_service) {
+                vec![
+                    Line::from(vec![
+                        Span::styled("Service: ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            service,
+                            Style::default()
+                                .fg(Color::Cyan)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled("  |  ", Style::default().fg(Color::DarkGray)),
+                        Span::styled("Tasks: ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            app.log_task_ids().len().to_string(),
+                            Style::default().fg(Color::White),
+                        ),
+                        Span::styled("  |  ", Style::default().fg(Color::DarkGray)),
+                        Span::styled("Task Filter: ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            app.log_task_filter.as_deref().unwrap_or("all"),
+                            Style::default().fg(Color::Magenta),
+                        ),
+                    ]),
+                    log_info,
+                ]
+            } else if let Some(task) = &app.selected_task {
                 vec![
                     Line::from(vec![
                         Span::styled("Task: ", Style::default().fg(Color::Gray)),
@@ -416,23 +591,7 @@ fn draw_info_header(f: &mut Frame, area: Rect, app: &App) {
                         Span::styled("Memory: ", Style::default().fg(Color::Gray)),
                         Span::styled(&task.memory, Style::default().fg(Color::White)),
                     ]),
-                    Line::from(vec![
-                        Span::styled("Log Entries: ", Style::default().fg(Color::Gray)),
-                        Span::styled(
-                            log_count_display,
-                            Style::default()
-                                .fg(Color::Green)
-                                .add_modifier(Modifier::BOLD),
-                        ),
-                        if app.auto_tail {
-                            Span::styled("  |  Auto-Tail: ON", Style::default().fg(Color::Green))
-                        } else {
-                            Span::styled(
-                                "  |  Auto-Tail: OFF",
-                                Style::default().fg(Color::DarkGray),
-                            )
-                        },
-                    ]),
+                    log_info,
                 ]
             } else {
                 vec![Line::from(vec![Span::styled(
@@ -626,12 +785,27 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
         let item_count = match app.state {
             AppState::Clusters => format!("{} clusters", app.clusters.len()),
             AppState::Services => format!("{} services", app.services.len()),
+            AppState::Tasks if app.show_stopped_tasks => {
+                format!("{} stopped tasks", app.stopped_tasks.len())
+            }
             AppState::Tasks => format!("{} tasks", app.tasks.len()),
             AppState::Logs => format!("{} logs", app.logs.len()),
+            AppState::LogInsights => format!(
+                "{} rows",
+                app.insights_results()
+                    .map_or(0, |results| results.rows.len())
+            ),
             AppState::Details => "details".to_string(),
             AppState::Metrics => "metrics".to_string(),
+            AppState::ServiceEvents => format!("{} events", app.service_events.len()),
             AppState::TaskDefinitions => format!("{} families", app.task_definition_families.len()),
+            AppState::TaskDefinitionRevisions => {
+                format!("{} revisions", app.task_definitions.len())
+            }
             AppState::TaskDefinitionDetail => "task definition".to_string(),
+            AppState::ContainerInstances => {
+                format!("{} instances", app.container_instances.len())
+            }
         };
 
         let line2 = Line::from(vec![
@@ -721,6 +895,14 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
             ));
         }
 
+        // Add find status and match count in the logs view
+        if let Some(find_status) = app.log_find_status() {
+            line3_spans.push(Span::styled(
+                format!(" | {find_status}"),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+
         // Add filter status if active
         if !filter_text.is_empty() {
             line3_spans.push(Span::styled(
@@ -729,6 +911,23 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
             ));
         }
 
+        // Add rollout status if a deployment is being monitored
+        if let Some(monitor) = &app.rollout_monitor {
+            let eta = monitor
+                .eta(Instant::now())
+                .map(|eta| format!(" ETA {}", format_duration(eta)))
+                .unwrap_or_default();
+            line3_spans.push(Span::styled(
+                format!(
+                    " | Rollout: {} {:.0}%{}",
+                    monitor.service,
+                    monitor.progress() * 100.0,
+                    eta
+                ),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+
         let line3 = Line::from(line3_spans);
 
         vec![line1, line2, line3]
@@ -738,19 +937,43 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(footer, area);
 }
 
-/// Renders the clusters list view.
+/// Renders the clusters table view.
 ///
-/// Displays all ECS clusters (filtered by search query if active) as a vertical list.
-/// The currently selected cluster is highlighted. Shows count of filtered vs total clusters.
+/// Displays all ECS clusters (filtered by search query if active) with their service,
+/// task and container instance counts, capacity providers and Container Insights
+/// setting. A detail pane below the table shows the default capacity provider strategy
+/// and tags of the highlighted cluster. Counts show "-" when DescribeClusters failed.
 ///
 /// # Arguments
 /// * `f` - The ratatui Frame to render into
-/// * `area` - The rectangular area allocated for the clusters list
+/// * `area` - The rectangular area allocated for the clusters table
 /// * `app` - The application state containing cluster data
 fn draw_clusters(f: &mut Frame, area: Rect, app: &App) {
     let filtered_clusters = app.get_filtered_clusters();
 
-    let items: Vec<ListItem> = filtered_clusters
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(7)])
+        .split(area);
+
+    let header = Row::new(vec![
+        "Name",
+        "Status",
+        "Services",
+        "Running",
+        "Pending",
+        "Instances",
+        "Capacity Providers",
+        "Insights",
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = filtered_clusters
         .iter()
         .enumerate()
         .map(|(i, cluster)| {
@@ -763,26 +986,135 @@ fn draw_clusters(f: &mut Frame, area: Rect, app: &App) {
                 Style::default().fg(Color::White)
             };
 
-            ListItem::new(cluster.as_str()).style(style)
+            match app.cluster_info(cluster) {
+                Some(info) => {
+                    let status_color = match info.status.as_str() {
+                        "ACTIVE" => Color::Green,
+                        "PROVISIONING" | "DEPROVISIONING" => Color::Yellow,
+                        _ => Color::Red,
+                    };
+                    let pending_color = if info.pending_tasks > 0 {
+                        Color::Yellow
+                    } else {
+                        Color::White
+                    };
+                    let insights = if info.container_insights.is_empty() {
+                        "-".to_string()
+                    } else {
+                        info.container_insights.clone()
+                    };
+                    Row::new(vec![
+                        Line::from(cluster.clone()),
+                        Line::from(Span::styled(
+                            info.status.clone(),
+                            Style::default().fg(status_color),
+                        )),
+                        Line::from(info.active_services.to_string()),
+                        Line::from(info.running_tasks.to_string()),
+                        Line::from(Span::styled(
+                            info.pending_tasks.to_string(),
+                            Style::default().fg(pending_color),
+                        )),
+                        Line::from(info.registered_container_instances.to_string()),
+                        Line::from(info.capacity_providers.join(", ")),
+                        Line::from(insights),
+                    ])
+                    .style(style)
+                }
+                None => {
+                    let mut cells = vec![Line::from(cluster.clone())];
+                    cells.extend((0..7).map(|_| Line::from("-")));
+                    Row::new(cells).style(style)
+                }
+            }
         })
         .collect();
 
     let title = if app.search_query.is_empty() {
         format!(
-            "Clusters ({}) - /:search | Enter:select",
+            "Clusters ({}) - /:search | Enter:select | i:instances",
             filtered_clusters.len()
         )
     } else {
         format!(
-            "Clusters ({}/{}) - Esc:clear | Enter:select",
+            "Clusters ({}/{}) - Esc:clear | Enter:select | i:instances",
             filtered_clusters.len(),
             app.clusters.len()
         )
     };
 
-    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(24),
+            Constraint::Percentage(10),
+            Constraint::Percentage(8),
+            Constraint::Percentage(8),
+            Constraint::Percentage(8),
+            Constraint::Percentage(9),
+            Constraint::Percentage(22),
+            Constraint::Percentage(11),
+        ],
+    )
+    .header(header)
+    .block(Block::default().title(title).borders(Borders::ALL));
 
-    f.render_widget(list, area);
+    f.render_widget(table, chunks[0]);
+
+    // Details of the highlighted cluster
+    let label = Style::default().fg(Color::Gray);
+    let lines = match filtered_clusters
+        .get(app.selected_index)
+        .and_then(|cluster| app.cluster_info(cluster))
+    {
+        Some(info) => {
+            let providers = if info.capacity_providers.is_empty() {
+                "none".to_string()
+            } else {
+                info.capacity_providers.join(", ")
+            };
+            let insights = if info.container_insights.is_empty() {
+                "not reported".to_string()
+            } else {
+                info.container_insights.clone()
+            };
+            let tags = if info.tags.is_empty() {
+                "none".to_string()
+            } else {
+                info.tags
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled("Capacity providers: ", label),
+                    Span::raw(providers),
+                ]),
+                Line::from(vec![
+                    Span::styled("Default strategy: ", label),
+                    Span::raw(info.strategy_summary()),
+                ]),
+                Line::from(vec![
+                    Span::styled("Container Insights: ", label),
+                    Span::raw(insights),
+                ]),
+                Line::from(vec![Span::styled("Tags: ", label), Span::raw(tags)]),
+            ]
+        }
+        None => vec![Line::from(Span::styled(
+            "No cluster statistics available",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+
+    let details = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .title("Cluster Details")
+            .borders(Borders::ALL),
+    );
+    f.render_widget(details, chunks[1]);
 }
 
 /// Renders the services table view.
@@ -840,12 +1172,12 @@ fn draw_services(f: &mut Frame, area: Rect, app: &App) {
 
     let title = if app.search_query.is_empty() {
         format!(
-            "Services ({}) - /:search | s:edit | Enter:tasks | d:describe | x:restart",
+            "Services ({}) - /:search | s:edit | Enter:tasks | l:logs | v:events | d:describe | e:export | x:restart",
             filtered_services.len()
         )
     } else {
         format!(
-            "Services ({}/{}) - Esc:clear | s:edit | Enter:tasks | d:describe | x:restart",
+            "Services ({}/{}) - Esc:clear | s:edit | Enter:tasks | l:logs | v:events | d:describe | e:export | x:restart",
             filtered_services.len(),
             app.services.len()
         )
@@ -879,6 +1211,10 @@ fn draw_services(f: &mut Frame, area: Rect, app: &App) {
 /// * `area` - The rectangular area allocated for the tasks table
 /// * `app` - The application state containing task data
 fn draw_tasks(f: &mut Frame, area: Rect, app: &App) {
+    if app.show_stopped_tasks {
+        return draw_stopped_tasks(f, area, app);
+    }
+
     let filtered_tasks = app.get_filtered_tasks();
 
     let header = Row::new(vec![
@@ -918,12 +1254,12 @@ fn draw_tasks(f: &mut Frame, area: Rect, app: &App) {
 
     let title = if app.search_query.is_empty() {
         format!(
-            "Tasks ({}) - /:search | e:exec | l:logs | d:describe | x:stop",
+            "Tasks ({}) - /:search | e:exec | l:logs | m:metrics | d:describe | x:stop | S:stopped",
             filtered_tasks.len()
         )
     } else {
         format!(
-            "Tasks ({}/{}) - Esc:clear | e:exec | l:logs | d:describe | x:stop",
+            "Tasks ({}/{}) - Esc:clear | e:exec | l:logs | m:metrics | d:describe | x:stop | S:stopped",
             filtered_tasks.len(),
             app.tasks.len()
         )
@@ -946,193 +1282,2258 @@ fn draw_tasks(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(table, area);
 }
 
-/// Renders the task definitions list view.
+/// Renders the stopped tasks view.
 ///
-/// Displays all ECS task definition families (filtered by search query if active) as a vertical list.
-/// The currently selected family is highlighted. Shows count of filtered vs total families.
+/// Lists recently stopped tasks with stop time, stop code, container exit codes, and
+/// stopped reason. A panel below the table shows the timeline of the selected task and
+/// the exit code and reason of each of its containers.
 ///
 /// # Arguments
 /// * `f` - The ratatui Frame to render into
-/// * `area` - The rectangular area allocated for the task definitions list
-/// * `app` - The application state containing task definition family data
-fn draw_task_definitions(f: &mut Frame, area: Rect, app: &App) {
-    let filtered_families = app.get_filtered_task_definition_families();
+/// * `area` - The rectangular area allocated for the view
+/// * `app` - The application state containing stopped task data
+fn draw_stopped_tasks(f: &mut Frame, area: Rect, app: &App) {
+    let filtered_tasks = app.get_filtered_stopped_tasks();
 
-    let items: Vec<ListItem> = filtered_families
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(9)])
+        .split(area);
+
+    let header = Row::new(vec![
+        "Task ID",
+        "Stopped At",
+        "Stop Code",
+        "Exit Codes",
+        "Stopped Reason",
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = filtered_tasks
         .iter()
         .enumerate()
-        .map(|(i, family)| {
+        .map(|(i, task)| {
             let style = if i == app.selected_index {
                 Style::default()
                     .fg(Color::Black)
                     .bg(Color::Cyan)
                     .add_modifier(Modifier::BOLD)
+            } else if task
+                .containers
+                .iter()
+                .any(|c| c.exit_code.is_some_and(|code| code != 0))
+            {
+                Style::default().fg(Color::Red)
             } else {
                 Style::default().fg(Color::White)
             };
 
-            ListItem::new(family.as_str()).style(style)
+            Row::new(vec![
+                task.task_id.clone(),
+                task.stopped_at
+                    .map(format_event_time)
+                    .unwrap_or_else(|| task.last_status.clone()),
+                task.stop_code.clone(),
+                task.exit_codes(),
+                task.stopped_reason.clone(),
+            ])
+            .style(style)
         })
         .collect();
 
     let title = if app.search_query.is_empty() {
         format!(
-            "Task Definition Families ({}) - /:search | Enter:view | d:describe",
-            filtered_families.len()
+            "Stopped Tasks ({}) - /:search | l:logs | m:metrics | d:describe | S:running",
+            filtered_tasks.len()
         )
     } else {
         format!(
-            "Task Definition Families ({}/{}) - Esc:clear | Enter:view | d:describe",
-            filtered_families.len(),
-            app.task_definition_families.len()
+            "Stopped Tasks ({}/{}) - Esc:clear | l:logs | m:metrics | d:describe | S:running",
+            filtered_tasks.len(),
+            app.stopped_tasks.len()
         )
     };
 
-    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
-
-    f.render_widget(list, area);
-}
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(22),
+            Constraint::Percentage(18),
+            Constraint::Percentage(17),
+            Constraint::Percentage(13),
+            Constraint::Percentage(30),
+        ],
+    )
+    .header(header)
+    .block(Block::default().title(title).borders(Borders::ALL));
 
-/// Renders the details view showing comprehensive information about a resource.
-///
-/// Displays detailed information about a selected service or task in a scrollable
-/// text view. The content is formatted as multi-line text with word wrapping.
-/// Supports scrolling with up/down arrow keys or j/k for long content.
-///
-/// # Arguments
-/// * `f` - The ratatui Frame to render into
-/// * `area` - The rectangular area allocated for the details view
-/// * `app` - The application state containing details text and scroll position
-fn draw_details(f: &mut Frame, area: Rect, app: &App) {
-    let default_text = "No details available".to_string();
+    f.render_widget(table, chunks[0]);
 
-    // Choose between formatted view and JSON view
-    let (content, view_type) = if app.show_json_view {
-        (app.details_json.as_ref().unwrap_or(&default_text), "JSON")
-    } else {
-        (app.details.as_ref().unwrap_or(&default_text), "Formatted")
+    // Details of the selected stopped task
+    let label = Style::default().fg(Color::Gray);
+    let format_time = |secs: Option<i64>| secs.map(format_event_time).unwrap_or("-".to_string());
+    let lines = match filtered_tasks.get(app.selected_index) {
+        Some(task) => {
+            let mut lines = vec![
+                Line::from(vec![
+                    Span::styled("Started: ", label),
+                    Span::raw(format_time(task.started_at)),
+                    Span::styled("  Stopping: ", label),
+                    Span::raw(format_time(task.stopping_at)),
+                    Span::styled("  Stopped: ", label),
+                    Span::raw(format_time(task.stopped_at)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Reason: ", label),
+                    Span::styled(
+                        task.stopped_reason.clone(),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                ]),
+            ];
+            for container in &task.containers {
+                let (exit, color) = match container.exit_code {
+                    Some(0) => ("exit 0".to_string(), Color::Green),
+                    Some(code) => (format!("exit {code}"), Color::Red),
+                    None => ("no exit code".to_string(), Color::DarkGray),
+                };
+                let mut spans = vec![
+                    Span::styled(format!("  {}: ", container.name), label),
+                    Span::styled(exit, Style::default().fg(color)),
+                ];
+                if !container.reason.is_empty() {
+                    spans.push(Span::raw(format!(" - {}", container.reason)));
+                }
+                lines.push(Line::from(spans));
+            }
+            lines
+        }
+        None => vec![Line::from(Span::styled(
+            "No stopped tasks (ECS keeps stopped tasks for about an hour)",
+            Style::default().fg(Color::DarkGray),
+        ))],
     };
 
-    let title = format!("Details - {view_type} View (↑↓:scroll | J:toggle | Esc/h:back)");
-
-    let paragraph = Paragraph::new(content.as_str())
-        .style(Style::default().fg(Color::White))
-        .block(Block::default().title(title).borders(Borders::ALL))
-        .wrap(Wrap { trim: false })
-        .scroll((app.details_scroll as u16, 0));
-
-    f.render_widget(paragraph, area);
+    let details = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().title("Stop Details").borders(Borders::ALL));
+    f.render_widget(details, chunks[1]);
 }
 
-/// Renders the logs view showing CloudWatch Logs for a task.
-///
-/// Displays log entries with timestamps, container names, and messages. Supports scrolling
-/// and auto-tail mode. Shows a scroll indicator with current position. Each log line is
-/// color-coded: timestamps in dark gray, container names in cyan, messages in white.
+/// Renders the container instances table view.
 ///
-/// When no logs are available, displays a helpful message explaining possible reasons.
+/// Displays the EC2 container instances of the selected cluster with agent status,
+/// reserved vs registered CPU and memory, and task counts. DRAINING instances are
+/// highlighted and disconnected agents shown in red.
 ///
 /// # Arguments
 /// * `f` - The ratatui Frame to render into
-/// * `area` - The rectangular area allocated for the logs view
-/// * `app` - The application state containing log entries
+/// * `area` - The rectangular area allocated for the table
+/// * `app` - The application state containing container instance data
+fn draw_container_instances(f: &mut Frame, area: Rect, app: &App) {
+    let filtered_instances = app.get_filtered_container_instances();
+
+    let header = Row::new(vec![
+        "EC2 Instance",
+        "Status",
+        "Agent",
+        "CPU (used/total)",
+        "Memory (used/total)",
+        "Running",
+        "Pending",
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = filtered_instances
+        .iter()
+        .enumerate()
+        .map(|(i, instance)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let status_color = match instance.status.as_str() {
+                "ACTIVE" => Color::Green,
+                "DRAINING" => Color::Yellow,
+                _ => Color::Red,
+            };
+            let agent = if instance.agent_connected {
+                Span::styled(
+                    format!("✓ {}", instance.agent_version),
+                    Style::default().fg(Color::Green),
+                )
+            } else {
+                Span::styled(
+                    format!("✗ {}", instance.agent_version),
+                    Style::default().fg(Color::Red),
+                )
+            };
+
+            Row::new(vec![
+                Line::from(instance.ec2_instance_id.clone()),
+                Line::from(Span::styled(
+                    instance.status.clone(),
+                    Style::default().fg(status_color),
+                )),
+                Line::from(agent),
+                Line::from(format!(
+                    "{}/{}",
+                    instance.registered_cpu - instance.remaining_cpu,
+                    instance.registered_cpu
+                )),
+                Line::from(format!(
+                    "{}/{} MiB",
+                    instance.registered_memory - instance.remaining_memory,
+                    instance.registered_memory
+                )),
+                Line::from(instance.running_tasks.to_string()),
+                Line::from(instance.pending_tasks.to_string()),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let title = if app.search_query.is_empty() {
+        format!(
+            "Container Instances ({}) - /:search | Enter:tasks | x:drain/activate | Esc/h:back",
+            filtered_instances.len()
+        )
+    } else {
+        format!(
+            "Container Instances ({}/{}) - Esc:clear | Enter:tasks | x:drain/activate",
+            filtered_instances.len(),
+            app.container_instances.len()
+        )
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(20),
+            Constraint::Percentage(11),
+            Constraint::Percentage(15),
+            Constraint::Percentage(16),
+            Constraint::Percentage(20),
+            Constraint::Percentage(9),
+            Constraint::Percentage(9),
+        ],
+    )
+    .header(header)
+    .block(Block::default().title(title).borders(Borders::ALL));
+
+    f.render_widget(table, area);
+}
+
+/// Renders the task definitions list view.
+///
+/// Displays all ECS task definition families (filtered by search query if active) as a vertical list.
+/// The currently selected family is highlighted. Shows count of filtered vs total families.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the task definitions list
+/// * `app` - The application state containing task definition family data
+fn draw_task_definitions(f: &mut Frame, area: Rect, app: &App) {
+    let filtered_families = app.get_filtered_task_definition_families();
+
+    let items: Vec<ListItem> = filtered_families
+        .iter()
+        .enumerate()
+        .map(|(i, family)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+
+            ListItem::new(family.as_str()).style(style)
+        })
+        .collect();
+
+    let title = if app.search_query.is_empty() {
+        format!(
+            "Task Definition Families ({}) - /:search | Enter:view | d:describe | X:cleanup",
+            filtered_families.len()
+        )
+    } else {
+        format!(
+            "Task Definition Families ({}/{}) - Esc:clear | Enter:view | d:describe | X:cleanup",
+            filtered_families.len(),
+            app.task_definition_families.len()
+        )
+    };
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+
+    f.render_widget(list, area);
+}
+
+/// Renders the revisions table of the selected task definition family.
+///
+/// Lists the newest revisions first with their status, registration, task size,
+/// network mode and compatibilities. Inactive revisions are dimmed.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the table
+/// * `app` - The application state containing the described revisions
+fn draw_task_definition_revisions(f: &mut Frame, area: Rect, app: &App) {
+    let filtered_revisions = app.get_filtered_task_definitions();
+
+    let header = Row::new(vec![
+        "Revision",
+        "Status",
+        "Registered",
+        "Registered By",
+        "CPU",
+        "Memory",
+        "Network",
+        "Compatibilities",
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = filtered_revisions
+        .iter()
+        .enumerate()
+        .map(|(i, revision)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else if app.task_definition_diff_base.as_deref() == Some(revision.arn.as_str()) {
+                Style::default().fg(Color::Magenta)
+            } else if revision.status == "ACTIVE" {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let registered_by = revision
+                .registered_by
+                .as_deref()
+                .map(|principal| principal.rsplit('/').next().unwrap_or(principal))
+                .unwrap_or("-");
+
+            let marker = if app.task_definition_diff_base.as_deref() == Some(revision.arn.as_str())
+            {
+                "◆ "
+            } else {
+                ""
+            };
+
+            Row::new(vec![
+                format!("{marker}{}", revision.revision),
+                revision.status.clone(),
+                revision
+                    .registered_at
+                    .map(format_event_time)
+                    .unwrap_or_else(|| "-".to_string()),
+                registered_by.to_string(),
+                revision.cpu.clone().unwrap_or_else(|| "-".to_string()),
+                revision.memory.clone().unwrap_or_else(|| "-".to_string()),
+                revision
+                    .network_mode
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+                revision.compatibilities.join(", "),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let family = app
+        .selected_task_definition_family
+        .as_deref()
+        .unwrap_or("Task Definition");
+    let title = if app.search_query.is_empty() {
+        format!(
+            "{family} Revisions ({}) - /:search | Enter:view | Space:mark | D:diff | N:new revision | e:export | X:cleanup | Esc/h:back",
+            filtered_revisions.len()
+        )
+    } else {
+        format!(
+            "{family} Revisions ({}/{}) - Esc:clear | Enter:view | Space:mark | D:diff | N:new revision | e:export | X:cleanup",
+            filtered_revisions.len(),
+            app.task_definitions.len()
+        )
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(9),
+            Constraint::Percentage(10),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Percentage(8),
+            Constraint::Percentage(8),
+            Constraint::Percentage(10),
+            Constraint::Percentage(19),
+        ],
+    )
+    .header(header)
+    .block(Block::default().title(title).borders(Borders::ALL));
+
+    f.render_widget(table, area);
+}
+
+/// Renders the structured view of a task definition revision.
+///
+/// Shows the revision's overview and IAM roles, then each container definition with
+/// its image, ports, environment, secret references, health check, mounts and log
+/// configuration, and finally the task's volumes. Scrolls with up/down or j/k.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the view
+/// * `app` - The application state containing the selected revision
+fn draw_task_definition_detail(f: &mut Frame, area: Rect, app: &App) {
+    let Some(revision) = &app.selected_task_definition else {
+        let empty = Paragraph::new("No task definition selected")
+            .style(Style::default().fg(Color::Yellow))
+            .block(
+                Block::default()
+                    .title("Task Definition")
+                    .borders(Borders::ALL),
+            );
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let title = format!(
+        "Task Definition {} (↑↓:scroll | D:diff previous | N:new revision | e:export | r:refresh | Esc/h:back)",
+        revision.family_revision()
+    );
+    let paragraph = Paragraph::new(task_definition_lines(revision))
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .wrap(Wrap { trim: false })
+        .scroll((app.details_scroll as u16, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+/// Formats a task definition revision as styled lines, section by section.
+fn task_definition_lines(revision: &TaskDefinitionInfo) -> Vec<Line<'static>> {
+    let section = |title: String| {
+        Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ))
+    };
+    let field = |indent: usize, label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(
+                format!("{}{label}: ", " ".repeat(indent)),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ])
+    };
+    let or_none = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+
+    let status_color = if revision.status == "ACTIVE" {
+        Color::Green
+    } else {
+        Color::DarkGray
+    };
+    let mut lines = vec![
+        section("Overview".to_string()),
+        field(2, "ARN", revision.arn.clone()),
+        Line::from(vec![
+            Span::styled("  Status: ", Style::default().fg(Color::Gray)),
+            Span::styled(revision.status.clone(), Style::default().fg(status_color)),
+        ]),
+        field(
+            2,
+            "Registered",
+            format!(
+                "{} by {}",
+                revision
+                    .registered_at
+                    .map(format_event_time)
+                    .unwrap_or_else(|| "-".to_string()),
+                or_none(&revision.registered_by)
+            ),
+        ),
+        field(
+            2,
+            "Task size",
+            format!(
+                "{} CPU units, {} MiB",
+                or_none(&revision.cpu),
+                or_none(&revision.memory)
+            ),
+        ),
+        field(2, "Network mode", or_none(&revision.network_mode)),
+        field(
+            2,
+            "Requires",
+            if revision.requires_compatibilities.is_empty() {
+                "-".to_string()
+            } else {
+                revision.requires_compatibilities.join(", ")
+            },
+        ),
+        field(2, "Compatible with", revision.compatibilities.join(", ")),
+    ];
+    if let Some(platform) = &revision.runtime_platform {
+        lines.push(field(
+            2,
+            "Platform",
+            format!(
+                "{} {}",
+                or_none(&platform.operating_system_family),
+                or_none(&platform.cpu_architecture)
+            ),
+        ));
+    }
+    if let Some(storage) = revision.ephemeral_storage_gib {
+        lines.push(field(2, "Ephemeral storage", format!("{storage} GiB")));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section("IAM Roles".to_string()));
+    lines.push(field(2, "Task role", or_none(&revision.task_role_arn)));
+    lines.push(field(
+        2,
+        "Execution role",
+        or_none(&revision.execution_role_arn),
+    ));
+
+    lines.push(Line::from(""));
+    lines.push(section(format!(
+        "Containers ({})",
+        revision.containers.len()
+    )));
+    for container in &revision.containers {
+        let essential = if container.essential.unwrap_or(true) {
+            " (essential)"
+        } else {
+            ""
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  ▸ {}", container.name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(essential, Style::default().fg(Color::DarkGray)),
+        ]));
+        lines.push(field(4, "Image", container.image.clone()));
+
+        let mut resources = vec![format!("{} CPU units", container.cpu)];
+        if let Some(memory) = container.memory {
+            resources.push(format!("{memory} MiB hard limit"));
+        }
+        if let Some(reservation) = container.memory_reservation {
+            resources.push(format!("{reservation} MiB reserved"));
+        }
+        lines.push(field(4, "Resources", resources.join(", ")));
+        if !container.entry_point.is_empty() {
+            lines.push(field(4, "Entry point", container.entry_point.join(" ")));
+        }
+        if !container.command.is_empty() {
+            lines.push(field(4, "Command", container.command.join(" ")));
+        }
+        if let Some(directory) = &container.working_directory {
+            lines.push(field(4, "Working directory", directory.clone()));
+        }
+        if let Some(user) = &container.user {
+            lines.push(field(4, "User", user.clone()));
+        }
+
+        if !container.port_mappings.is_empty() {
+            let ports: Vec<String> = container
+                .port_mappings
+                .iter()
+                .map(|port| {
+                    let mut text = format!(
+                        "{}/{}",
+                        port.container_port
+                            .map(|p| p.to_string())
+                            .unwrap_or_else(|| "?".to_string()),
+                        port.protocol.as_deref().unwrap_or("tcp")
+                    );
+                    if let Some(host_port) =
+                        port.host_port.filter(|&p| Some(p) != port.container_port)
+                    {
+                        text.push_str(&format!(" → host {host_port}"));
+                    }
+                    let names: Vec<&str> = [port.name.as_deref(), port.app_protocol.as_deref()]
+                        .into_iter()
+                        .flatten()
+                        .collect();
+                    if !names.is_empty() {
+                        text.push_str(&format!(" ({})", names.join(", ")));
+                    }
+                    text
+                })
+                .collect();
+            lines.push(field(4, "Ports", ports.join(", ")));
+        }
+
+        if !container.environment.is_empty() {
+            lines.push(field(
+                4,
+                "Environment",
+                format!("{} variables", container.environment.len()),
+            ));
+            for variable in &container.environment {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("      {}", variable.name),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled("=", Style::default().fg(Color::DarkGray)),
+                    Span::raw(variable.value.clone()),
+                ]));
+            }
+        }
+        if !container.secrets.is_empty() {
+            lines.push(field(
+                4,
+                "Secrets",
+                format!("{} references", container.secrets.len()),
+            ));
+            for secret in &container.secrets {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("      {}", secret.name),
+                        Style::default().fg(Color::Magenta),
+                    ),
+                    Span::styled(" ← ", Style::default().fg(Color::DarkGray)),
+                    Span::raw(secret.value_from.clone()),
+                ]));
+            }
+        }
+
+        if let Some(check) = &container.health_check {
+            lines.push(field(4, "Health check", check.command.join(" ")));
+            let timing: Vec<String> = [
+                check.interval.map(|s| format!("every {s}s")),
+                check.timeout.map(|s| format!("timeout {s}s")),
+                check.retries.map(|n| format!("{n} retries")),
+                check.start_period.map(|s| format!("start period {s}s")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            if !timing.is_empty() {
+                lines.push(field(6, "Timing", timing.join(", ")));
+            }
+        }
+
+        for mount in &container.mount_points {
+            let access = if mount.read_only.unwrap_or(false) {
+                "read-only"
+            } else {
+                "read-write"
+            };
+            lines.push(field(
+                4,
+                "Mount",
+                format!(
+                    "{} → {} ({access})",
+                    mount.source_volume, mount.container_path
+                ),
+            ));
+        }
+
+        if let Some(log_config) = &container.log_configuration {
+            let options: Vec<String> = log_config
+                .options
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .chain(
+                    log_config
+                        .secret_options
+                        .iter()
+                        .map(|secret| format!("{}←{}", secret.name, secret.value_from)),
+                )
+                .collect();
+            lines.push(field(
+                4,
+                "Logs",
+                if options.is_empty() {
+                    log_config.log_driver.clone()
+                } else {
+                    format!("{} ({})", log_config.log_driver, options.join(", "))
+                },
+            ));
+        }
+
+        if !container.depends_on.is_empty() {
+            let dependencies: Vec<String> = container
+                .depends_on
+                .iter()
+                .map(|dependency| format!("{} {}", dependency.container_name, dependency.condition))
+                .collect();
+            lines.push(field(4, "Depends on", dependencies.join(", ")));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section(format!("Volumes ({})", revision.volumes.len())));
+    if revision.volumes.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No volumes",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for volume in &revision.volumes {
+        let source = if let Some(efs) = &volume.efs {
+            let mut text = format!(
+                "EFS {}{}",
+                efs.file_system_id,
+                efs.root_directory.as_deref().unwrap_or("")
+            );
+            if let Some(access_point) = &efs.access_point_id {
+                text.push_str(&format!(" via {access_point}"));
+            }
+            if efs.transit_encryption.as_deref() == Some("ENABLED") {
+                text.push_str(", encrypted in transit");
+            }
+            if efs.iam.as_deref() == Some("ENABLED") {
+                text.push_str(", IAM authorization");
+            }
+            text
+        } else if let Some(path) = &volume.host_source_path {
+            format!("host path {path}")
+        } else {
+            "task storage".to_string()
+        };
+        lines.push(field(2, &volume.name, source));
+    }
+
+    lines
+}
+
+/// Renders the details view showing comprehensive information about a resource.
+///
+/// Displays detailed information about a selected service or task in a scrollable
+/// text view. The content is formatted as multi-line text with word wrapping.
+/// Supports scrolling with up/down arrow keys or j/k for long content.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the details view
+/// * `app` - The application state containing details text and scroll position
+fn draw_details(f: &mut Frame, area: Rect, app: &App) {
+    let default_text = "No details available".to_string();
+
+    // Choose between formatted view and JSON view
+    let (content, view_type) = if app.show_json_view {
+        (app.details_json.as_ref().unwrap_or(&default_text), "JSON")
+    } else {
+        (app.details.as_ref().unwrap_or(&default_text), "Formatted")
+    };
+
+    let title = format!("Details - {view_type} View (↑↓:scroll | J:toggle | Esc/h:back)");
+
+    let paragraph = Paragraph::new(content.as_str())
+        .style(Style::default().fg(Color::White))
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .wrap(Wrap { trim: false })
+        .scroll((app.details_scroll as u16, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+/// Renders the logs view showing CloudWatch Logs for a task.
+///
+/// Displays log entries with timestamps, container names, and messages. Supports scrolling
+/// and auto-tail mode. Shows a scroll indicator with current position. Each log line is
+/// color-coded: timestamps in dark gray, container names in cyan, messages in white.
+///
+/// When no logs are available, displays a helpful message explaining possible reasons.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the logs view
+/// * `app` - The application state containing log entries
 fn draw_logs(f: &mut Frame, area: Rect, app: &App) {
     // Get filtered logs
     let filtered_logs = app.get_filtered_logs();
 
-    if filtered_logs.is_empty() {
-        let no_logs = if !app.log_search_query.is_empty() || app.log_level_filter.is_some() {
-            Paragraph::new("No logs match the current search/filter criteria.\n\nTry:\n- Press Esc to clear search\n- Press 'f' to cycle through log level filters")
+    if filtered_logs.is_empty() {
+        let no_logs = if !app.log_search_query.is_empty()
+            || app.log_level_filter.is_some()
+            || app.log_task_filter.is_some()
+        {
+            Paragraph::new("No logs match the current search/filter criteria.\n\nTry:\n- Press Esc to clear search\n- Press 'f' to cycle through log level filters")
+        } else if app.show_service_logs {
+            Paragraph::new("No logs available for this service in the loaded time window.\n\nThis could mean:\n- No container of the service's task definition uses CloudWatch Logs (awslogs)\n- The service's tasks haven't produced logs recently (scroll up to look further back)")
+        } else if !app.log_unavailable.is_empty() {
+            let reasons: Vec<String> = app
+                .log_unavailable
+                .iter()
+                .map(|unavailable| format!("- {}: {}", unavailable.container_name, unavailable.reason))
+                .collect();
+            Paragraph::new(format!(
+                "No logs available for this task.\n\nLogs cannot be read for these containers:\n{}",
+                reasons.join("\n")
+            ))
+        } else {
+            Paragraph::new("No logs available for this task.\n\nThis could mean:\n- The task has no CloudWatch Logs configured\n- The log stream hasn't been created yet\n- The task hasn't produced any logs")
+        }
+            .style(Style::default().fg(Color::Yellow))
+            .block(
+                Block::default()
+                    .title(format!(
+                        "Logs{} (Press Esc or h to go back | r:refresh g:jump I:insights)",
+                        log_position_status(app)
+                    ))
+                    .borders(Borders::ALL),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(no_logs, area);
+        return;
+    }
+
+    // Calculate visible window
+    let available_height = area.height.saturating_sub(2) as usize; // Account for borders
+    let total_logs = filtered_logs.len();
+
+    // Determine which logs to show based on scroll position; expanded multi-line
+    // entries take one row per line
+    let (start_idx, end_idx) = log_window(&filtered_logs, app, available_height);
+    let visible_logs = &filtered_logs[start_idx..end_idx];
+
+    // Column values of the visible entries, padded to the widest value of each column
+    let column_values: Vec<Vec<String>> = visible_logs
+        .iter()
+        .map(|log| {
+            app.log_columns
+                .iter()
+                .map(|column| log.field(column).unwrap_or_else(|| "-".to_string()))
+                .collect()
+        })
+        .collect();
+    let column_widths: Vec<usize> = (0..app.log_columns.len())
+        .map(|column| {
+            column_values
+                .iter()
+                .map(|values| values[column].chars().count())
+                .max()
+                .unwrap_or(0)
+                .min(MAX_LOG_COLUMN_WIDTH)
+        })
+        .collect();
+    let selected = app
+        .selected_log_index(total_logs)
+        .filter(|_| !app.auto_tail);
+    let find_regex = app.log_find_regex();
+
+    // Format log entries as Lines
+    let log_lines: Vec<Line> = visible_logs
+        .iter()
+        .zip(&column_values)
+        .enumerate()
+        .flat_map(|(offset, (log, values))| {
+            // Convert timestamp (milliseconds) to datetime
+            let datetime = DateTime::from_timestamp_millis(log.timestamp)
+                .map(|dt| dt.with_timezone(&Local))
+                .unwrap_or_else(Local::now);
+
+            let timestamp_str = datetime.format("%Y-%m-%d %H:%M:%S%.3f").to_string();
+
+            let mut spans = Vec::new();
+            if app.is_log_bookmarked(log) {
+                spans.push(Span::styled("★ ", Style::default().fg(Color::Yellow)));
+            }
+            spans.push(Span::styled(
+                format!("[{timestamp_str}] "),
+                Style::default().fg(Color::DarkGray),
+            ));
+            // Service-wide logs show which task each entry comes from
+            if let Some(task_id) = &log.task_id {
+                spans.push(Span::styled(
+                    format!("[{}] ", task_id.chars().take(8).collect::<String>()),
+                    Style::default().fg(Color::Magenta),
+                ));
+            }
+            spans.push(Span::styled(
+                format!("[{}] ", log.container_name),
+                Style::default().fg(Color::Cyan),
+            ));
+            for (value, width) in values.iter().zip(&column_widths) {
+                spans.push(Span::styled(
+                    format!("{} ", pad_column(value, *width)),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            // With columns shown, JSON lines show only their message field
+            let message = log
+                .structured
+                .as_ref()
+                .and_then(|structured| structured.message.as_deref())
+                .filter(|_| !app.log_columns.is_empty())
+                .unwrap_or(&log.message);
+            spans.extend(highlight_matches(
+                message,
+                find_regex.as_ref(),
+                Style::default().fg(Color::White),
+            ));
+
+            let expanded = app.is_log_expanded(log);
+            if !log.continuation.is_empty() && !expanded {
+                spans.push(Span::styled(
+                    format!(" [+{} lines]", log.continuation.len()),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            let mut lines = vec![Line::from(spans)];
+            if expanded {
+                lines.extend(log.continuation.iter().map(|line| {
+                    Line::from(highlight_matches(
+                        &line.replace('\t', "    "),
+                        find_regex.as_ref(),
+                        Style::default().fg(Color::Gray),
+                    ))
+                }));
+            }
+            if selected == Some(start_idx + offset) {
+                lines = lines
+                    .into_iter()
+                    .map(|line| line.style(Style::default().bg(Color::DarkGray)))
+                    .collect();
+            }
+            lines
+        })
+        .collect();
+    // Keep the newest line in view when the newest entry is taller than the view
+    let overflow = if app.auto_tail {
+        log_lines.len().saturating_sub(available_height)
+    } else {
+        0
+    };
+
+    let scroll_indicator = if start_idx > 0 || end_idx < total_logs {
+        let start = start_idx + 1;
+        format!(" [{start}-{end_idx}/{total_logs}]")
+    } else {
+        format!(" [{total_logs}]")
+    };
+
+    // Build filter/search status
+    let mut filter_status = String::new();
+    if let Some(ref level) = app.log_level_filter {
+        filter_status.push_str(&format!(" | Filter: {level:?}"));
+    }
+    if let Some(ref task_id) = app.log_task_filter {
+        filter_status.push_str(&format!(" | Task: {task_id}"));
+    }
+    if !app.log_search_query.is_empty() {
+        filter_status.push_str(&format!(" | Search: '{}'", app.log_search_query));
+    }
+    if !app.log_unavailable.is_empty() {
+        let containers: Vec<&str> = app
+            .log_unavailable
+            .iter()
+            .map(|unavailable| unavailable.container_name.as_str())
+            .collect();
+        filter_status.push_str(&format!(" | No logs: {}", containers.join(", ")));
+    }
+    if app.log_columns_mode {
+        filter_status.push_str(&format!(" | Columns: {}_", app.log_columns_input));
+    } else if !app.log_columns.is_empty() {
+        filter_status.push_str(&format!(" | Columns: {}", app.log_columns.join(", ")));
+    }
+    if !app.log_bookmarks.is_empty() {
+        filter_status.push_str(&format!(" | Bookmarks: {}", app.log_bookmarks.len()));
+    }
+    filter_status.push_str(&log_position_status(app));
+
+    let (name, task_key) = if app.show_service_logs {
+        ("Service Logs", " T:task")
+    } else {
+        ("Logs", "")
+    };
+    let title = if app.auto_tail {
+        format!("{name}{scroll_indicator}{filter_status} (AUTO-TAIL | /:search f:filter{task_key} s:find n/N:match b/B:bookmark C/A:context c:columns Enter:entry o/O:expand g:jump I:insights e:export t:toggle)")
+    } else {
+        format!("{name}{scroll_indicator}{filter_status} (↑↓:scroll | /:search f:filter{task_key} s:find n/N:match b/B:bookmark C/A:context c:columns Enter:entry o/O:expand g:jump I:insights e:export t:toggle)")
+    };
+
+    let logs_widget = Paragraph::new(log_lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if app.auto_tail {
+                    Color::Green
+                } else {
+                    Color::White
+                })),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((overflow as u16, 0));
+
+    f.render_widget(logs_widget, area);
+}
+
+/// Splits text into spans, highlighting the matches of the find query.
+fn highlight_matches(text: &str, regex: Option<&Regex>, style: Style) -> Vec<Span<'static>> {
+    let Some(regex) = regex else {
+        return vec![Span::styled(text.to_string(), style)];
+    };
+    let highlight = Style::default()
+        .fg(Color::Black)
+        .bg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let mut spans = Vec::new();
+    let mut end = 0;
+    for found in regex.find_iter(text).filter(|found| !found.is_empty()) {
+        if found.start() > end {
+            spans.push(Span::styled(text[end..found.start()].to_string(), style));
+        }
+        spans.push(Span::styled(found.as_str().to_string(), highlight));
+        end = found.end();
+    }
+    if end < text.len() || spans.is_empty() {
+        spans.push(Span::styled(text[end..].to_string(), style));
+    }
+    spans
+}
+
+/// Renders the list of bookmarked log entries; Enter selects the highlighted one.
+fn draw_log_bookmarks(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let width = 100.min(area.width.saturating_sub(4));
+    let height = (app.log_bookmarks.len() + 2).clamp(3, 20) as u16;
+    let modal_area = Rect {
+        x: area.width.saturating_sub(width) / 2,
+        y: area.height.saturating_sub(height) / 2,
+        width,
+        height: height.min(area.height),
+    };
+    f.render_widget(Clear, modal_area);
+
+    let items: Vec<ListItem> = app
+        .log_bookmarks
+        .iter()
+        .map(|log| {
+            let time = DateTime::from_timestamp_millis(log.timestamp)
+                .map(|dt| {
+                    dt.with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M:%S%.3f")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            let task = log
+                .task_id
+                .as_ref()
+                .map(|task_id| format!("[{}] ", task_id.chars().take(8).collect::<String>()))
+                .unwrap_or_default();
+            ListItem::new(format!(
+                "[{time}] {task}[{}] {}",
+                log.container_name, log.message
+            ))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Bookmarks ({}) (↑↓:navigate | Enter:go to entry | Esc:close)",
+                    app.log_bookmarks.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut state = ListState::default();
+    state.select(Some(app.modal_selected_index));
+    f.render_stateful_widget(list, modal_area, &mut state);
+}
+
+/// Returns the range of filtered log entries shown in a Logs view `height` rows tall.
+///
+/// Entries take one row, plus one per continuation line when expanded. While tailing
+/// the window ends at the newest entry; otherwise it starts at the scroll position,
+/// but not past the start of the last full page.
+fn log_window(logs: &[LogEntry], app: &App, height: usize) -> (usize, usize) {
+    let entry_height = |log: &LogEntry| {
+        1 + if app.is_log_expanded(log) {
+            log.continuation.len()
+        } else {
+            0
+        }
+    };
+
+    // Start of the last page: as many of the newest entries as fit, at least one
+    let mut last_page_start = logs.len();
+    let mut rows = 0;
+    while last_page_start > 0 {
+        rows += entry_height(&logs[last_page_start - 1]);
+        if rows > height && last_page_start < logs.len() {
+            break;
+        }
+        last_page_start -= 1;
+    }
+
+    let start = if app.auto_tail {
+        last_page_start
+    } else {
+        app.log_scroll.min(last_page_start)
+    };
+    let mut end = start;
+    let mut rows = 0;
+    while end < logs.len() && rows < height {
+        rows += entry_height(&logs[end]);
+        end += 1;
+    }
+    (start, end)
+}
+
+/// Renders the Logs Insights view: the query editor above a table of results.
+///
+/// The query box shows the time range and the number of log groups queried, and is
+/// highlighted while the query is edited. The results table has one column per
+/// result field, in the order the fields first appear in the results.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the Logs Insights view
+/// * `app` - The application state containing the query and its results
+fn draw_log_insights(f: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(0)])
+        .split(area);
+
+    let query_text = if app.insights_editing {
+        format!("{}_", app.insights_query)
+    } else {
+        app.insights_query.clone()
+    };
+    let query_title = format!(
+        "Query (last {} over {} log group(s)) - /:edit Enter:run T:range n:saved S:save Esc/h:back",
+        app.insights_time_range.label(),
+        app.insights_log_groups.len()
+    );
+    let query = Paragraph::new(query_text)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title(query_title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if app.insights_editing {
+                    Color::Yellow
+                } else {
+                    Color::White
+                })),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(query, chunks[0]);
+
+    let Some(results) = app.insights_results() else {
+        let message = if app.insights_run.is_some() {
+            "Waiting for the first results..."
+        } else {
+            "Press Enter to run the query.\n\nQueries use the CloudWatch Logs Insights query syntax, e.g.\n  fields @timestamp, @message | filter @message like /ERROR/ | sort @timestamp desc\n  stats count(*) by bin(5m)"
+        };
+        let placeholder = Paragraph::new(message)
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().title("Results").borders(Borders::ALL))
+            .wrap(Wrap { trim: false });
+        f.render_widget(placeholder, chunks[1]);
+        return;
+    };
+
+    let status_color = match results.status.as_str() {
+        "Complete" => Color::Green,
+        "Scheduled" | "Running" => Color::Yellow,
+        _ => Color::Red,
+    };
+    let title = format!(
+        "Results ({} rows) | {} | {:.0} of {:.0} records matched",
+        results.rows.len(),
+        results.status,
+        results.records_matched,
+        results.records_scanned
+    );
+
+    if results.fields.is_empty() {
+        let empty = Paragraph::new(if results.is_finished() {
+            "The query returned no results."
+        } else {
+            "No results yet..."
+        })
+        .style(Style::default().fg(Color::Yellow))
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(status_color)),
+        );
+        f.render_widget(empty, chunks[1]);
+        return;
+    }
+
+    let header = Row::new(results.fields.clone())
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+    let rows: Vec<Row> = results
+        .rows
+        .iter()
+        .map(|row| Row::new(row.clone()).style(Style::default().fg(Color::White)))
+        .collect();
+
+    // Timestamps have a fixed width; the other fields share the rest
+    let widths: Vec<Constraint> = results
+        .fields
+        .iter()
+        .map(|field| {
+            if field == "@timestamp" {
+                Constraint::Length(23)
+            } else {
+                Constraint::Fill(1)
+            }
+        })
+        .collect();
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(status_color)),
+        );
+
+    let mut state = TableState::default().with_selected(Some(app.selected_index));
+    f.render_stateful_widget(table, chunks[1], &mut state);
+}
+
+/// Widest a log column gets; longer values are truncated.
+const MAX_LOG_COLUMN_WIDTH: usize = 30;
+
+/// Pads a log column value to `width` characters, truncating longer values with '…'.
+fn pad_column(value: &str, width: usize) -> String {
+    if value.chars().count() > width {
+        let truncated: String = value.chars().take(width.saturating_sub(1)).collect();
+        format!("{truncated}…")
+    } else {
+        format!("{value:<width$}")
+    }
+}
+
+/// Renders the log entry modal: the selected entry's metadata and well-known fields,
+/// followed by the full log line with JSON pretty-printed.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `app` - The application state containing the selected log entry
+fn draw_log_entry_detail(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let width = 100.min(area.width.saturating_sub(4));
+    let height = area.height.saturating_sub(6);
+
+    let modal_area = Rect {
+        x: area.width.saturating_sub(width) / 2,
+        y: area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let lines: Vec<Line> = app
+        .log_entry_detail_lines()
+        .into_iter()
+        .map(Line::from)
+        .collect();
+    let detail = Paragraph::new(lines)
+        .scroll((app.modal_selected_index as u16, 0))
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .title("Log Entry (↑↓:scroll | Esc:close)")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .style(Style::default().bg(Color::Black)),
+        );
+
+    f.render_widget(detail, modal_area);
+}
+
+/// Renders two task definition revisions side by side.
+///
+/// Each row is a field of either revision: added fields are green, removed ones red,
+/// and changed ones show the old value in red and the new value in green. Unchanged
+/// fields are dimmed and hidden unless toggled on.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `app` - The application state containing the diff
+fn draw_task_definition_diff(f: &mut Frame, app: &App) {
+    let Some(diff) = &app.task_definition_diff else {
+        return;
+    };
+    let area = f.area();
+    let width = area.width.saturating_sub(4);
+    let height = area.height.saturating_sub(4);
+    let modal_area = Rect {
+        x: area.width.saturating_sub(width) / 2,
+        y: area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, modal_area);
+
+    let (old_label, new_label) = if diff.from_service_editor {
+        (
+            format!("{} (running)", diff.old.family_revision()),
+            format!("{} (selected)", diff.new.family_revision()),
+        )
+    } else {
+        (diff.old.family_revision(), diff.new.family_revision())
+    };
+    let header = Row::new(vec!["Field".to_string(), old_label, new_label])
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+    let fields = diff.visible_fields();
+    let rows: Vec<Row> = fields
+        .iter()
+        .skip(app.modal_selected_index)
+        .map(|field| {
+            let (marker, field_color, old_color, new_color) = match field.change() {
+                FieldChange::Added => ("+", Color::Green, Color::DarkGray, Color::Green),
+                FieldChange::Removed => ("-", Color::Red, Color::Red, Color::DarkGray),
+                FieldChange::Changed => ("~", Color::Yellow, Color::Red, Color::Green),
+                FieldChange::Unchanged => (" ", Color::DarkGray, Color::DarkGray, Color::DarkGray),
+            };
+            let value = |value: &Option<String>| value.clone().unwrap_or_else(|| "—".to_string());
+            Row::new(vec![
+                Line::from(Span::styled(
+                    format!("{marker} {}", field.field),
+                    Style::default().fg(field_color),
+                )),
+                Line::from(Span::styled(
+                    value(&field.old),
+                    Style::default().fg(old_color),
+                )),
+                Line::from(Span::styled(
+                    value(&field.new),
+                    Style::default().fg(new_color),
+                )),
+            ])
+        })
+        .collect();
+
+    let (added, removed, changed) = diff.summary();
+    let unchanged_key = if diff.show_unchanged {
+        "u:hide unchanged"
+    } else {
+        "u:show unchanged"
+    };
+    let title = format!(
+        "Compare {} → {}: {changed} changed, {added} added, {removed} removed ({unchanged_key} | ↑↓:scroll | Esc:close)",
+        diff.old.family_revision(),
+        diff.new.family_revision()
+    );
+    let table = if rows.is_empty() {
+        Table::new(
+            vec![Row::new(vec![Line::from(Span::styled(
+                "The revisions have the same settings",
+                Style::default().fg(Color::DarkGray),
+            ))])],
+            [Constraint::Percentage(100)],
+        )
+    } else {
+        Table::new(
+            rows,
+            [
+                Constraint::Percentage(30),
+                Constraint::Percentage(35),
+                Constraint::Percentage(35),
+            ],
+        )
+        .header(header)
+    }
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(table, modal_area);
+}
+
+/// Renders the dialog registering a new task definition revision.
+///
+/// Lists the editable fields with the one being typed highlighted, the option to
+/// deploy the new revision, and the problems found when the dialog was last
+/// submitted. The list scrolls to keep the highlighted field visible.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `app` - The application state containing the dialog
+fn draw_task_definition_editor(f: &mut Frame, app: &App) {
+    let Some(editor) = &app.task_definition_editor else {
+        return;
+    };
+    let area = f.area();
+    let width = 110.min(area.width.saturating_sub(4));
+    let field_count = editor.fields.len() + usize::from(editor.deploy_target.is_some());
+    let wanted_height = field_count + editor.problems.len() + 7;
+    let height = (wanted_height as u16).min(area.height.saturating_sub(4));
+    let modal_area = Rect {
+        x: area.width.saturating_sub(width) / 2,
+        y: area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, modal_area);
+
+    let selected_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(
+                "New revision of {} copied from {}",
+                editor.base.family,
+                editor.source.family_revision()
+            ),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(""),
+    ];
+    for (index, field) in editor.fields.iter().enumerate() {
+        let selected = index == editor.field;
+        let style = if selected {
+            selected_style
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let marker = if selected { "▶ " } else { "  " };
+        let value = match (selected, field.value.is_empty()) {
+            (true, _) => format!("{}_", field.value),
+            (false, true) => "—".to_string(),
+            (false, false) => field.value.clone(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{marker}{:<28}", field.label), style),
+            Span::styled(value, style),
+        ]));
+    }
+    if let Some((cluster, service)) = &editor.deploy_target {
+        let style = if editor.editing_deploy() {
+            selected_style
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let marker = if editor.editing_deploy() {
+            "▶ "
+        } else {
+            "  "
+        };
+        let checkbox = if editor.deploy { "[x]" } else { "[ ]" };
+        lines.push(Line::from(Span::styled(
+            format!("{marker}{checkbox} Deploy the new revision to {service} in cluster {cluster}"),
+            style,
+        )));
+    }
+    if !editor.problems.is_empty() {
+        lines.push(Line::from(""));
+        for problem in &editor.problems {
+            lines.push(Line::from(Span::styled(
+                format!("✗ {problem}"),
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+
+    // Keep the selected field (two header lines above the fields) in view
+    let visible = usize::from(height.saturating_sub(2));
+    let scroll = (editor.field + 3).saturating_sub(visible);
+    let dialog = Paragraph::new(lines).scroll((scroll as u16, 0)).block(
+        Block::default()
+            .title("Register Task Definition (Tab:next field | Space:toggle deploy | Ctrl+E:edit JSON | Enter:register | Esc:cancel)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(dialog, modal_area);
+}
+
+/// Renders the log export dialog.
+///
+/// Lists the export options (format, filters, time range, full history and
+/// destination path) with the field being edited highlighted.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `app` - The application state containing the export options
+fn draw_log_export(f: &mut Frame, app: &App) {
+    let Some(dialog) = &app.log_export else {
+        return;
+    };
+    let area = f.area();
+    let width = 90.min(area.width.saturating_sub(4));
+    let height = 11;
+
+    let modal_area = Rect {
+        x: area.width.saturating_sub(width) / 2,
+        y: area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let checkbox = |checked: bool| if checked { "[x]" } else { "[ ]" };
+    let filters = if app.log_search_query.is_empty()
+        && app.log_level_filter.is_none()
+        && app.log_task_filter.is_none()
+    {
+        "no filter is active".to_string()
+    } else {
+        "the current search, level and task filters".to_string()
+    };
+    let fields = [
+        (
+            ExportField::Format,
+            "Format",
+            format!("< {} >", dialog.format.label()),
+        ),
+        (
+            ExportField::ApplyFilters,
+            "Apply filters",
+            format!("{} {filters}", checkbox(dialog.apply_filters)),
+        ),
+        (
+            ExportField::Range,
+            "Time range",
+            format!("< {} >", dialog.range.label()),
+        ),
+        (
+            ExportField::FullHistory,
+            "Full history",
+            format!(
+                "{} fetch every older page instead of the {} loaded entries",
+                checkbox(dialog.full_history),
+                app.logs.len()
+            ),
+        ),
+        (
+            ExportField::Path,
+            "Path",
+            if dialog.field == ExportField::Path {
+                format!("{}_", dialog.path)
+            } else {
+                dialog.path.clone()
+            },
+        ),
+    ];
+
+    let mut lines = vec![Line::from("")];
+    for (field, label, value) in fields {
+        let style = if field == dialog.field {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let marker = if field == dialog.field { "▶ " } else { "  " };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{marker}{label:<14}"), style),
+            Span::styled(value, style),
+        ]));
+    }
+
+    let export = Paragraph::new(lines).block(
+        Block::default()
+            .title("Export Logs (Tab:next field | Space/←→:change | Enter:export | Esc:cancel)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(export, modal_area);
+}
+
+/// Renders the task definition and service export dialog.
+///
+/// Shows what is exported, the format and the destination path, with the field
+/// being edited highlighted.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `app` - The application state containing the export options
+fn draw_definition_export(f: &mut Frame, app: &App) {
+    let Some(dialog) = &app.definition_export else {
+        return;
+    };
+    let area = f.area();
+    let width = 90.min(area.width.saturating_sub(4));
+    let height = 9;
+
+    let modal_area = Rect {
+        x: area.width.saturating_sub(width) / 2,
+        y: area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let input = match dialog.source {
+        DefinitionSource::TaskDefinition(_) => "register-task-definition",
+        DefinitionSource::Service { .. } => "create-service",
+    };
+    let fields = [
+        (
+            DefinitionExportField::Format,
+            "Format",
+            format!("< {} >", dialog.format.label()),
+        ),
+        (
+            DefinitionExportField::Path,
+            "Path",
+            if dialog.field == DefinitionExportField::Path {
+                format!("{}_", dialog.path)
+            } else {
+                dialog.path.clone()
+            },
+        ),
+    ];
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Export      ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                dialog.source.label(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+    for (field, label, value) in fields {
+        let style = if field == dialog.field {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
         } else {
-            Paragraph::new("No logs available for this task.\n\nThis could mean:\n- The task has no CloudWatch Logs configured\n- The log stream hasn't been created yet\n- The task hasn't produced any logs")
+            Style::default().fg(Color::White)
+        };
+        let marker = if field == dialog.field { "▶ " } else { "  " };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{marker}{label:<10}"), style),
+            Span::styled(value, style),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("  ARNs, revision and other fields set by ECS are left out, ready for {input}"),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let export = Paragraph::new(lines).block(
+        Block::default()
+            .title("Export (Tab:next field | Space/←→:change format | Enter:export | Esc:cancel)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(export, modal_area);
+}
+
+/// Renders the task definition cleanup dialog.
+///
+/// Before the cleanup runs, shows the rules with the field being edited highlighted
+/// and, once previewed, the revisions they select. While the cleanup runs, shows its
+/// progress bar, counts and failures.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `app` - The application state containing the cleanup dialog
+fn draw_task_definition_cleanup(f: &mut Frame, app: &App) {
+    let Some(cleanup) = &app.task_definition_cleanup else {
+        return;
+    };
+    let area = f.area();
+    let width = 90.min(area.width.saturating_sub(4));
+    let height = 24.min(area.height.saturating_sub(4));
+
+    let modal_area = Rect {
+        x: area.width.saturating_sub(width) / 2,
+        y: area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+    let muted = Style::default().fg(Color::DarkGray);
+    let revision = |arn: &str| arn.rsplit('/').next().unwrap_or(arn).to_string();
+
+    let title = if cleanup.progress.is_some() {
+        format!("Clean Up {} (Enter/Esc:close when done)", cleanup.family)
+    } else if cleanup.plan.is_some() {
+        format!(
+            "Clean Up {} (Tab:next rule | Space:toggle | Enter:clean up | Esc:cancel)",
+            cleanup.family
+        )
+    } else {
+        format!(
+            "Clean Up {} (Tab:next rule | Space:toggle | Enter:preview | Esc:cancel)",
+            cleanup.family
+        )
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    if let Some(progress) = &cleanup.progress {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Min(0)])
+            .split(inner);
+        render_progress_bar(
+            f,
+            chunks[0],
+            progress.fraction(),
+            &format!(
+                "{} of {} revisions processed",
+                progress.processed, progress.total
+            ),
+            &app.theme,
+        );
+
+        let mut counts = vec![
+            Span::styled("  Deregistered: ", label),
+            Span::styled(progress.deregistered.to_string(), value),
+        ];
+        if cleanup.delete {
+            counts.push(Span::styled(" | Deleted: ", label));
+            counts.push(Span::styled(progress.deleted.to_string(), value));
         }
-            .style(Style::default().fg(Color::Yellow))
-            .block(
-                Block::default()
-                    .title("Logs (Press Esc or h to go back | r:refresh)")
-                    .borders(Borders::ALL),
-            )
-            .wrap(Wrap { trim: false });
-        f.render_widget(no_logs, area);
+        counts.push(Span::styled(" | Failed: ", label));
+        counts.push(Span::styled(
+            progress.failures.len().to_string(),
+            if progress.failures.is_empty() {
+                value
+            } else {
+                Style::default().fg(Color::Red)
+            },
+        ));
+        let mut lines = vec![Line::from(counts), Line::from("")];
+        for (arn, reason) in &progress.failures {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}: ", revision(arn)),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::styled(reason.clone(), value),
+            ]));
+        }
+        if progress.is_finished() {
+            lines.push(Line::from(Span::styled("  Done", muted)));
+        }
+        f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[1]);
         return;
     }
 
-    // Calculate visible window
-    let available_height = area.height.saturating_sub(2) as usize; // Account for borders
-    let total_logs = filtered_logs.len();
+    let checkbox = |checked: bool| if checked { "[x]" } else { "[ ]" };
+    let editing = |field: CleanupField, text: &str| {
+        if field == cleanup.field {
+            format!("{text}_")
+        } else {
+            text.to_string()
+        }
+    };
+    let fields = [
+        (
+            CleanupField::OlderThanDays,
+            "Older than",
+            format!(
+                "{} days",
+                editing(CleanupField::OlderThanDays, &cleanup.older_than_days)
+            ),
+        ),
+        (
+            CleanupField::KeepLatest,
+            "Keep latest",
+            format!(
+                "{} revisions",
+                editing(CleanupField::KeepLatest, &cleanup.keep_latest)
+            ),
+        ),
+        (
+            CleanupField::SkipInUse,
+            "Skip in use",
+            format!(
+                "{} keep revisions used by a service in any cluster of the region",
+                checkbox(cleanup.skip_in_use)
+            ),
+        ),
+        (
+            CleanupField::Delete,
+            "Delete",
+            format!(
+                "{} delete the revisions permanently once deregistered",
+                checkbox(cleanup.delete)
+            ),
+        ),
+    ];
+
+    let mut lines = vec![Line::from("")];
+    for (field, name, text) in fields {
+        let style = if field == cleanup.field {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            value
+        };
+        let marker = if field == cleanup.field { "▶ " } else { "  " };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{marker}{name:<13}"), style),
+            Span::styled(text, style),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    match &cleanup.plan {
+        None => lines.push(Line::from(Span::styled(
+            "  Press Enter to preview the revisions these rules select",
+            muted,
+        ))),
+        Some(plan) => {
+            let mut summary = vec![Span::styled(
+                format!(
+                    "  {} of {} active revisions selected",
+                    plan.revisions.len(),
+                    plan.active
+                ),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )];
+            if !plan.in_use.is_empty() {
+                summary.push(Span::styled(
+                    format!(", {} old revisions kept as in use", plan.in_use.len()),
+                    label,
+                ));
+            }
+            lines.push(Line::from(summary));
+
+            // Lists as many revisions as fit, oldest first
+            let room = (inner.height as usize).saturating_sub(lines.len() + 1);
+            let shown = if plan.revisions.len() > room {
+                room.saturating_sub(1)
+            } else {
+                plan.revisions.len()
+            };
+            for arn in plan.revisions.iter().take(shown) {
+                lines.push(Line::from(Span::styled(
+                    format!("    - {}", revision(arn)),
+                    value,
+                )));
+            }
+            if plan.revisions.len() > shown {
+                lines.push(Line::from(Span::styled(
+                    format!("    ... and {} more", plan.revisions.len() - shown),
+                    muted,
+                )));
+            }
+        }
+    }
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
 
-    // Determine which logs to show based on scroll position
-    let start_idx = if app.auto_tail {
-        total_logs.saturating_sub(available_height)
+/// Formats the jump-to-time input, or the time the logs were jumped to, for the
+/// Logs view title.
+fn log_position_status(app: &App) -> String {
+    if app.log_jump_mode {
+        format!(" | Jump to: {}_", app.log_jump_input)
+    } else if let Some(start_time) = app.log_start_time {
+        let time = DateTime::from_timestamp_millis(start_time)
+            .map(|dt| {
+                dt.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        format!(" | From: {time}")
     } else {
-        app.log_scroll
-            .min(total_logs.saturating_sub(available_height.min(total_logs)))
-    };
+        String::new()
+    }
+}
 
-    let end_idx = (start_idx + available_height).min(total_logs);
+/// Renders the service events view showing deployments and the event stream.
+///
+/// The top table lists the service's deployments with their rollout state and task
+/// counts; rollout states are color-coded (completed in green, in progress in yellow,
+/// failed in red). Below it, the service events are listed newest first, filtered by
+/// the search query if active.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the service events view
+/// * `app` - The application state containing deployments and events
+fn draw_service_events(f: &mut Frame, area: Rect, app: &App) {
+    let monitor = app.rollout_monitor.as_ref().filter(|m| {
+        app.selected_cluster.as_deref() == Some(m.cluster.as_str())
+            && app.selected_service.as_deref() == Some(m.service.as_str())
+    });
 
-    // Format log entries as Lines
-    let log_lines: Vec<Line> = filtered_logs[start_idx..end_idx]
-        .iter()
-        .map(|log| {
-            // Convert timestamp (milliseconds) to datetime
-            let datetime = DateTime::from_timestamp_millis(log.timestamp)
-                .map(|dt| dt.with_timezone(&Local))
-                .unwrap_or_else(Local::now);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(if monitor.is_some() { 5 } else { 0 }),
+            Constraint::Length(app.service_deployments.len() as u16 + 4),
+            Constraint::Min(0),
+        ])
+        .split(area);
 
-            let timestamp_str = datetime.format("%Y-%m-%d %H:%M:%S%.3f").to_string();
+    if let Some(monitor) = monitor {
+        draw_rollout_monitor(f, chunks[0], app, monitor);
+    }
 
-            Line::from(vec![
+    // Deployments table
+    let header = Row::new(vec![
+        "Deployment",
+        "Status",
+        "Rollout",
+        "Task Definition",
+        "Desired",
+        "Running",
+        "Pending",
+        "Failed",
+        "Updated",
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = app
+        .service_deployments
+        .iter()
+        .map(|deployment| {
+            let rollout_color = match deployment.rollout_state.as_str() {
+                "COMPLETED" => Color::Green,
+                "IN_PROGRESS" => Color::Yellow,
+                "FAILED" => Color::Red,
+                _ => Color::White,
+            };
+            let task_definition = deployment
+                .task_definition
+                .split('/')
+                .next_back()
+                .unwrap_or(&deployment.task_definition);
+
+            Row::new(vec![
+                Span::raw(deployment.id.clone()),
+                Span::raw(deployment.status.clone()),
                 Span::styled(
-                    format!("[{timestamp_str}] "),
-                    Style::default().fg(Color::DarkGray),
+                    deployment.rollout_state.clone(),
+                    Style::default().fg(rollout_color),
                 ),
+                Span::raw(task_definition.to_string()),
+                Span::raw(deployment.desired_count.to_string()),
+                Span::raw(deployment.running_count.to_string()),
+                Span::raw(deployment.pending_count.to_string()),
                 Span::styled(
-                    format!("[{}] ", log.container_name),
-                    Style::default().fg(Color::Cyan),
+                    deployment.failed_tasks.to_string(),
+                    Style::default().fg(if deployment.failed_tasks > 0 {
+                        Color::Red
+                    } else {
+                        Color::White
+                    }),
                 ),
-                Span::styled(&log.message, Style::default().fg(Color::White)),
+                Span::raw(format_event_time(deployment.updated_at)),
             ])
+            .style(Style::default().fg(Color::White))
         })
         .collect();
 
-    let scroll_indicator = if total_logs > available_height {
-        let start = start_idx + 1;
-        format!(" [{start}-{end_idx}/{total_logs}]")
+    let mut deployments_title = if app.rollout_in_progress() {
+        format!(
+            "Deployments ({}) - ROLLOUT IN PROGRESS (auto-refresh 5s)",
+            app.service_deployments.len()
+        )
     } else {
-        format!(" [{total_logs}]")
+        format!("Deployments ({})", app.service_deployments.len())
     };
-
-    // Build filter/search status
-    let mut filter_status = String::new();
-    if let Some(ref level) = app.log_level_filter {
-        filter_status.push_str(&format!(" | Filter: {level:?}"));
-    }
-    if !app.log_search_query.is_empty() {
-        filter_status.push_str(&format!(" | Search: '{}'", app.log_search_query));
+    if let Some(primary) = app
+        .service_deployments
+        .iter()
+        .find(|d| d.status == "PRIMARY" && !d.rollout_state_reason.is_empty())
+    {
+        deployments_title.push_str(&format!(" | {}", primary.rollout_state_reason));
     }
 
-    let title = if app.auto_tail {
-        format!("Logs{scroll_indicator}{filter_status} (AUTO-TAIL | /:search f:filter e:export t:toggle)")
-    } else {
-        format!("Logs{scroll_indicator}{filter_status} (↑↓:scroll | /:search f:filter e:export t:toggle)")
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(18),
+            Constraint::Percentage(9),
+            Constraint::Percentage(11),
+            Constraint::Percentage(20),
+            Constraint::Percentage(7),
+            Constraint::Percentage(7),
+            Constraint::Percentage(7),
+            Constraint::Percentage(6),
+            Constraint::Percentage(15),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(deployments_title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if app.rollout_in_progress() {
+                Color::Yellow
+            } else {
+                Color::White
+            })),
+    );
+
+    f.render_widget(table, chunks[1]);
+
+    // Events list
+    let filtered_events = app.get_filtered_service_events();
+
+    let items: Vec<ListItem> = filtered_events
+        .iter()
+        .enumerate()
+        .map(|(i, event)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("[{}] ", format_event_time(event.created_at)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(event.message.clone()),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    let title = if app.search_query.is_empty() {
+        format!(
+            "Events ({}) - /:search | r:refresh | Esc/h:back",
+            filtered_events.len()
+        )
+    } else {
+        format!(
+            "Events ({}/{}) - Esc:clear | r:refresh",
+            filtered_events.len(),
+            app.service_events.len()
+        )
+    };
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    let mut state = ListState::default();
+    state.select(Some(app.selected_index));
+
+    f.render_stateful_widget(list, chunks[2], &mut state);
+}
+
+/// Renders the live rollout panel for a monitored deployment.
+///
+/// Shows the followed deployment against the one it replaces, task counts,
+/// elapsed time and ETA, circuit breaker settings, and a progress bar.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the panel
+/// * `app` - The application state (for the theme)
+/// * `monitor` - The rollout monitor of the displayed service
+fn draw_rollout_monitor(f: &mut Frame, area: Rect, app: &App, monitor: &RolloutMonitor) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(area);
+
+    let now = Instant::now();
+    let label = Style::default().fg(Color::Gray);
+    let deployment = monitor.deployment();
+
+    let deployment_line = match deployment {
+        Some(d) => {
+            let previous = monitor
+                .deployments
+                .iter()
+                .find(|other| other.id != d.id && other.status == "ACTIVE")
+                .map(|other| format!(" (replacing {})", other.id))
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::styled("Deployment: ", label),
+                Span::styled(
+                    format!("{} {}", d.id, d.rollout_state),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(previous, Style::default().fg(Color::DarkGray)),
+            ])
+        }
+        None => Line::from(vec![
+            Span::styled("Deployment: ", label),
+            Span::styled(
+                "waiting for first update...",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
     };
 
-    let logs_widget = Paragraph::new(log_lines)
-        .style(Style::default().fg(Color::White))
-        .block(
-            Block::default()
-                .title(title)
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(if app.auto_tail {
-                    Color::Green
+    let tasks_line = match deployment {
+        Some(d) => Line::from(vec![
+            Span::styled("Tasks: ", label),
+            Span::styled(
+                format!(
+                    "{}/{} running, {} pending, ",
+                    d.running_count, d.desired_count, d.pending_count
+                ),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format!("{} failed", d.failed_tasks),
+                Style::default().fg(if d.failed_tasks > 0 {
+                    Color::Red
                 } else {
                     Color::White
-                })),
+                }),
+            ),
+        ]),
+        None => Line::from(""),
+    };
+
+    let eta = monitor
+        .eta(now)
+        .map(format_duration)
+        .unwrap_or_else(|| "--".to_string());
+    let circuit_breaker = if monitor.circuit_breaker_triggered() {
+        Span::styled(
+            "TRIGGERED",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         )
-        .wrap(Wrap { trim: false });
+    } else if monitor.circuit_breaker_enabled {
+        let mode = if monitor.circuit_breaker_rollback {
+            "enabled (rollback)"
+        } else {
+            "enabled"
+        };
+        Span::styled(mode, Style::default().fg(Color::Green))
+    } else {
+        Span::styled("disabled", Style::default().fg(Color::DarkGray))
+    };
 
-    f.render_widget(logs_widget, area);
+    let status_line = Line::from(vec![
+        Span::styled("Elapsed: ", label),
+        Span::styled(
+            format_duration(now.duration_since(monitor.started_at)),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" | ETA: ", label),
+        Span::styled(eta, Style::default().fg(Color::White)),
+        Span::styled(" | Circuit breaker: ", label),
+        circuit_breaker,
+    ]);
+
+    let details = Paragraph::new(vec![deployment_line, tasks_line, status_line]).block(
+        Block::default()
+            .title(format!("Rollout Monitor - {}", monitor.service))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(details, chunks[0]);
+
+    render_progress_bar(f, chunks[1], monitor.progress(), "Progress", &app.theme);
+}
+
+/// Formats a Unix timestamp in seconds as local date and time.
+fn format_event_time(secs: i64) -> String {
+    DateTime::from_timestamp(secs, 0)
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| "N/A".to_string())
 }
 
 /// Renders the metrics view showing CloudWatch metrics for a service.
@@ -1145,6 +3546,10 @@ fn draw_logs(f: &mut Frame, area: Rect, app: &App) {
 /// * `area` - The rectangular area allocated for the metrics view
 /// * `app` - The application state containing metrics data
 fn draw_metrics(f: &mut Frame, area: Rect, app: &App) {
+    if app.show_task_metrics {
+        draw_task_metrics(f, area, app);
+        return;
+    }
     let Some(metrics) = app.metrics.as_ref() else {
         let no_metrics = Paragraph::new("No metrics available for this service.\n\nThis could mean:\n- The service has no CloudWatch metrics enabled\n- The service hasn't been running long enough to generate metrics\n- There was an error fetching metrics\n\nPress 'm' from Services view to load metrics")
             .style(Style::default().fg(Color::Yellow))
@@ -1383,6 +3788,236 @@ fn draw_metrics(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(metrics_widget, metrics_area);
 }
 
+/// Renders the metrics view for a single task from Container Insights.
+///
+/// Charts the task's CPU and memory usage against its reservation, network
+/// throughput and storage I/O, followed by CPU and memory charts per container.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area allocated for the metrics view
+/// * `app` - The application state containing task metrics data
+fn draw_task_metrics(f: &mut Frame, area: Rect, app: &App) {
+    let Some(metrics) = app.task_metrics.as_ref() else {
+        let loading = Paragraph::new("Loading Container Insights metrics...")
+            .style(Style::default().fg(Color::Yellow))
+            .block(
+                Block::default()
+                    .title("Task Metrics (Press Esc or h to go back)")
+                    .borders(Borders::ALL),
+            );
+        f.render_widget(loading, area);
+        return;
+    };
+
+    let show_charts = app.config.metrics.show_charts;
+    let mut content_lines: Vec<Line> = vec![Line::from(vec![
+        Span::styled("Task: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            metrics.task_id.clone(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" | Cluster: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            metrics.cluster_name.clone(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ])];
+    content_lines.push(Line::from(""));
+
+    if !metrics.has_data() {
+        content_lines.push(Line::from(Span::styled(
+            "No Container Insights metrics for this task.",
+            Style::default().fg(Color::Yellow),
+        )));
+        content_lines.push(Line::from(""));
+        content_lines.push(Line::from(
+            "Task-level metrics require Container Insights with enhanced observability on the cluster,",
+        ));
+        content_lines.push(Line::from(
+            "and appear about a minute after the task starts. Press T to try a longer time range.",
+        ));
+    } else {
+        let units = |value: f64| format!("{value:.1} units");
+        let mebibytes = |value: f64| format!("{value:.0} MiB");
+        let rate = |value: f64| format!("{}/s", format_bytes(value));
+
+        push_metric_series(
+            &mut content_lines,
+            &metrics.cpu_utilized,
+            "CPU Utilized (units)",
+            Color::Green,
+            10,
+            show_charts,
+            &units,
+        );
+        if let Some(reserved) = metrics.cpu_reserved {
+            content_lines.push(Line::from(vec![
+                Span::styled("  Reserved: ", Style::default().fg(Color::Gray)),
+                Span::raw(units(reserved)),
+            ]));
+        }
+        content_lines.push(Line::from(""));
+
+        push_metric_series(
+            &mut content_lines,
+            &metrics.memory_utilized,
+            "Memory Utilized (MiB)",
+            Color::Cyan,
+            10,
+            show_charts,
+            &mebibytes,
+        );
+        if let Some(reserved) = metrics.memory_reserved {
+            content_lines.push(Line::from(vec![
+                Span::styled("  Reserved: ", Style::default().fg(Color::Gray)),
+                Span::raw(mebibytes(reserved)),
+            ]));
+        }
+        content_lines.push(Line::from(""));
+
+        for (datapoints, title, color) in [
+            (&metrics.network_rx, "Network Rx (bytes/s)", Color::Magenta),
+            (&metrics.network_tx, "Network Tx (bytes/s)", Color::Magenta),
+        ] {
+            push_metric_series(
+                &mut content_lines,
+                datapoints,
+                title,
+                color,
+                6,
+                show_charts,
+                &rate,
+            );
+            content_lines.push(Line::from(""));
+        }
+        for (datapoints, title) in [
+            (&metrics.storage_read, "Storage Read (bytes)"),
+            (&metrics.storage_write, "Storage Write (bytes)"),
+        ] {
+            push_metric_series(
+                &mut content_lines,
+                datapoints,
+                title,
+                Color::Blue,
+                6,
+                show_charts,
+                &format_bytes,
+            );
+            content_lines.push(Line::from(""));
+        }
+
+        if !metrics.containers.is_empty() {
+            content_lines.push(Line::from(Span::styled(
+                "Containers",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            content_lines.push(Line::from(""));
+            for container in &metrics.containers {
+                push_metric_series(
+                    &mut content_lines,
+                    &container.cpu_utilized,
+                    &format!("{} - CPU Utilized (units)", container.name),
+                    Color::Green,
+                    5,
+                    show_charts,
+                    &units,
+                );
+                push_metric_series(
+                    &mut content_lines,
+                    &container.memory_utilized,
+                    &format!("{} - Memory Utilized (MiB)", container.name),
+                    Color::Cyan,
+                    5,
+                    show_charts,
+                    &mebibytes,
+                );
+                content_lines.push(Line::from(""));
+            }
+        }
+    }
+
+    let metrics_widget = Paragraph::new(content_lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title(format!(
+                    "Task Metrics [{}] (T:cycle range | r:refresh | Esc/h:back | ↑↓:scroll)",
+                    metrics.time_range.label()
+                ))
+                .borders(Borders::ALL),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((app.metrics_scroll as u16, 0));
+
+    f.render_widget(metrics_widget, area);
+}
+
+/// Appends a chart and an average/maximum/latest summary line for one metric series.
+///
+/// Only the title is shown above the summary when charts are disabled in the config.
+fn push_metric_series(
+    lines: &mut Vec<Line<'static>>,
+    datapoints: &[crate::aws::MetricDatapoint],
+    title: &str,
+    color: Color,
+    height: usize,
+    show_chart: bool,
+    format_value: &dyn Fn(f64) -> String,
+) {
+    if show_chart && !datapoints.is_empty() {
+        let chart_datapoints: Vec<ChartDatapoint> = datapoints
+            .iter()
+            .filter_map(|dp| {
+                dp.average.map(|value| ChartDatapoint {
+                    timestamp: dp.timestamp,
+                    value,
+                })
+            })
+            .collect();
+        let chart_config = ChartConfig {
+            height,
+            line_color: color,
+            ..ChartConfig::default()
+        };
+        lines.extend(render_chart(&chart_datapoints, &chart_config, title));
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!("  {title}"),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    let averages: Vec<f64> = datapoints.iter().filter_map(|dp| dp.average).collect();
+    let Some(latest) = averages.last() else {
+        lines.push(Line::from(Span::styled(
+            "  No data available",
+            Style::default().fg(Color::Yellow),
+        )));
+        return;
+    };
+    let average = averages.iter().sum::<f64>() / averages.len() as f64;
+    let maximum = datapoints
+        .iter()
+        .filter_map(|dp| dp.maximum)
+        .fold(0.0f64, |a, b| a.max(b));
+
+    lines.push(Line::from(vec![
+        Span::styled("  Average: ", Style::default().fg(Color::Gray)),
+        Span::styled(format_value(average), Style::default().fg(Color::Green)),
+        Span::styled("  |  Maximum: ", Style::default().fg(Color::Gray)),
+        Span::styled(format_value(maximum), Style::default().fg(Color::Yellow)),
+        Span::styled("  |  Latest: ", Style::default().fg(Color::Gray)),
+        Span::styled(format_value(*latest), Style::default().fg(Color::White)),
+    ]));
+}
+
 /// Renders the CloudWatch alarms section.
 ///
 /// Displays alarms associated with the service, showing alarm name, state,
@@ -1497,6 +4132,18 @@ fn draw_help(f: &mut Frame, area: Rect) {
             Span::styled("  3           ", Style::default().fg(Color::Yellow)),
             Span::raw("Tasks view"),
         ]),
+        Line::from(vec![
+            Span::styled("  4           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Task definitions (Enter: revisions, then a revision's details)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  N           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Register a new revision from an edited copy (Ctrl+E: edit as JSON)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  X           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Clean up old revisions of a family by rule (preview, then deregister)"),
+        ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Actions",
@@ -1528,18 +4175,34 @@ fn draw_help(f: &mut Frame, area: Rect) {
             Span::styled("  e           ", Style::default().fg(Color::Yellow)),
             Span::raw("ECS Exec shell (from Tasks view)"),
         ]),
+        Line::from(vec![
+            Span::styled("  e           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Export a service or task definition revision (JSON, YAML, CloudFormation, Terraform)"),
+        ]),
         Line::from(vec![
             Span::styled("  l           ", Style::default().fg(Color::Yellow)),
-            Span::raw("View logs (from Tasks view)"),
+            Span::raw("View logs (task from Tasks view, all tasks of a service from Services view)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  S           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Toggle stopped tasks with exit codes (in Tasks view)"),
         ]),
         Line::from(vec![
             Span::styled("  m           ", Style::default().fg(Color::Yellow)),
-            Span::raw("View metrics (from Services view)"),
+            Span::raw("View metrics (from Services or Tasks view)"),
         ]),
         Line::from(vec![
             Span::styled("  s           ", Style::default().fg(Color::Yellow)),
             Span::raw("Edit service (from Services view)"),
         ]),
+        Line::from(vec![
+            Span::styled("  i           ", Style::default().fg(Color::Yellow)),
+            Span::raw("View container instances (from Clusters/Services view)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  v           ", Style::default().fg(Color::Yellow)),
+            Span::raw("View service events and deployments (from Services view)"),
+        ]),
         Line::from(vec![
             Span::styled("  T           ", Style::default().fg(Color::Yellow)),
             Span::raw("Cycle time range (in Metrics view: 1h/6h/24h/7d)"),
@@ -1548,9 +4211,43 @@ fn draw_help(f: &mut Frame, area: Rect) {
             Span::styled("  t           ", Style::default().fg(Color::Yellow)),
             Span::raw("Toggle auto-tail (in Logs view)"),
         ]),
+        Line::from(vec![
+            Span::styled("  g           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Jump to a time in Logs view (-30m, 2026-10-14T09:00); ↑ at the top loads older logs"),
+        ]),
+        Line::from(vec![
+            Span::styled("  c           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Choose fields of JSON logs shown as columns (Logs view, e.g. level, logger, trace_id)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Enter       ", Style::default().fg(Color::Yellow)),
+            Span::raw("Show the selected log entry with its JSON pretty-printed (Logs view)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  s / n / N   ", Style::default().fg(Color::Yellow)),
+            Span::raw("Find and highlight text without filtering / next / previous match (Logs view; M: regex)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  b / B / [ ] ", Style::default().fg(Color::Yellow)),
+            Span::raw("Bookmark the selected log entry / list bookmarks / previous / next bookmark"),
+        ]),
+        Line::from(vec![
+            Span::styled("  C / A       ", Style::default().fg(Color::Yellow)),
+            Span::raw("Logs around the selected entry from the task's containers / all tasks of the service"),
+        ]),
+        Line::from(vec![
+            Span::styled("  o / O       ", Style::default().fg(Color::Yellow)),
+            Span::raw("Expand or collapse the selected / every multi-line log entry, e.g. stack traces (Logs view)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  I           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Logs Insights query over the shown logs (Logs view; /:edit Enter:run T:range n:saved S:save)"),
+        ]),
         Line::from(vec![
             Span::styled("  x           ", Style::default().fg(Color::Yellow)),
-            Span::raw("Execute action (restart service/stop task)"),
+            Span::raw(
+                "Execute action (restart service/stop task/drain instance, asks for confirmation)",
+            ),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -1561,7 +4258,7 @@ fn draw_help(f: &mut Frame, area: Rect) {
         )]),
         Line::from(vec![
             Span::styled("  /           ", Style::default().fg(Color::Yellow)),
-            Span::raw("Enter search mode (Clusters/Services/Tasks)"),
+            Span::raw("Enter search mode (Clusters/Services/Tasks/Events)"),
         ]),
         Line::from(vec![
             Span::styled("  M           ", Style::default().fg(Color::Yellow)),
@@ -1583,9 +4280,17 @@ fn draw_help(f: &mut Frame, area: Rect) {
             Span::styled("  f           ", Style::default().fg(Color::Yellow)),
             Span::raw("Cycle log level filter (Logs view)"),
         ]),
+        Line::from(vec![
+            Span::styled("  /           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Search logs; field=value and field!=value filter by JSON fields (Logs view)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  T           ", Style::default().fg(Color::Yellow)),
+            Span::raw("Cycle task filter (service-wide Logs view)"),
+        ]),
         Line::from(vec![
             Span::styled("  e           ", Style::default().fg(Color::Yellow)),
-            Span::raw("Export logs to file (Logs view)"),
+            Span::raw("Export logs as text, JSON Lines or CSV (Logs view; Tab:option Space:change Enter:export)"),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -1869,7 +4574,12 @@ fn draw_service_editor(f: &mut Frame, app: &App) {
 
     // Calculate height based on number of task definition revisions
     let revisions_count = app.service_editor_available_revisions.len().min(10);
-    let height = (revisions_count + 12) as u16; // 12 = header + fields + padding
+    // Auto scaling: label, policies, latest activity, capacity inputs and suspend toggle
+    let scaling_height = match &app.service_editor_scaling {
+        Some(scaling) => (scaling.policies.len().min(3) + 8) as u16,
+        None => 1,
+    };
+    let height = (revisions_count as u16 + 12 + scaling_height).min(area.height.saturating_sub(2)); // 12 = header + fields + padding
 
     let modal_area = Rect {
         x: area.width.saturating_sub(width) / 2,
@@ -1883,7 +4593,7 @@ fn draw_service_editor(f: &mut Frame, app: &App) {
 
     // Create main container
     let block = Block::default()
-        .title("Edit Service (Tab:switch field | Enter:save | Esc:cancel)")
+        .title("Edit Service (Tab:switch field | Space:toggle | Enter:save | Esc:cancel)")
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan))
         .style(Style::default().bg(Color::Black));
@@ -1899,11 +4609,12 @@ fn draw_service_editor(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Service info
-            Constraint::Length(3), // Desired count field
-            Constraint::Length(1), // Spacing
-            Constraint::Length(2), // Task definition label
-            Constraint::Min(5),    // Task definition list
+            Constraint::Length(3),              // Service info
+            Constraint::Length(3),              // Desired count field
+            Constraint::Length(1),              // Spacing
+            Constraint::Length(2),              // Task definition label
+            Constraint::Min(5),                 // Task definition list
+            Constraint::Length(scaling_height), // Auto scaling
         ])
         .split(inner);
 
@@ -1948,7 +4659,10 @@ fn draw_service_editor(f: &mut Frame, app: &App) {
     f.render_widget(desired_count_widget, chunks[1]);
 
     // Task Definition label
-    let task_def_label = Paragraph::new("Task Definition Revision:").style(
+    let task_def_label = Paragraph::new(
+        "Task Definition Revision (D: diff with running | N: edit as new revision):",
+    )
+    .style(
         Style::default()
             .fg(Color::White)
             .add_modifier(Modifier::BOLD),
@@ -2013,6 +4727,182 @@ fn draw_service_editor(f: &mut Frame, app: &App) {
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(no_revisions, chunks[4]);
     }
+
+    draw_service_editor_scaling(f, chunks[5], app);
+}
+
+/// Renders the auto scaling section of the service editor.
+///
+/// Shows the attached policies and latest scaling activity, the editable minimum
+/// and maximum capacity (fields 2 and 3) and the suspend toggle (field 4). Services
+/// without a scalable target get a single "not configured" line.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `area` - The rectangular area below the task definition list
+/// * `app` - The application state containing the service editor fields
+fn draw_service_editor_scaling(f: &mut Frame, area: Rect, app: &App) {
+    let label = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+    let Some(scaling) = &app.service_editor_scaling else {
+        let not_configured = Paragraph::new(Line::from(vec![
+            Span::styled("Auto Scaling: ", label),
+            Span::styled("not configured", Style::default().fg(Color::DarkGray)),
+        ]));
+        f.render_widget(not_configured, area);
+        return;
+    };
+
+    let policy_lines = scaling.policies.len().min(3) as u16;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),                // Label
+            Constraint::Length(policy_lines + 1), // Policies and latest activity
+            Constraint::Length(3),                // Min/max capacity fields
+            Constraint::Length(1),                // Suspend toggle
+        ])
+        .split(area);
+
+    f.render_widget(Paragraph::new("Auto Scaling:").style(label), chunks[0]);
+
+    let mut lines: Vec<Line> = scaling
+        .policies
+        .iter()
+        .take(3)
+        .map(|policy| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {} ", policy.name),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!("({}) ", policy.policy_type),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(policy.summary.clone()),
+            ])
+        })
+        .collect();
+    lines.push(match scaling.activities.first() {
+        Some(activity) => {
+            let color = match activity.status.as_str() {
+                "Successful" => Color::Green,
+                "Failed" => Color::Red,
+                _ => Color::Yellow,
+            };
+            Line::from(vec![
+                Span::styled("  Last activity: ", Style::default().fg(Color::Gray)),
+                Span::styled(format_event_time(activity.start_time), Style::default()),
+                Span::styled(
+                    format!(" [{}] ", activity.status),
+                    Style::default().fg(color),
+                ),
+                Span::raw(activity.description.clone()),
+            ])
+        }
+        None => Line::from(Span::styled(
+            "  No recent scaling activity",
+            Style::default().fg(Color::DarkGray),
+        )),
+    });
+    f.render_widget(Paragraph::new(lines), chunks[1]);
+
+    let capacity_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[2]);
+    for (field, title, input, area) in [
+        (
+            2,
+            "Min Capacity",
+            &app.service_editor_min_capacity_input,
+            capacity_chunks[0],
+        ),
+        (
+            3,
+            "Max Capacity",
+            &app.service_editor_max_capacity_input,
+            capacity_chunks[1],
+        ),
+    ] {
+        let editing = app.service_editor_editing_field == field;
+        let (style, border) = if editing {
+            (
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+                Style::default().fg(Color::Cyan),
+            )
+        } else {
+            (
+                Style::default().fg(Color::White),
+                Style::default().fg(Color::Gray),
+            )
+        };
+        let widget = Paragraph::new(format!(
+            "{title}: {input}{}",
+            if editing { "█" } else { "" }
+        ))
+        .style(style)
+        .block(Block::default().borders(Borders::ALL).border_style(border));
+        f.render_widget(widget, area);
+    }
+
+    let suspend_style = if app.service_editor_editing_field == 4 {
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else if app.service_editor_scaling_suspended {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let suspend = Paragraph::new(format!(
+        "[{}] Suspend scaling (Space to toggle)",
+        if app.service_editor_scaling_suspended {
+            "x"
+        } else {
+            " "
+        }
+    ))
+    .style(suspend_style);
+    f.render_widget(suspend, chunks[3]);
+}
+
+/// Renders the confirmation dialog for a pending destructive action.
+///
+/// Lists every change the action will make, followed by either the typed-name
+/// prompt for production resources or the y/n key hints.
+///
+/// # Arguments
+/// * `f` - The ratatui Frame to render into
+/// * `app` - The application state containing the pending confirmation
+fn draw_confirmation(f: &mut Frame, app: &App) {
+    let Some(confirmation) = &app.confirmation else {
+        return;
+    };
+
+    let mut message = confirmation.changes.join("\n");
+    message.push_str("\n\n");
+    match &confirmation.required_name {
+        Some(name) => message.push_str(&format!(
+            "Production resource - type '{name}' to confirm:\n{}_",
+            confirmation.typed_name
+        )),
+        None => message.push_str("y: confirm | n/Esc: cancel | Tab: switch"),
+    }
+
+    render_confirmation_dialog(
+        f,
+        &confirmation.title,
+        &message,
+        confirmation.confirm_selected,
+        &app.theme,
+    );
 }
 
 /// Renders the port forwarding setup modal.
@@ -284,6 +284,33 @@ pub fn responsive_column_widths(terminal_width: u16, full_widths: &[u16]) -> Vec
     }
 }
 
+/// Formats a byte count with a binary unit suffix
+///
+/// # Arguments
+/// * `bytes` - Number of bytes (fractional values come from averaged metrics)
+///
+/// # Examples
+/// ```
+/// use ecs_voyager::ui::utils::format_bytes;
+///
+/// assert_eq!(format_bytes(512.0), "512 B");
+/// assert_eq!(format_bytes(1536.0), "1.5 KiB");
+/// ```
+pub fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{value:.0} {}", UNITS[unit])
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -553,4 +580,16 @@ mod tests {
         assert_eq!(result.len(), 3);
         assert_eq!(result, vec![32, 24, 24]);
     }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0.0), "0 B");
+        assert_eq!(format_bytes(512.0), "512 B");
+        assert_eq!(format_bytes(1536.0), "1.5 KiB");
+        assert_eq!(format_bytes(5.0 * 1024.0 * 1024.0), "5.0 MiB");
+        assert_eq!(
+            format_bytes(3.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+            "3072.0 GiB"
+        );
+    }
 }
@@ -91,7 +91,6 @@ pub fn get_spinner_frame() -> &'static str {
 /// * `progress` - Progress value (0.0 to 1.0)
 /// * `label` - Label text to display
 /// * `theme` - Theme for colors
-#[allow(dead_code)]
 pub fn render_progress_bar(f: &mut Frame, area: Rect, progress: f32, label: &str, theme: &Theme) {
     let progress = progress.clamp(0.0, 1.0);
     let bar_width = (area.width.saturating_sub(4) as f32 * progress) as u16;
@@ -127,9 +126,8 @@ pub fn render_progress_bar(f: &mut Frame, area: Rect, progress: f32, label: &str
     f.render_widget(widget, area);
 }
 
-/// Toast notification type (for future use)
+/// Toast notification type
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[allow(dead_code)]
 pub enum ToastType {
     Success,
     Error,
@@ -144,7 +142,6 @@ pub enum ToastType {
 /// * `message` - Message to display
 /// * `toast_type` - Type of toast (affects color)
 /// * `theme` - Theme for colors
-#[allow(dead_code)]
 pub fn render_toast(f: &mut Frame, message: &str, toast_type: ToastType, theme: &Theme) {
     let screen = f.area();
     let width = message.len().min(60) as u16 + 4;
@@ -192,7 +189,6 @@ pub fn render_toast(f: &mut Frame, message: &str, toast_type: ToastType, theme:
 /// * `message` - Message to display
 /// * `confirm_selected` - Whether the confirm button is selected
 /// * `theme` - Theme for colors
-#[allow(dead_code)]
 pub fn render_confirmation_dialog(
     f: &mut Frame,
     title: &str,
@@ -201,8 +197,11 @@ pub fn render_confirmation_dialog(
     theme: &Theme,
 ) {
     let screen = f.area();
-    let width = 60.min(screen.width.saturating_sub(4));
-    let height = 10;
+    let width = 70.min(screen.width.saturating_sub(4));
+    // Leave room for the message, the buttons, and the borders
+    let height = (message.lines().count() as u16 + 7)
+        .max(10)
+        .min(screen.height);
 
     let area = Rect {
         x: screen.width.saturating_sub(width) / 2,